@@ -1,4 +1,6 @@
 pub mod cut;
+pub mod regex;
 
 // Re-export key types
-pub use cut::*;
\ No newline at end of file
+pub use cut::*;
+pub use regex::{Captures, Regex};
\ No newline at end of file
@@ -0,0 +1,82 @@
+// === Types ===
+
+/// Zero-width assertions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Look {
+    /// `^`: start of text.
+    Start,
+    /// `$`: end of text.
+    End,
+    /// `\b`
+    WordBoundary,
+    /// `\B`
+    NotWordBoundary,
+}
+
+/// A set of chars: ranges and nested sets (for `\D` inside `[...]`), optionally negated.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CharClass {
+    pub negated: bool,
+    pub ranges: Vec<(char, char)>,
+    pub nested: Vec<CharClass>,
+}
+
+/// Parsed pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Empty,
+    Char(char),
+    /// `.`: any char except `\n`.
+    Any,
+    Class(CharClass),
+    Look(Look),
+    /// Group with its capture index, `None` for `(?:...)`.
+    Group(Box<Node>, Option<usize>),
+    Concat(Vec<Node>),
+    Alt(Vec<Node>),
+    Repeat { node: Box<Node>, min: u32, max: Option<u32>, greedy: bool },
+}
+
+// === Impl ===
+
+impl CharClass {
+    #[inline]
+    pub fn from_ranges(ranges: &[(char, char)], negated: bool) -> Self {
+        Self { negated, ranges: ranges.to_vec(), nested: Vec::new() }
+    }
+
+    /// `\d`
+    pub fn digit(negated: bool) -> Self {
+        Self::from_ranges(&[('0', '9')], negated)
+    }
+
+    /// `\w`: ASCII letters, digits and `_`.
+    pub fn word(negated: bool) -> Self {
+        Self::from_ranges(&[('0', '9'), ('A', 'Z'), ('a', 'z'), ('_', '_')], negated)
+    }
+
+    /// `\s`: ASCII whitespace.
+    pub fn space(negated: bool) -> Self {
+        Self::from_ranges(&[(' ', ' '), ('\t', '\r')], negated)
+    }
+
+    fn contains_exact(&self, c: char) -> bool {
+        self.ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi)
+            || self.nested.iter().any(|n| n.matches(c, false))
+    }
+
+    /// Whether `c` is in the set; `fold_case` also tries the other case.
+    pub fn matches(&self, c: char, fold_case: bool) -> bool {
+        let hit = self.contains_exact(c)
+            || (fold_case
+                && (c.to_lowercase().any(|l| self.contains_exact(l))
+                    || c.to_uppercase().any(|u| self.contains_exact(u))));
+        hit != self.negated
+    }
+}
+
+/// ASCII word char, as used by `\b`.
+#[inline]
+pub fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
@@ -0,0 +1,163 @@
+// === Imports ===
+use crate::prelude::*;
+use super::ast::{CharClass, Look, Node};
+use super::parse::Parsed;
+
+// === Types ===
+
+/// Upper bound on compiled program size (counted repeats are expanded).
+pub const MAX_INSTS: usize = 100_000;
+
+/// NFA instruction.
+#[derive(Debug, Clone)]
+pub enum Inst {
+    Char(char),
+    Any,
+    Class(CharClass),
+    /// Try `.0` first, then `.1`.
+    Split(usize, usize),
+    Jmp(usize),
+    /// Record the current position in capture slot `k`.
+    Save(usize),
+    Assert(Look),
+    Match,
+}
+
+/// Compiled Thompson NFA.
+#[derive(Debug, Clone)]
+pub struct Program {
+    pub insts: Vec<Inst>,
+    /// Two slots per group, including group 0.
+    pub n_slots: usize,
+    pub case_insensitive: bool,
+}
+
+struct Compiler {
+    insts: Vec<Inst>,
+}
+
+// === Impl ===
+
+/// Compiles a parsed pattern. The whole match is wrapped in group 0.
+pub fn compile(parsed: &Parsed) -> ErrorResult<Program> {
+    let mut c = Compiler { insts: Vec::new() };
+    c.push(Inst::Save(0))?;
+    c.emit(&parsed.node)?;
+    c.push(Inst::Save(1))?;
+    c.push(Inst::Match)?;
+    Ok(Program {
+        insts: c.insts,
+        n_slots: 2 * (parsed.n_groups + 1),
+        case_insensitive: parsed.case_insensitive,
+    })
+}
+
+impl Compiler {
+    #[inline]
+    fn pc(&self) -> usize {
+        self.insts.len()
+    }
+
+    fn push(&mut self, inst: Inst) -> ErrorResult<usize> {
+        if self.insts.len() >= MAX_INSTS {
+            return Err(ErebusError::InvalidPattern(format!(
+                "pattern compiles to more than {MAX_INSTS} instructions"
+            )));
+        }
+        self.insts.push(inst);
+        Ok(self.insts.len() - 1)
+    }
+
+    /// Points a placeholder `Split`/`Jmp` at `target`.
+    fn patch(&mut self, at: usize, target: usize) {
+        match &mut self.insts[at] {
+            Inst::Jmp(t) => *t = target,
+            Inst::Split(_, b) => *b = target,
+            _ => unreachable!("patch target must be Jmp or Split"),
+        }
+    }
+
+    /// `Split` to `body` (next pc) or an exit patched later, ordered by greediness.
+    fn split_exit(&mut self, greedy: bool) -> ErrorResult<usize> {
+        let body = self.pc() + 1;
+        let inst = if greedy { Inst::Split(body, usize::MAX) } else { Inst::Split(usize::MAX, body) };
+        self.push(inst)
+    }
+
+    fn patch_exit(&mut self, at: usize, exit: usize, greedy: bool) {
+        if greedy {
+            self.patch(at, exit);
+        } else if let Inst::Split(a, _) = &mut self.insts[at] {
+            *a = exit;
+        }
+    }
+
+    fn emit(&mut self, node: &Node) -> ErrorResult<()> {
+        match node {
+            Node::Empty => {}
+            Node::Char(c) => { self.push(Inst::Char(*c))?; }
+            Node::Any => { self.push(Inst::Any)?; }
+            Node::Class(class) => { self.push(Inst::Class(class.clone()))?; }
+            Node::Look(look) => { self.push(Inst::Assert(*look))?; }
+            Node::Group(inner, index) => match index {
+                Some(i) => {
+                    self.push(Inst::Save(2 * i))?;
+                    self.emit(inner)?;
+                    self.push(Inst::Save(2 * i + 1))?;
+                }
+                None => self.emit(inner)?,
+            },
+            Node::Concat(items) => {
+                for item in items {
+                    self.emit(item)?;
+                }
+            }
+            Node::Alt(branches) => {
+                let mut jumps = Vec::with_capacity(branches.len());
+                for (i, branch) in branches.iter().enumerate() {
+                    if i + 1 < branches.len() {
+                        let split = self.push(Inst::Split(self.pc() + 1, usize::MAX))?;
+                        self.emit(branch)?;
+                        jumps.push(self.push(Inst::Jmp(usize::MAX))?);
+                        let next = self.pc();
+                        self.patch(split, next);
+                    } else {
+                        self.emit(branch)?;
+                    }
+                }
+                let end = self.pc();
+                for j in jumps {
+                    self.patch(j, end);
+                }
+            }
+            Node::Repeat { node, min, max, greedy } => {
+                for _ in 0..*min {
+                    self.emit(node)?;
+                }
+                match max {
+                    None => {
+                        // L: split(body, exit); body; jmp L
+                        let split = self.split_exit(*greedy)?;
+                        self.emit(node)?;
+                        self.push(Inst::Jmp(split))?;
+                        let exit = self.pc();
+                        self.patch_exit(split, exit, *greedy);
+                    }
+                    Some(max) => {
+                        // Nested optionals: x{0,3} == (x(x(x)?)?)?
+                        let mut splits = Vec::with_capacity((max - min) as usize);
+                        for _ in *min..*max {
+                            splits.push(self.split_exit(*greedy)?);
+                            self.emit(node)?;
+                        }
+                        let exit = self.pc();
+                        for s in splits {
+                            self.patch_exit(s, exit, *greedy);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+// === Imports ===
+use crate::prelude::*;
+use super::compile::{compile, Program};
+use super::parse::parse;
+use super::pike::Slots;
+
+// === Types ===
+
+/// Compiled regular expression. Immutable once built, so a single
+/// instance can be shared across threads.
+#[derive(Debug, Clone)]
+pub struct Regex {
+    pattern: String,
+    prog: Program,
+    n_groups: usize,
+}
+
+/// Capture groups of a single match. Group 0 is the whole match.
+#[derive(Debug, Clone)]
+pub struct Captures<'t> {
+    text: &'t str,
+    slots: Slots,
+}
+
+// === Impl ===
+
+impl Regex {
+    /// Compiles `pattern`, returning `InvalidPattern` on syntax errors.
+    pub fn new(pattern: &str) -> ErrorResult<Self> {
+        let parsed = parse(pattern)?;
+        let prog = compile(&parsed)?;
+        Ok(Self { pattern: pattern.to_string(), prog, n_groups: parsed.n_groups })
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Number of capture groups, excluding group 0.
+    #[inline]
+    pub fn group_count(&self) -> usize {
+        self.n_groups
+    }
+
+    #[inline]
+    pub fn is_match(&self, text: &str) -> bool {
+        self.prog.exec(text, 0, true).is_some()
+    }
+
+    /// Byte range of the leftmost match.
+    #[inline]
+    pub fn find(&self, text: &str) -> Option<(usize, usize)> {
+        self.find_at(text, 0)
+    }
+
+    /// Byte range of the leftmost match at or after `start`.
+    pub fn find_at(&self, text: &str, start: usize) -> Option<(usize, usize)> {
+        self.captures_at(text, start).and_then(|c| c.range(0))
+    }
+
+    #[inline]
+    pub fn captures<'t>(&self, text: &'t str) -> Option<Captures<'t>> {
+        self.captures_at(text, 0)
+    }
+
+    pub fn captures_at<'t>(&self, text: &'t str, start: usize) -> Option<Captures<'t>> {
+        self.prog.exec(text, start, false).map(|slots| Captures { text, slots })
+    }
+
+    /// All non-overlapping matches, left to right. An empty match moves the
+    /// search forward by one char.
+    pub fn captures_iter<'t>(&self, text: &'t str) -> Vec<Captures<'t>> {
+        let mut out = Vec::new();
+        let mut start = 0;
+        while start <= text.len() {
+            let Some(caps) = self.captures_at(text, start) else { break };
+            let (s, e) = caps.range(0).unwrap();
+            start = if e > s {
+                e
+            } else {
+                e + text[e..].chars().next().map_or(1, char::len_utf8)
+            };
+            out.push(caps);
+        }
+        out
+    }
+
+    /// Byte ranges of all non-overlapping matches.
+    pub fn find_iter(&self, text: &str) -> Vec<(usize, usize)> {
+        self.captures_iter(text).iter().filter_map(|c| c.range(0)).collect()
+    }
+
+    /// Replaces every match with `rep`, expanding `$N` / `${N}` to group `N`
+    /// (empty if it did not participate) and `$$` to `$`.
+    pub fn replace_all(&self, text: &str, rep: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+        for caps in self.captures_iter(text) {
+            let (s, e) = caps.range(0).unwrap();
+            out.push_str(&text[last..s]);
+            caps.expand(rep, &mut out);
+            last = e;
+        }
+        out.push_str(&text[last..]);
+        out
+    }
+
+    /// Splits `text` on every match.
+    pub fn split<'t>(&self, text: &'t str) -> Vec<&'t str> {
+        let mut out = Vec::new();
+        let mut last = 0;
+        for (s, e) in self.find_iter(text) {
+            // An empty match at the very start or end would only add an empty piece.
+            if s == e && (s == 0 || s == text.len()) {
+                continue;
+            }
+            out.push(&text[last..s]);
+            last = e;
+        }
+        out.push(&text[last..]);
+        out
+    }
+}
+
+impl<'t> Captures<'t> {
+    /// Number of groups, including group 0.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.len() / 2
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Byte range of group `i`, `None` if it did not participate.
+    #[inline]
+    pub fn range(&self, i: usize) -> Option<(usize, usize)> {
+        match (self.slots.get(2 * i)?, self.slots.get(2 * i + 1)?) {
+            (Some(s), Some(e)) => Some((*s, *e)),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&'t str> {
+        self.range(i).map(|(s, e)| &self.text[s..e])
+    }
+
+    /// Appends `rep` to `out` with group references expanded.
+    pub fn expand(&self, rep: &str, out: &mut String) {
+        let mut rest = rep;
+        while let Some(at) = rest.find('$') {
+            out.push_str(&rest[..at]);
+            rest = &rest[at + 1..];
+            if let Some(tail) = rest.strip_prefix('$') {
+                out.push('$');
+                rest = tail;
+                continue;
+            }
+            let (digits, tail) = match rest.strip_prefix('{') {
+                Some(inner) => match inner.find('}') {
+                    Some(close) if close > 0 && inner[..close].bytes().all(|b| b.is_ascii_digit()) => {
+                        (&inner[..close], &inner[close + 1..])
+                    }
+                    _ => ("", rest),
+                },
+                None => {
+                    let n = rest.bytes().take_while(u8::is_ascii_digit).count();
+                    (&rest[..n], &rest[n..])
+                }
+            };
+            match digits.parse::<usize>() {
+                Ok(i) => {
+                    out.push_str(self.get(i).unwrap_or(""));
+                    rest = tail;
+                }
+                // Not a group reference: keep the `$` literally.
+                Err(_) => out.push('$'),
+            }
+        }
+        out.push_str(rest);
+    }
+}
+
+/// Escapes regex metacharacters so `text` matches literally.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.^$|?*+()[]{}".contains(c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
@@ -0,0 +1,66 @@
+// === Imports ===
+use crate::prelude::*;
+use super::engine::escape;
+
+// === Impl ===
+
+/// Any single char, including `\n`.
+const ANY_CHAR: &str = r"[\s\S]";
+
+/// Translates a shell glob into an anchored regex: `*` (any run), `?` (one
+/// char), `[...]` classes with `!` or `^` negation, and `\` escapes.
+pub fn glob_to_regex(pattern: &str) -> ErrorResult<String> {
+    let mut out = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => { out.push_str(ANY_CHAR); out.push('*'); }
+            '?' => out.push_str(ANY_CHAR),
+            '\\' => match chars.next() {
+                Some(e) => out.push_str(&escape(&e.to_string())),
+                None => return Err(ErebusError::InvalidPattern("trailing '\\' in glob".into())),
+            },
+            '[' => {
+                out.push('[');
+                if matches!(chars.peek(), Some('!') | Some('^')) {
+                    chars.next();
+                    out.push('^');
+                }
+                let mut first = true;
+                loop {
+                    match chars.next() {
+                        Some(']') if !first => break,
+                        Some(c @ ('\\' | '[' | ']' | '^')) => { out.push('\\'); out.push(c); }
+                        Some(c) => out.push(c),
+                        None => {
+                            return Err(ErebusError::InvalidPattern("unclosed '[' in glob".into()));
+                        }
+                    }
+                    first = false;
+                }
+                out.push(']');
+            }
+            c => out.push_str(&escape(&c.to_string())),
+        }
+    }
+    out.push('$');
+    Ok(out)
+}
+
+/// Translates a SQL `LIKE` pattern into an anchored regex: `%` (any run),
+/// `_` (one char) and `\` to escape either.
+pub fn like_to_regex(pattern: &str) -> String {
+    let mut out = String::from("^");
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => { out.push_str(ANY_CHAR); out.push('*'); }
+            '_' => out.push_str(ANY_CHAR),
+            // A trailing backslash matches itself.
+            '\\' => out.push_str(&escape(&chars.next().unwrap_or('\\').to_string())),
+            c => out.push_str(&escape(&c.to_string())),
+        }
+    }
+    out.push('$');
+    out
+}
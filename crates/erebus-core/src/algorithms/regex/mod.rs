@@ -0,0 +1,14 @@
+// Small regex engine: parser -> Thompson NFA -> Pike VM.
+// Runs in O(pattern * text) with no backtracking, so a pattern can be
+// compiled once and shared across threads.
+
+pub mod ast;
+pub mod parse;
+pub mod compile;
+pub mod pike;
+pub mod engine;
+pub mod glob;
+
+// Re-export key types
+pub use engine::{escape, Captures, Regex};
+pub use glob::{glob_to_regex, like_to_regex};
@@ -0,0 +1,280 @@
+// === Imports ===
+use crate::prelude::*;
+use super::ast::{CharClass, Look, Node};
+
+// === Types ===
+
+/// Largest `{n,m}` bound accepted, to keep compiled programs small.
+pub const MAX_REPEAT: u32 = 1000;
+
+/// Deepest group nesting and tree height accepted, so parsing, compiling
+/// and dropping the tree can't overflow the stack.
+pub const MAX_NEST: usize = 250;
+
+/// Result of parsing: the tree, number of capture groups (excluding
+/// group 0) and whether `(?i)` was set.
+#[derive(Debug)]
+pub struct Parsed {
+    pub node: Node,
+    pub n_groups: usize,
+    pub case_insensitive: bool,
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    n_groups: usize,
+    depth: usize,   // open groups
+    height: usize,  // height of the node last returned
+}
+
+// === Impl ===
+
+#[inline]
+fn err(msg: impl Into<String>) -> ErebusError {
+    ErebusError::InvalidPattern(msg.into())
+}
+
+/// Parses `pattern`. Supports literals, `.`, `[...]` classes, `\d\w\s\b`
+/// (and negations), `^`, `$`, groups, `(?:...)`, `|`, `* + ? {n} {n,} {n,m}`
+/// with lazy `?` suffixes, and a leading `(?i)` flag.
+pub fn parse(pattern: &str) -> ErrorResult<Parsed> {
+    let (pattern, case_insensitive) = match pattern.strip_prefix("(?i)") {
+        Some(rest) => (rest, true),
+        None => (pattern, false),
+    };
+    let mut p = Parser { chars: pattern.chars().collect(), pos: 0, n_groups: 0, depth: 0, height: 0 };
+    let node = p.parse_alt()?;
+    if p.pos < p.chars.len() {
+        return Err(err(format!("unmatched ')' at {}", p.pos)));
+    }
+    Ok(Parsed { node, n_groups: p.n_groups, case_insensitive })
+}
+
+impl Parser {
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    #[inline]
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn next(&mut self) -> ErrorResult<char> {
+        let c = self.peek().ok_or_else(|| err("unexpected end of pattern"))?;
+        self.pos += 1;
+        Ok(c)
+    }
+
+    #[inline]
+    fn check_nest(n: usize) -> ErrorResult<()> {
+        if n > MAX_NEST {
+            return Err(err(format!("pattern nests deeper than {MAX_NEST}")));
+        }
+        Ok(())
+    }
+
+    fn parse_alt(&mut self) -> ErrorResult<Node> {
+        let mut branches = vec![self.parse_concat()?];
+        let mut h = self.height;
+        while self.eat('|') {
+            branches.push(self.parse_concat()?);
+            h = h.max(self.height);
+        }
+        if branches.len() == 1 {
+            self.height = h;
+            return Ok(branches.pop().unwrap());
+        }
+        self.height = h + 1;
+        Self::check_nest(self.height)?;
+        Ok(Node::Alt(branches))
+    }
+
+    fn parse_concat(&mut self) -> ErrorResult<Node> {
+        let mut items = Vec::new();
+        let mut h = 1;
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            items.push(self.parse_repeat()?);
+            h = h.max(self.height);
+        }
+        if items.len() > 1 {
+            h += 1;
+            Self::check_nest(h)?;
+        }
+        self.height = h;
+        Ok(match items.len() {
+            0 => Node::Empty,
+            1 => items.pop().unwrap(),
+            _ => Node::Concat(items),
+        })
+    }
+
+    fn parse_repeat(&mut self) -> ErrorResult<Node> {
+        let mut node = self.parse_atom()?;
+        loop {
+            let (min, max) = match self.peek() {
+                Some('*') => { self.pos += 1; (0, None) }
+                Some('+') => { self.pos += 1; (1, None) }
+                Some('?') => { self.pos += 1; (0, Some(1)) }
+                Some('{') if self.is_counted_repeat() => self.parse_counted()?,
+                _ => return Ok(node),
+            };
+            if matches!(node, Node::Look(_) | Node::Empty) {
+                return Err(err("nothing to repeat"));
+            }
+            self.height += 1;
+            Self::check_nest(self.height)?;
+            let greedy = !self.eat('?');
+            node = Node::Repeat { node: Box::new(node), min, max, greedy };
+        }
+    }
+
+    /// `{` starts a counted repeat only when followed by digits; otherwise it is a literal.
+    fn is_counted_repeat(&self) -> bool {
+        matches!(self.chars.get(self.pos + 1), Some(c) if c.is_ascii_digit())
+    }
+
+    fn parse_number(&mut self) -> ErrorResult<u32> {
+        let start = self.pos;
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let s: String = self.chars[start..self.pos].iter().collect();
+        let n: u32 = s.parse().map_err(|_| err("invalid repeat count"))?;
+        if n > MAX_REPEAT {
+            return Err(err(format!("repeat count {n} exceeds {MAX_REPEAT}")));
+        }
+        Ok(n)
+    }
+
+    fn parse_counted(&mut self) -> ErrorResult<(u32, Option<u32>)> {
+        self.pos += 1; // '{'
+        let min = self.parse_number()?;
+        let max = if self.eat(',') {
+            if self.peek() == Some('}') { None } else { Some(self.parse_number()?) }
+        } else {
+            Some(min)
+        };
+        if !self.eat('}') {
+            return Err(err("unclosed counted repeat"));
+        }
+        if matches!(max, Some(m) if m < min) {
+            return Err(err("repeat max is below min"));
+        }
+        Ok((min, max))
+    }
+
+    fn parse_atom(&mut self) -> ErrorResult<Node> {
+        let c = self.next()?;
+        self.height = 1;
+        Ok(match c {
+            '(' => {
+                let index = if self.chars[self.pos..].starts_with(&['?', ':']) {
+                    self.pos += 2;
+                    None
+                } else {
+                    self.n_groups += 1;
+                    Some(self.n_groups)
+                };
+                self.depth += 1;
+                Self::check_nest(self.depth)?;
+                let inner = self.parse_alt()?;
+                self.depth -= 1;
+                if !self.eat(')') {
+                    return Err(err("unclosed group"));
+                }
+                self.height += 1;
+                Self::check_nest(self.height)?;
+                Node::Group(Box::new(inner), index)
+            }
+            '[' => Node::Class(self.parse_class()?),
+            '.' => Node::Any,
+            '^' => Node::Look(Look::Start),
+            '$' => Node::Look(Look::End),
+            '\\' => self.parse_escape()?,
+            '*' | '+' | '?' => return Err(err(format!("nothing to repeat before '{c}'"))),
+            c => Node::Char(c),
+        })
+    }
+
+    fn parse_escape(&mut self) -> ErrorResult<Node> {
+        let c = self.next()?;
+        Ok(match c {
+            'd' => Node::Class(CharClass::digit(false)),
+            'D' => Node::Class(CharClass::digit(true)),
+            'w' => Node::Class(CharClass::word(false)),
+            'W' => Node::Class(CharClass::word(true)),
+            's' => Node::Class(CharClass::space(false)),
+            'S' => Node::Class(CharClass::space(true)),
+            'b' => Node::Look(Look::WordBoundary),
+            'B' => Node::Look(Look::NotWordBoundary),
+            c => Node::Char(Self::escaped_char(c)?),
+        })
+    }
+
+    fn escaped_char(c: char) -> ErrorResult<char> {
+        Ok(match c {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'f' => '\x0C',
+            'v' => '\x0B',
+            '0' => '\0',
+            c if c.is_ascii_alphanumeric() => {
+                return Err(err(format!("unsupported escape '\\{c}'")));
+            }
+            c => c,
+        })
+    }
+
+    fn parse_class(&mut self) -> ErrorResult<CharClass> {
+        let mut class = CharClass { negated: self.eat('^'), ..Default::default() };
+        let mut first = true;
+        loop {
+            let c = self.next().map_err(|_| err("unclosed character class"))?;
+            if c == ']' && !first {
+                return Ok(class);
+            }
+            first = false;
+
+            let lo = if c == '\\' {
+                match self.next()? {
+                    'd' => { class.nested.push(CharClass::digit(false)); continue; }
+                    'D' => { class.nested.push(CharClass::digit(true)); continue; }
+                    'w' => { class.nested.push(CharClass::word(false)); continue; }
+                    'W' => { class.nested.push(CharClass::word(true)); continue; }
+                    's' => { class.nested.push(CharClass::space(false)); continue; }
+                    'S' => { class.nested.push(CharClass::space(true)); continue; }
+                    e => Self::escaped_char(e)?,
+                }
+            } else {
+                c
+            };
+
+            // Range `lo-hi`, unless '-' is last in the class.
+            if self.peek() == Some('-') && !matches!(self.chars.get(self.pos + 1), Some(']') | None) {
+                self.pos += 1;
+                let hi = match self.next()? {
+                    '\\' => Self::escaped_char(self.next()?)?,
+                    h => h,
+                };
+                if hi < lo {
+                    return Err(err(format!("invalid class range {lo}-{hi}")));
+                }
+                class.ranges.push((lo, hi));
+            } else {
+                class.ranges.push((lo, lo));
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+// === Imports ===
+use super::ast::{is_word_char, Look};
+use super::compile::{Inst, Program};
+
+// === Types ===
+
+/// Capture slots: byte offsets into the haystack.
+pub type Slots = Vec<Option<usize>>;
+
+/// Thread list for one step, deduplicated by pc.
+struct Threads {
+    list: Vec<(usize, Slots)>,
+    seen: Vec<usize>,
+    stamp: usize,
+}
+
+// === Impl ===
+
+impl Threads {
+    fn new(n: usize) -> Self {
+        Self { list: Vec::new(), seen: vec![0; n], stamp: 1 }
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.list.clear();
+        self.stamp += 1;
+    }
+
+    /// Marks `pc` as visited, returning false if it already was.
+    #[inline]
+    fn visit(&mut self, pc: usize) -> bool {
+        if self.seen[pc] == self.stamp {
+            return false;
+        }
+        self.seen[pc] = self.stamp;
+        true
+    }
+}
+
+#[inline]
+fn char_eq(a: char, b: char, fold_case: bool) -> bool {
+    a == b || (fold_case && a.to_lowercase().eq(b.to_lowercase()))
+}
+
+fn look_holds(look: Look, text: &str, pos: usize) -> bool {
+    match look {
+        Look::Start => pos == 0,
+        Look::End => pos == text.len(),
+        Look::WordBoundary | Look::NotWordBoundary => {
+            let before = text[..pos].chars().next_back().is_some_and(is_word_char);
+            let after = text[pos..].chars().next().is_some_and(is_word_char);
+            (before != after) == (look == Look::WordBoundary)
+        }
+    }
+}
+
+impl Program {
+    /// Follows epsilon edges from `pc` in priority order, adding the
+    /// resulting char-consuming (or `Match`) threads to `threads`.
+    fn add_thread(&self, threads: &mut Threads, pc: usize, slots: Slots, text: &str, pos: usize) {
+        let mut stack = vec![(pc, slots)];
+        while let Some((pc, mut slots)) = stack.pop() {
+            if !threads.visit(pc) {
+                continue;
+            }
+            match &self.insts[pc] {
+                Inst::Jmp(t) => stack.push((*t, slots)),
+                Inst::Split(a, b) => {
+                    stack.push((*b, slots.clone()));
+                    stack.push((*a, slots));
+                }
+                Inst::Save(k) => {
+                    slots[*k] = Some(pos);
+                    stack.push((pc + 1, slots));
+                }
+                Inst::Assert(look) => {
+                    if look_holds(*look, text, pos) {
+                        stack.push((pc + 1, slots));
+                    }
+                }
+                _ => threads.list.push((pc, slots)),
+            }
+        }
+    }
+
+    /// Leftmost-first search starting at byte offset `start`. With
+    /// `earliest`, returns as soon as any match is found (slots are then
+    /// only meaningful as a yes/no answer).
+    pub fn exec(&self, text: &str, start: usize, earliest: bool) -> Option<Slots> {
+        let n = self.insts.len();
+        let mut clist = Threads::new(n);
+        let mut nlist = Threads::new(n);
+        let mut matched: Option<Slots> = None;
+        let mut pos = start;
+
+        loop {
+            // New attempt at this position, lowest priority.
+            if matched.is_none() {
+                self.add_thread(&mut clist, 0, vec![None; self.n_slots], text, pos);
+            }
+            if clist.list.is_empty() {
+                break;
+            }
+
+            let c = text[pos..].chars().next();
+            let next = pos + c.map_or(0, char::len_utf8);
+            for (pc, slots) in std::mem::take(&mut clist.list) {
+                let step = match (&self.insts[pc], c) {
+                    (Inst::Match, _) => {
+                        if earliest {
+                            return Some(slots);
+                        }
+                        // Lower-priority threads are cut off.
+                        matched = Some(slots);
+                        break;
+                    }
+                    (Inst::Char(x), Some(c)) => char_eq(c, *x, self.case_insensitive),
+                    (Inst::Any, Some(c)) => c != '\n',
+                    (Inst::Class(class), Some(c)) => class.matches(c, self.case_insensitive),
+                    _ => false,
+                };
+                if step {
+                    self.add_thread(&mut nlist, pc + 1, slots, text, next);
+                }
+            }
+
+            if c.is_none() {
+                break;
+            }
+            pos = next;
+            std::mem::swap(&mut clist, &mut nlist);
+            nlist.clear();
+        }
+        matched
+    }
+}
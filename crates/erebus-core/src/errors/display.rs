@@ -30,6 +30,8 @@ impl fmt::Display for ErebusError {
                 write!(f, "{}", reason),
             ErebusError::InvalidCutLabels { expected, found } =>
                 write!(f, "Cut labels mismatch: expected {}, found {}", expected, found),
+            ErebusError::InvalidPattern(msg) =>
+                write!(f, "Invalid pattern: {}", msg),
 
             // --- Time ---
             ErebusError::InvalidYear(y) =>
@@ -40,6 +42,12 @@ impl fmt::Display for ErebusError {
                 write!(f, "Invalid day: {:04}-{:02}-{:02}", year, month, day),
             ErebusError::InvalidDate(s) =>
                 write!(f, "Invalid date: {}", s),
+            ErebusError::InvalidTime { hour, minute, second, nanos } =>
+                write!(f, "Invalid time: {:02}:{:02}:{:02}.{:09}", hour, minute, second, nanos),
+            ErebusError::AmbiguousLocalTime(ns) =>
+                write!(f, "Ambiguous local time: {} ns", ns),
+            ErebusError::NonExistentLocalTime(ns) =>
+                write!(f, "Non-existent local time: {} ns", ns),
 
             // --- IO ---
             ErebusError::IoError(e) =>
@@ -26,12 +26,16 @@ pub enum ErebusError {
     ColumnDataMismatch,
     InvalidCutBins { reason: String },
     InvalidCutLabels { expected: usize, found: usize },
+    InvalidPattern(String),
 
     // --- Time ---
     InvalidYear(i32),
     InvalidMonth(u8),
     InvalidDay { year: i32, month: u8, day: u8 },
     InvalidDate(String),
+    InvalidTime { hour: u8, minute: u8, second: u8, nanos: u32 },
+    AmbiguousLocalTime(i64),
+    NonExistentLocalTime(i64),
 
     // --- IO ---
     IoError(io::Error),
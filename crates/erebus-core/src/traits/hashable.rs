@@ -1,5 +1,11 @@
 pub trait HashableValue {}
 impl HashableValue for i64 {}
+impl HashableValue for i32 {}
+impl HashableValue for i16 {}
+impl HashableValue for i128 {}
+impl HashableValue for u8 {}
+impl HashableValue for u32 {}
+impl HashableValue for u64 {}
 impl HashableValue for bool {}
 impl HashableValue for String {}
 
@@ -2,6 +2,7 @@ pub mod arc_clone;
 pub mod sentinel;
 pub mod viewable;
 pub mod numeric;
+pub mod numeric_cast;
 pub mod reducer;
 pub mod reducer_indexed;
 pub mod hashable;
@@ -11,6 +12,7 @@ pub use arc_clone::*;
 pub use sentinel::*;
 pub use viewable::*;
 pub use numeric::*;
+pub use numeric_cast::*;
 pub use reducer::*;
 pub use reducer_indexed::*;
 pub use hashable::*;
\ No newline at end of file
@@ -7,20 +7,63 @@ pub trait Numeric:
     std::ops::MulAssign<Self> +
     'static
 {
+    /// Accumulator type for sums; wider than `Self` for narrow ints.
+    type Wide: Numeric;
+
     fn zero() -> Self;
     fn one() -> Self;
     fn to_f64(self) -> f64;      // for mixed-type aggregates
     fn abs(self) -> Self;        // for norms
+    fn to_wide(self) -> Self::Wide;  // for sums
 }
-impl Numeric for i64 {
-    #[inline] fn zero() -> Self { 0 }
-    #[inline] fn one() -> Self { 1 }
-    #[inline] fn to_f64(self) -> f64 { self as f64 }
-    #[inline] fn abs(self) -> Self { self.abs() }
+
+macro_rules! impl_numeric_signed {
+    ($($t:ty => $w:ty),*) => {
+        $(#[allow(clippy::unnecessary_cast)]
+        impl Numeric for $t {
+            type Wide = $w;
+            #[inline] fn zero() -> Self { 0 }
+            #[inline] fn one() -> Self { 1 }
+            #[inline] fn to_f64(self) -> f64 { self as f64 }
+            #[inline] fn abs(self) -> Self { self.abs() }
+            #[inline] fn to_wide(self) -> $w { self as $w }
+        })*
+    };
 }
+
+macro_rules! impl_numeric_unsigned {
+    ($($t:ty => $w:ty),*) => {
+        $(#[allow(clippy::unnecessary_cast)]
+        impl Numeric for $t {
+            type Wide = $w;
+            #[inline] fn zero() -> Self { 0 }
+            #[inline] fn one() -> Self { 1 }
+            #[inline] fn to_f64(self) -> f64 { self as f64 }
+            #[inline] fn abs(self) -> Self { self }
+            #[inline] fn to_wide(self) -> $w { self as $w }
+        })*
+    };
+}
+
+impl_numeric_signed!(i64 => i64, i32 => i64, i16 => i64, i128 => i128);
+impl_numeric_unsigned!(u8 => u64, u32 => u64, u64 => u64);
+
 impl Numeric for f64 {
+    type Wide = f64;
+
     #[inline] fn zero() -> Self { 0.0 }
     #[inline] fn one() -> Self { 1.0 }
     #[inline] fn to_f64(self) -> f64 { self }
     #[inline] fn abs(self) -> Self { self.abs() }
+    #[inline] fn to_wide(self) -> f64 { self }
+}
+
+impl Numeric for f32 {
+    type Wide = f32;
+
+    #[inline] fn zero() -> Self { 0.0 }
+    #[inline] fn one() -> Self { 1.0 }
+    #[inline] fn to_f64(self) -> f64 { self as f64 }
+    #[inline] fn abs(self) -> Self { self.abs() }
+    #[inline] fn to_wide(self) -> f32 { self }
 }
@@ -0,0 +1,131 @@
+// === Impl ===
+
+/// Conversion between numeric element types, in three flavours.
+///
+/// - `cast_checked`: `None` when the value is not representable in `U`
+///   (out of range, or NaN/infinity into an int). Int to float and
+///   float widening always succeed, possibly rounding.
+/// - `cast_saturating`: clamps to `U`'s range; NaN becomes 0 for ints.
+/// - `cast_wrapping`: two's-complement wrap for ints (as with `as`).
+///   Floats into ints truncate, then wrap through i128 (beyond i128
+///   they saturate first); f64 into f32 overflows to infinity.
+pub trait NumericCast<U>: Sized {
+    fn cast_checked(self) -> Option<U>;
+    fn cast_saturating(self) -> U;
+    fn cast_wrapping(self) -> U;
+}
+
+macro_rules! impl_cast_int_to_int {
+    ($from:ty => $($to:ty),*) => {
+        $(#[allow(clippy::unnecessary_cast)]
+        impl NumericCast<$to> for $from {
+            #[inline]
+            fn cast_checked(self) -> Option<$to> {
+                let w = self as i128;
+                (w >= <$to>::MIN as i128 && w <= <$to>::MAX as i128).then_some(self as $to)
+            }
+            #[inline]
+            fn cast_saturating(self) -> $to {
+                let w = self as i128;
+                if w < <$to>::MIN as i128 {
+                    <$to>::MIN
+                } else if w > <$to>::MAX as i128 {
+                    <$to>::MAX
+                } else {
+                    self as $to
+                }
+            }
+            #[inline]
+            fn cast_wrapping(self) -> $to { self as $to }
+        })*
+    };
+}
+
+macro_rules! impl_cast_int_to_float {
+    ($from:ty => $($to:ty),*) => {
+        $(impl NumericCast<$to> for $from {
+            #[inline] fn cast_checked(self) -> Option<$to> { Some(self as $to) }
+            #[inline] fn cast_saturating(self) -> $to { self as $to }
+            #[inline] fn cast_wrapping(self) -> $to { self as $to }
+        })*
+    };
+}
+
+macro_rules! impl_cast_float_to_int {
+    ($from:ty => $($to:ty),*) => {
+        $(#[allow(clippy::unnecessary_cast)]
+        impl NumericCast<$to> for $from {
+            #[inline]
+            fn cast_checked(self) -> Option<$to> {
+                if !self.is_finite() {
+                    return None;
+                }
+                // Exact power-of-two bounds: [lo, hi)
+                let signed = <$to>::MIN != 0;
+                let bits = <$to>::BITS as i32 - signed as i32;
+                let hi = (2.0 as $from).powi(bits);
+                let lo = if signed { -hi } else { 0.0 };
+                let t = self.trunc();
+                (t >= lo && t < hi).then_some(t as $to)
+            }
+            #[inline]
+            fn cast_saturating(self) -> $to { self as $to }
+            #[inline]
+            fn cast_wrapping(self) -> $to { (self as i128) as $to }
+        })*
+    };
+}
+
+impl_cast_int_to_int!(i16 => i16, i32, i64, i128, u8, u32, u64);
+impl_cast_int_to_int!(i32 => i16, i32, i64, i128, u8, u32, u64);
+impl_cast_int_to_int!(i64 => i16, i32, i64, i128, u8, u32, u64);
+impl_cast_int_to_int!(i128 => i16, i32, i64, i128, u8, u32, u64);
+impl_cast_int_to_int!(u8 => i16, i32, i64, i128, u8, u32, u64);
+impl_cast_int_to_int!(u32 => i16, i32, i64, i128, u8, u32, u64);
+impl_cast_int_to_int!(u64 => i16, i32, i64, i128, u8, u32, u64);
+
+impl_cast_int_to_float!(i16 => f32, f64);
+impl_cast_int_to_float!(i32 => f32, f64);
+impl_cast_int_to_float!(i64 => f32, f64);
+impl_cast_int_to_float!(i128 => f32, f64);
+impl_cast_int_to_float!(u8 => f32, f64);
+impl_cast_int_to_float!(u32 => f32, f64);
+impl_cast_int_to_float!(u64 => f32, f64);
+
+impl_cast_float_to_int!(f32 => i16, i32, i64, i128, u8, u32, u64);
+impl_cast_float_to_int!(f64 => i16, i32, i64, i128, u8, u32, u64);
+
+impl NumericCast<f64> for f64 {
+    #[inline] fn cast_checked(self) -> Option<f64> { Some(self) }
+    #[inline] fn cast_saturating(self) -> f64 { self }
+    #[inline] fn cast_wrapping(self) -> f64 { self }
+}
+
+impl NumericCast<f32> for f32 {
+    #[inline] fn cast_checked(self) -> Option<f32> { Some(self) }
+    #[inline] fn cast_saturating(self) -> f32 { self }
+    #[inline] fn cast_wrapping(self) -> f32 { self }
+}
+
+impl NumericCast<f64> for f32 {
+    #[inline] fn cast_checked(self) -> Option<f64> { Some(self as f64) }
+    #[inline] fn cast_saturating(self) -> f64 { self as f64 }
+    #[inline] fn cast_wrapping(self) -> f64 { self as f64 }
+}
+
+impl NumericCast<f32> for f64 {
+    #[inline]
+    fn cast_checked(self) -> Option<f32> {
+        (!self.is_finite() || self.abs() <= f32::MAX as f64).then_some(self as f32)
+    }
+    #[inline]
+    fn cast_saturating(self) -> f32 {
+        if self.is_finite() {
+            self.clamp(f32::MIN as f64, f32::MAX as f64) as f32
+        } else {
+            self as f32
+        }
+    }
+    #[inline]
+    fn cast_wrapping(self) -> f32 { self as f32 }
+}
@@ -6,15 +6,28 @@ pub trait Sentinel {
     fn is_sentinel(&self) -> bool;
 }
 
-impl Sentinel for i64 {
-    #[inline] fn sentinel() -> Self { i64::MIN }
-    #[inline] fn is_sentinel(&self) -> bool { *self == i64::MIN }
+// Signed ints and floats use their minimum; unsigned ints use their
+// maximum, since 0 is far too common to reserve.
+macro_rules! impl_sentinel {
+    ($($t:ty => $v:expr),*) => {
+        $(impl Sentinel for $t {
+            #[inline] fn sentinel() -> Self { $v }
+            #[inline] fn is_sentinel(&self) -> bool { *self == $v }
+        })*
+    };
 }
 
-impl Sentinel for f64 {
-    #[inline] fn sentinel() -> Self { f64::MIN }
-    #[inline] fn is_sentinel(&self) -> bool { *self == f64::MIN }
-}
+impl_sentinel!(
+    i64 => i64::MIN,
+    i32 => i32::MIN,
+    i16 => i16::MIN,
+    i128 => i128::MIN,
+    u8 => u8::MAX,
+    u32 => u32::MAX,
+    u64 => u64::MAX,
+    f64 => f64::MIN,
+    f32 => f32::MIN
+);
 
 impl Sentinel for bool {
     #[inline] fn sentinel() -> Self { false }
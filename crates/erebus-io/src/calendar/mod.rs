@@ -0,0 +1,5 @@
+pub mod write;
+pub mod read;
+
+pub use write::*;
+pub use read::*;
@@ -0,0 +1,45 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::date_vector::read::read_date_delta_body;
+use std::io::{Read, Seek};
+
+// === Impl ===
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_calendar<R: Read + Seek>(
+    reader: &mut ErebusReader<R>,
+) -> ErrorResult<(u8, Vec<i32>)>
+{
+    reader.read_magic_and_version()?;
+
+    let global = reader.read_global_header()?;
+
+    if global.object_type != ObjectType::Calendar {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected Calendar, got {:?}", global.object_type),
+        ));
+    }
+    if global.base_type != BaseType::Date || global.encoding != EncodingType::DateDelta {
+        return Err(ErebusError::InvalidHeader);
+    }
+
+    let weekend_mask = reader.read_bytes(1)?[0];
+    let (holidays, validity) = read_date_delta_body(reader, global.compression)?;
+    if validity.not_all() {
+        return Err(ErebusError::InvalidOperation("Calendar holidays contain nulls".into()));
+    }
+
+    Ok((weekend_mask, holidays))
+}
+
+// Top-level entrypoint: reader.calendar()
+pub struct CalendarReader<'a, R: Read + Seek> {
+    pub(crate) reader: &'a mut ErebusReader<R>,
+}
+
+impl<'a, R: Read + Seek> CalendarReader<'a, R> {
+    /// Returns the weekend mask and the holiday days.
+    pub fn read(self) -> ErrorResult<(u8, Vec<i32>)> {
+        read_calendar(self.reader)
+    }
+}
@@ -0,0 +1,40 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::date_vector::write::write_date_delta_body;
+use std::io::{Write, Seek};
+
+// === Impl ===
+
+/// Layout after the global header:
+///   [WEEKEND_MASK: u8][DateDelta body of the holiday days]
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_calendar<W: Write + Seek>(
+    writer: &mut ErebusWriter<W>,
+    weekend_mask: u8,
+    holidays: &[i32],
+) -> ErrorResult<()>
+{
+    writer.write_magic_and_version()?;
+    writer.write_global_header(&ErebusHeader::new(
+        ObjectType::Calendar,
+        BaseType::Date,
+        EncodingType::DateDelta,
+        writer.compression(),
+    ))?;
+
+    writer.write_bytes(&[weekend_mask])?;
+    write_date_delta_body(writer, holidays, &bitvec![1; holidays.len()])
+}
+
+// Top-level entrypoint: writer.calendar()
+pub struct CalendarWriter<'a, W: Write + Seek> {
+    pub(crate) writer: &'a mut ErebusWriter<W>,
+}
+
+impl<'a, W: Write + Seek> CalendarWriter<'a, W> {
+    /// Bit `wd - 1` of `weekend_mask` marks ISO weekday `wd` as weekend.
+    /// `holidays` are days since 1970-01-01.
+    pub fn write(self, weekend_mask: u8, holidays: &[i32]) -> ErrorResult<()> {
+        write_calendar(self.writer, weekend_mask, holidays)
+    }
+}
@@ -0,0 +1,138 @@
+// === Imports ===
+use crate::prelude::*;
+use std::io::{Read, Write};
+
+// === Impl ===
+
+/// EncodingType variant for dictionary-encoded text
+pub const ENCODING_TEXT_DICTIONARY: EncodingType = EncodingType::TextDictionary;
+
+/// Type-specific header for a categorical text column.
+///
+/// The dictionary is stored once as varint-length-prefixed UTF-8 strings,
+/// followed by one code per valid row in the narrowest width that can
+/// address every category (1, 2 or 4 bytes).
+///
+/// - n_rows:       logical row count
+/// - validity_len: length in bytes of the validity bitmap
+/// - n_categories: number of dictionary entries
+/// - dict_len:     length in bytes of the uncompressed dictionary stream
+/// - code_width:   bytes per code
+/// - codes_len:    length in bytes of the uncompressed code stream
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) struct TextDictHeader {
+    pub n_rows: u64,
+    pub validity_len: u64,
+    pub n_categories: u64,
+    pub dict_len: u64,
+    pub code_width: u8,
+    pub codes_len: u64,
+}
+
+impl TextDictHeader {
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn write<W: Write>(&self, w: &mut W) -> ErrorResult<()> {
+        w.write_all(&self.n_rows.to_le_bytes())?;
+        w.write_all(&self.validity_len.to_le_bytes())?;
+        w.write_all(&self.n_categories.to_le_bytes())?;
+        w.write_all(&self.dict_len.to_le_bytes())?;
+        w.write_all(&[self.code_width])?;
+        w.write_all(&self.codes_len.to_le_bytes())?;
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn read<R: Read>(r: &mut R) -> ErrorResult<Self> {
+        let mut buf = [0u8; 8];
+        let mut next_u64 = |r: &mut R| -> ErrorResult<u64> {
+            r.read_exact(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        };
+
+        let n_rows = next_u64(r)?;
+        let validity_len = next_u64(r)?;
+        let n_categories = next_u64(r)?;
+        let dict_len = next_u64(r)?;
+
+        let mut width = [0u8; 1];
+        r.read_exact(&mut width)?;
+        let code_width = width[0];
+        if !matches!(code_width, 1 | 2 | 4) {
+            return Err(ErebusError::InvalidHeader);
+        }
+
+        let codes_len = next_u64(r)?;
+
+        Ok(Self { n_rows, validity_len, n_categories, dict_len, code_width, codes_len })
+    }
+}
+
+/// Narrowest code width in bytes that can address `n_categories` entries.
+#[inline]
+pub fn dictionary_code_width(n_categories: usize) -> u8 {
+    if n_categories <= 1 << 8 {
+        1
+    } else if n_categories <= 1 << 16 {
+        2
+    } else {
+        4
+    }
+}
+
+/// Serialize the dictionary as varint-length-prefixed strings.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn encode_dictionary(dictionary: &[String]) -> Vec<u8> {
+    let total: usize = dictionary.iter().map(|s| s.len() + 1).sum();
+    let mut out = Vec::with_capacity(total);
+    for s in dictionary {
+        write_varint_i64(&mut out, s.len() as i64);
+        out.extend_from_slice(s.as_bytes());
+    }
+    out
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn decode_dictionary(bytes: &[u8], count: usize) -> ErrorResult<Vec<String>> {
+    let corrupt = |msg: &str| ErebusError::InvalidOperation(format!("Corrupt TextDictionary payload: {msg}"));
+
+    let mut out = Vec::with_capacity(count);
+    let mut pos = 0usize;
+    for _ in 0..count {
+        let len = read_varint_i64(bytes, &mut pos)?;
+        let end = usize::try_from(len).ok()
+            .and_then(|len| pos.checked_add(len))
+            .filter(|&end| end <= bytes.len())
+            .ok_or_else(|| corrupt("string runs past the dictionary"))?;
+        let s = std::str::from_utf8(&bytes[pos..end]).map_err(|_| corrupt("invalid UTF-8"))?;
+        out.push(s.to_string());
+        pos = end;
+    }
+
+    if pos != bytes.len() {
+        return Err(corrupt(&format!("{} trailing dictionary bytes", bytes.len() - pos)));
+    }
+    Ok(out)
+}
+
+/// Pack the codes of valid rows at `width` bytes each.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn encode_codes<C: Copy + Into<u32>>(codes: &[C], validity: &BitVec, width: u8) -> Vec<u8> {
+    let mut out = Vec::with_capacity(validity.count_ones() * width as usize);
+    for i in validity.iter_ones() {
+        let c: u32 = codes[i].into();
+        out.extend_from_slice(&c.to_le_bytes()[..width as usize]);
+    }
+    out
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn decode_codes(bytes: &[u8], width: u8) -> Vec<u32> {
+    bytes
+        .chunks_exact(width as usize)
+        .map(|chunk| {
+            let mut buf = [0u8; 4];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            u32::from_le_bytes(buf)
+        })
+        .collect()
+}
@@ -0,0 +1,108 @@
+// === Imports ===
+use crate::prelude::*;
+use super::format::{TextDictHeader, decode_codes, decode_dictionary};
+use std::io::{Read, Seek};
+
+// === Impl ===
+
+/// Reads the TextDictionary header, validity, dictionary and codes
+/// (no global header). Null rows are returned as code 0.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_text_dict_body<R: Read + Seek>(
+    reader: &mut ErebusReader<R>,
+    compression: CompressionType,
+) -> ErrorResult<(Vec<String>, Vec<u32>, BitVec)>
+{
+    let header = TextDictHeader::read(reader.inner_mut())?;
+    let n_rows = header.n_rows as usize;
+    let n_categories = header.n_categories as usize;
+
+    let validity_bytes = reader.read_bytes(header.validity_len as usize)?;
+    if validity_bytes.len() * 8 < n_rows {
+        return Err(ErebusError::InvalidHeader);
+    }
+    let validity = unpack_validity_bitmap(&validity_bytes, n_rows);
+
+    let mut read_stream = |len: u64, what: &str| -> ErrorResult<Vec<u8>> {
+        let bytes = match compression {
+            CompressionType::None => reader.read_bytes(len as usize)?,
+            _ => reader.read_stream_bytes(compression)?,
+        };
+        if bytes.len() as u64 != len {
+            return Err(ErebusError::InvalidOperation(format!(
+                "Corrupt TextDictionary {}: expected {} bytes, got {}",
+                what,
+                len,
+                bytes.len()
+            )));
+        }
+        Ok(bytes)
+    };
+
+    let dict_bytes = read_stream(header.dict_len, "dictionary")?;
+    let code_bytes = read_stream(header.codes_len, "codes")?;
+
+    let dictionary = decode_dictionary(&dict_bytes, n_categories)?;
+
+    let valid_count = validity.count_ones();
+    if code_bytes.len() != valid_count * header.code_width as usize {
+        return Err(ErebusError::InvalidOperation(format!(
+            "Corrupt TextDictionary codes: {} bytes for {} valid rows",
+            code_bytes.len(),
+            valid_count
+        )));
+    }
+    let valid_codes = decode_codes(&code_bytes, header.code_width);
+    if valid_codes.iter().any(|&c| c as usize >= n_categories) {
+        return Err(ErebusError::InvalidOperation(
+            "Corrupt TextDictionary codes: code outside the dictionary".into(),
+        ));
+    }
+
+    let mut codes = vec![0u32; n_rows];
+    for (i, c) in validity.iter_ones().zip(valid_codes) {
+        codes[i] = c;
+    }
+
+    Ok((dictionary, codes, validity))
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_categorical_text_dict<R: Read + Seek>(
+    reader: &mut ErebusReader<R>,
+) -> ErrorResult<(Vec<String>, Vec<u32>, BitVec)>
+{
+    reader.read_magic_and_version()?;
+
+    let global = reader.read_global_header()?;
+
+    if global.object_type != ObjectType::VectorData {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected VectorData, got {:?}", global.object_type),
+        ));
+    }
+    if global.base_type != BaseType::Text {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected Text, got {:?}", global.base_type),
+        ));
+    }
+    if global.encoding != EncodingType::TextDictionary {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected TextDictionary, got {:?}", global.encoding),
+        ));
+    }
+
+    read_text_dict_body(reader, global.compression)
+}
+
+// Top-level entrypoint: reader.categorical()
+pub struct CategoricalReader<'a, R: Read + Seek> {
+    pub(crate) reader: &'a mut ErebusReader<R>,
+}
+
+impl<'a, R: Read + Seek> CategoricalReader<'a, R> {
+    /// Returns the dictionary, one code per row and the validity bitmap.
+    pub fn read(self) -> ErrorResult<(Vec<String>, Vec<u32>, BitVec)> {
+        read_categorical_text_dict(self.reader)
+    }
+}
@@ -0,0 +1,89 @@
+// === Imports ===
+use crate::prelude::*;
+use super::format::{TextDictHeader, encode_codes, encode_dictionary};
+use std::io::{Write, Seek};
+
+// === Impl ===
+
+/// Writes the TextDictionary header, validity, dictionary and codes
+/// (no global header).
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_text_dict_body<W: Write + Seek, C: Copy + Into<u32>>(
+    writer: &mut ErebusWriter<W>,
+    dictionary: &[String],
+    codes: &[C],
+    validity: &BitVec,
+) -> ErrorResult<()>
+{
+    if codes.len() != validity.len() {
+        return Err(ErebusError::LengthMismatch {
+            expected: codes.len(),
+            found: validity.len(),
+        });
+    }
+    if let Some(i) = validity.iter_ones().find(|&i| codes[i].into() as usize >= dictionary.len()) {
+        return Err(ErebusError::InvalidOperation(format!(
+            "Code {} at row {} is outside a dictionary of {} entries",
+            codes[i].into(),
+            i,
+            dictionary.len()
+        )));
+    }
+
+    let code_width = dictionary_code_width(dictionary.len());
+    let validity_bytes = pack_validity_bitmap(validity);
+    let dict_bytes = encode_dictionary(dictionary);
+    let code_bytes = encode_codes(codes, validity, code_width);
+
+    let header = TextDictHeader {
+        n_rows: codes.len() as u64,
+        validity_len: validity_bytes.len() as u64,
+        n_categories: dictionary.len() as u64,
+        dict_len: dict_bytes.len() as u64,
+        code_width,
+        codes_len: code_bytes.len() as u64,
+    };
+    header.write(writer.inner_mut())?;
+
+    writer.write_bytes(&validity_bytes)?;
+    writer.write_stream_bytes(&dict_bytes)?;
+    writer.write_stream_bytes(&code_bytes)?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_categorical_text_dict<W: Write + Seek, C: Copy + Into<u32>>(
+    writer: &mut ErebusWriter<W>,
+    dictionary: &[String],
+    codes: &[C],
+    validity: &BitVec,
+) -> ErrorResult<()>
+{
+    writer.write_magic_and_version()?;
+    writer.write_global_header(&ErebusHeader::new(
+        ObjectType::VectorData,
+        BaseType::Text,
+        EncodingType::TextDictionary,
+        writer.compression(),
+    ))?;
+
+    write_text_dict_body(writer, dictionary, codes, validity)
+}
+
+// Top-level entrypoint: writer.categorical()
+pub struct CategoricalWriter<'a, W: Write + Seek> {
+    pub(crate) writer: &'a mut ErebusWriter<W>,
+}
+
+impl<'a, W: Write + Seek> CategoricalWriter<'a, W> {
+    /// `codes` index into `dictionary`; codes under null rows are not stored.
+    pub fn write<C: Copy + Into<u32>>(
+        self,
+        dictionary: &[String],
+        codes: &[C],
+        validity: &BitVec,
+    ) -> ErrorResult<()> {
+        write_categorical_text_dict(self.writer, dictionary, codes, validity)
+    }
+}
@@ -0,0 +1,116 @@
+// === Imports ===
+use crate::prelude::*;
+use std::io::{Read, Write};
+
+// === Impl ===
+
+/// EncodingType variant for compact integers
+pub const ENCODING_I64_COMPACT: EncodingType = EncodingType::I64Compact;
+
+/// Type-specific header for an integer column using COMPACT encoding.
+///
+/// Valid values are stored frame-of-reference style: as unsigned offsets
+/// from `base` (the minimum valid value), each in the narrowest of
+/// 1/2/4/8 bytes that holds the largest offset. Ids in a narrow band
+/// far from zero therefore still pack into one or two bytes.
+///
+/// - n_rows:       logical row count
+/// - validity_len: length in bytes of the validity bitmap
+/// - base:         minimum valid value (0 when there are none)
+/// - width:        bytes per offset
+/// - payload_len:  length in bytes of the uncompressed offset stream
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) struct I64CompactHeader {
+    pub n_rows: u64,
+    pub validity_len: u64,
+    pub base: i64,
+    pub width: u8,
+    pub payload_len: u64,
+}
+
+impl I64CompactHeader {
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn write<W: Write>(&self, w: &mut W) -> ErrorResult<()> {
+        w.write_all(&self.n_rows.to_le_bytes())?;
+        w.write_all(&self.validity_len.to_le_bytes())?;
+        w.write_all(&self.base.to_le_bytes())?;
+        w.write_all(&[self.width])?;
+        w.write_all(&self.payload_len.to_le_bytes())?;
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn read<R: Read>(r: &mut R) -> ErrorResult<Self> {
+        let mut buf = [0u8; 8];
+
+        r.read_exact(&mut buf)?;
+        let n_rows = u64::from_le_bytes(buf);
+
+        r.read_exact(&mut buf)?;
+        let validity_len = u64::from_le_bytes(buf);
+
+        r.read_exact(&mut buf)?;
+        let base = i64::from_le_bytes(buf);
+
+        let mut width = [0u8; 1];
+        r.read_exact(&mut width)?;
+        let width = width[0];
+        if !matches!(width, 1 | 2 | 4 | 8) {
+            return Err(ErebusError::InvalidHeader);
+        }
+
+        r.read_exact(&mut buf)?;
+        let payload_len = u64::from_le_bytes(buf);
+
+        Ok(Self { n_rows, validity_len, base, width, payload_len })
+    }
+}
+
+/// Narrowest width in bytes (1, 2, 4 or 8) holding `max_offset`.
+#[inline]
+pub fn compact_offset_width(max_offset: u64) -> u8 {
+    if max_offset <= u8::MAX as u64 {
+        1
+    } else if max_offset <= u16::MAX as u64 {
+        2
+    } else if max_offset <= u32::MAX as u64 {
+        4
+    } else {
+        8
+    }
+}
+
+/// Frame-of-reference encode the valid entries of `values`.
+/// Returns `(base, width, payload)`.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn encode_compact_ints<C: Copy + Into<i64>>(values: &[C], validity: &BitVec) -> (i64, u8, Vec<u8>) {
+    let (mut lo, mut hi) = (i64::MAX, i64::MIN);
+    for i in validity.iter_ones() {
+        let v: i64 = values[i].into();
+        lo = lo.min(v);
+        hi = hi.max(v);
+    }
+    if lo > hi {
+        return (0, 1, Vec::new());
+    }
+
+    let width = compact_offset_width(hi.wrapping_sub(lo) as u64);
+    let mut out = Vec::with_capacity(validity.count_ones() * width as usize);
+    for i in validity.iter_ones() {
+        let offset = values[i].into().wrapping_sub(lo) as u64;
+        out.extend_from_slice(&offset.to_le_bytes()[..width as usize]);
+    }
+    (lo, width, out)
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn decode_compact_ints(bytes: &[u8], base: i64, width: u8) -> Vec<i64> {
+    bytes
+        .chunks_exact(width as usize)
+        .map(|chunk| {
+            let mut buf = [0u8; 8];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            base.wrapping_add(u64::from_le_bytes(buf) as i64)
+        })
+        .collect()
+}
@@ -0,0 +1,7 @@
+pub mod format;
+pub mod write;
+pub mod read;
+
+pub use format::*;
+pub use write::*;
+pub use read::*;
@@ -0,0 +1,87 @@
+// === Imports ===
+use crate::prelude::*;
+use super::format::{I64CompactHeader, decode_compact_ints};
+use std::io::{Read, Seek};
+
+// === Impl ===
+
+/// Reads the I64Compact header, validity and payload (no global header).
+/// Null rows are returned as 0.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_i64_compact_body<R: Read + Seek>(
+    reader: &mut ErebusReader<R>,
+    compression: CompressionType,
+) -> ErrorResult<(Vec<i64>, BitVec)>
+{
+    let header = I64CompactHeader::read(reader.inner_mut())?;
+    let n_rows = header.n_rows as usize;
+
+    let validity_bytes = reader.read_bytes(header.validity_len as usize)?;
+    if validity_bytes.len() * 8 < n_rows {
+        return Err(ErebusError::InvalidHeader);
+    }
+    let validity = unpack_validity_bitmap(&validity_bytes, n_rows);
+
+    let payload = match compression {
+        CompressionType::None => reader.read_bytes(header.payload_len as usize)?,
+        _ => reader.read_stream_bytes(compression)?,
+    };
+    let valid_count = validity.count_ones();
+    if payload.len() as u64 != header.payload_len || payload.len() != valid_count * header.width as usize {
+        return Err(ErebusError::InvalidOperation(format!(
+            "Corrupt I64Compact payload: {} bytes for {} valid rows of width {}",
+            payload.len(),
+            valid_count,
+            header.width
+        )));
+    }
+
+    let valid_values = decode_compact_ints(&payload, header.base, header.width);
+
+    let mut values = vec![0i64; n_rows];
+    for (i, v) in validity.iter_ones().zip(valid_values) {
+        values[i] = v;
+    }
+
+    Ok((values, validity))
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_vectordata_i64_compact<R: Read + Seek>(
+    reader: &mut ErebusReader<R>,
+) -> ErrorResult<(Vec<i64>, BitVec)>
+{
+    reader.read_magic_and_version()?;
+
+    let global = reader.read_global_header()?;
+
+    if global.object_type != ObjectType::VectorData {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected VectorData, got {:?}", global.object_type),
+        ));
+    }
+    if global.base_type != BaseType::I64 {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected I64, got {:?}", global.base_type),
+        ));
+    }
+    if global.encoding != EncodingType::I64Compact {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected I64Compact, got {:?}", global.encoding),
+        ));
+    }
+
+    read_i64_compact_body(reader, global.compression)
+}
+
+// Top-level entrypoint: reader.compact_int()
+pub struct CompactIntReader<'a, R: Read + Seek> {
+    pub(crate) reader: &'a mut ErebusReader<R>,
+}
+
+impl<'a, R: Read + Seek> CompactIntReader<'a, R> {
+    /// Returns the values widened to i64 and the validity bitmap.
+    pub fn read(self) -> ErrorResult<(Vec<i64>, BitVec)> {
+        read_vectordata_i64_compact(self.reader)
+    }
+}
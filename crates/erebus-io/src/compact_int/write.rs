@@ -0,0 +1,69 @@
+// === Imports ===
+use crate::prelude::*;
+use super::format::{I64CompactHeader, encode_compact_ints};
+use std::io::{Write, Seek};
+
+// === Impl ===
+
+/// Writes the I64Compact header, validity and payload (no global header).
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_i64_compact_body<W: Write + Seek, C: Copy + Into<i64>>(
+    writer: &mut ErebusWriter<W>,
+    values: &[C],
+    validity: &BitVec,
+) -> ErrorResult<()>
+{
+    if values.len() != validity.len() {
+        return Err(ErebusError::LengthMismatch {
+            expected: values.len(),
+            found: validity.len(),
+        });
+    }
+
+    let validity_bytes = pack_validity_bitmap(validity);
+    let (base, width, payload) = encode_compact_ints(values, validity);
+
+    let header = I64CompactHeader {
+        n_rows: values.len() as u64,
+        validity_len: validity_bytes.len() as u64,
+        base,
+        width,
+        payload_len: payload.len() as u64,
+    };
+    header.write(writer.inner_mut())?;
+
+    writer.write_bytes(&validity_bytes)?;
+    writer.write_stream_bytes(&payload)?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_vectordata_i64_compact<W: Write + Seek, C: Copy + Into<i64>>(
+    writer: &mut ErebusWriter<W>,
+    values: &[C],
+    validity: &BitVec,
+) -> ErrorResult<()>
+{
+    writer.write_magic_and_version()?;
+    writer.write_global_header(&ErebusHeader::new(
+        ObjectType::VectorData,
+        BaseType::I64,
+        EncodingType::I64Compact,
+        writer.compression(),
+    ))?;
+
+    write_i64_compact_body(writer, values, validity)
+}
+
+// Top-level entrypoint: writer.compact_int()
+pub struct CompactIntWriter<'a, W: Write + Seek> {
+    pub(crate) writer: &'a mut ErebusWriter<W>,
+}
+
+impl<'a, W: Write + Seek> CompactIntWriter<'a, W> {
+    /// Accepts any signed width up to i64; null rows are not stored.
+    pub fn write<C: Copy + Into<i64>>(self, values: &[C], validity: &BitVec) -> ErrorResult<()> {
+        write_vectordata_i64_compact(self.writer, values, validity)
+    }
+}
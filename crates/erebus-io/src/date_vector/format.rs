@@ -0,0 +1,95 @@
+// === Imports ===
+use crate::prelude::*;
+use std::io::{Read, Write};
+
+// === Impl ===
+
+/// EncodingType variant for dates
+pub const ENCODING_DATE_DELTA: EncodingType = EncodingType::DateDelta;
+
+/// Type-specific header for a date vector using DELTA encoding.
+///
+/// Valid day counts are stored as zigzag varint differences from the
+/// previous valid day (the first from 0). A sorted daily index is
+/// mostly one-byte deltas, which the compression stage then collapses.
+///
+/// - n_rows:       logical row count
+/// - validity_len: length in bytes of the validity bitmap
+/// - payload_len:  length in bytes of the uncompressed delta stream
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) struct DateDeltaHeader {
+    pub n_rows: u64,
+    pub validity_len: u64,
+    pub payload_len: u64,
+}
+
+impl DateDeltaHeader {
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn new(n_rows: u64, validity_len: u64, payload_len: u64) -> Self {
+        Self { n_rows, validity_len, payload_len }
+    }
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn write<W: Write>(&self, w: &mut W) -> ErrorResult<()> {
+        w.write_all(&self.n_rows.to_le_bytes())?;
+        w.write_all(&self.validity_len.to_le_bytes())?;
+        w.write_all(&self.payload_len.to_le_bytes())?;
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn read<R: Read>(r: &mut R) -> ErrorResult<Self> {
+        let mut buf = [0u8; 8];
+
+        r.read_exact(&mut buf)?;
+        let n_rows = u64::from_le_bytes(buf);
+
+        r.read_exact(&mut buf)?;
+        let validity_len = u64::from_le_bytes(buf);
+
+        r.read_exact(&mut buf)?;
+        let payload_len = u64::from_le_bytes(buf);
+
+        Ok(Self { n_rows, validity_len, payload_len })
+    }
+}
+
+/// Delta-encode the valid entries of `days`.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn encode_date_deltas(days: &[i32], validity: &BitVec) -> Vec<u8> {
+    let mut out = Vec::with_capacity(days.len());
+    let mut prev = 0i64;
+    for i in 0..days.len() {
+        if validity[i] {
+            let d = days[i] as i64;
+            write_varint_i64(&mut out, d - prev);
+            prev = d;
+        }
+    }
+    out
+}
+
+/// Decode `count` delta-encoded days.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn decode_date_deltas(bytes: &[u8], count: usize) -> ErrorResult<Vec<i32>> {
+    let mut out = Vec::with_capacity(count);
+    let mut pos = 0usize;
+    let mut prev = 0i64;
+
+    for _ in 0..count {
+        let d = prev.checked_add(read_varint_i64(bytes, &mut pos)?);
+        let d = d.and_then(|d| i32::try_from(d).ok()).ok_or_else(|| {
+            ErebusError::InvalidOperation("Corrupt DateDelta payload: day out of range".into())
+        })?;
+        out.push(d);
+        prev = d as i64;
+    }
+
+    if pos != bytes.len() {
+        return Err(ErebusError::InvalidOperation(format!(
+            "Corrupt DateDelta payload: {} trailing bytes",
+            bytes.len() - pos
+        )));
+    }
+    Ok(out)
+}
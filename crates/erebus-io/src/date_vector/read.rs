@@ -0,0 +1,90 @@
+// === Imports ===
+use crate::prelude::*;
+use super::format::{DateDeltaHeader, decode_date_deltas};
+use std::io::{Read, Seek};
+
+// === Impl ===
+
+/// Reads the DateDelta header, validity and payload (no global header).
+/// Null rows are returned as day 0.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_date_delta_body<R: Read + Seek>(
+    reader: &mut ErebusReader<R>,
+    compression: CompressionType,
+) -> ErrorResult<(Vec<i32>, BitVec)>
+{
+    let header = DateDeltaHeader::read(reader.inner_mut())?;
+    let n_rows = header.n_rows as usize;
+
+    let validity_bytes = reader.read_bytes(header.validity_len as usize)?;
+    if validity_bytes.len() * 8 < n_rows {
+        return Err(ErebusError::InvalidHeader);
+    }
+    let validity = unpack_validity_bitmap(&validity_bytes, n_rows);
+
+    let payload = match compression {
+        CompressionType::None => reader.read_bytes(header.payload_len as usize)?,
+        _ => reader.read_stream_bytes(compression)?,
+    };
+    if payload.len() as u64 != header.payload_len {
+        return Err(ErebusError::InvalidOperation(format!(
+            "Corrupt DateDelta payload: expected {} bytes, got {}",
+            header.payload_len,
+            payload.len()
+        )));
+    }
+
+    let valid_count = validity.count_ones();
+    let valid_days = decode_date_deltas(&payload, valid_count)?;
+
+    let mut days = vec![0i32; n_rows];
+    let mut idx = 0;
+    for (i, d) in days.iter_mut().enumerate() {
+        if validity[i] {
+            *d = valid_days[idx];
+            idx += 1;
+        }
+    }
+
+    Ok((days, validity))
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_date_vector_delta<R: Read + Seek>(
+    reader: &mut ErebusReader<R>,
+) -> ErrorResult<(Vec<i32>, BitVec)>
+{
+    reader.read_magic_and_version()?;
+
+    let global = reader.read_global_header()?;
+
+    if global.object_type != ObjectType::VectorData {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected VectorData, got {:?}", global.object_type),
+        ));
+    }
+    if global.base_type != BaseType::Date {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected Date, got {:?}", global.base_type),
+        ));
+    }
+    if global.encoding != EncodingType::DateDelta {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected DateDelta, got {:?}", global.encoding),
+        ));
+    }
+
+    read_date_delta_body(reader, global.compression)
+}
+
+// Top-level entrypoint: reader.date_vector()
+pub struct DateVectorReader<'a, R: Read + Seek> {
+    pub(crate) reader: &'a mut ErebusReader<R>,
+}
+
+impl<'a, R: Read + Seek> DateVectorReader<'a, R> {
+    /// Returns days since 1970-01-01 and the validity bitmap.
+    pub fn read(self) -> ErrorResult<(Vec<i32>, BitVec)> {
+        read_date_vector_delta(self.reader)
+    }
+}
@@ -0,0 +1,67 @@
+// === Imports ===
+use crate::prelude::*;
+use super::format::{DateDeltaHeader, encode_date_deltas};
+use std::io::{Write, Seek};
+
+// === Impl ===
+
+/// Writes the DateDelta header, validity and payload (no global header).
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_date_delta_body<W: Write + Seek>(
+    writer: &mut ErebusWriter<W>,
+    days: &[i32],
+    validity: &BitVec,
+) -> ErrorResult<()>
+{
+    if days.len() != validity.len() {
+        return Err(ErebusError::LengthMismatch {
+            expected: days.len(),
+            found: validity.len(),
+        });
+    }
+
+    let validity_bytes = pack_validity_bitmap(validity);
+    let payload = encode_date_deltas(days, validity);
+
+    let header = DateDeltaHeader::new(
+        days.len() as u64,
+        validity_bytes.len() as u64,
+        payload.len() as u64,
+    );
+    header.write(writer.inner_mut())?;
+
+    writer.write_bytes(&validity_bytes)?;
+    writer.write_stream_bytes(&payload)?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_date_vector_delta<W: Write + Seek>(
+    writer: &mut ErebusWriter<W>,
+    days: &[i32],
+    validity: &BitVec,
+) -> ErrorResult<()>
+{
+    writer.write_magic_and_version()?;
+    writer.write_global_header(&ErebusHeader::new(
+        ObjectType::VectorData,
+        BaseType::Date,
+        EncodingType::DateDelta,
+        writer.compression(),
+    ))?;
+
+    write_date_delta_body(writer, days, validity)
+}
+
+// Top-level entrypoint: writer.date_vector()
+pub struct DateVectorWriter<'a, W: Write + Seek> {
+    pub(crate) writer: &'a mut ErebusWriter<W>,
+}
+
+impl<'a, W: Write + Seek> DateVectorWriter<'a, W> {
+    /// `days` are days since 1970-01-01; null rows are not stored.
+    pub fn write(self, days: &[i32], validity: &BitVec) -> ErrorResult<()> {
+        write_date_vector_delta(self.writer, days, validity)
+    }
+}
@@ -27,12 +27,14 @@ pub const EREBUS_VERSION: u8 = 1;
 /// Vector       → no nulls, contiguous bytes
 /// VectorData   → validity + typed payload (supports nulls)
 /// Table        → multiple columns, each with its own internal encoding
+/// Calendar     → weekend mask + holiday dates
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
 pub enum ObjectType {
     Vector = 1,
     VectorData = 2,
     Table = 3,
+    Calendar = 4,
 }
 
 impl ObjectType {
@@ -41,6 +43,7 @@ impl ObjectType {
             1 => Ok(Self::Vector),
             2 => Ok(Self::VectorData),
             3 => Ok(Self::Table),
+            4 => Ok(Self::Calendar),
             _ => Err(ErebusError::InvalidDtype(format!("Invalid object type {}", v)))
         }
     }
@@ -60,7 +63,15 @@ pub enum BaseType {
     I64 = 2,
     Bool = 3,
     Text = 4,
-    // Add more: F32, U64, Timestamp, etc.
+    Date = 5, // days since 1970-01-01, i32
+    F32 = 6,
+    I32 = 7,
+    I16 = 8,
+    U8 = 9,
+    U32 = 10,
+    U64 = 11,
+    I128 = 12,
+    // Add more: Timestamp, etc.
 }
 
 impl BaseType {
@@ -70,6 +81,14 @@ impl BaseType {
             2 => Ok(Self::I64),
             3 => Ok(Self::Bool),
             4 => Ok(Self::Text),
+            5 => Ok(Self::Date),
+            6 => Ok(Self::F32),
+            7 => Ok(Self::I32),
+            8 => Ok(Self::I16),
+            9 => Ok(Self::U8),
+            10 => Ok(Self::U32),
+            11 => Ok(Self::U64),
+            12 => Ok(Self::I128),
             _ => Err(ErebusError::InvalidDtype(format!("Invalid base type {}", v))),
         }
     }
@@ -81,6 +100,10 @@ impl BaseType {
 pub enum EncodingType {
     F64Raw = 1,
     F64Factored = 2,
+    DateDelta = 3,
+    TextDictionary = 4,
+    I64Compact = 5,
+    NumericRaw = 6,
     // add others later...
 }
 
@@ -89,6 +112,10 @@ impl EncodingType {
         match v {
             1 => Ok(Self::F64Raw),
             2 => Ok(Self::F64Factored),
+            3 => Ok(Self::DateDelta),
+            4 => Ok(Self::TextDictionary),
+            5 => Ok(Self::I64Compact),
+            6 => Ok(Self::NumericRaw),
             _ => Err(ErebusError::InvalidDtype(format!("Invalid encoding {}", v))),
         }
     }
@@ -6,6 +6,10 @@ pub mod format;
 pub mod write;
 pub mod read;
 pub mod vector_data;
+pub mod date_vector;
+pub mod calendar;
+pub mod categorical;
+pub mod compact_int;
 pub mod utils;
 pub mod compression;
 
@@ -13,5 +17,9 @@ pub use format::*;
 pub use write::*;
 pub use read::*;
 pub use vector_data::*;
+pub use date_vector::{DateVectorReader, DateVectorWriter, ENCODING_DATE_DELTA};
+pub use calendar::{CalendarReader, CalendarWriter};
+pub use categorical::{CategoricalReader, CategoricalWriter, ENCODING_TEXT_DICTIONARY, dictionary_code_width};
+pub use compact_int::{CompactIntReader, CompactIntWriter, ENCODING_I64_COMPACT, compact_offset_width};
 pub use utils::*;
 pub use compression::*;
\ No newline at end of file
@@ -11,5 +11,9 @@ pub use crate::format::*;
 pub use crate::write::*;
 pub use crate::read::*;
 pub use crate::vector_data::*;
+pub use crate::date_vector::{DateVectorReader, DateVectorWriter, ENCODING_DATE_DELTA};
+pub use crate::calendar::{CalendarReader, CalendarWriter};
+pub use crate::categorical::{CategoricalReader, CategoricalWriter, ENCODING_TEXT_DICTIONARY, dictionary_code_width};
+pub use crate::compact_int::{CompactIntReader, CompactIntWriter, ENCODING_I64_COMPACT, compact_offset_width};
 pub use crate::utils::*;
 pub use crate::compression::*;
\ No newline at end of file
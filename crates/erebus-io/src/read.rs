@@ -208,4 +208,20 @@ impl<R: Read + Seek> ErebusReader<R> {
     pub fn vector_data(&mut self) -> VectorDataReader<'_, R> {
         VectorDataReader { reader: self }
     }
+
+    pub fn date_vector(&mut self) -> DateVectorReader<'_, R> {
+        DateVectorReader { reader: self }
+    }
+
+    pub fn calendar(&mut self) -> CalendarReader<'_, R> {
+        CalendarReader { reader: self }
+    }
+
+    pub fn categorical(&mut self) -> CategoricalReader<'_, R> {
+        CategoricalReader { reader: self }
+    }
+
+    pub fn compact_int(&mut self) -> CompactIntReader<'_, R> {
+        CompactIntReader { reader: self }
+    }
 }
\ No newline at end of file
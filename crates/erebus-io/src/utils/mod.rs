@@ -1,3 +1,5 @@
 pub mod bitmap;
+pub mod varint;
 
-pub use bitmap::*;
\ No newline at end of file
+pub(crate) use bitmap::*;
+pub(crate) use varint::*;
@@ -0,0 +1,39 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+
+/// Append `v` as a zigzag LEB128 varint.
+/// Small magnitudes of either sign take a single byte.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_varint_i64(out: &mut Vec<u8>, v: i64) {
+    let mut z = ((v << 1) ^ (v >> 63)) as u64;
+    while z >= 0x80 {
+        out.push((z as u8) | 0x80);
+        z >>= 7;
+    }
+    out.push(z as u8);
+}
+
+/// Read a zigzag LEB128 varint starting at `*pos`, advancing `*pos`.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_varint_i64(bytes: &[u8], pos: &mut usize) -> ErrorResult<i64> {
+    let mut z: u64 = 0;
+    let mut shift = 0u32;
+    loop {
+        let b = *bytes.get(*pos).ok_or_else(|| {
+            ErebusError::InvalidOperation("Truncated varint stream".into())
+        })?;
+        *pos += 1;
+
+        if shift >= 64 {
+            return Err(ErebusError::InvalidOperation("Varint too long".into()));
+        }
+        z |= ((b & 0x7F) as u64) << shift;
+        if b & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(((z >> 1) as i64) ^ -((z & 1) as i64))
+}
@@ -1,7 +1,9 @@
 pub mod read;
 pub mod write;
 pub mod f64;
+pub mod numeric_raw;
 
 pub use read::*;
 pub use write::*;
-pub use f64::*;
\ No newline at end of file
+pub use f64::*;
+pub use numeric_raw::{NumericRawReader, NumericRawWriter, RawElement, ENCODING_NUMERIC_RAW};
\ No newline at end of file
@@ -0,0 +1,89 @@
+// === Imports ===
+use crate::prelude::*;
+use std::io::{Read, Write};
+
+// === Impl ===
+
+/// EncodingType variant for raw fixed-width numerics
+pub const ENCODING_NUMERIC_RAW: EncodingType = EncodingType::NumericRaw;
+
+/// Fixed-width element stored little-endian by the NumericRaw encoding.
+/// `BASE_TYPE` tags the global header so a reader can reject a mismatch.
+pub trait RawElement: Copy + Default {
+    const BASE_TYPE: BaseType;
+    const WIDTH: usize;
+
+    fn write_le(self, out: &mut Vec<u8>);
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_raw_element {
+    ($($t:ty => $base:ident),*) => {$(
+        impl RawElement for $t {
+            const BASE_TYPE: BaseType = BaseType::$base;
+            const WIDTH: usize = std::mem::size_of::<$t>();
+
+            #[inline]
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            #[inline]
+            fn read_le(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().unwrap())
+            }
+        }
+    )*};
+}
+
+impl_raw_element!(
+    f32 => F32,
+    i64 => I64,
+    i32 => I32,
+    i16 => I16,
+    i128 => I128,
+    u8 => U8,
+    u32 => U32,
+    u64 => U64
+);
+
+/// Type-specific header for VectorData<T> using NumericRaw encoding.
+///
+/// The element type comes from the global header's BaseType; valid values
+/// follow the validity bitmap as `WIDTH`-byte little-endian words.
+///
+/// - n_rows:       logical row count
+/// - validity_len: length in bytes of the validity bitmap
+/// - payload_len:  length in bytes of the uncompressed value stream
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) struct NumericRawHeader {
+    pub n_rows: u64,
+    pub validity_len: u64,
+    pub payload_len: u64,
+}
+
+impl NumericRawHeader {
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn write<W: Write>(&self, w: &mut W) -> ErrorResult<()> {
+        w.write_all(&self.n_rows.to_le_bytes())?;
+        w.write_all(&self.validity_len.to_le_bytes())?;
+        w.write_all(&self.payload_len.to_le_bytes())?;
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn read<R: Read>(r: &mut R) -> ErrorResult<Self> {
+        let mut buf = [0u8; 8];
+
+        r.read_exact(&mut buf)?;
+        let n_rows = u64::from_le_bytes(buf);
+
+        r.read_exact(&mut buf)?;
+        let validity_len = u64::from_le_bytes(buf);
+
+        r.read_exact(&mut buf)?;
+        let payload_len = u64::from_le_bytes(buf);
+
+        Ok(Self { n_rows, validity_len, payload_len })
+    }
+}
@@ -0,0 +1,76 @@
+// === Imports ===
+use crate::prelude::*;
+use super::format::NumericRawHeader;
+use std::io::{Read, Seek};
+use std::marker::PhantomData;
+
+// === Impl ===
+
+/// Reads a NumericRaw column of `T`. Null rows are returned as `T::default()`.
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn read_vectordata_numeric_raw<R: Read + Seek, T: RawElement>(
+    reader: &mut ErebusReader<R>,
+) -> ErrorResult<(Vec<T>, BitVec)>
+{
+    reader.read_magic_and_version()?;
+
+    let global = reader.read_global_header()?;
+
+    if global.object_type != ObjectType::VectorData {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected VectorData, got {:?}", global.object_type),
+        ));
+    }
+    if global.base_type != T::BASE_TYPE {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected {:?}, got {:?}", T::BASE_TYPE, global.base_type),
+        ));
+    }
+    if global.encoding != EncodingType::NumericRaw {
+        return Err(ErebusError::InvalidOperation(
+            format!("Expected NumericRaw, got {:?}", global.encoding),
+        ));
+    }
+
+    let header = NumericRawHeader::read(reader.inner_mut())?;
+    let n_rows = header.n_rows as usize;
+
+    let validity_bytes = reader.read_bytes(header.validity_len as usize)?;
+    if validity_bytes.len() * 8 < n_rows {
+        return Err(ErebusError::InvalidHeader);
+    }
+    let validity = unpack_validity_bitmap(&validity_bytes, n_rows);
+
+    let payload = match global.compression {
+        CompressionType::None => reader.read_bytes(header.payload_len as usize)?,
+        compression => reader.read_stream_bytes(compression)?,
+    };
+    let valid_count = validity.count_ones();
+    if payload.len() as u64 != header.payload_len || payload.len() != valid_count * T::WIDTH {
+        return Err(ErebusError::InvalidOperation(format!(
+            "Corrupt NumericRaw payload: {} bytes for {} valid rows of width {}",
+            payload.len(),
+            valid_count,
+            T::WIDTH
+        )));
+    }
+
+    let mut values = vec![T::default(); n_rows];
+    for (i, chunk) in validity.iter_ones().zip(payload.chunks_exact(T::WIDTH)) {
+        values[i] = T::read_le(chunk);
+    }
+
+    Ok((values, validity))
+}
+
+// Entrypoint: reader.vector_data().raw::<T>()
+pub struct NumericRawReader<'a, R: Read + Seek, T: RawElement> {
+    pub(crate) reader: &'a mut ErebusReader<R>,
+    pub(crate) _elem: PhantomData<T>,
+}
+
+impl<'a, R: Read + Seek, T: RawElement> NumericRawReader<'a, R, T> {
+    pub fn read(self) -> ErrorResult<(Vec<T>, BitVec)> {
+        read_vectordata_numeric_raw(self.reader)
+    }
+}
@@ -0,0 +1,61 @@
+// === Imports ===
+use crate::prelude::*;
+use super::format::NumericRawHeader;
+use std::io::{Write, Seek};
+use std::marker::PhantomData;
+
+// === Impl ===
+
+#[cfg_attr(feature = "internal", visibility::make(pub))]
+pub(crate) fn write_vectordata_numeric_raw<W: Write + Seek, T: RawElement>(
+    writer: &mut ErebusWriter<W>,
+    values: &[T],
+    validity: &BitVec,
+) -> ErrorResult<()>
+{
+    if values.len() != validity.len() {
+        return Err(ErebusError::LengthMismatch {
+            expected: values.len(),
+            found: validity.len(),
+        });
+    }
+
+    let validity_bytes = pack_validity_bitmap(validity);
+
+    let mut payload = Vec::with_capacity(validity.count_ones() * T::WIDTH);
+    for i in validity.iter_ones() {
+        values[i].write_le(&mut payload);
+    }
+
+    writer.write_magic_and_version()?;
+    writer.write_global_header(&ErebusHeader::new(
+        ObjectType::VectorData,
+        T::BASE_TYPE,
+        EncodingType::NumericRaw,
+        writer.compression(),
+    ))?;
+
+    let header = NumericRawHeader {
+        n_rows: values.len() as u64,
+        validity_len: validity_bytes.len() as u64,
+        payload_len: payload.len() as u64,
+    };
+    header.write(writer.inner_mut())?;
+
+    writer.write_bytes(&validity_bytes)?;
+    writer.write_stream_bytes(&payload)?;
+
+    Ok(())
+}
+
+// Entrypoint: writer.vector_data().raw::<T>()
+pub struct NumericRawWriter<'a, W: Write + Seek, T: RawElement> {
+    pub(crate) writer: &'a mut ErebusWriter<W>,
+    pub(crate) _elem: PhantomData<T>,
+}
+
+impl<'a, W: Write + Seek, T: RawElement> NumericRawWriter<'a, W, T> {
+    pub fn write(self, values: &[T], validity: &BitVec) -> ErrorResult<()> {
+        write_vectordata_numeric_raw(self.writer, values, validity)
+    }
+}
@@ -1,6 +1,7 @@
 // === Imports ===
 use crate::prelude::*;
 use std::io::{Read, Seek};
+use std::marker::PhantomData;
 
 // === Impl ===
 
@@ -13,4 +14,9 @@ impl<'a, R: Read + Seek> VectorDataReader<'a, R> {
     pub fn f64(self) -> F64Reader<'a, R> {
         F64Reader { reader: self.reader }
     }
+
+    /// Fixed-width numerics other than f64, e.g. `raw::<u32>()`.
+    pub fn raw<T: RawElement>(self) -> NumericRawReader<'a, R, T> {
+        NumericRawReader { reader: self.reader, _elem: PhantomData }
+    }
 }
\ No newline at end of file
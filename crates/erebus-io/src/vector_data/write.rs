@@ -1,6 +1,7 @@
 // === Imports ===
 use crate::prelude::*;
 use std::io::{Write, Seek};
+use std::marker::PhantomData;
 
 // === Impl ===
 
@@ -13,4 +14,9 @@ impl<'a, W: Write + Seek> VectorDataWriter<'a, W> {
     pub fn f64(self) -> F64Writer<'a, W> {
         F64Writer { writer: self.writer }
     }
+
+    /// Fixed-width numerics other than f64, e.g. `raw::<u32>()`.
+    pub fn raw<T: RawElement>(self) -> NumericRawWriter<'a, W, T> {
+        NumericRawWriter { writer: self.writer, _elem: PhantomData }
+    }
 }
\ No newline at end of file
@@ -122,4 +122,20 @@ impl<W: Write + Seek> ErebusWriter<W> {
     pub fn vector_data(&mut self) -> VectorDataWriter<W> {
         VectorDataWriter { writer: self }
     }
+
+    pub fn date_vector(&mut self) -> DateVectorWriter<'_, W> {
+        DateVectorWriter { writer: self }
+    }
+
+    pub fn calendar(&mut self) -> CalendarWriter<'_, W> {
+        CalendarWriter { writer: self }
+    }
+
+    pub fn categorical(&mut self) -> CategoricalWriter<'_, W> {
+        CategoricalWriter { writer: self }
+    }
+
+    pub fn compact_int(&mut self) -> CompactIntWriter<'_, W> {
+        CompactIntWriter { writer: self }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,34 @@
+// === Imports ===
+use erebus_io::prelude::*;
+use std::io::{Cursor, Seek, SeekFrom};
+
+// === Tests ===
+
+#[test]
+fn test_calendar_roundtrip() {
+    let holidays = vec![19_358, 19_373, 19_408, 19_485];
+
+    for c in [CompressionType::None, CompressionType::Zstd] {
+        let mut writer = ErebusWriter::new(Cursor::new(Vec::new())).with_compression(c);
+        writer.calendar().write(0b0110_0000, &holidays).unwrap();
+
+        let mut cursor = writer.inner_mut().clone();
+        cursor.seek(SeekFrom::Start(0)).unwrap();
+        let mut reader = ErebusReader::new(cursor);
+
+        let (mask, out) = reader.calendar().read().unwrap();
+        assert_eq!(mask, 0b0110_0000);
+        assert_eq!(out, holidays);
+    }
+}
+
+#[test]
+fn test_calendar_reader_rejects_date_vector() {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    writer.date_vector().write(&[1, 2], &bitvec![1; 2]).unwrap();
+
+    let mut cursor = writer.inner_mut().clone();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    let mut reader = ErebusReader::new(cursor);
+    assert!(reader.calendar().read().is_err());
+}
@@ -0,0 +1 @@
+mod text_dict;
\ No newline at end of file
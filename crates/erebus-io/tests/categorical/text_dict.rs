@@ -0,0 +1,60 @@
+// === Imports ===
+use erebus_io::prelude::*;
+use std::io::{Cursor, Seek, SeekFrom};
+
+// === Tests ===
+
+fn dict(xs: &[&str]) -> Vec<String> {
+    xs.iter().map(|s| s.to_string()).collect()
+}
+
+fn rewind(writer: &mut ErebusWriter<Cursor<Vec<u8>>>) -> ErebusReader<Cursor<Vec<u8>>> {
+    let mut cursor = writer.inner_mut().clone();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    ErebusReader::new(cursor)
+}
+
+#[test]
+fn test_text_dictionary_roundtrip_with_nulls() {
+    let dictionary = dict(&["AAPL", "", "Ünïcode"]);
+    let codes: Vec<u16> = vec![2, 0, 9, 1, 0];
+    let validity = bitvec![1, 1, 0, 1, 1];
+
+    for c in [CompressionType::None, CompressionType::Zstd, CompressionType::Lz4] {
+        let mut writer = ErebusWriter::new(Cursor::new(Vec::new())).with_compression(c);
+        writer.categorical().write(&dictionary, &codes, &validity).unwrap();
+
+        let (out_dict, out_codes, out_validity) = rewind(&mut writer).categorical().read().unwrap();
+        assert_eq!(out_dict, dictionary);
+        assert_eq!(out_validity, validity);
+        assert_eq!(out_codes, vec![2, 0, 0, 1, 0]);
+    }
+}
+
+#[test]
+fn test_text_dictionary_uses_narrow_codes() {
+    assert_eq!(dictionary_code_width(256), 1);
+    assert_eq!(dictionary_code_width(257), 2);
+    assert_eq!(dictionary_code_width(70_000), 4);
+
+    let dictionary = dict(&["a", "b"]);
+    let codes: Vec<u32> = (0..10_000).map(|i| i % 2).collect();
+    let validity = bitvec![1; codes.len()];
+
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    writer.categorical().write(&dictionary, &codes, &validity).unwrap();
+    // One byte per row plus headers, dictionary and validity
+    assert!(writer.inner_mut().get_ref().len() < 10_000 + 2_000);
+}
+
+#[test]
+fn test_text_dictionary_rejects_bad_input() {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    assert!(writer.categorical().write(&dict(&["a"]), &[0u8, 0], &bitvec![1; 1]).is_err());
+    assert!(writer.categorical().write(&dict(&["a"]), &[1u8], &bitvec![1; 1]).is_err());
+
+    // Wrong object on disk
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    writer.date_vector().write(&[1, 2], &bitvec![1; 2]).unwrap();
+    assert!(rewind(&mut writer).categorical().read().is_err());
+}
@@ -0,0 +1,57 @@
+// === Imports ===
+use erebus_io::prelude::*;
+use std::io::{Cursor, Seek, SeekFrom};
+
+// === Tests ===
+
+fn roundtrip<C: Copy + Into<i64>>(values: &[C], validity: &BitVec, compression: CompressionType) -> (Vec<i64>, BitVec, usize) {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new())).with_compression(compression);
+    writer.compact_int().write(values, validity).unwrap();
+
+    let mut cursor = writer.inner_mut().clone();
+    let size = cursor.get_ref().len();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    let (out, out_validity) = ErebusReader::new(cursor).compact_int().read().unwrap();
+    (out, out_validity, size)
+}
+
+#[test]
+fn test_compact_int_roundtrip_with_nulls() {
+    let values: Vec<i64> = vec![-7, 99, 123_456_789, i64::MIN, i64::MAX, 0];
+    let mut validity = bitvec![1; values.len()];
+    validity.set(5, false);
+
+    for c in [CompressionType::None, CompressionType::Zstd, CompressionType::Lz4] {
+        let (out, out_validity, _) = roundtrip(&values, &validity, c);
+        assert_eq!(out_validity, validity);
+        assert_eq!(out, vec![-7, 99, 123_456_789, i64::MIN, i64::MAX, 0]);
+    }
+}
+
+#[test]
+fn test_compact_int_frame_of_reference_is_small() {
+    // Ids in a narrow band far from zero pack into one byte each
+    let values: Vec<i64> = (0..10_000).map(|i| 9_000_000_000 + i % 200).collect();
+    let validity = bitvec![1; values.len()];
+
+    let (out, _, size) = roundtrip(&values, &validity, CompressionType::None);
+    assert_eq!(out, values);
+    assert!(size < 10_000 + 2_000);
+
+    assert_eq!(compact_offset_width(255), 1);
+    assert_eq!(compact_offset_width(256), 2);
+    assert_eq!(compact_offset_width(u64::MAX), 8);
+}
+
+#[test]
+fn test_compact_int_narrow_inputs_and_errors() {
+    let (out, _, _) = roundtrip(&[-3i8, 4, 100], &bitvec![1; 3], CompressionType::Zstd);
+    assert_eq!(out, vec![-3, 4, 100]);
+
+    let (out, validity, _) = roundtrip(&[5i16, 6], &bitvec![0; 2], CompressionType::None);
+    assert_eq!((out, validity), (vec![0, 0], bitvec![0; 2]));
+
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    assert!(writer.compact_int().write(&[1i32, 2], &bitvec![1; 1]).is_err());
+}
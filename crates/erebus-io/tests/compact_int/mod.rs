@@ -0,0 +1 @@
+mod compact;
\ No newline at end of file
@@ -0,0 +1 @@
+mod categorical;
\ No newline at end of file
@@ -0,0 +1 @@
+mod compact_int;
\ No newline at end of file
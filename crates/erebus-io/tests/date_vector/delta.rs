@@ -0,0 +1,72 @@
+// === Imports ===
+use erebus_io::prelude::*;
+use std::io::{Cursor, Seek, SeekFrom};
+
+// === Tests ===
+
+fn roundtrip(days: &[i32], validity: &BitVec, compression: CompressionType) -> (Vec<i32>, BitVec, usize) {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new())).with_compression(compression);
+    writer.date_vector().write(days, validity).unwrap();
+
+    let mut cursor = writer.inner_mut().clone();
+    let size = cursor.get_ref().len();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    let mut reader = ErebusReader::new(cursor);
+    let (out, out_validity) = reader.date_vector().read().unwrap();
+    (out, out_validity, size)
+}
+
+#[test]
+fn test_date_vector_delta_roundtrip_with_nulls() {
+    // Unsorted, negative (pre-1970) and extreme values
+    let days = vec![19_723, 0, -25_567, 7, i32::MAX, i32::MIN, 19_000];
+    let mut validity = bitvec![1; days.len()];
+    validity.set(1, false);
+
+    for c in [CompressionType::None, CompressionType::Zstd, CompressionType::Lz4] {
+        let (out, out_validity, _) = roundtrip(&days, &validity, c);
+        assert_eq!(out_validity, validity);
+        assert_eq!(out, vec![19_723, 0, -25_567, 7, i32::MAX, i32::MIN, 19_000]);
+    }
+}
+
+#[test]
+fn test_date_vector_delta_sorted_index_is_small() {
+    // Ten years of weekdays: deltas are 1 or 3
+    let days: Vec<i32> = (18_000..21_650).filter(|d| (d + 3) % 7 < 5).collect();
+    let validity = bitvec![1; days.len()];
+
+    let (out, _, raw_size) = roundtrip(&days, &validity, CompressionType::None);
+    assert_eq!(out, days);
+    // One byte per row plus headers and bitmap, instead of four
+    assert!(raw_size < days.len() * 2);
+
+    let (out, _, zstd_size) = roundtrip(&days, &validity, CompressionType::Zstd);
+    assert_eq!(out, days);
+    assert!(zstd_size < raw_size / 4);
+}
+
+#[test]
+fn test_date_vector_empty() {
+    let (out, validity, _) = roundtrip(&[], &BitVec::new(), CompressionType::None);
+    assert!(out.is_empty());
+    assert!(validity.is_empty());
+}
+
+#[test]
+fn test_date_vector_rejects_wrong_object() {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    writer.vector_data().f64().raw().write(&[1.0], &bitvec![1; 1]).unwrap();
+
+    let mut cursor = writer.inner_mut().clone();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    let mut reader = ErebusReader::new(cursor);
+    assert!(reader.date_vector().read().is_err());
+}
+
+#[test]
+fn test_date_vector_write_length_mismatch() {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    assert!(writer.date_vector().write(&[1, 2], &bitvec![1; 1]).is_err());
+}
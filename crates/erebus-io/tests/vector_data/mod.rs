@@ -1 +1,2 @@
-mod f64;
\ No newline at end of file
+mod f64;
+mod numeric_raw;
\ No newline at end of file
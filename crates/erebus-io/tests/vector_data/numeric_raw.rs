@@ -0,0 +1,73 @@
+// === Imports ===
+use erebus_io::prelude::*;
+use std::io::{Cursor, Seek, SeekFrom};
+
+// === Tests ===
+
+fn roundtrip<T: RawElement>(values: &[T], validity: &BitVec, compression: CompressionType) -> (Vec<T>, BitVec) {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new())).with_compression(compression);
+    writer.vector_data().raw::<T>().write(values, validity).unwrap();
+
+    let mut cursor = writer.inner_mut().clone();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    ErebusReader::new(cursor).vector_data().raw::<T>().read().unwrap()
+}
+
+#[test]
+fn test_numeric_raw_roundtrip_each_type() {
+    let mut validity = bitvec![1; 4];
+    validity.set(1, false);
+
+    for c in [CompressionType::None, CompressionType::Zstd, CompressionType::Lz4] {
+        let (out, v) = roundtrip(&[1.5f32, 9.0, f32::MIN, -0.25], &validity, c);
+        assert_eq!(v, validity);
+        assert_eq!(out, vec![1.5, 0.0, f32::MIN, -0.25]);
+
+        let (out, _) = roundtrip(&[i32::MIN, 7, -1, i32::MAX], &validity, c);
+        assert_eq!(out, vec![i32::MIN, 0, -1, i32::MAX]);
+
+        let (out, _) = roundtrip(&[-300i16, 7, 0, i16::MAX], &validity, c);
+        assert_eq!(out, vec![-300, 0, 0, i16::MAX]);
+
+        let (out, _) = roundtrip(&[0u8, 7, 128, 255], &validity, c);
+        assert_eq!(out, vec![0, 0, 128, 255]);
+
+        let (out, _) = roundtrip(&[u32::MAX, 7, 1, 2], &validity, c);
+        assert_eq!(out, vec![u32::MAX, 0, 1, 2]);
+
+        let (out, _) = roundtrip(&[u64::MAX, 7, 1, 2], &validity, c);
+        assert_eq!(out, vec![u64::MAX, 0, 1, 2]);
+
+        let (out, _) = roundtrip(&[i128::MIN, 7, 1, i128::MAX], &validity, c);
+        assert_eq!(out, vec![i128::MIN, 0, 1, i128::MAX]);
+
+        let (out, _) = roundtrip(&[i64::MIN, 7, 1, i64::MAX], &validity, c);
+        assert_eq!(out, vec![i64::MIN, 0, 1, i64::MAX]);
+    }
+}
+
+#[test]
+fn test_numeric_raw_rejects_other_base_type() {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    writer.vector_data().raw::<u32>().write(&[1, 2, 3], &bitvec![1; 3]).unwrap();
+
+    let mut cursor = writer.inner_mut().clone();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+
+    let err = ErebusReader::new(cursor).vector_data().raw::<i32>().read();
+    assert!(err.is_err());
+}
+
+#[test]
+fn test_numeric_raw_header_tags_and_length_mismatch() {
+    assert_eq!(BaseType::from_u8(BaseType::I128.to_u8()).unwrap(), BaseType::I128);
+    assert_eq!(<u8 as RawElement>::BASE_TYPE, BaseType::U8);
+    assert_eq!(<i128 as RawElement>::WIDTH, 16);
+    assert_eq!(EncodingType::from_u8(6).unwrap(), EncodingType::NumericRaw);
+    assert!(BaseType::from_u8(13).is_err());
+
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    let res = writer.vector_data().raw::<f32>().write(&[1.0, 2.0], &bitvec![1; 3]);
+    assert!(res.is_err());
+}
@@ -11,4 +11,18 @@ pub trait Calendar {
     fn is_business_day(&self, date: Date) -> bool {
         !self.is_weekend(date) && !self.is_holiday(date)
     }
+
+    /// All business days in `[start, end]`, in ascending order.
+    /// Implementors with direct access to their weekend and holiday
+    /// data should override this to avoid a per-day lookup.
+    fn business_days_between(&self, start: Date, end: Date) -> DateVector {
+        let mut days = Vec::new();
+        for d in start.days()..=end.days() {
+            if self.is_business_day(Date { days: d }) {
+                days.push(d);
+            }
+        }
+        let n = days.len();
+        DateVector { days, validity: bitvec![1; n] }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,94 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// Rule for rolling a date that falls on a non-business day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusinessDayConvention {
+
+    /// Leave the date as is.
+    Unadjusted,
+
+    /// Roll forward to the next business day.
+    Following,
+
+    /// Roll forward, unless that crosses into the next month,
+    /// in which case roll backward.
+    ModifiedFollowing,
+
+    /// Roll backward to the previous business day.
+    Preceding,
+
+    /// Roll backward, unless that crosses into the previous month,
+    /// in which case roll forward.
+    ModifiedPreceding,
+}
+
+// === Impls ===
+
+impl Default for BusinessDayConvention {
+    #[inline]
+    fn default() -> Self {
+        BusinessDayConvention::ModifiedFollowing
+    }
+}
+
+impl BusinessDayConvention {
+
+    /// Adjusts `date` on `cal` under this convention.
+    /// Errors if `cal` has no business day within [`MAX_ROLL_DAYS`].
+    #[inline]
+    pub fn adjust<C: Calendar>(&self, cal: &C, date: Date) -> ErrorResult<Date> {
+        Ok(match *self {
+            BusinessDayConvention::Unadjusted => date,
+            BusinessDayConvention::Following => roll_following(cal, date)?,
+            BusinessDayConvention::Preceding => roll_preceding(cal, date)?,
+            BusinessDayConvention::ModifiedFollowing => {
+                let d = roll_following(cal, date)?;
+                if days_to_ymd(d.days).1 != days_to_ymd(date.days).1 {
+                    roll_preceding(cal, date)?
+                } else {
+                    d
+                }
+            }
+            BusinessDayConvention::ModifiedPreceding => {
+                let d = roll_preceding(cal, date)?;
+                if days_to_ymd(d.days).1 != days_to_ymd(date.days).1 {
+                    roll_following(cal, date)?
+                } else {
+                    d
+                }
+            }
+        })
+    }
+}
+
+/// Longest run of non-business days a roll will step over before giving up,
+/// so a calendar with no business days errors instead of looping forever.
+pub const MAX_ROLL_DAYS: i32 = 366 * 10;
+
+#[inline]
+fn roll_following<C: Calendar>(cal: &C, date: Date) -> ErrorResult<Date> {
+    roll(cal, date, 1)
+}
+
+#[inline]
+fn roll_preceding<C: Calendar>(cal: &C, date: Date) -> ErrorResult<Date> {
+    roll(cal, date, -1)
+}
+
+#[inline]
+fn roll<C: Calendar>(cal: &C, date: Date, step: i32) -> ErrorResult<Date> {
+    let mut d = date.days;
+    for _ in 0..=MAX_ROLL_DAYS {
+        if cal.is_business_day(Date { days: d }) {
+            return Ok(Date { days: d });
+        }
+        d += step;
+    }
+    Err(ErebusError::InvalidOperation(format!(
+        "no business day within {} days of day {}",
+        MAX_ROLL_DAYS, date.days
+    )))
+}
@@ -45,8 +45,22 @@ impl HolidaySet {
         Ok(Self::new(days))
     }
 
+    /// Sorted holiday day counts.
+    #[inline]
+    pub fn days(&self) -> &[i32] {
+        &self.days
+    }
+
     #[inline]
     pub fn is_holiday(&self, date: Date) -> bool {
         self.days.binary_search(&date.days()).is_ok()
     }
+
+    /// Sorted holidays falling in `[start, end]`.
+    #[inline]
+    pub(crate) fn days_between(&self, start: i32, end: i32) -> &[i32] {
+        let lo = self.days.partition_point(|&d| d < start);
+        let hi = self.days.partition_point(|&d| d <= end);
+        &self.days[lo..hi.max(lo)]
+    }
 }
\ No newline at end of file
@@ -0,0 +1,65 @@
+// === Imports ===
+use crate::prelude::*;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+
+// === Impl ===
+
+impl HolidaySet {
+
+    /// Writes the holidays as a `.erebus` date object.
+    #[inline]
+    pub fn write_to<W: Write + Seek>(&self, writer: &mut ErebusWriter<W>) -> ErrorResult<()> {
+        writer.date_vector().write(self.days(), &bitvec![1; self.days().len()])
+    }
+
+    /// Reads a `.erebus` date object; null rows are skipped.
+    pub fn read_from<R: Read + Seek>(reader: &mut ErebusReader<R>) -> ErrorResult<Self> {
+        let (days, validity) = reader.date_vector().read()?;
+        let days = days.into_iter().zip(validity.iter()).filter(|(_, v)| **v).map(|(d, _)| d);
+        Ok(Self::new(days.collect()))
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P, compression: CompressionType) -> ErrorResult<()> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut writer = ErebusWriter::new(file).with_compression(compression);
+        self.write_to(&mut writer)?;
+        writer.inner_mut().flush()?;
+        Ok(())
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ErrorResult<Self> {
+        let mut reader = ErebusReader::new(BufReader::new(File::open(path)?));
+        Self::read_from(&mut reader)
+    }
+}
+
+impl MarketCalendar {
+
+    /// Writes the weekend rule and holidays as a `.erebus` calendar object.
+    /// Trading hours are not stored.
+    #[inline]
+    pub fn write_to<W: Write + Seek>(&self, writer: &mut ErebusWriter<W>) -> ErrorResult<()> {
+        writer.calendar().write(self.weekend().to_mask(), self.holidays().days())
+    }
+
+    /// Reads a `.erebus` calendar object.
+    pub fn read_from<R: Read + Seek>(reader: &mut ErebusReader<R>) -> ErrorResult<Self> {
+        let (mask, days) = reader.calendar().read()?;
+        Ok(Self::new(WeekendRule::from_mask(mask)?, HolidaySet::new(days)))
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P, compression: CompressionType) -> ErrorResult<()> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut writer = ErebusWriter::new(file).with_compression(compression);
+        self.write_to(&mut writer)?;
+        writer.inner_mut().flush()?;
+        Ok(())
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ErrorResult<Self> {
+        let mut reader = ErebusReader::new(BufReader::new(File::open(path)?));
+        Self::read_from(&mut reader)
+    }
+}
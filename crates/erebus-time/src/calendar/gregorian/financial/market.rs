@@ -6,6 +6,7 @@ use crate::prelude::*;
 pub struct MarketCalendar {
     weekend: WeekendRule,
     holidays: HolidaySet,
+    hours: MarketHours,
 }
 
 // === Impls ===
@@ -16,7 +17,29 @@ impl MarketCalendar {
         weekend: WeekendRule,
         holidays: HolidaySet,
     ) -> Self {
-        Self { weekend, holidays }
+        Self { weekend, holidays, hours: MarketHours::default() }
+    }
+
+    /// Sets the trading hours (default: UTC, all day).
+    #[inline]
+    pub fn with_hours(mut self, hours: MarketHours) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    #[inline]
+    pub fn hours(&self) -> &MarketHours {
+        &self.hours
+    }
+
+    #[inline]
+    pub fn weekend(&self) -> WeekendRule {
+        self.weekend
+    }
+
+    #[inline]
+    pub fn holidays(&self) -> &HolidaySet {
+        &self.holidays
     }
 }
 
@@ -30,4 +53,33 @@ impl Calendar for MarketCalendar {
     fn is_holiday(&self, date: Date) -> bool {
         self.holidays.is_holiday(date)
     }
+
+    /// Walks the weekday cycle and the sorted holiday list side by side,
+    /// so no per-day holiday search is needed.
+    fn business_days_between(&self, start: Date, end: Date) -> DateVector {
+        let (s, e) = (start.days(), end.days());
+        if s > e {
+            return DateVector { days: Vec::new(), validity: BitVec::new() };
+        }
+
+        let holidays = self.holidays.days_between(s, e);
+        let mut h = 0usize;
+        let mut wd = days_to_weekday_iso(s);
+
+        let mut days = Vec::with_capacity((e - s + 1) as usize * 5 / 7 + 1);
+        for d in s..=e {
+            if !self.weekend.is_weekend_weekday(wd) {
+                while h < holidays.len() && holidays[h] < d {
+                    h += 1;
+                }
+                if h >= holidays.len() || holidays[h] != d {
+                    days.push(d);
+                }
+            }
+            wd = if wd == 7 { 1 } else { wd + 1 };
+        }
+
+        let n = days.len();
+        DateVector { days, validity: bitvec![1; n] }
+    }
 }
\ No newline at end of file
@@ -1,7 +1,12 @@
+pub mod adjust;
 pub mod holidays;
+pub mod io;
 pub mod market;
 pub mod nyse;
+pub mod session;
 
+pub use adjust::*;
 pub use holidays::*;
 pub use market::*;
-pub use nyse::*;
\ No newline at end of file
+pub use nyse::*;
+pub use session::*;
\ No newline at end of file
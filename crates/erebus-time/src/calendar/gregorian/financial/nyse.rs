@@ -7,13 +7,41 @@ use std::io::Cursor;
 const NYSE_HOLIDAYS_CSV: &str =
     include_str!("../../../../data/calendars/nyse/nyse_holidays.csv");
 
+/// America/New_York TZif, embedded so sessions before the 2007 US DST
+/// change resolve the same way on hosts without a zoneinfo database.
+const NEW_YORK_TZIF: &[u8] =
+    include_bytes!("../../../../data/timezones/America/New_York");
+
+/// First year from which the rule-based early closes below match
+/// the published NYSE schedule.
+const NYSE_EARLY_CLOSE_FROM: i32 = 2003;
+
 /// Returns the NYSE market calendar.
+///
+/// Regular hours are 09:30-16:00 America/New_York. The market closes
+/// at 13:00 on the day after Thanksgiving, and on July 3 and December 24
+/// when those are trading days (on a Friday they are observed holidays).
 pub fn get_nyse_calendar() -> ErrorResult<MarketCalendar> {
     let cursor = Cursor::new(NYSE_HOLIDAYS_CSV.as_bytes());
     let holidays = HolidaySet::from_csv_reader(cursor)?;
+    let last_year = holidays.days().last().map_or(NYSE_EARLY_CLOSE_FROM, |&d| days_to_ymd(d).0);
+
+    let tz = TimeZone::from_tzif_bytes("America/New_York", NEW_YORK_TZIF)?;
+    let mut hours = MarketHours::new(tz, TradingSession::regular((9, 30), (16, 0))?);
+
+    let base = MarketCalendar::new(WeekendRule::SaturdaySunday, holidays);
+    for year in NYSE_EARLY_CLOSE_FROM..=last_year {
+        let mut candidates = vec![ymd_to_days(year, 7, 3), ymd_to_days(year, 12, 24)];
+        if let Some(thanksgiving) = nth_weekday_of_month(year, 11, 4, 4) {
+            candidates.push(thanksgiving + 1);
+        }
+        for days in candidates {
+            let date = Date { days };
+            if base.is_business_day(date) {
+                hours = hours.with_early_close(date, (13, 0))?;
+            }
+        }
+    }
 
-    Ok(MarketCalendar::new(
-        WeekendRule::SaturdaySunday,
-        holidays,
-    ))
-}
\ No newline at end of file
+    Ok(base.with_hours(hours))
+}
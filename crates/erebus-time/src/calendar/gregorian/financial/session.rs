@@ -0,0 +1,283 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Constants ===
+
+const SECONDS_PER_DAY: u32 = 86_400;
+
+/// How far `next_open` / `previous_close` look before giving up.
+const MAX_SEARCH_DAYS: i32 = 3_660;
+
+// === Types ===
+
+/// Trading interval `[open, close)` in seconds after local midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionSegment {
+    open: u32,
+    close: u32,
+}
+
+/// Trading hours of one day: ordered, non-overlapping segments.
+/// Gaps between segments are breaks (e.g. a lunch break).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TradingSession {
+    segments: Vec<SessionSegment>,
+}
+
+/// Exchange trading hours in a time zone, with per-date overrides
+/// for early closes, half days and other special sessions.
+#[derive(Debug, Clone)]
+pub struct MarketHours {
+    tz: TimeZone,
+    regular: TradingSession,
+    special: Vec<(i32, TradingSession)>, // sorted by day, unique
+}
+
+// === Impls ===
+
+#[inline]
+fn hm_to_secs(hour: u8, minute: u8) -> ErrorResult<u32> {
+    // 24:00 is allowed as an end of day
+    if (hour, minute) != (24, 0) {
+        validate_hms_strict(hour, minute, 0, 0)?;
+    }
+    Ok(hour as u32 * 3600 + minute as u32 * 60)
+}
+
+impl SessionSegment {
+
+    /// Segment from `open` to `close`, both `(hour, minute)` local time.
+    /// `close` may be `(24, 0)`.
+    pub fn new(open: (u8, u8), close: (u8, u8)) -> ErrorResult<Self> {
+        let (o, c) = (hm_to_secs(open.0, open.1)?, hm_to_secs(close.0, close.1)?);
+        if o >= c {
+            return Err(ErebusError::InvalidOperation(format!(
+                "Session opens at {:02}:{:02} but closes at {:02}:{:02}",
+                open.0, open.1, close.0, close.1
+            )));
+        }
+        Ok(Self { open: o, close: c })
+    }
+
+    /// Open, in seconds after local midnight.
+    #[inline]
+    pub fn open_seconds(&self) -> u32 {
+        self.open
+    }
+
+    /// Close, in seconds after local midnight.
+    #[inline]
+    pub fn close_seconds(&self) -> u32 {
+        self.close
+    }
+}
+
+impl TradingSession {
+
+    /// Session made of `segments`, which must be ordered and must not overlap.
+    pub fn new(segments: Vec<SessionSegment>) -> ErrorResult<Self> {
+        if segments.is_empty() {
+            return Err(ErebusError::InvalidOperation("Session needs at least one segment".into()));
+        }
+        if segments.windows(2).any(|w| w[0].close > w[1].open) {
+            return Err(ErebusError::InvalidOperation(
+                "Session segments must be ordered and non-overlapping".into(),
+            ));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Single continuous session.
+    #[inline]
+    pub fn regular(open: (u8, u8), close: (u8, u8)) -> ErrorResult<Self> {
+        Self::new(vec![SessionSegment::new(open, close)?])
+    }
+
+    /// Trading around the clock, midnight to midnight.
+    #[inline]
+    pub fn all_day() -> Self {
+        Self { segments: vec![SessionSegment { open: 0, close: SECONDS_PER_DAY }] }
+    }
+
+    #[inline]
+    pub fn segments(&self) -> &[SessionSegment] {
+        &self.segments
+    }
+
+    /// This session cut off at `close`; later segments are dropped.
+    pub fn with_close(&self, close: (u8, u8)) -> ErrorResult<Self> {
+        let c = hm_to_secs(close.0, close.1)?;
+        let segments: Vec<SessionSegment> = self
+            .segments
+            .iter()
+            .filter(|s| s.open < c)
+            .map(|s| SessionSegment { open: s.open, close: s.close.min(c) })
+            .collect();
+        Self::new(segments)
+    }
+
+    /// Only the first segment, i.e. the morning session of a split day.
+    #[inline]
+    pub fn first_segment(&self) -> Self {
+        Self { segments: vec![self.segments[0]] }
+    }
+
+    /// Total trading seconds.
+    #[inline]
+    pub fn duration_seconds(&self) -> u32 {
+        self.segments.iter().map(|s| s.close - s.open).sum()
+    }
+}
+
+impl Default for MarketHours {
+    /// UTC, trading all day.
+    #[inline]
+    fn default() -> Self {
+        Self::new(TimeZone::utc(), TradingSession::all_day())
+    }
+}
+
+impl MarketHours {
+
+    #[inline]
+    pub fn new(tz: TimeZone, regular: TradingSession) -> Self {
+        Self { tz, regular, special: Vec::new() }
+    }
+
+    #[inline]
+    pub fn time_zone(&self) -> &TimeZone {
+        &self.tz
+    }
+
+    #[inline]
+    pub fn regular(&self) -> &TradingSession {
+        &self.regular
+    }
+
+    /// Replaces the session on `date`.
+    pub fn with_special_session(mut self, date: Date, session: TradingSession) -> Self {
+        match self.special.binary_search_by_key(&date.days, |(d, _)| *d) {
+            Ok(i) => self.special[i].1 = session,
+            Err(i) => self.special.insert(i, (date.days, session)),
+        }
+        self
+    }
+
+    /// Closes early at `close` on `date`.
+    #[inline]
+    pub fn with_early_close(self, date: Date, close: (u8, u8)) -> ErrorResult<Self> {
+        let session = self.regular.with_close(close)?;
+        Ok(self.with_special_session(date, session))
+    }
+
+    /// Trades only the first (morning) segment on `date`.
+    #[inline]
+    pub fn with_half_day(self, date: Date) -> Self {
+        let session = self.regular.first_segment();
+        self.with_special_session(date, session)
+    }
+
+    /// Session in effect on `date`, ignoring weekends and holidays.
+    #[inline]
+    pub fn session_on(&self, date: Date) -> &TradingSession {
+        match self.special.binary_search_by_key(&date.days, |(d, _)| *d) {
+            Ok(i) => &self.special[i].1,
+            Err(_) => &self.regular,
+        }
+    }
+
+    /// UTC nanoseconds of local second `secs` on local day `days`.
+    /// Non-existent local times map to the end of the gap.
+    #[inline]
+    fn local_to_utc(&self, days: i32, secs: u32) -> Option<i64> {
+        let local = (days as i64)
+            .checked_mul(NANOS_PER_DAY)?
+            .checked_add(secs as i64 * NANOS_PER_SECOND)?;
+        self.tz
+            .to_utc(Timestamp::from_nanos(local), Disambiguate::Latest)
+            .ok()
+            .flatten()
+            .map(|t| t.nanos())
+    }
+
+    /// Local day and nanoseconds after local midnight of UTC `instant`.
+    #[inline]
+    fn utc_to_local(&self, instant: i64) -> Option<(i32, i64)> {
+        let offset = self.tz.utc_offset_seconds(Timestamp::from_nanos(instant)) as i64;
+        let local = instant.checked_add(offset * NANOS_PER_SECOND)?;
+        Some((local.div_euclid(NANOS_PER_DAY) as i32, local.rem_euclid(NANOS_PER_DAY)))
+    }
+}
+
+impl MarketCalendar {
+
+    /// UTC `[open, close)` nanosecond bounds of each segment traded on
+    /// `date`. Empty on weekends, holidays and unrepresentable dates.
+    pub fn session_bounds(&self, date: Date) -> Vec<(i64, i64)> {
+        if !self.is_business_day(date) {
+            return Vec::new();
+        }
+        let hours = self.hours();
+        hours
+            .session_on(date)
+            .segments()
+            .iter()
+            .filter_map(|s| Some((hours.local_to_utc(date.days, s.open)?, hours.local_to_utc(date.days, s.close)?)))
+            .collect()
+    }
+
+    /// Whether the market is trading at UTC nanosecond `instant`.
+    pub fn is_open(&self, instant: i64) -> bool {
+        let hours = self.hours();
+        let Some((days, tod)) = hours.utc_to_local(instant) else {
+            return false;
+        };
+        let date = Date { days };
+        self.is_business_day(date)
+            && hours.session_on(date).segments().iter().any(|s| {
+                tod >= s.open as i64 * NANOS_PER_SECOND && tod < s.close as i64 * NANOS_PER_SECOND
+            })
+    }
+
+    /// First segment open at or after `instant`.
+    pub fn next_open(&self, instant: i64) -> Option<i64> {
+        let (day, _) = self.hours().utc_to_local(instant)?;
+        (day - 1..day + MAX_SEARCH_DAYS)
+            .flat_map(|d| self.session_bounds(Date { days: d }))
+            .map(|(open, _)| open)
+            .find(|&open| open >= instant)
+    }
+
+    /// Last segment close at or before `instant`.
+    pub fn previous_close(&self, instant: i64) -> Option<i64> {
+        let (day, _) = self.hours().utc_to_local(instant)?;
+        (day - MAX_SEARCH_DAYS..=day + 1)
+            .rev()
+            .flat_map(|d| self.session_bounds(Date { days: d }).into_iter().rev())
+            .map(|(_, close)| close)
+            .find(|&close| close <= instant)
+    }
+
+    /// Whole trading minutes in `[start, end)`; zero if `end <= start`.
+    pub fn trading_minutes_between(&self, start: i64, end: i64) -> i64 {
+        if end <= start {
+            return 0;
+        }
+        let hours = self.hours();
+        let (Some((d0, _)), Some((d1, _))) = (hours.utc_to_local(start), hours.utc_to_local(end)) else {
+            return 0;
+        };
+
+        let days = self.business_days_between(Date { days: d0 - 1 }, Date { days: d1 + 1 });
+        let mut total = 0i64;
+        for i in 0..days.len() {
+            for (open, close) in self.session_bounds(Date { days: days.days[i] }) {
+                let (a, b) = (open.max(start), close.min(end));
+                if a < b {
+                    total += b - a;
+                }
+            }
+        }
+        total / NANOS_PER_MINUTE
+    }
+}
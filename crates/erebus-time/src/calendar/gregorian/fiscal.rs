@@ -0,0 +1,181 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// Weeks per period within each 13-week fiscal quarter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetailPattern {
+    W445,
+    W454,
+    W544,
+}
+
+/// How a 52/53-week fiscal year picks its last day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YearEndRule {
+
+    /// Last `week_end` weekday of the closing month.
+    LastWeekday,
+
+    /// `week_end` weekday nearest to the closing month's last day.
+    NearestWeekday,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FiscalKind {
+    Months,
+    Weeks {
+        pattern: RetailPattern,
+        week_end: u8, // ISO weekday of the last day of each fiscal week
+        rule: YearEndRule,
+    },
+}
+
+/// Fiscal year layout.
+///
+/// Fiscal years are labelled by the calendar year in which they end:
+/// with an October start, FY2024 runs Oct 2023 – Sep 2024.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FiscalCalendar {
+    start_month: u8,
+    kind: FiscalKind,
+}
+
+/// Position of a date within its fiscal year.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FiscalDate {
+    pub year: i32,
+    pub quarter: u8, // 1..=4
+    pub period: u8,  // 1..=12
+    pub week: u8,    // 1..=53
+}
+
+// === Impls ===
+
+impl RetailPattern {
+    #[inline]
+    fn weeks(self) -> [u8; 3] {
+        match self {
+            RetailPattern::W445 => [4, 4, 5],
+            RetailPattern::W454 => [4, 5, 4],
+            RetailPattern::W544 => [5, 4, 4],
+        }
+    }
+}
+
+impl FiscalCalendar {
+
+    /// Fiscal year of whole calendar months starting in `start_month`.
+    pub fn months(start_month: u8) -> ErrorResult<Self> {
+        validate_month(start_month)?;
+        Ok(Self { start_month, kind: FiscalKind::Months })
+    }
+
+    /// 52/53-week fiscal year made of 13-week quarters split by `pattern`.
+    /// The year ends on ISO weekday `week_end` near the end of the month
+    /// before `start_month`; a 53rd week is added to the last period.
+    pub fn weeks(
+        start_month: u8,
+        pattern: RetailPattern,
+        week_end: u8,
+        rule: YearEndRule,
+    ) -> ErrorResult<Self> {
+        validate_month(start_month)?;
+        if !(1..=7).contains(&week_end) {
+            return Err(ErebusError::InvalidOperation(format!("Invalid ISO weekday: {}", week_end)));
+        }
+        Ok(Self { start_month, kind: FiscalKind::Weeks { pattern, week_end, rule } })
+    }
+
+    #[inline]
+    pub fn start_month(&self) -> u8 {
+        self.start_month
+    }
+
+    /// Days since epoch of the last day of fiscal year `fy`.
+    fn year_end_days(&self, fy: i32) -> i32 {
+        let end_month = if self.start_month == 1 { 12 } else { self.start_month - 1 };
+        let month_end = ymd_to_days(fy, end_month, days_in_month(fy, end_month));
+
+        match self.kind {
+            FiscalKind::Months => month_end,
+            FiscalKind::Weeks { week_end, rule, .. } => {
+                let back = (days_to_weekday_iso(month_end) as i32 - week_end as i32).rem_euclid(7);
+                let last = month_end - back;
+                match rule {
+                    YearEndRule::LastWeekday => last,
+                    YearEndRule::NearestWeekday if back > 3 => last + 7,
+                    YearEndRule::NearestWeekday => last,
+                }
+            }
+        }
+    }
+
+    /// First and last date of fiscal year `fy`.
+    #[inline]
+    pub fn year_bounds(&self, fy: i32) -> (Date, Date) {
+        (
+            Date { days: self.year_end_days(fy - 1) + 1 },
+            Date { days: self.year_end_days(fy) },
+        )
+    }
+
+    /// Number of weeks in fiscal year `fy` (52 or 53 for week-based years).
+    #[inline]
+    pub fn weeks_in_year(&self, fy: i32) -> u8 {
+        let (start, end) = self.year_bounds(fy);
+        ((end.days - start.days) / 7 + 1) as u8
+    }
+
+    /// Fiscal year containing `days`.
+    fn year_of(&self, days: i32) -> i32 {
+        let (y, m, _) = days_to_ymd(days);
+        let mut fy = if self.start_month > 1 && m >= self.start_month { y + 1 } else { y };
+
+        // Week-based ends can fall a few days either side of the month end
+        while days > self.year_end_days(fy) {
+            fy += 1;
+        }
+        while days <= self.year_end_days(fy - 1) {
+            fy -= 1;
+        }
+        fy
+    }
+
+    /// Maps `date` to fiscal year, quarter, period and week.
+    pub fn fiscal_date(&self, date: Date) -> FiscalDate {
+        self.fiscal_date_days(date.days)
+    }
+
+    pub(crate) fn fiscal_date_days(&self, days: i32) -> FiscalDate {
+        let year = self.year_of(days);
+        let start = self.year_end_days(year - 1) + 1;
+        let week = ((days - start) / 7 + 1) as u8;
+
+        let period = match self.kind {
+            FiscalKind::Months => {
+                let m = days_to_ymd(days).1;
+                (m as i32 - self.start_month as i32).rem_euclid(12) as u8 + 1
+            }
+            FiscalKind::Weeks { pattern, .. } => {
+                let q = ((week - 1) / 13).min(3);
+                let wq = week - 13 * q; // 1..=14, week 53 lands in the last period
+                let [a, b, _] = pattern.weeks();
+                let p = if wq <= a { 1 } else if wq <= a + b { 2 } else { 3 };
+                q * 3 + p
+            }
+        };
+
+        FiscalDate { year, quarter: (period - 1) / 3 + 1, period, week }
+    }
+}
+
+#[inline]
+fn validate_month(month: u8) -> ErrorResult<()> {
+    if (1..=12).contains(&month) {
+        Ok(())
+    } else {
+        Err(ErebusError::InvalidMonth(month))
+    }
+}
@@ -5,6 +5,7 @@ pub mod weekend;
 pub mod week;
 pub mod weekday;
 pub mod financial;
+pub mod fiscal;
 pub mod calendar;
 
 pub use days::*;
@@ -14,4 +15,5 @@ pub use weekend::*;
 pub use week::*;
 pub use weekday::*;
 pub use financial::*;
+pub use fiscal::*;
 pub use calendar::*;
\ No newline at end of file
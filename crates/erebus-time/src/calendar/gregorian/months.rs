@@ -43,4 +43,24 @@ pub fn short_month_name(month: u8) -> &'static str {
 #[inline]
 pub fn long_month_name(month: u8) -> &'static str {
     unsafe { *MONTHS_LONG.get_unchecked((month - 1) as usize) }
-}
\ No newline at end of file
+}
+
+/// How a day-of-month is carried across a month shift.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthEndRule {
+
+    /// Keep the day-of-month, clamped to the target month length.
+    /// Jan 31 + 1M = Feb 28/29, Feb 28 + 1M = Mar 28.
+    Clamp,
+
+    /// Like `Clamp`, but a month-end date stays a month end.
+    /// Feb 28 + 1M = Mar 31.
+    Preserve,
+}
+
+impl Default for MonthEndRule {
+    #[inline]
+    fn default() -> Self {
+        MonthEndRule::Clamp
+    }
+}
@@ -42,4 +42,60 @@ impl WeekConvention {
             }
         }
     }
-}
\ No newline at end of file
+
+    /// Week number of `days since epoch` under this convention.
+    /// ISO: 1..=53 within the ISO week-based year.
+    /// US / Custom: week 1 is the (possibly partial) week holding Jan 1, 1..=54.
+    #[inline]
+    pub fn week_of_year(&self, days: i32) -> u8 {
+        let first_day = match *self {
+            WeekConvention::ISO => return iso_year_week(days).1,
+            WeekConvention::US => 7,
+            WeekConvention::Custom { first_day } => first_day,
+        };
+        let jan1 = days - day_of_year(days) as i32 + 1;
+        let lead = (days_to_weekday_iso(jan1) as i32 - first_day as i32).rem_euclid(7);
+        ((days - jan1 + lead) / 7 + 1) as u8
+    }
+}
+
+/// Day of year (1-based) for `days since epoch`.
+#[inline]
+pub fn day_of_year(days: i32) -> u16 {
+    let (y, _, _) = days_to_ymd(days);
+    (days - ymd_to_days(y, 1, 1) + 1) as u16
+}
+
+/// Week of year with Sunday as first day (strftime `%U`).
+/// Days before the first Sunday are in week 0.
+#[inline]
+pub fn week_of_year_sunday(days: i32) -> u8 {
+    let yday0 = day_of_year(days) as i32 - 1;
+    let wd = (days_to_weekday_iso(days) % 7) as i32; // Sunday = 0
+    ((yday0 + 7 - wd) / 7) as u8
+}
+
+/// Week of year with Monday as first day (strftime `%W`).
+/// Days before the first Monday are in week 0.
+#[inline]
+pub fn week_of_year_monday(days: i32) -> u8 {
+    let yday0 = day_of_year(days) as i32 - 1;
+    let wd = days_to_weekday_iso(days) as i32 - 1; // Monday = 0
+    ((yday0 + 7 - wd) / 7) as u8
+}
+
+/// ISO-8601 `(week-based year, week)` (strftime `%G`, `%V`).
+#[inline]
+pub fn iso_year_week(days: i32) -> (i32, u8) {
+    // The week belongs to the year containing its Thursday
+    let thursday = days + 4 - days_to_weekday_iso(days) as i32;
+    let (y, _, _) = days_to_ymd(thursday);
+    (y, ((thursday - ymd_to_days(y, 1, 1)) / 7 + 1) as u8)
+}
+
+/// Days since epoch of the Monday starting ISO week `week` of `iso_year`.
+#[inline]
+pub fn iso_week_start(iso_year: i32, week: u8) -> i32 {
+    let jan4 = ymd_to_days(iso_year, 1, 4);
+    jan4 - (days_to_weekday_iso(jan4) as i32 - 1) + (week as i32 - 1) * 7
+}
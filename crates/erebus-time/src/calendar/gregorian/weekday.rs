@@ -20,4 +20,64 @@ pub fn days_to_weekday_iso(days: i32) -> u8 {
     // Then weekday = ((days + 3) mod 7) + 1
     let d = (days as i64 + 3).rem_euclid(7) as u8;
     d + 1
-}
\ No newline at end of file
+}
+
+/// Returns days since epoch of the `n`-th occurrence (1-based) of ISO
+/// weekday `weekday` in the given month.
+/// Returns `None` if the month does not contain an `n`-th occurrence.
+#[inline]
+pub fn nth_weekday_of_month(year: i32, month: u8, weekday: u8, n: u8) -> Option<i32> {
+    debug_assert!((1..=7).contains(&weekday));
+    if n == 0 {
+        return None;
+    }
+
+    let first = ymd_to_days(year, month, 1);
+    let first_wd = days_to_weekday_iso(first);
+    let offset = (weekday as i32 - first_wd as i32).rem_euclid(7);
+    let day = 1 + offset + 7 * (n as i32 - 1);
+
+    if day > days_in_month(year, month) as i32 {
+        return None;
+    }
+    Some(first + day - 1)
+}
+
+/// Returns days since epoch of the IMM date (third Wednesday) of the given month.
+#[inline]
+pub fn imm_date(year: i32, month: u8) -> i32 {
+    // Every month has at least four Wednesdays
+    let first = ymd_to_days(year, month, 1);
+    let offset = (3 - days_to_weekday_iso(first) as i32).rem_euclid(7);
+    first + offset + 14
+}
+
+/// Short English weekday names (strftime `%a`), Monday first.
+pub const WEEKDAYS_SHORT: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Long English weekday names (strftime `%A`), Monday first.
+pub const WEEKDAYS_LONG: [&str; 7] = [
+    "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday",
+];
+
+/// ISO weekday of a short name prefix.
+#[inline]
+pub fn weekday_from_short(s: &str) -> Option<u8> {
+    WEEKDAYS_SHORT.iter().position(|name| s.starts_with(name)).map(|i| (i + 1) as u8)
+}
+
+/// ISO weekday of a long name prefix.
+#[inline]
+pub fn weekday_from_long(s: &str) -> Option<u8> {
+    WEEKDAYS_LONG.iter().position(|name| s.starts_with(name)).map(|i| (i + 1) as u8)
+}
+
+#[inline]
+pub fn short_weekday_name(iso: u8) -> &'static str {
+    WEEKDAYS_SHORT[(iso - 1) as usize]
+}
+
+#[inline]
+pub fn long_weekday_name(iso: u8) -> &'static str {
+    WEEKDAYS_LONG[(iso - 1) as usize]
+}
@@ -37,8 +37,12 @@ impl WeekendRule {
     #[inline]
     pub fn is_weekend(&self, date: Date) -> bool {
         // ISO weekday: 1..=7 (Mon..Sun)
-        let wd = days_to_weekday_iso(date.days());
+        self.is_weekend_weekday(days_to_weekday_iso(date.days()))
+    }
 
+    /// Returns true if ISO weekday `wd` (1=Mon .. 7=Sun) is a weekend day.
+    #[inline]
+    pub fn is_weekend_weekday(&self, wd: u8) -> bool {
         match *self {
             WeekendRule::SaturdaySunday => wd == 6 || wd == 7,
             WeekendRule::FridaySaturday => wd == 5 || wd == 6,
@@ -47,4 +51,39 @@ impl WeekendRule {
             }
         }
     }
-}
\ No newline at end of file
+
+    /// Bit `wd - 1` set for each weekend ISO weekday `wd`.
+    #[inline]
+    pub fn to_mask(&self) -> u8 {
+        (1..=7u8)
+            .filter(|&wd| self.is_weekend_weekday(wd))
+            .fold(0, |m, wd| m | (1 << (wd - 1)))
+    }
+
+    /// Inverse of [`WeekendRule::to_mask`]. Only Friday to Sunday
+    /// can be weekend days.
+    pub fn from_mask(mask: u8) -> ErrorResult<Self> {
+        if mask & !0b0111_0000 != 0 {
+            return Err(ErebusError::InvalidOperation(format!("Unsupported weekend mask: {:#09b}", mask)));
+        }
+        let (fri, sat, sun) = (mask & 0b1_0000 != 0, mask & 0b10_0000 != 0, mask & 0b100_0000 != 0);
+        Ok(match (fri, sat, sun) {
+            (false, true, true) => WeekendRule::SaturdaySunday,
+            (true, true, false) => WeekendRule::FridaySaturday,
+            _ => WeekendRule::Custom { fri, sat, sun },
+        })
+    }
+}
+
+/// A weekend rule on its own is a calendar without holidays.
+impl Calendar for WeekendRule {
+    #[inline]
+    fn is_weekend(&self, date: Date) -> bool {
+        WeekendRule::is_weekend(self, date)
+    }
+
+    #[inline]
+    fn is_holiday(&self, _date: Date) -> bool {
+        false
+    }
+}
@@ -0,0 +1,136 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impls ===
+
+/// Shifts days-since-epoch by a number of calendar months.
+#[inline]
+pub(crate) fn shift_months_days(days: i32, months: i32, rule: MonthEndRule) -> i32 {
+    let (y, m, d) = days_to_ymd(days);
+
+    let total = y * 12 + (m as i32 - 1) + months;
+    let ny = total.div_euclid(12);
+    let nm = (total.rem_euclid(12) + 1) as u8;
+
+    let dim = days_in_month(ny, nm);
+    let nd = match rule {
+        MonthEndRule::Preserve if d == days_in_month(y, m) => dim,
+        _ => d.min(dim),
+    };
+    ymd_to_days(ny, nm, nd)
+}
+
+impl Date {
+
+    #[inline]
+    pub fn add_days(&self, n: i32) -> Date {
+        Date { days: self.days + n }
+    }
+
+    #[inline]
+    pub fn add_weeks(&self, n: i32) -> Date {
+        Date { days: self.days + 7 * n }
+    }
+
+    /// Shifts by `n` calendar months, resolving short months with `rule`.
+    #[inline]
+    pub fn add_months(&self, n: i32, rule: MonthEndRule) -> Date {
+        Date { days: shift_months_days(self.days, n, rule) }
+    }
+
+    /// Shifts by `n` calendar years, resolving Feb 29 with `rule`.
+    #[inline]
+    pub fn add_years(&self, n: i32, rule: MonthEndRule) -> Date {
+        Date { days: shift_months_days(self.days, 12 * n, rule) }
+    }
+
+    /// Signed number of days from `self` to `other`.
+    #[inline]
+    pub fn days_between(&self, other: Date) -> i32 {
+        other.days - self.days
+    }
+}
+
+impl DateVector {
+
+    /// Applies `f` to every valid element; nulls are carried through.
+    #[inline]
+    pub(crate) fn map_valid_days<F>(&self, f: F) -> DateVector
+    where
+        F: Fn(i32) -> i32,
+    {
+        let n = self.days.len();
+        let mut out = vec![0i32; n];
+
+        for (i, o) in out.iter_mut().enumerate() {
+            unsafe {
+                if *self.validity.get_unchecked(i) {
+                    *o = f(*self.days.get_unchecked(i));
+                }
+            }
+        }
+
+        DateVector { days: out, validity: self.validity.clone() }
+    }
+
+    /// Like [`map_valid_days`](Self::map_valid_days), but `None` yields a null.
+    #[inline]
+    pub(crate) fn map_valid_days_opt<F>(&self, f: F) -> DateVector
+    where
+        F: Fn(i32) -> Option<i32>,
+    {
+        let n = self.days.len();
+        let mut out = vec![0i32; n];
+        let mut validity = self.validity.clone();
+
+        for (i, o) in out.iter_mut().enumerate() {
+            if validity[i] {
+                match f(self.days[i]) {
+                    Some(d) => *o = d,
+                    None => validity.set(i, false),
+                }
+            }
+        }
+
+        DateVector { days: out, validity }
+    }
+
+    pub fn add_days(&self, n: i32) -> DateVector {
+        self.map_valid_days(|d| d + n)
+    }
+
+    pub fn add_weeks(&self, n: i32) -> DateVector {
+        self.map_valid_days(|d| d + 7 * n)
+    }
+
+    pub fn add_months(&self, n: i32, rule: MonthEndRule) -> DateVector {
+        self.map_valid_days(|d| shift_months_days(d, n, rule))
+    }
+
+    pub fn add_years(&self, n: i32, rule: MonthEndRule) -> DateVector {
+        self.map_valid_days(|d| shift_months_days(d, 12 * n, rule))
+    }
+
+    /// Signed day counts from each element of `self` to the matching element of `other`.
+    /// Rows where either side is null are null.
+    pub fn days_between(&self, other: &DateVector) -> ErrorResult<VectorData<i64>> {
+        let n = self.days.len();
+        if other.days.len() != n {
+            return Err(ErebusError::LengthMismatch {
+                expected: n,
+                found: other.days.len(),
+            });
+        }
+
+        let validity = self.validity.clone() & &other.validity;
+        let mut out = vec![0i64; n];
+        for (i, o) in out.iter_mut().enumerate() {
+            unsafe {
+                if *validity.get_unchecked(i) {
+                    *o = *other.days.get_unchecked(i) as i64 - *self.days.get_unchecked(i) as i64;
+                }
+            }
+        }
+        Ok(VectorData { data: out, validity })
+    }
+}
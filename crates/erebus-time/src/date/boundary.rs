@@ -0,0 +1,149 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impls ===
+
+#[inline]
+pub(crate) fn month_start_days(days: i32) -> i32 {
+    let d = days_to_ymd(days).2;
+    days - d as i32 + 1
+}
+
+#[inline]
+pub(crate) fn month_end_days(days: i32) -> i32 {
+    let (y, m, d) = days_to_ymd(days);
+    days + (days_in_month(y, m) - d) as i32
+}
+
+#[inline]
+pub(crate) fn quarter_start_days(days: i32) -> i32 {
+    let (y, m, _) = days_to_ymd(days);
+    let qm = (m - 1) / 3 * 3 + 1;
+    ymd_to_days(y, qm, 1)
+}
+
+#[inline]
+pub(crate) fn quarter_end_days(days: i32) -> i32 {
+    let (y, m, _) = days_to_ymd(days);
+    let qm = (m - 1) / 3 * 3 + 3;
+    ymd_to_days(y, qm, days_in_month(y, qm))
+}
+
+#[inline]
+pub(crate) fn year_start_days(days: i32) -> i32 {
+    ymd_to_days(days_to_ymd(days).0, 1, 1)
+}
+
+#[inline]
+pub(crate) fn year_end_days(days: i32) -> i32 {
+    ymd_to_days(days_to_ymd(days).0, 12, 31)
+}
+
+impl Date {
+
+    #[inline]
+    pub fn month_start(&self) -> Date {
+        Date { days: month_start_days(self.days) }
+    }
+
+    #[inline]
+    pub fn month_end(&self) -> Date {
+        Date { days: month_end_days(self.days) }
+    }
+
+    #[inline]
+    pub fn quarter_start(&self) -> Date {
+        Date { days: quarter_start_days(self.days) }
+    }
+
+    #[inline]
+    pub fn quarter_end(&self) -> Date {
+        Date { days: quarter_end_days(self.days) }
+    }
+
+    #[inline]
+    pub fn year_start(&self) -> Date {
+        Date { days: year_start_days(self.days) }
+    }
+
+    #[inline]
+    pub fn year_end(&self) -> Date {
+        Date { days: year_end_days(self.days) }
+    }
+
+    #[inline]
+    pub fn is_month_end(&self) -> bool {
+        month_end_days(self.days) == self.days
+    }
+
+    #[inline]
+    pub fn is_quarter_end(&self) -> bool {
+        quarter_end_days(self.days) == self.days
+    }
+
+    /// First business day of the month containing `self`;
+    /// `None` if the month has none.
+    #[inline]
+    pub fn first_business_day_of_month<C: Calendar>(&self, cal: &C) -> Option<Date> {
+        (month_start_days(self.days)..=month_end_days(self.days))
+            .map(|days| Date { days })
+            .find(|&d| cal.is_business_day(d))
+    }
+
+    /// Last business day of the month containing `self`;
+    /// `None` if the month has none.
+    #[inline]
+    pub fn last_business_day_of_month<C: Calendar>(&self, cal: &C) -> Option<Date> {
+        (month_start_days(self.days)..=month_end_days(self.days))
+            .rev()
+            .map(|days| Date { days })
+            .find(|&d| cal.is_business_day(d))
+    }
+}
+
+impl DateVector {
+
+    pub fn month_start(&self) -> DateVector {
+        self.map_valid_days(month_start_days)
+    }
+
+    pub fn month_end(&self) -> DateVector {
+        self.map_valid_days(month_end_days)
+    }
+
+    pub fn quarter_start(&self) -> DateVector {
+        self.map_valid_days(quarter_start_days)
+    }
+
+    pub fn quarter_end(&self) -> DateVector {
+        self.map_valid_days(quarter_end_days)
+    }
+
+    pub fn year_start(&self) -> DateVector {
+        self.map_valid_days(year_start_days)
+    }
+
+    pub fn year_end(&self) -> DateVector {
+        self.map_valid_days(year_end_days)
+    }
+
+    /// `false` at null rows.
+    pub fn is_month_end(&self) -> Vec<bool> {
+        self.extract_valid(|d| month_end_days(d) == d)
+    }
+
+    /// `false` at null rows.
+    pub fn is_quarter_end(&self) -> Vec<bool> {
+        self.extract_valid(|d| quarter_end_days(d) == d)
+    }
+
+    /// Null where the month has no business day.
+    pub fn first_business_day_of_month<C: Calendar>(&self, cal: &C) -> DateVector {
+        self.map_valid_days_opt(|d| Date { days: d }.first_business_day_of_month(cal).map(|b| b.days))
+    }
+
+    /// Null where the month has no business day.
+    pub fn last_business_day_of_month<C: Calendar>(&self, cal: &C) -> DateVector {
+        self.map_valid_days_opt(|d| Date { days: d }.last_business_day_of_month(cal).map(|b| b.days))
+    }
+}
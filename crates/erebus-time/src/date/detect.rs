@@ -0,0 +1,196 @@
+// === Imports ===
+use crate::prelude::*;
+use super::parse::{compile_ymd_format, parse_ymd_exact, YmdExtractor};
+
+// === Constants ===
+
+/// Candidate formats for [`DateVector::parse_auto`], in tie-break order.
+/// Unpadded `%-d` / `%-m` also accept zero-padded values; `%b` also
+/// accepts long names, so `%B` comes first.
+const CANDIDATES: &[(&str, Order)] = &[
+    ("%Y-%m-%d", Order::Unambiguous),
+    ("%Y/%m/%d", Order::Unambiguous),
+    ("%Y%m%d", Order::Unambiguous),
+    ("%Y.%m.%d", Order::Unambiguous),
+    ("%-m/%-d/%Y", Order::MonthFirst),
+    ("%-m-%-d-%Y", Order::MonthFirst),
+    ("%-m/%-d/%y", Order::MonthFirst),
+    ("%-d/%-m/%Y", Order::DayFirst),
+    ("%-d-%-m-%Y", Order::DayFirst),
+    ("%-d.%-m.%Y", Order::DayFirst),
+    ("%-d/%-m/%y", Order::DayFirst),
+    ("%-d-%b-%Y", Order::Unambiguous),
+    ("%-d-%b-%y", Order::Unambiguous),
+    ("%-d %B %Y", Order::Unambiguous),
+    ("%-d %b %Y", Order::Unambiguous),
+    ("%B %-d, %Y", Order::Unambiguous),
+    ("%b %-d, %Y", Order::Unambiguous),
+    ("%b %-d %Y", Order::Unambiguous),
+];
+
+// === Types ===
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Order {
+    Unambiguous,
+    MonthFirst,
+    DayFirst,
+}
+
+/// Settings for [`DateVector::parse_auto_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseAutoOptions {
+
+    /// Maximum number of non-empty values sampled to rank formats.
+    pub sample_size: usize,
+
+    /// Resolve `03/05/2024`-style ties as day-first (5 March)
+    /// instead of month-first (3 May).
+    pub day_first: bool,
+}
+
+/// Rows that no format could parse.
+/// Empty or blank inputs are treated as nulls, not failures.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DateParseReport {
+    pub failed: Vec<usize>,
+}
+
+/// Result of [`DateVector::parse_auto`].
+#[derive(Debug, Clone)]
+pub struct AutoParse {
+
+    /// Best-ranked format.
+    pub format: String,
+
+    /// All formats tried per element, best first.
+    pub formats: Vec<String>,
+
+    pub dates: DateVector,
+    pub report: DateParseReport,
+}
+
+// === Impls ===
+
+impl Default for ParseAutoOptions {
+    #[inline]
+    fn default() -> Self {
+        Self { sample_size: 1000, day_first: false }
+    }
+}
+
+impl DateParseReport {
+    #[inline]
+    pub fn is_clean(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl DateVector {
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn _parse_any(values: &[&str], extractors: &[YmdExtractor]) -> (Self, DateParseReport) {
+        let n = values.len();
+        let mut days = vec![0i32; n];
+        let mut validity = bitvec![0; n];
+        let mut report = DateParseReport::default();
+
+        for (i, raw) in values.iter().enumerate() {
+            let s = raw.trim();
+            if s.is_empty() {
+                continue;
+            }
+            match extractors.iter().find_map(|ex| parse_ymd_exact(s, ex)) {
+                Some(d) => {
+                    days[i] = d;
+                    validity.set(i, true);
+                }
+                None => report.failed.push(i),
+            }
+        }
+
+        (Self { days, validity }, report)
+    }
+
+    /// Parses each element with the first of `formats` that matches
+    /// the whole (trimmed) string. Failed rows are null and reported.
+    pub fn parse_any(values: &[&str], formats: &[&str]) -> ErrorResult<(Self, DateParseReport)> {
+        let extractors = formats
+            .iter()
+            .map(|f| compile_ymd_format(f))
+            .collect::<ErrorResult<Vec<_>>>()?;
+        Ok(Self::_parse_any(values, &extractors))
+    }
+
+    /// Detects the format(s) of a string column and parses it.
+    /// See [`DateVector::parse_auto_with`].
+    #[inline]
+    pub fn parse_auto(values: &[&str]) -> ErrorResult<AutoParse> {
+        Self::parse_auto_with(values, ParseAutoOptions::default())
+    }
+
+    /// Detects the format(s) of a string column and parses it.
+    ///
+    /// Up to `sample_size` evenly spaced non-empty values are tried
+    /// against a fixed set of common formats, which are ranked by match
+    /// count. Month-first and day-first formats are never mixed: the
+    /// order with more matches wins, and `day_first` breaks ties.
+    /// Every matching format of the winning order is then tried per element.
+    pub fn parse_auto_with(values: &[&str], options: ParseAutoOptions) -> ErrorResult<AutoParse> {
+        let non_empty: Vec<&str> = values.iter().map(|s| s.trim()).filter(|s| !s.is_empty()).collect();
+        let sample = sample_evenly(&non_empty, options.sample_size.max(1));
+
+        let extractors = CANDIDATES
+            .iter()
+            .map(|(f, _)| compile_ymd_format(f))
+            .collect::<ErrorResult<Vec<_>>>()?;
+
+        let counts: Vec<usize> = extractors
+            .iter()
+            .map(|ex| sample.iter().filter(|s| parse_ymd_exact(s, ex).is_some()).count())
+            .collect();
+
+        // Month-first vs day-first: total matches of each order
+        let total = |order: Order| -> usize {
+            CANDIDATES
+                .iter()
+                .zip(&counts)
+                .filter(|((_, o), _)| *o == order)
+                .map(|(_, &c)| c)
+                .sum()
+        };
+        let (mf, df) = (total(Order::MonthFirst), total(Order::DayFirst));
+        let losing = if mf > df || (mf == df && !options.day_first) {
+            Order::DayFirst
+        } else {
+            Order::MonthFirst
+        };
+
+        let mut ranked: Vec<usize> = (0..CANDIDATES.len())
+            .filter(|&k| counts[k] > 0 && CANDIDATES[k].1 != losing)
+            .collect();
+        // Stable: equal counts keep the table order
+        ranked.sort_by_key(|&k| std::cmp::Reverse(counts[k]));
+
+        if ranked.is_empty() {
+            return Err(ErebusError::InvalidOperation(
+                "Could not detect a date format for any sampled value".into(),
+            ));
+        }
+
+        let formats: Vec<String> = ranked.iter().map(|&k| CANDIDATES[k].0.to_string()).collect();
+        let chosen: Vec<YmdExtractor> = ranked.iter().map(|&k| extractors[k].clone()).collect();
+        let (dates, report) = Self::_parse_any(values, &chosen);
+
+        Ok(AutoParse { format: formats[0].clone(), formats, dates, report })
+    }
+}
+
+/// Up to `k` values spread evenly over `values`, first one included.
+fn sample_evenly<'a>(values: &[&'a str], k: usize) -> Vec<&'a str> {
+    let n = values.len();
+    if n <= k {
+        return values.to_vec();
+    }
+    (0..k).map(|i| values[i * n / k]).collect()
+}
@@ -28,6 +28,36 @@ impl Date {
     pub fn ymd(&self) -> (i32, u8, u8) {
         days_to_ymd(self.days)
     }
+
+    /// Quarter (1–4).
+    #[inline]
+    pub fn quarter(&self) -> u8 {
+        (self.month() - 1) / 3 + 1
+    }
+
+    /// Day of year (1–366).
+    #[inline]
+    pub fn day_of_year(&self) -> u16 {
+        day_of_year(self.days)
+    }
+
+    /// Number of days in the year (365 or 366).
+    #[inline]
+    pub fn days_in_year(&self) -> u16 {
+        if is_leap_year(self.year()) { 366 } else { 365 }
+    }
+
+    /// Week number under `conv`, see [`WeekConvention::week_of_year`].
+    #[inline]
+    pub fn week_of_year(&self, conv: WeekConvention) -> u8 {
+        conv.week_of_year(self.days)
+    }
+
+    /// ISO-8601 (week-based year, week).
+    #[inline]
+    pub fn iso_year_week(&self) -> (i32, u8) {
+        iso_year_week(self.days)
+    }
 }
 
 impl DateVector {
@@ -110,4 +140,67 @@ impl DateVector {
 
         out
     }
+
+    /// Applies `f` to each valid day; null rows get `T::default()`.
+    #[inline]
+    pub(crate) fn extract_valid<T, F>(&self, f: F) -> Vec<T>
+    where
+        T: Copy + Default,
+        F: Fn(i32) -> T,
+    {
+        let n = self.days.len();
+        let mut out = vec![T::default(); n];
+
+        for (i, o) in out.iter_mut().enumerate() {
+            unsafe {
+                if *self.validity.get_unchecked(i) {
+                    *o = f(*self.days.get_unchecked(i));
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Extract quarters vector (1–4).
+    pub fn quarters(&self) -> Vec<u8> {
+        self.extract_valid(|d| (days_to_ymd(d).1 - 1) / 3 + 1)
+    }
+
+    /// Extract days-of-year vector (1–366).
+    pub fn days_of_year(&self) -> Vec<u16> {
+        self.extract_valid(day_of_year)
+    }
+
+    /// Extract year lengths (365 or 366).
+    pub fn days_in_year(&self) -> Vec<u16> {
+        self.extract_valid(|d| if is_leap_year(days_to_ymd(d).0) { 366 } else { 365 })
+    }
+
+    /// Extract week numbers under `conv`.
+    pub fn week_of_year(&self, conv: WeekConvention) -> Vec<u8> {
+        self.extract_valid(|d| conv.week_of_year(d))
+    }
+
+    /// Extract ISO-8601 (week-based year, week) tuples.
+    pub fn iso_year_week(&self) -> Vec<(i32, u8)> {
+        self.extract_valid(iso_year_week)
+    }
+}
+
+impl Date {
+
+    /// Position of this date in `cal`'s fiscal year.
+    #[inline]
+    pub fn fiscal(&self, cal: &FiscalCalendar) -> FiscalDate {
+        cal.fiscal_date_days(self.days)
+    }
+}
+
+impl DateVector {
+
+    /// Fiscal positions; null rows get `FiscalDate::default()`.
+    pub fn fiscal(&self, cal: &FiscalCalendar) -> Vec<FiscalDate> {
+        self.extract_valid(|d| cal.fiscal_date_days(d))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,223 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::calendar::gregorian::{
+    short_month_name, long_month_name, short_weekday_name, long_weekday_name,
+};
+use std::fmt::Write;
+
+// === Types ===
+
+/// Pre-compiled output format.
+/// Compiling once lets vectorized formatting skip pattern parsing per element.
+#[derive(Debug, Clone)]
+pub struct DateFormatter {
+    items: Vec<FormatItem>,
+}
+
+#[derive(Debug, Clone)]
+enum FormatItem {
+    Literal(String),
+    Year4,        // %Y
+    Year2,        // %y
+    IsoYear4,     // %G
+    Month2,       // %m
+    Day2,         // %d
+    MonthNoPad,   // %-m
+    DayNoPad,     // %-d
+    DaySpace,     // %e
+    DayOfYear,    // %j
+    MonthShort,   // %b
+    MonthLong,    // %B
+    WeekdayShort, // %a
+    WeekdayLong,  // %A
+    WeekSunday,   // %U
+    WeekMonday,   // %W
+    WeekIso,      // %V
+    Hour2,        // %H
+    Minute2,      // %M
+    Second2,      // %S
+    Fraction,     // %.f
+}
+
+// === Impls ===
+
+/// Compiles a strftime-style format using the same tokens as the parser.
+pub fn compile_format(fmt: &str) -> ErrorResult<DateFormatter> {
+    let bytes = fmt.as_bytes();
+    let mut items = Vec::new();
+    let mut literal = String::new();
+    let mut i = 0usize;
+
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            // Copy a full UTF-8 character
+            let ch = fmt[i..].chars().next().unwrap();
+            literal.push(ch);
+            i += ch.len_utf8();
+            continue;
+        }
+
+        if i + 1 >= bytes.len() {
+            return Err(ErebusError::InvalidOperation("Dangling '%' in format".into()));
+        }
+
+        let item = match bytes[i + 1] {
+            b'Y' => FormatItem::Year4,
+            b'y' => FormatItem::Year2,
+            b'G' => FormatItem::IsoYear4,
+            b'm' => FormatItem::Month2,
+            b'd' => FormatItem::Day2,
+            b'e' => FormatItem::DaySpace,
+            b'j' => FormatItem::DayOfYear,
+            b'a' => FormatItem::WeekdayShort,
+            b'A' => FormatItem::WeekdayLong,
+            b'U' => FormatItem::WeekSunday,
+            b'W' => FormatItem::WeekMonday,
+            b'V' => FormatItem::WeekIso,
+            b'b' => FormatItem::MonthShort,
+            b'B' => FormatItem::MonthLong,
+            b'H' => FormatItem::Hour2,
+            b'M' => FormatItem::Minute2,
+            b'S' => FormatItem::Second2,
+            b'%' => {
+                literal.push('%');
+                i += 2;
+                continue;
+            }
+            b'-' if bytes.get(i + 2) == Some(&b'd') => {
+                i += 1;
+                FormatItem::DayNoPad
+            }
+            b'-' if bytes.get(i + 2) == Some(&b'm') => {
+                i += 1;
+                FormatItem::MonthNoPad
+            }
+            b'.' if bytes.get(i + 2) == Some(&b'f') => {
+                i += 1;
+                FormatItem::Fraction
+            }
+            other => {
+                return Err(ErebusError::InvalidOperation(
+                    format!("Unsupported token: %{}", other as char),
+                ));
+            }
+        };
+
+        if !literal.is_empty() {
+            items.push(FormatItem::Literal(std::mem::take(&mut literal)));
+        }
+        items.push(item);
+        i += 2;
+    }
+
+    if !literal.is_empty() {
+        items.push(FormatItem::Literal(literal));
+    }
+
+    Ok(DateFormatter { items })
+}
+
+impl DateFormatter {
+
+    /// Appends the formatted date to `out`; time tokens print as midnight.
+    #[inline]
+    pub(crate) fn write_date(&self, out: &mut String, days: i32) {
+        self.write_datetime(out, days, (0, 0, 0, 0));
+    }
+
+    /// Appends the formatted date and time-of-day to `out`.
+    pub(crate) fn write_datetime(
+        &self,
+        out: &mut String,
+        days: i32,
+        hms: (u8, u8, u8, u32),
+    ) {
+        let (y, m, d) = days_to_ymd(days);
+        let (hh, mm, ss, ns) = hms;
+
+        for item in &self.items {
+            match item {
+                FormatItem::Literal(s) => out.push_str(s),
+                FormatItem::Year4 => { let _ = write!(out, "{:04}", y); }
+                FormatItem::Year2 => push_2(out, y.rem_euclid(100) as u8),
+                FormatItem::IsoYear4 => { let _ = write!(out, "{:04}", iso_year_week(days).0); }
+                FormatItem::Month2 => push_2(out, m),
+                FormatItem::Day2 => push_2(out, d),
+                FormatItem::MonthNoPad => { let _ = write!(out, "{}", m); }
+                FormatItem::DayNoPad => { let _ = write!(out, "{}", d); }
+                FormatItem::DaySpace => {
+                    out.push(if d < 10 { ' ' } else { (b'0' + d / 10) as char });
+                    out.push((b'0' + d % 10) as char);
+                }
+                FormatItem::DayOfYear => { let _ = write!(out, "{:03}", day_of_year(days)); }
+                FormatItem::WeekdayShort => out.push_str(short_weekday_name(days_to_weekday_iso(days))),
+                FormatItem::WeekdayLong => out.push_str(long_weekday_name(days_to_weekday_iso(days))),
+                FormatItem::WeekSunday => push_2(out, week_of_year_sunday(days)),
+                FormatItem::WeekMonday => push_2(out, week_of_year_monday(days)),
+                FormatItem::WeekIso => push_2(out, iso_year_week(days).1),
+                FormatItem::MonthShort => out.push_str(short_month_name(m)),
+                FormatItem::MonthLong => out.push_str(long_month_name(m)),
+                FormatItem::Hour2 => push_2(out, hh),
+                FormatItem::Minute2 => push_2(out, mm),
+                FormatItem::Second2 => push_2(out, ss),
+                FormatItem::Fraction => push_fraction(out, ns),
+            }
+        }
+    }
+}
+
+#[inline]
+fn push_2(out: &mut String, v: u8) {
+    out.push((b'0' + v / 10) as char);
+    out.push((b'0' + v % 10) as char);
+}
+
+/// Writes nothing for whole seconds, otherwise `.` + 3, 6 or 9 digits.
+#[inline]
+fn push_fraction(out: &mut String, ns: u32) {
+    if ns == 0 {
+        return;
+    }
+    if ns.is_multiple_of(1_000_000) {
+        let _ = write!(out, ".{:03}", ns / 1_000_000);
+    } else if ns.is_multiple_of(1_000) {
+        let _ = write!(out, ".{:06}", ns / 1_000);
+    } else {
+        let _ = write!(out, ".{:09}", ns);
+    }
+}
+
+impl Date {
+
+    /// Formats with the same tokens accepted by [`Date::from_ymd_str`].
+    pub fn format(&self, fmt: &str) -> ErrorResult<String> {
+        let formatter = compile_format(fmt)?;
+        let mut out = String::new();
+        formatter.write_date(&mut out, self.days);
+        Ok(out)
+    }
+}
+
+impl DateVector {
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _format(&self, formatter: &DateFormatter) -> VectorData<String> {
+        let n = self.days.len();
+        let mut out = vec![String::new(); n];
+
+        for (i, o) in out.iter_mut().enumerate() {
+            if self.validity[i] {
+                formatter.write_date(o, self.days[i]);
+            }
+        }
+
+        VectorData { data: out, validity: self.validity.clone() }
+    }
+
+    /// Formats every element; null rows stay null.
+    pub fn format(&self, fmt: &str) -> ErrorResult<VectorData<String>> {
+        let formatter = compile_format(fmt)?;
+        Ok(self._format(&formatter))
+    }
+}
\ No newline at end of file
@@ -136,21 +136,33 @@ impl DateVector {
         }
     }
 
-//     /// Returns a zero-copy borrowed view.
-//     #[inline]
-//     pub fn slice_view(&self, start: usize, end: usize) -> DateVectorView<'_> {
-//         let len = self.days.len();
-//         if start >= end || start >= len {
-//             return DateVectorView {
-//                 days: &[],
-//                 validity: BitSlice::empty(),
-//             };
-//         }
-//
-//         let end = end.min(len);
-//         DateVectorView {
-//             days: &self.days[start..end],
-//             validity: &self.validity[start..end],
-//         }
-//     }
+    /// Returns a zero-copy borrowed view over the range `[start, end)`.
+    #[inline]
+    pub fn slice_view(&self, start: usize, end: usize) -> DateVectorView<'_> {
+        let len = self.days.len();
+        if start >= end || start >= len {
+            return DateVectorView {
+                days: &[],
+                validity: BitSlice::empty(),
+            };
+        }
+
+        let end = end.min(len);
+        DateVectorView {
+            days: &self.days[start..end],
+            validity: &self.validity[start..end],
+        }
+    }
+}
+
+impl<'a> Viewable<'a, i32> for DateVector {
+    type ViewType = DateVectorView<'a>;
+
+    #[inline]
+    fn view(&'a self) -> Self::ViewType {
+        DateVectorView {
+            days: &self.days,
+            validity: &self.validity,
+        }
+    }
 }
\ No newline at end of file
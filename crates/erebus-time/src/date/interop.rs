@@ -0,0 +1,69 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// Parses string columns into dates.
+pub trait ToDate {
+    /// Parses each valid string with `fmt`. Null or unparseable entries are null.
+    fn to_date(&self, fmt: &str) -> ErrorResult<DateVector>;
+}
+
+// === Impls ===
+
+impl DateVector {
+
+    /// Days since 1970-01-01 as `VectorData<i64>`, nulls preserved.
+    #[inline]
+    pub fn to_vector_data(&self) -> VectorData<i64> {
+        VectorData {
+            data: self.days.iter().map(|&d| d as i64).collect(),
+            validity: self.validity.clone(),
+        }
+    }
+
+    /// Builds dates from days since 1970-01-01.
+    /// Errors if a valid value does not fit the `i32` day range.
+    pub fn from_vector_data(v: &VectorData<i64>) -> ErrorResult<Self> {
+        let mut days = vec![0i32; v.data.len()];
+        for (i, d) in days.iter_mut().enumerate() {
+            if v.validity[i] {
+                *d = i32::try_from(v.data[i]).map_err(|_| {
+                    ErebusError::InvalidOperation(format!("Day count out of range: {}", v.data[i]))
+                })?;
+            }
+        }
+        Ok(Self { days, validity: v.validity.clone() })
+    }
+
+    /// Formats each date with `fmt`; same as [`DateVector::format`].
+    #[inline]
+    pub fn to_text(&self, fmt: &str) -> ErrorResult<VectorData<String>> {
+        self.format(fmt)
+    }
+}
+
+impl From<&DateVector> for VectorData<i64> {
+    #[inline]
+    fn from(v: &DateVector) -> Self {
+        v.to_vector_data()
+    }
+}
+
+impl TryFrom<&VectorData<i64>> for DateVector {
+    type Error = ErebusError;
+
+    #[inline]
+    fn try_from(v: &VectorData<i64>) -> ErrorResult<Self> {
+        DateVector::from_vector_data(v)
+    }
+}
+
+impl ToDate for VectorData<String> {
+    fn to_date(&self, fmt: &str) -> ErrorResult<DateVector> {
+        let values: Vec<&str> = self.data.iter().map(|s| s.as_str()).collect();
+        let mut out = DateVector::from_ymd_str_vectors(&values, fmt)?;
+        out.validity &= &self.validity;
+        Ok(out)
+    }
+}
@@ -0,0 +1,37 @@
+// === Imports ===
+use crate::prelude::*;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+
+// === Impl ===
+
+impl DateVector {
+
+    /// Writes this vector as a delta-encoded `.erebus` date object,
+    /// using the writer's compression.
+    #[inline]
+    pub fn write_to<W: Write + Seek>(&self, writer: &mut ErebusWriter<W>) -> ErrorResult<()> {
+        writer.date_vector().write(&self.days, &self.validity)
+    }
+
+    /// Reads a `.erebus` date object.
+    #[inline]
+    pub fn read_from<R: Read + Seek>(reader: &mut ErebusReader<R>) -> ErrorResult<Self> {
+        let (days, validity) = reader.date_vector().read()?;
+        Ok(Self { days, validity })
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P, compression: CompressionType) -> ErrorResult<()> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut writer = ErebusWriter::new(file).with_compression(compression);
+        self.write_to(&mut writer)?;
+        writer.inner_mut().flush()?;
+        Ok(())
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ErrorResult<Self> {
+        let mut reader = ErebusReader::new(BufReader::new(File::open(path)?));
+        Self::read_from(&mut reader)
+    }
+}
@@ -1,12 +1,35 @@
+mod arithmetic;
+mod boundary;
 mod convert;
 mod date;
+mod detect;
 mod extract;
+mod format;
 mod indexing;
+mod interop;
+mod io;
 mod parse;
+mod range;
+mod reduce;
+mod resample;
+mod search;
+mod serial;
+mod set;
+mod sort;
 mod validate;
 mod validity;
+mod view;
 mod weekday;
 mod weekend;
 
 pub use date::{Date, DateVector};
-pub use convert::*;
\ No newline at end of file
+pub use interop::ToDate;
+pub use view::DateVectorView;
+pub use detect::{AutoParse, DateParseReport, ParseAutoOptions};
+pub use range::{DateFreq, RangeBound};
+pub use resample::{Aggregation, FillMethod, ResampleOptions};
+pub use serial::SerialEpoch;
+pub use convert::*;
+pub(crate) use arithmetic::shift_months_days;
+pub(crate) use format::{compile_format, DateFormatter};
+pub(crate) use parse::{compile_ymd_format, extract_ymd_hms, YmdExtractor};
\ No newline at end of file
@@ -2,56 +2,125 @@
 use crate::prelude::*;
 use super::convert::ymd_to_days;
 use super::validate::{validate_ymd_lenient, validate_ymd_strict};
-use crate::calendar::gregorian::{month_from_short, month_from_long};
+use crate::calendar::gregorian::{
+    month_from_short, month_from_long, weekday_from_short, weekday_from_long,
+};
+
+// === Constants ===
+
+/// Variable-width fields (%b, %B, %A, %-d, %-m) allowed in one format.
+const MAX_VARIABLE: usize = 4;
 
 // === Types ===
 
 #[derive(Debug, Clone)]
 pub struct YmdExtractor {
     year: FieldExtractor,
-    month: FieldExtractor,
-    day: FieldExtractor,
+    month: Option<FieldExtractor>,
+    day: Option<FieldExtractor>,
+    yday: Option<FieldExtractor>,
+    weekday: Option<FieldExtractor>,
+    week: Option<FieldExtractor>,
+    hour: Option<FieldExtractor>,
+    minute: Option<FieldExtractor>,
+    second: Option<FieldExtractor>,
+    fraction: Option<usize>, // offset of `%.f`, always the last token
+    variables: Vec<VariableField>,
     total_len: usize,
     literals: Vec<LiteralCheck>,
 }
 
+/// ((year, month, day), (hour, minute, second, nanos))
+pub(crate) type YmdHms = ((i32, u8, u8), (u8, u8, u8, u32));
+
 #[derive(Debug, Clone)]
 struct LiteralCheck {
     idx: usize,
     byte: u8,
 }
 
+/// Field whose width is only known at runtime: names (3-char minimum)
+/// and unpadded numbers (1-2 digits). Longer matches shift later fields.
+#[derive(Debug, Clone, Copy)]
+struct VariableField {
+    offset: usize,
+    term: Option<u8>, // literal terminator if known (e.g. space, '-', ',', etc.)
+    digits: bool,
+}
+
+/// Runtime shifts caused by variable-width fields:
+/// `(compile-time end, cumulative shift)` for each of them.
+#[derive(Debug, Clone, Copy, Default)]
+struct Shifts {
+    points: [(usize, usize); MAX_VARIABLE],
+    len: usize,
+}
+
 #[derive(Debug, Clone)]
 enum FieldExtractor {
     Fixed {
         offset: usize,
-        width: usize,
         kind: FixedKind,
     },
-    MonthName {
+    Name {
+        offset: usize,
+        kind: NameKind,
+        term: Option<u8>,
+    },
+    /// `%-m` / `%-d`: 1 or 2 digits.
+    Unpadded {
         offset: usize,
-        long: bool,        // %B vs %b
-        term: Option<u8>,  // literal terminator if known (e.g. space, '-', ',', etc.)
     },
 }
 
 #[derive(Debug, Clone, Copy)]
 enum FixedKind {
-    Year4,   // %Y
-    Year2,   // %y
-    Month2,  // %m
-    Day2,    // %d
+    Year4,        // %Y
+    Year2,        // %y
+    IsoYear4,     // %G
+    Month2,       // %m
+    Day2,         // %d
+    DaySpace,     // %e
+    DayOfYear,    // %j
+    WeekdayShort, // %a
+    WeekSunday,   // %U
+    WeekMonday,   // %W
+    WeekIso,      // %V
+    Hour2,        // %H
+    Minute2,      // %M
+    Second2,      // %S
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NameKind {
+    MonthShort,  // %b
+    MonthLong,   // %B
+    WeekdayLong, // %A
 }
 
 // === Impls ===
 
-impl YmdExtractor {
+impl FixedKind {
+    #[inline]
+    fn width(self) -> usize {
+        match self {
+            FixedKind::Year4 | FixedKind::IsoYear4 => 4,
+            FixedKind::DayOfYear | FixedKind::WeekdayShort => 3,
+            _ => 2,
+        }
+    }
+}
+
+impl Shifts {
     #[inline]
-    fn variable_offset(&self) -> Option<usize> {
-        match self.month {
-            FieldExtractor::MonthName { offset, .. } => Some(offset),
-            _ => None,
+    fn adjust(&self, idx: usize) -> usize {
+        let mut shift = 0;
+        for &(end, s) in &self.points[..self.len] {
+            if idx >= end {
+                shift = s;
+            }
         }
+        idx + shift
     }
 }
 
@@ -63,7 +132,15 @@ pub fn compile_ymd_format(fmt: &str) -> ErrorResult<YmdExtractor> {
     let mut year = None;
     let mut month = None;
     let mut day = None;
-
+    let mut yday = None;
+    let mut weekday = None;
+    let mut week = None;
+    let mut hour = None;
+    let mut minute = None;
+    let mut second = None;
+    let mut fraction = None;
+
+    let mut variables = Vec::new();
     let mut literals = Vec::new();
 
     while i < bytes.len() {
@@ -72,59 +149,75 @@ pub fn compile_ymd_format(fmt: &str) -> ErrorResult<YmdExtractor> {
                 return Err(ErebusError::InvalidOperation("Dangling '%' in format".into()));
             }
 
-            match bytes[i + 1] {
-                b'Y' => {
-                    ensure_unique(&year, "year")?;
-                    year = Some(FieldExtractor::Fixed {
-                        offset: out_pos,
-                        width: 4,
-                        kind: FixedKind::Year4,
-                    });
-                    out_pos += 4;
-                }
-                b'y' => {
-                    ensure_unique(&year, "year")?;
-                    year = Some(FieldExtractor::Fixed {
-                        offset: out_pos,
-                        width: 2,
-                        kind: FixedKind::Year2,
-                    });
-                    out_pos += 2;
-                }
-                b'm' => {
-                    ensure_unique(&month, "month")?;
-                    month = Some(FieldExtractor::Fixed {
-                        offset: out_pos,
-                        width: 2,
-                        kind: FixedKind::Month2,
-                    });
-                    out_pos += 2;
-                }
-                b'd' => {
-                    ensure_unique(&day, "day")?;
-                    day = Some(FieldExtractor::Fixed {
-                        offset: out_pos,
-                        width: 2,
-                        kind: FixedKind::Day2,
-                    });
-                    out_pos += 2;
-                }
-                b'b' | b'B' => {
-                    ensure_unique(&month, "month")?;
+            let fixed = |slot: &mut Option<FieldExtractor>, name: &str, kind: FixedKind, pos: &mut usize| {
+                ensure_unique(slot, name)?;
+                *slot = Some(FieldExtractor::Fixed { offset: *pos, kind });
+                *pos += kind.width();
+                Ok::<(), ErebusError>(())
+            };
 
-                    // If the format has a literal immediately after %b/%B, use it as terminator.
+            match bytes[i + 1] {
+                b'Y' => fixed(&mut year, "year", FixedKind::Year4, &mut out_pos)?,
+                b'y' => fixed(&mut year, "year", FixedKind::Year2, &mut out_pos)?,
+                b'G' => fixed(&mut year, "year", FixedKind::IsoYear4, &mut out_pos)?,
+                b'm' => fixed(&mut month, "month", FixedKind::Month2, &mut out_pos)?,
+                b'd' => fixed(&mut day, "day", FixedKind::Day2, &mut out_pos)?,
+                b'e' => fixed(&mut day, "day", FixedKind::DaySpace, &mut out_pos)?,
+                b'j' => fixed(&mut yday, "day of year", FixedKind::DayOfYear, &mut out_pos)?,
+                b'a' => fixed(&mut weekday, "weekday", FixedKind::WeekdayShort, &mut out_pos)?,
+                b'U' => fixed(&mut week, "week", FixedKind::WeekSunday, &mut out_pos)?,
+                b'W' => fixed(&mut week, "week", FixedKind::WeekMonday, &mut out_pos)?,
+                b'V' => fixed(&mut week, "week", FixedKind::WeekIso, &mut out_pos)?,
+                b'H' => fixed(&mut hour, "hour", FixedKind::Hour2, &mut out_pos)?,
+                b'M' => fixed(&mut minute, "minute", FixedKind::Minute2, &mut out_pos)?,
+                b'S' => fixed(&mut second, "second", FixedKind::Second2, &mut out_pos)?,
+                b'b' | b'B' | b'A' => {
+                    let (slot, name, kind) = match bytes[i + 1] {
+                        b'b' => (&mut month, "month", NameKind::MonthShort),
+                        b'B' => (&mut month, "month", NameKind::MonthLong),
+                        _ => (&mut weekday, "weekday", NameKind::WeekdayLong),
+                    };
+                    ensure_unique(slot, name)?;
+
+                    ensure_variable_room(&variables)?;
+
+                    // If the format has a literal immediately after the name, use it as terminator.
                     // Example: "%B %d, %Y" => terminator is ' ' (space).
                     let term = bytes.get(i + 2).copied().filter(|c| *c != b'%');
 
-                    month = Some(FieldExtractor::MonthName {
-                        offset: out_pos,
-                        long: bytes[i + 1] == b'B',
-                        term,
-                    });
+                    *slot = Some(FieldExtractor::Name { offset: out_pos, kind, term });
+                    variables.push(VariableField { offset: out_pos, term, digits: false });
 
-                    // Minimum width: 3 (Jan/Feb/Mar). Long months can be longer.
+                    // Minimum width: 3 (Jan, Mon). Long names can be longer.
                     out_pos += 3;
                 }
+                b'-' if matches!(bytes.get(i + 2), Some(b'd') | Some(b'm')) => {
+                    let (slot, name) = if bytes[i + 2] == b'd' {
+                        (&mut day, "day")
+                    } else {
+                        (&mut month, "month")
+                    };
+                    ensure_unique(slot, name)?;
+                    ensure_variable_room(&variables)?;
+
+                    *slot = Some(FieldExtractor::Unpadded { offset: out_pos });
+                    variables.push(VariableField { offset: out_pos, term: None, digits: true });
+                    out_pos += 1;
+                    i += 3;
+                    continue;
+                }
+                b'.' if bytes.get(i + 2) == Some(&b'f') => {
+                    // Optional fractional seconds: "" or "." followed by 1-9 digits.
+                    // Variable width, so it must close the format.
+                    if i + 3 != bytes.len() {
+                        return Err(ErebusError::InvalidOperation(
+                            "%.f must be the last token in format".into(),
+                        ));
+                    }
+                    fraction = Some(out_pos);
+                    i += 3;
+                    continue;
+                }
                 _ => {
                     return Err(ErebusError::InvalidOperation(
                         format!("Unsupported token: %{}", bytes[i + 1] as char),
@@ -143,15 +236,53 @@ pub fn compile_ymd_format(fmt: &str) -> ErrorResult<YmdExtractor> {
         }
     }
 
+    let year = year.ok_or_else(|| ErebusError::InvalidOperation("Missing year".into()))?;
+
+    let iso_year = matches!(year, FieldExtractor::Fixed { kind: FixedKind::IsoYear4, .. });
+    if iso_year && (month.is_some() || yday.is_some()) {
+        return Err(ErebusError::InvalidOperation("%G can only be combined with %V".into()));
+    }
+
+    // The date is fixed by month + day, day of year, or week + weekday
+    if yday.is_none() && !(week.is_some() && weekday.is_some()) {
+        if month.is_none() {
+            return Err(ErebusError::InvalidOperation("Missing month".into()));
+        }
+        if day.is_none() {
+            return Err(ErebusError::InvalidOperation("Missing day".into()));
+        }
+    }
+    if month.is_some() != day.is_some() {
+        return Err(ErebusError::InvalidOperation("Month and day must be given together".into()));
+    }
+
     Ok(YmdExtractor {
-        year: year.ok_or_else(|| ErebusError::InvalidOperation("Missing year".into()))?,
-        month: month.ok_or_else(|| ErebusError::InvalidOperation("Missing month".into()))?,
-        day: day.ok_or_else(|| ErebusError::InvalidOperation("Missing day".into()))?,
+        year,
+        month,
+        day,
+        yday,
+        weekday,
+        week,
+        hour,
+        minute,
+        second,
+        fraction,
+        variables,
         total_len: out_pos,
         literals,
     })
 }
 
+fn ensure_variable_room(variables: &[VariableField]) -> ErrorResult<()> {
+    if variables.len() == MAX_VARIABLE {
+        Err(ErebusError::InvalidOperation(
+            "Too many variable-width fields (%b, %B, %A, %-d, %-m) in format".into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn ensure_unique<T>(slot: &Option<T>, name: &str) -> ErrorResult<()> {
     if slot.is_some() {
         Err(ErebusError::InvalidOperation(format!(
@@ -196,168 +327,278 @@ pub fn parse_ymd_str_vector_lenient(values: &[&str], ex: &YmdExtractor) -> DateV
     DateVector { days, validity }
 }
 
-fn extract_year(b: &[u8], f: &FieldExtractor) -> Option<i32> {
-    match *f {
-        FieldExtractor::Fixed { offset, kind, .. } => match kind {
-            FixedKind::Year4 => parse_i32_4(b, offset),
-            FixedKind::Year2 => parse_i32_2(b, offset).map(infer_century),
-            _ => None,
-        },
-        _ => None,
+/// Like [`parse_ymd_lenient`], but the format must consume the whole string.
+pub(crate) fn parse_ymd_exact(s: &str, ex: &YmdExtractor) -> Option<i32> {
+    let b = s.as_bytes();
+    let shifts = compute_shifts(b, ex)?;
+    if ex.fraction.is_none() && shifts.adjust(ex.total_len) != b.len() {
+        return None;
     }
-}
-
-fn extract_month(b: &[u8], f: &FieldExtractor) -> Option<u8> {
-    match *f {
-        FieldExtractor::Fixed { offset, kind, .. } => match kind {
-            FixedKind::Month2 => parse_u8_2(b, offset),
-            _ => None,
-        },
-        FieldExtractor::MonthName { offset, long, term } => {
-            // Determine end of month token
-            let end = match term {
-                Some(t) => {
-                    let mut j = offset;
-                    while j < b.len() && b[j] != t {
-                        j += 1;
-                    }
-                    if j == offset { return None; } // empty
-                    j
-                }
-                None => b.len(),
-            };
-
-            let s = std::str::from_utf8(&b[offset..end]).ok()?;
-            if long {
-                month_from_long(s)
-            } else {
-                month_from_short(s)
-            }
-        }
+    let (y, m, d) = extract_date(b, ex, &shifts)?;
+    if validate_ymd_lenient(y, m, d) {
+        Some(ymd_to_days(y, m, d))
+    } else {
+        None
     }
 }
 
-fn extract_day(b: &[u8], f: &FieldExtractor) -> Option<u8> {
-    match *f {
-        FieldExtractor::Fixed { offset, kind, .. } => match kind {
-            FixedKind::Day2 => parse_u8_2(b, offset),
-            _ => None,
-        },
-        _ => None,
-    }
+fn extract_ymd(s: &str, ex: &YmdExtractor) -> Option<(i32, u8, u8)> {
+    let b = s.as_bytes();
+    let shifts = compute_shifts(b, ex)?;
+    extract_date(b, ex, &shifts)
 }
 
-fn extract_ymd(s: &str, ex: &YmdExtractor) -> Option<(i32, u8, u8)> {
+/// Extracts date and time-of-day fields.
+/// Missing time fields default to zero.
+pub(crate) fn extract_ymd_hms(s: &str, ex: &YmdExtractor) -> Option<YmdHms> {
     let b = s.as_bytes();
+    let shifts = compute_shifts(b, ex)?;
+    let ymd = extract_date(b, ex, &shifts)?;
 
+    let time = |f: &Option<FieldExtractor>| match f {
+        Some(f) => read_field(b, f, &shifts).map(|v| v as u8),
+        None => Some(0),
+    };
+    let hour = time(&ex.hour)?;
+    let minute = time(&ex.minute)?;
+    let second = time(&ex.second)?;
+    let nanos = match ex.fraction {
+        Some(off) => parse_fraction(b, shifts.adjust(off))?,
+        None => 0,
+    };
+
+    Some((ymd, (hour, minute, second, nanos)))
+}
+
+/// Checks the minimum length and measures each variable-width field.
+fn compute_shifts(b: &[u8], ex: &YmdExtractor) -> Option<Shifts> {
     // still keep minimum length check
     if b.len() < ex.total_len {
         return None;
     }
 
-    // If month is variable-length (%b / %B), compute runtime shift
-    let (base_end, shift) = match ex.month {
-        FieldExtractor::MonthName { offset, term, .. } => {
-            let base_end = offset + 3; // compile-time assumption
-
-            // find actual month token end (terminator or end-of-string)
-            let end = match term {
-                Some(t) => {
-                    let mut j = offset;
-                    while j < b.len() && b[j] != t {
-                        j += 1;
-                    }
-                    // month cannot be empty
-                    if j == offset { return None; }
-                    j
-                }
-                None => b.len(),
-            };
+    let mut shifts = Shifts::default();
+    let mut cumulative = 0usize;
+
+    for v in &ex.variables {
+        let start = v.offset + cumulative;
+        let min = if v.digits { 1 } else { 3 };
+        let actual_len = if v.digits {
+            digits_len(b, start)
+        } else {
+            name_end(b, start, v.term)? - start
+        };
+        if actual_len < min { return None; }
+
+        cumulative += actual_len - min;
+        shifts.points[shifts.len] = (v.offset + min, cumulative);
+        shifts.len += 1;
+    }
+
+    Some(shifts)
+}
 
-            let actual_len = end.checked_sub(offset)?;
-            if actual_len < 3 { return None; }
+/// Number of digits (at most 2) starting at `start`.
+#[inline]
+fn digits_len(b: &[u8], start: usize) -> usize {
+    b.get(start..).unwrap_or(&[]).iter().take(2).take_while(|&&c| is_digit(c)).count()
+}
 
-            let shift = actual_len - 3;
-            (base_end, shift)
+/// End of a name starting at `start`: the terminator or end-of-string.
+#[inline]
+fn name_end(b: &[u8], start: usize, term: Option<u8>) -> Option<usize> {
+    match term {
+        Some(t) => {
+            let j = start + b.get(start..)?.iter().position(|&c| c == t)?;
+            // name cannot be empty
+            if j == start { None } else { Some(j) }
         }
-        _ => (usize::MAX, 0), // no variable field, no shift
-    };
+        None => Some(b.len()),
+    }
+}
 
-    // Validate literals, but adjust those after the month token
+/// Resolves the date from month + day, day of year, or week + weekday,
+/// then checks any remaining redundant fields agree with it.
+/// Month + day are returned unvalidated so callers can report them.
+fn extract_date(b: &[u8], ex: &YmdExtractor, shifts: &Shifts) -> Option<(i32, u8, u8)> {
+    // Validate literals, but adjust those after variable-width names
     for lit in &ex.literals {
-        let idx = adjust_idx(lit.idx, base_end, shift);
-        if *b.get(idx)? != lit.byte {
+        if *b.get(shifts.adjust(lit.idx))? != lit.byte {
             return None;
         }
     }
 
-    // Extract year/day using adjusted offsets if needed
-    let year = extract_year_shifted(b, &ex.year, base_end, shift)?;
-    let month = extract_month_shifted(b, &ex.month, base_end, shift)?;
-    let day = extract_day_shifted(b, &ex.day, base_end, shift)?;
+    let read = |f: &Option<FieldExtractor>| match f {
+        Some(f) => read_field(b, f, shifts).map(Some),
+        None => Some(None),
+    };
 
-    Some((year, month, day))
-}
+    let year = read_field(b, &ex.year, shifts)?;
+    let month = read(&ex.month)?;
+    let day = read(&ex.day)?;
+    let yday = read(&ex.yday)?;
+    let weekday = read(&ex.weekday)?;
+    let week = read(&ex.week)?;
 
-#[inline]
-fn adjust_idx(idx: usize, base_end: usize, shift: usize) -> usize {
-    if idx >= base_end { idx + shift } else { idx }
-}
+    let redundant = yday.is_some() || weekday.is_some() || week.is_some();
 
-fn extract_year_shifted(b: &[u8], f: &FieldExtractor, base_end: usize, shift: usize) -> Option<i32> {
-    match *f {
-        FieldExtractor::Fixed { offset, kind, .. } => {
-            let off = adjust_idx(offset, base_end, shift);
-            match kind {
-                FixedKind::Year4 => parse_i32_4(b, off),
-                FixedKind::Year2 => parse_i32_2(b, off).map(infer_century),
-                _ => None,
+    let days = match (month, day) {
+        (Some(m), Some(d)) => {
+            let (m, d) = (m as u8, d as u8);
+            if !redundant {
+                return Some((year, m, d));
             }
+            if !validate_ymd_lenient(year, m, d) {
+                return None;
+            }
+            ymd_to_days(year, m, d)
         }
-        _ => None,
+        _ => match yday {
+            Some(j) => {
+                let len = if is_leap_year(year) { 366 } else { 365 };
+                if !(1..=len).contains(&j) {
+                    return None;
+                }
+                ymd_to_days(year, 1, 1) + j - 1
+            }
+            None => date_from_week(year, week?, weekday?, ex)?,
+        },
+    };
+
+    if yday.is_some_and(|j| j != day_of_year(days) as i32)
+        || weekday.is_some_and(|w| w != days_to_weekday_iso(days) as i32)
+        || week.is_some_and(|w| w != week_number(days, ex.week.as_ref().unwrap()) as i32)
+    {
+        return None;
     }
+
+    Some(days_to_ymd(days))
 }
 
-fn extract_day_shifted(b: &[u8], f: &FieldExtractor, base_end: usize, shift: usize) -> Option<u8> {
+#[inline]
+fn week_kind(f: &FieldExtractor) -> FixedKind {
     match *f {
-        FieldExtractor::Fixed { offset, kind, .. } => {
-            let off = adjust_idx(offset, base_end, shift);
-            match kind {
-                FixedKind::Day2 => parse_u8_2(b, off),
-                _ => None,
+        FieldExtractor::Fixed { kind, .. } => kind,
+        _ => unreachable!("week fields are fixed-width"),
+    }
+}
+
+#[inline]
+fn week_number(days: i32, f: &FieldExtractor) -> u8 {
+    match week_kind(f) {
+        FixedKind::WeekSunday => week_of_year_sunday(days),
+        FixedKind::WeekMonday => week_of_year_monday(days),
+        _ => iso_year_week(days).1,
+    }
+}
+
+/// Date of ISO `weekday` in `week` of `year`.
+/// With %V, `year` is the ISO week-based year for %G. For %Y / %y it is
+/// the calendar year of the result, preferring the matching ISO year;
+/// this is ambiguous around new year, so %G should be used to round-trip.
+fn date_from_week(year: i32, week: i32, weekday: i32, ex: &YmdExtractor) -> Option<i32> {
+    match week_kind(ex.week.as_ref()?) {
+        FixedKind::WeekIso => {
+            if !(1..=53).contains(&week) {
+                return None;
             }
+            let iso_year = matches!(ex.year, FieldExtractor::Fixed { kind: FixedKind::IsoYear4, .. });
+            let candidates: &[i32] = if iso_year { &[0] } else { &[0, -1, 1] };
+
+            candidates.iter().find_map(|&k| {
+                let d = iso_week_start(year + k, week as u8) + weekday - 1;
+                let ok = iso_year_week(d) == (year + k, week as u8)
+                    && (iso_year || days_to_ymd(d).0 == year);
+                if ok { Some(d) } else { None }
+            })
+        }
+        kind => {
+            if week > 53 {
+                return None;
+            }
+            // Week 1 starts on the year's first Sunday (%U) or Monday (%W)
+            let first_day = if matches!(kind, FixedKind::WeekSunday) { 7 } else { 1 };
+            let jan1 = ymd_to_days(year, 1, 1);
+            let first = jan1 + (first_day - days_to_weekday_iso(jan1) as i32).rem_euclid(7);
+            let days = first + (week - 1) * 7 + (weekday - first_day).rem_euclid(7);
+            if days_to_ymd(days).0 == year { Some(days) } else { None }
         }
-        _ => None,
     }
 }
 
-fn extract_month_shifted(b: &[u8], f: &FieldExtractor, base_end: usize, shift: usize) -> Option<u8> {
+/// Reads one field as an integer (names map to their 1-based index).
+fn read_field(b: &[u8], f: &FieldExtractor, shifts: &Shifts) -> Option<i32> {
     match *f {
-        FieldExtractor::Fixed { offset, kind, .. } => {
-            let off = adjust_idx(offset, base_end, shift);
-            match kind {
-                FixedKind::Month2 => parse_u8_2(b, off),
-                _ => None,
-            }
-        }
-        FieldExtractor::MonthName { offset, long, term } => {
-            // MonthName offset itself is never shifted: it starts where it starts.
-            let end = match term {
-                Some(t) => {
-                    let mut j = offset;
-                    while j < b.len() && b[j] != t {
-                        j += 1;
-                    }
-                    if j == offset { return None; }
-                    j
+        FieldExtractor::Fixed { offset, kind } => {
+            let off = shifts.adjust(offset);
+            let (v, max) = match kind {
+                FixedKind::Year4 | FixedKind::IsoYear4 => return parse_i32_4(b, off),
+                FixedKind::Year2 => return parse_i32_2(b, off).map(infer_century),
+                FixedKind::Month2 | FixedKind::Day2 => return parse_u8_2(b, off).map(|v| v as i32),
+                FixedKind::DaySpace => {
+                    let v = if b.get(off) == Some(&b' ') {
+                        let c = *b.get(off + 1)?;
+                        if !is_digit(c) { return None; }
+                        c - b'0'
+                    } else {
+                        parse_u8_2(b, off)?
+                    };
+                    return Some(v as i32);
+                }
+                FixedKind::DayOfYear => return parse_i32_3(b, off),
+                FixedKind::WeekdayShort => {
+                    let s = std::str::from_utf8(b.get(off..off + 3)?).ok()?;
+                    return weekday_from_short(s).map(|v| v as i32);
+                }
+                FixedKind::WeekSunday | FixedKind::WeekMonday | FixedKind::WeekIso => {
+                    (parse_u8_2(b, off)?, 53)
                 }
-                None => b.len(),
+                FixedKind::Hour2 => (parse_u8_2(b, off)?, 23),
+                FixedKind::Minute2 | FixedKind::Second2 => (parse_u8_2(b, off)?, 59),
             };
+            if v > max { None } else { Some(v as i32) }
+        }
+        FieldExtractor::Unpadded { offset } => {
+            let start = shifts.adjust(offset);
+            let digits = b.get(start..start + digits_len(b, start))?;
+            Some(digits.iter().fold(0, |v, &c| v * 10 + (c - b'0') as i32))
+        }
+        FieldExtractor::Name { offset, kind, term } => {
+            let start = shifts.adjust(offset);
+            let end = name_end(b, start, term)?;
+            let s = std::str::from_utf8(&b[start..end]).ok()?;
+            let v = match kind {
+                NameKind::MonthShort => month_from_short(s),
+                NameKind::MonthLong => month_from_long(s),
+                NameKind::WeekdayLong => weekday_from_long(s),
+            };
+            v.map(|v| v as i32)
+        }
+    }
+}
 
-            let s = std::str::from_utf8(&b[offset..end]).ok()?;
-            if long { month_from_long(s) } else { month_from_short(s) }
+/// Parses an optional `.` + 1-9 digits running to the end of input.
+fn parse_fraction(b: &[u8], off: usize) -> Option<u32> {
+    if off == b.len() {
+        return Some(0);
+    }
+    if b[off] != b'.' {
+        return None;
+    }
+
+    let digits = &b[off + 1..];
+    if digits.is_empty() || digits.len() > 9 {
+        return None;
+    }
+
+    let mut v = 0u32;
+    for &d in digits {
+        if !is_digit(d) {
+            return None;
         }
+        v = v * 10 + (d - b'0') as u32;
     }
+    Some(v * 10u32.pow(9 - digits.len() as u32))
 }
 
 #[inline]
@@ -375,6 +616,18 @@ fn parse_u8_2(b: &[u8], off: usize) -> Option<u8> {
     }
 }
 
+fn parse_i32_3(b: &[u8], off: usize) -> Option<i32> {
+    let mut v = 0i32;
+    for i in 0..3 {
+        let d = *b.get(off + i)?;
+        if !is_digit(d) {
+            return None;
+        }
+        v = v * 10 + (d - b'0') as i32;
+    }
+    Some(v)
+}
+
 fn parse_i32_4(b: &[u8], off: usize) -> Option<i32> {
     let mut v = 0i32;
     for i in 0..4 {
@@ -404,4 +657,4 @@ fn infer_century(y: i32) -> i32 {
     // 00–69 → 2000–2069
     // 70–99 → 1970–1999
     if y <= 69 { 2000 + y } else { 1900 + y }
-}
\ No newline at end of file
+}
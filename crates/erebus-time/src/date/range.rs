@@ -0,0 +1,215 @@
+// === Imports ===
+use crate::prelude::*;
+use super::boundary::{month_end_days, quarter_end_days, year_end_days};
+use std::fmt;
+
+// === Types ===
+
+/// Step used by [`DateVector::range`].
+/// Anchored frequencies (month end, weekly, ...) start at the first
+/// anchor on or after the range start.
+#[derive(Clone, Copy)]
+pub enum DateFreq<'a> {
+
+    /// Every calendar day.
+    Daily,
+
+    /// Every business day of the given calendar.
+    BusinessDaily(&'a dyn Calendar),
+
+    /// Every week on the given ISO weekday (1=Mon .. 7=Sun).
+    Weekly { weekday: u8 },
+
+    MonthStart,
+    MonthEnd,
+    QuarterEnd,
+    YearEnd,
+
+    /// The `n`-th ISO weekday of each month (e.g. 3rd Friday).
+    /// Months without an `n`-th occurrence are skipped.
+    NthWeekday { weekday: u8, n: u8 },
+
+    /// Third Wednesday of each month.
+    Imm,
+}
+
+/// Whether a range includes its `end` date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeBound {
+    Inclusive,
+    Exclusive,
+}
+
+// === Impls ===
+
+// Calendars are not Debug, so BusinessDaily prints without its calendar
+impl fmt::Debug for DateFreq<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DateFreq::Daily => write!(f, "Daily"),
+            DateFreq::BusinessDaily(_) => write!(f, "BusinessDaily(..)"),
+            DateFreq::Weekly { weekday } => f.debug_struct("Weekly").field("weekday", &weekday).finish(),
+            DateFreq::MonthStart => write!(f, "MonthStart"),
+            DateFreq::MonthEnd => write!(f, "MonthEnd"),
+            DateFreq::QuarterEnd => write!(f, "QuarterEnd"),
+            DateFreq::YearEnd => write!(f, "YearEnd"),
+            DateFreq::NthWeekday { weekday, n } => f
+                .debug_struct("NthWeekday")
+                .field("weekday", &weekday)
+                .field("n", &n)
+                .finish(),
+            DateFreq::Imm => write!(f, "Imm"),
+        }
+    }
+}
+
+impl DateFreq<'_> {
+
+    fn validate(&self) -> ErrorResult<()> {
+        match *self {
+            DateFreq::Weekly { weekday } if !(1..=7).contains(&weekday) => Err(
+                ErebusError::InvalidOperation(format!("Invalid ISO weekday: {}", weekday)),
+            ),
+            DateFreq::NthWeekday { weekday, .. } if !(1..=7).contains(&weekday) => Err(
+                ErebusError::InvalidOperation(format!("Invalid ISO weekday: {}", weekday)),
+            ),
+            DateFreq::NthWeekday { n, .. } if !(1..=5).contains(&n) => Err(
+                ErebusError::InvalidOperation(format!("Invalid weekday occurrence: {}", n)),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// First anchor on or after `d`.
+    /// Not used for `BusinessDaily`, which is generated in bulk.
+    #[inline]
+    fn first_on_or_after(&self, d: i32) -> i32 {
+        match *self {
+            DateFreq::Daily | DateFreq::BusinessDaily(_) => d,
+            DateFreq::Weekly { weekday } => {
+                d + (weekday as i32 - days_to_weekday_iso(d) as i32).rem_euclid(7)
+            }
+            DateFreq::MonthStart => {
+                if days_to_ymd(d).2 == 1 { d } else { month_end_days(d) + 1 }
+            }
+            DateFreq::MonthEnd => month_end_days(d),
+            DateFreq::QuarterEnd => quarter_end_days(d),
+            DateFreq::YearEnd => year_end_days(d),
+            DateFreq::NthWeekday { weekday, n } => nth_weekday_on_or_after(d, weekday, n),
+            DateFreq::Imm => nth_weekday_on_or_after(d, 3, 3),
+        }
+    }
+
+    /// Anchor following `d`, where `d` is itself an anchor.
+    #[inline]
+    fn next(&self, d: i32) -> i32 {
+        match *self {
+            DateFreq::Daily | DateFreq::BusinessDaily(_) => d + 1,
+            DateFreq::Weekly { .. } => d + 7,
+            DateFreq::MonthStart => month_end_days(d) + 1,
+            DateFreq::MonthEnd => month_end_days(d + 1),
+            DateFreq::QuarterEnd => quarter_end_days(d + 1),
+            DateFreq::YearEnd => year_end_days(d + 1),
+            DateFreq::NthWeekday { weekday, n } => {
+                nth_weekday_on_or_after(month_end_days(d) + 1, weekday, n)
+            }
+            DateFreq::Imm => nth_weekday_on_or_after(month_end_days(d) + 1, 3, 3),
+        }
+    }
+}
+
+#[inline]
+fn nth_weekday_on_or_after(d: i32, weekday: u8, n: u8) -> i32 {
+    let mut m_start = d - days_to_ymd(d).2 as i32 + 1;
+    loop {
+        let (y, m, _) = days_to_ymd(m_start);
+        if let Some(x) = nth_weekday_of_month(y, m, weekday, n) {
+            if x >= d {
+                return x;
+            }
+        }
+        m_start = month_end_days(m_start) + 1;
+    }
+}
+
+impl DateVector {
+
+    /// Builds all dates of `freq` between `start` and `end`.
+    /// `start` is always inclusive; `bound` controls `end`.
+    /// Returns an empty vector if no date falls in the range.
+    pub fn range(
+        start: Date,
+        end: Date,
+        freq: DateFreq<'_>,
+        bound: RangeBound,
+    ) -> ErrorResult<DateVector> {
+        freq.validate()?;
+
+        let last = match bound {
+            RangeBound::Inclusive => end.days,
+            RangeBound::Exclusive => end.days - 1,
+        };
+
+        if start.days > last {
+            return Ok(DateVector { days: Vec::new(), validity: BitVec::new() });
+        }
+
+        if let DateFreq::BusinessDaily(cal) = freq {
+            return Ok(cal.business_days_between(start, Date { days: last }));
+        }
+
+        let mut days = Vec::new();
+        let mut d = freq.first_on_or_after(start.days);
+        while d <= last {
+            days.push(d);
+            d = freq.next(d);
+        }
+
+        let n = days.len();
+        Ok(DateVector { days, validity: bitvec![1; n] })
+    }
+
+    /// Builds `periods` dates of `freq` starting at `start` (inclusive).
+    /// Errors if a business-day calendar has a gap longer than [`MAX_ROLL_DAYS`].
+    pub fn range_periods(
+        start: Date,
+        periods: usize,
+        freq: DateFreq<'_>,
+    ) -> ErrorResult<DateVector> {
+        freq.validate()?;
+
+        let mut days = Vec::with_capacity(periods);
+
+        if let DateFreq::BusinessDaily(cal) = freq {
+            // Pull business days in windows sized to the remaining count
+            let mut from = start.days;
+            let mut last_hit = start.days;
+            while days.len() < periods {
+                let remaining = (periods - days.len()) as i32;
+                let to = from + remaining * 7 / 5 + 7;
+                let chunk = cal.business_days_between(Date { days: from }, Date { days: to });
+                let take = chunk.days.len().min(periods - days.len());
+                days.extend_from_slice(&chunk.days[..take]);
+                if take > 0 {
+                    last_hit = days[days.len() - 1];
+                } else if to - last_hit > MAX_ROLL_DAYS {
+                    return Err(ErebusError::InvalidOperation(format!(
+                        "no business day within {} days of day {}",
+                        MAX_ROLL_DAYS, last_hit
+                    )));
+                }
+                from = to + 1;
+            }
+        } else if periods > 0 {
+            let mut d = freq.first_on_or_after(start.days);
+            days.push(d);
+            while days.len() < periods {
+                d = freq.next(d);
+                days.push(d);
+            }
+        }
+
+        let n = days.len();
+        Ok(DateVector { days, validity: bitvec![1; n] })
+    }
+}
@@ -0,0 +1,44 @@
+// === Imports ===
+use crate::prelude::*;
+use std::collections::HashSet;
+
+// === Impl ===
+
+impl DateVector {
+
+    /// Valid day counts in row order.
+    #[inline]
+    pub(crate) fn valid_days(&self) -> impl Iterator<Item = i32> + '_ {
+        self.days
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| self.validity[i])
+            .map(|(_, &d)| d)
+    }
+
+    /// Earliest valid date, `None` if all null.
+    #[inline]
+    pub fn min(&self) -> Option<Date> {
+        self.valid_days().min().map(|days| Date { days })
+    }
+
+    /// Latest valid date, `None` if all null.
+    #[inline]
+    pub fn max(&self) -> Option<Date> {
+        self.valid_days().max().map(|days| Date { days })
+    }
+
+    /// Number of distinct valid dates.
+    #[inline]
+    pub fn n_unique(&self) -> usize {
+        self.valid_days().collect::<HashSet<i32>>().len()
+    }
+
+    /// Distinct valid dates in order of first appearance, nulls dropped.
+    pub fn unique(&self) -> Self {
+        let mut seen = HashSet::with_capacity(self.days.len());
+        let days: Vec<i32> = self.valid_days().filter(|&d| seen.insert(d)).collect();
+        let n = days.len();
+        Self { days, validity: bitvec![1; n] }
+    }
+}
@@ -0,0 +1,150 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// How a grid date with no observations in its bucket is filled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillMethod {
+
+    /// Last observation before the grid date.
+    Forward,
+
+    /// First observation after the grid date.
+    Backward,
+
+    /// Closest observation; ties go to the earlier one.
+    Nearest,
+
+    /// Leave the grid date null.
+    Null,
+}
+
+/// How several observations falling into one bucket are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    First,
+    Last,
+    Mean,
+    Sum,
+    Min,
+    Max,
+}
+
+/// Settings for [`DateVector::resample`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResampleOptions {
+    pub fill: FillMethod,
+
+    /// Largest distance in days a filled value may be carried.
+    /// `None` means no limit.
+    pub max_gap: Option<u32>,
+
+    pub agg: Aggregation,
+}
+
+// === Impls ===
+
+impl Default for ResampleOptions {
+    #[inline]
+    fn default() -> Self {
+        Self { fill: FillMethod::Null, max_gap: None, agg: Aggregation::Last }
+    }
+}
+
+impl Aggregation {
+    #[inline]
+    fn apply(self, bucket: &VectorData<f64>) -> Option<f64> {
+        match self {
+            Aggregation::First => bucket.first_valid(),
+            Aggregation::Last => bucket.last_valid(),
+            Aggregation::Mean => Some(bucket.mean()),
+            Aggregation::Sum => Some(bucket.sum()),
+            Aggregation::Min => bucket.min(),
+            Aggregation::Max => bucket.max(),
+        }
+    }
+}
+
+impl DateVector {
+
+    /// Aligns the series `(self, values)` onto `grid`.
+    ///
+    /// Grid date `grid[k]` collects the observations dated in
+    /// `(grid[k-1], grid[k]]`; the first grid date only takes observations
+    /// on that exact date. Non-empty buckets are combined with `agg`, empty
+    /// ones are filled with `fill` within `max_gap` days. Rows with a null
+    /// date or value are ignored. `grid` must be strictly increasing and
+    /// null-free, e.g. built with [`DateVector::range`].
+    pub fn resample(
+        &self,
+        values: &VectorData<f64>,
+        grid: &DateVector,
+        options: ResampleOptions,
+    ) -> ErrorResult<VectorData<f64>> {
+        if values.data.len() != self.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: self.len(),
+                found: values.data.len(),
+            });
+        }
+        if grid.validity.not_all() || grid.days.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(ErebusError::InvalidOperation(
+                "Resample grid must be strictly increasing without nulls".into(),
+            ));
+        }
+
+        // Valid observations in date order (stable, so equal dates keep input order)
+        let order: Vec<usize> = self
+            .sort_indices(true, true)
+            .into_iter()
+            .filter(|&i| self.validity[i] && values.validity[i])
+            .collect();
+        let obs_days: Vec<i32> = order.iter().map(|&i| self.days[i]).collect();
+        let obs = VectorData {
+            data: order.iter().map(|&i| values.data[i]).collect::<Vec<f64>>(),
+            validity: bitvec![1; order.len()],
+        };
+
+        let m = grid.len();
+        let mut data = vec![0.0f64; m];
+        let mut validity = bitvec![0; m];
+        let max_gap = options.max_gap.map_or(i64::MAX, |g| g as i64);
+
+        for (k, &g) in grid.days.iter().enumerate() {
+            let hi = obs_days.partition_point(|&d| d <= g);
+            let lo = if k == 0 {
+                obs_days.partition_point(|&d| d < g)
+            } else {
+                obs_days.partition_point(|&d| d <= grid.days[k - 1])
+            };
+
+            let value = if lo < hi {
+                options.agg.apply(&obs.slice(lo, hi))
+            } else {
+                // Neighbours in date order: prev < g < next
+                let prev = (hi > 0).then(|| hi - 1);
+                let next = (hi < obs_days.len()).then_some(hi);
+                let gap = |j: usize| (obs_days[j] as i64 - g as i64).abs();
+
+                let pick = match options.fill {
+                    FillMethod::Forward => prev,
+                    FillMethod::Backward => next,
+                    FillMethod::Nearest => match (prev, next) {
+                        (Some(p), Some(q)) => Some(if gap(q) < gap(p) { q } else { p }),
+                        (p, q) => p.or(q),
+                    },
+                    FillMethod::Null => None,
+                };
+                pick.filter(|&j| gap(j) <= max_gap).map(|j| obs.data[j])
+            };
+
+            if let Some(v) = value {
+                data[k] = v;
+                validity.set(k, true);
+            }
+        }
+
+        Ok(VectorData { data, validity })
+    }
+}
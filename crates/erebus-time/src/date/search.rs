@@ -0,0 +1,52 @@
+// === Imports ===
+use crate::prelude::*;
+use std::collections::HashSet;
+
+// === Impl ===
+
+impl DateVector {
+
+    /// # Safety
+    /// Assumes `self` is sorted ascending with no nulls.
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _searchsorted(&self, values: &DateVector, right: bool) -> Vec<Option<usize>> {
+        (0..values.len())
+            .map(|i| {
+                values._get(i).map(|v| {
+                    if right {
+                        self.days.partition_point(|&d| d <= v.days)
+                    } else {
+                        self.days.partition_point(|&d| d < v.days)
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Insertion points of `values` into this sorted vector: the first
+    /// position with a date `>=` the value, or `>` when `right` is set.
+    /// Null values map to `None`. Errors if `self` has nulls or is unsorted.
+    pub fn searchsorted(&self, values: &DateVector, right: bool) -> ErrorResult<Vec<Option<usize>>> {
+        if self.validity.not_all() {
+            return Err(ErebusError::InvalidOperation(
+                "searchsorted requires a DateVector without nulls".into(),
+            ));
+        }
+        if !self.is_sorted() {
+            return Err(ErebusError::InvalidOperation(
+                "searchsorted requires a DateVector sorted ascending".into(),
+            ));
+        }
+        Ok(self._searchsorted(values, right))
+    }
+
+    /// Whether each date occurs among the valid dates of `other`.
+    /// Null rows are `false`.
+    pub fn is_in(&self, other: &DateVector) -> Vec<bool> {
+        let set: HashSet<i32> = other.valid_days().collect();
+        (0..self.days.len())
+            .map(|i| self.validity[i] && set.contains(&self.days[i]))
+            .collect()
+    }
+}
@@ -0,0 +1,187 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Constants ===
+
+/// Unix days of Excel 1900 serial 0 once the fake 1900-02-29 is skipped
+/// (i.e. 1899-12-30).
+const EXCEL_1900_EPOCH: i64 = -25_569;
+
+/// Serial of the fake 1900-02-29 in the Excel 1900 system.
+const EXCEL_1900_LEAP_BUG: i64 = 60;
+
+/// Unix days of Excel 1904 serial 0 (1904-01-01).
+const EXCEL_1904_EPOCH: i64 = -24_107;
+
+/// Unix days of SAS/Stata day 0 (1960-01-01).
+const SAS_STATA_EPOCH: i64 = -3_653;
+
+/// Julian Day Number of 1970-01-01.
+const JDN_UNIX_EPOCH: i64 = 2_440_588;
+
+// === Types ===
+
+/// Day-count representation used by [`DateVector::from_serial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerialEpoch {
+
+    /// Excel 1900 date system: 1 = 1900-01-01. Serial 60 is the
+    /// non-existent 1900-02-29 and becomes null.
+    Excel1900,
+
+    /// Excel 1904 date system: 0 = 1904-01-01.
+    Excel1904,
+
+    /// Julian Day Number: 2440588 = 1970-01-01. Fractional inputs are
+    /// Julian Dates, where day N spans `[N - 0.5, N + 0.5)`.
+    JulianDay,
+
+    /// SAS date value: 0 = 1960-01-01.
+    Sas,
+
+    /// Stata `%td` date: 0 = 1960-01-01.
+    Stata,
+
+    /// Days since 1970-01-01.
+    Unix,
+}
+
+// === Impls ===
+
+impl SerialEpoch {
+
+    /// Unix days of serial `x`, `None` if out of range.
+    #[inline]
+    fn days_of(self, x: i64) -> Option<i32> {
+        let days = match self {
+            SerialEpoch::Excel1900 => match x {
+                ..=0 | EXCEL_1900_LEAP_BUG => return None,
+                1..=59 => x.checked_add(EXCEL_1900_EPOCH + 1)?,
+                _ => x.checked_add(EXCEL_1900_EPOCH)?,
+            },
+            SerialEpoch::Excel1904 if x < 0 => return None,
+            SerialEpoch::Excel1904 => x.checked_add(EXCEL_1904_EPOCH)?,
+            SerialEpoch::JulianDay => x.checked_sub(JDN_UNIX_EPOCH)?,
+            SerialEpoch::Sas | SerialEpoch::Stata => x.checked_add(SAS_STATA_EPOCH)?,
+            SerialEpoch::Unix => x,
+        };
+        i32::try_from(days).ok()
+    }
+
+    /// Serial of Unix day `days`, `None` if the system cannot represent it.
+    #[inline]
+    fn serial_of(self, days: i32) -> Option<i64> {
+        let d = days as i64;
+        match self {
+            SerialEpoch::Excel1900 => {
+                let x = d - EXCEL_1900_EPOCH;
+                match x {
+                    // Before 1900-03-01 serials run one behind
+                    ..=1 => None,
+                    2..=EXCEL_1900_LEAP_BUG => Some(x - 1),
+                    _ => Some(x),
+                }
+            }
+            SerialEpoch::Excel1904 => {
+                let x = d - EXCEL_1904_EPOCH;
+                (x >= 0).then_some(x)
+            }
+            SerialEpoch::JulianDay => Some(d + JDN_UNIX_EPOCH),
+            SerialEpoch::Sas | SerialEpoch::Stata => Some(d - SAS_STATA_EPOCH),
+            SerialEpoch::Unix => Some(d),
+        }
+    }
+
+    /// Whole serial day containing the fractional value `x`.
+    #[inline]
+    fn floor_f64(self, x: f64) -> Option<i64> {
+        let x = match self {
+            SerialEpoch::JulianDay => x + 0.5,
+            _ => x,
+        }
+        .floor();
+        (x.is_finite() && x >= i64::MIN as f64 && x < i64::MAX as f64).then_some(x as i64)
+    }
+}
+
+/// Unix days of a yyyymmdd integer, `None` unless it is a real date in years 0..=9999.
+#[inline]
+fn yyyymmdd_to_days(x: i64) -> Option<i32> {
+    if !(0..=99_991_231).contains(&x) {
+        return None;
+    }
+    let (y, m, d) = ((x / 10_000) as i32, (x / 100 % 100) as u8, (x % 100) as u8);
+    Date::from_ymd(y, m, d).ok().map(|date| date.days)
+}
+
+impl DateVector {
+
+    /// Maps each valid value through `f`; `None` results become null.
+    #[inline]
+    fn from_mapped<T: Copy>(data: &[T], validity: &BitVec, f: impl Fn(T) -> Option<i32>) -> Self {
+        let n = data.len();
+        let mut days = vec![0i32; n];
+        let mut out_validity = bitvec![0; n];
+        for (i, &x) in data.iter().enumerate() {
+            if validity[i] {
+                if let Some(d) = f(x) {
+                    days[i] = d;
+                    out_validity.set(i, true);
+                }
+            }
+        }
+        Self { days, validity: out_validity }
+    }
+
+    /// Maps each valid day through `f`; `None` results become null.
+    #[inline]
+    fn to_mapped<T: Copy + Default>(&self, f: impl Fn(i32) -> Option<T>) -> VectorData<T> {
+        let n = self.days.len();
+        let mut data = vec![T::default(); n];
+        let mut validity = bitvec![0; n];
+        for (i, &d) in self.days.iter().enumerate() {
+            if self.validity[i] {
+                if let Some(x) = f(d) {
+                    data[i] = x;
+                    validity.set(i, true);
+                }
+            }
+        }
+        VectorData { data, validity }
+    }
+
+    /// Dates from integer day serials. Out-of-range values become null.
+    pub fn from_serial(v: &VectorData<i64>, epoch: SerialEpoch) -> Self {
+        Self::from_mapped(&v.data, &v.validity, |x| epoch.days_of(x))
+    }
+
+    /// Dates from fractional day serials; the time-of-day part is dropped.
+    /// Non-finite or out-of-range values become null.
+    pub fn from_serial_f64(v: &VectorData<f64>, epoch: SerialEpoch) -> Self {
+        Self::from_mapped(&v.data, &v.validity, |x| epoch.days_of(epoch.floor_f64(x)?))
+    }
+
+    /// Integer day serials. Dates the system cannot represent become null.
+    pub fn to_serial(&self, epoch: SerialEpoch) -> VectorData<i64> {
+        self.to_mapped(|d| epoch.serial_of(d))
+    }
+
+    /// Day serials as `f64` (whole numbers; Julian Day Numbers at noon).
+    pub fn to_serial_f64(&self, epoch: SerialEpoch) -> VectorData<f64> {
+        self.to_mapped(|d| epoch.serial_of(d).map(|x| x as f64))
+    }
+
+    /// Dates from yyyymmdd integers such as `20240229`.
+    /// Impossible dates and years outside 0..=9999 become null.
+    pub fn from_yyyymmdd(v: &VectorData<i64>) -> Self {
+        Self::from_mapped(&v.data, &v.validity, yyyymmdd_to_days)
+    }
+
+    /// yyyymmdd integers. Years outside 0..=9999 become null.
+    pub fn to_yyyymmdd(&self) -> VectorData<i64> {
+        self.to_mapped(|d| {
+            let (y, m, day) = days_to_ymd(d);
+            (0..=9999).contains(&y).then(|| y as i64 * 10_000 + m as i64 * 100 + day as i64)
+        })
+    }
+}
@@ -0,0 +1,62 @@
+// === Imports ===
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+// === Impl ===
+
+impl DateVector {
+
+    /// Valid days sorted ascending and de-duplicated.
+    fn sorted_distinct_days(&self) -> Vec<i32> {
+        let mut days: Vec<i32> = self.valid_days().collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    /// Merges the distinct valid days of both vectors, keeping those
+    /// for which `keep(in_self, in_other)` holds.
+    fn merge_with<F: Fn(bool, bool) -> bool>(&self, other: &DateVector, keep: F) -> Self {
+        let a = self.sorted_distinct_days();
+        let b = other.sorted_distinct_days();
+        let mut days = Vec::with_capacity(a.len().max(b.len()));
+        let (mut i, mut j) = (0usize, 0usize);
+
+        while i < a.len() || j < b.len() {
+            let ord = match (a.get(i), b.get(j)) {
+                (Some(x), Some(y)) => x.cmp(y),
+                (Some(_), None) => Ordering::Less,
+                _ => Ordering::Greater,
+            };
+            let (d, in_a, in_b) = match ord {
+                Ordering::Less => { i += 1; (a[i - 1], true, false) }
+                Ordering::Greater => { j += 1; (b[j - 1], false, true) }
+                Ordering::Equal => { i += 1; j += 1; (a[i - 1], true, true) }
+            };
+            if keep(in_a, in_b) {
+                days.push(d);
+            }
+        }
+
+        let n = days.len();
+        Self { days, validity: bitvec![1; n] }
+    }
+
+    /// Dates in either vector. Sorted, distinct, nulls dropped.
+    #[inline]
+    pub fn union(&self, other: &DateVector) -> Self {
+        self.merge_with(other, |a, b| a || b)
+    }
+
+    /// Dates in both vectors. Sorted, distinct, nulls dropped.
+    #[inline]
+    pub fn intersect(&self, other: &DateVector) -> Self {
+        self.merge_with(other, |a, b| a && b)
+    }
+
+    /// Dates in `self` but not in `other`. Sorted, distinct, nulls dropped.
+    #[inline]
+    pub fn difference(&self, other: &DateVector) -> Self {
+        self.merge_with(other, |a, b| a && !b)
+    }
+}
@@ -0,0 +1,38 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+
+impl DateVector {
+
+    /// Sorted row indices. Day counts are radix sorted as `i64` keys,
+    /// so equal dates keep their input order when ascending.
+    pub fn sort_indices(&self, ascending: bool, nulls_last: bool) -> Vec<usize> {
+        let keys = VectorData {
+            data: self.days.iter().map(|&d| d as i64).collect::<Vec<i64>>(),
+            validity: self.validity.clone(),
+        };
+        keys.sort_indices(ascending, nulls_last, Some("radix"))
+    }
+
+    /// Returns a sorted copy.
+    #[inline]
+    pub fn sort(&self, ascending: bool, nulls_last: bool) -> Self {
+        let idx = self.sort_indices(ascending, nulls_last);
+        self._take(&idx)
+    }
+
+    /// Whether valid dates are in non-decreasing order (nulls are skipped).
+    pub fn is_sorted(&self) -> bool {
+        let mut prev = i32::MIN;
+        for (i, &d) in self.days.iter().enumerate() {
+            if self.validity[i] {
+                if d < prev {
+                    return false;
+                }
+                prev = d;
+            }
+        }
+        true
+    }
+}
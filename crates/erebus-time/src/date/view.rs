@@ -0,0 +1,62 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// A zero-copy borrowed view of [`DateVector`].
+/// Holds slices of both the day counts and the validity bitmap.
+#[derive(Debug, Clone, Copy)]
+pub struct DateVectorView<'a> {
+    pub days: &'a [i32],
+    pub validity: &'a BitSlice,
+}
+
+// === Impls ===
+
+impl<'a> DateVectorView<'a> {
+
+    /// Number of elements in this view.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.days.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+
+    /// Returns whether the value at index `i` is valid (non-null).
+    #[inline]
+    pub fn is_valid_at(&self, i: usize) -> bool {
+        self.validity.get(i).map(|b| *b).unwrap_or(false)
+    }
+
+    /// Returns the date at `i` if valid.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<Date> {
+        if self.is_valid_at(i) {
+            self.days.get(i).map(|&days| Date { days })
+        } else {
+            None
+        }
+    }
+
+    /// Iterates only valid dates.
+    #[inline]
+    pub fn iter_valid(&self) -> impl Iterator<Item = Date> + 'a {
+        self.days
+            .iter()
+            .zip(self.validity.iter())
+            .filter_map(|(&days, v)| if *v { Some(Date { days }) } else { None })
+    }
+
+    /// Copies the viewed range into an owned [`DateVector`].
+    #[inline]
+    pub fn to_owned(&self) -> DateVector {
+        DateVector {
+            days: self.days.to_vec(),
+            validity: self.validity.to_bitvec(),
+        }
+    }
+}
@@ -46,6 +46,13 @@ impl Date {
     pub fn previous_business_day<C: Calendar>(&self, cal: &C) -> Date {
         self.shift_business_days(cal, -1)
     }
+
+    /// Rolls a non-business day according to `conv`.
+    /// Errors if `cal` has no business day within [`MAX_ROLL_DAYS`].
+    #[inline]
+    pub fn adjust<C: Calendar>(&self, cal: &C, conv: BusinessDayConvention) -> ErrorResult<Date> {
+        conv.adjust(cal, *self)
+    }
 }
 
 impl DateVector {
@@ -106,6 +113,21 @@ impl DateVector {
         self.shift_business_days(cal, -1)
     }
 
+    pub fn adjust<C: Calendar>(&self, cal: &C, conv: BusinessDayConvention) -> ErrorResult<DateVector> {
+        let len = self.days.len();
+        let mut out = vec![0i32; len];
+        let validity = self.validity.clone();
+
+        for i in 0..len {
+            if unsafe { *self.validity.get_unchecked(i) } {
+                let d = Date { days: self.days[i] };
+                out[i] = conv.adjust(cal, d)?.days();
+            }
+        }
+
+        Ok(DateVector { days: out, validity })
+    }
+
     pub fn count_business_days<C: Calendar>(&self, cal: &C) -> usize {
         let mut count = 0;
         let n = self.days.len();
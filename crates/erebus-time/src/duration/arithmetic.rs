@@ -0,0 +1,277 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::date::shift_months_days;
+use std::ops::{Add, Neg, Sub};
+
+// === Impls ===
+
+// --- Duration ---
+
+impl Add for Duration {
+    type Output = Duration;
+
+    /// # Panics
+    /// Panics on `i64` overflow; use [`Duration::checked_add`] to avoid it.
+    #[inline]
+    fn add(self, rhs: Duration) -> Duration {
+        self.checked_add(rhs).expect("overflow when adding durations")
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    /// # Panics
+    /// Panics on `i64` overflow; use [`Duration::checked_sub`] to avoid it.
+    #[inline]
+    fn sub(self, rhs: Duration) -> Duration {
+        self.checked_sub(rhs).expect("overflow when subtracting durations")
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    /// # Panics
+    /// Panics when negating `i64::MIN` nanoseconds.
+    #[inline]
+    fn neg(self) -> Duration {
+        self.checked_neg().expect("overflow when negating duration")
+    }
+}
+
+impl std::ops::Mul<i64> for Duration {
+    type Output = Duration;
+
+    /// # Panics
+    /// Panics on `i64` overflow; use [`Duration::checked_mul`] to avoid it.
+    #[inline]
+    fn mul(self, rhs: i64) -> Duration {
+        self.checked_mul(rhs).expect("overflow when multiplying duration")
+    }
+}
+
+// --- Timestamp +/- Duration ---
+
+impl Add<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// # Panics
+    /// Panics if the result leaves the `i64` nanosecond range.
+    #[inline]
+    fn add(self, rhs: Duration) -> Timestamp {
+        let nanos = self.nanos.checked_add(rhs.nanos);
+        Timestamp { nanos: nanos.expect("overflow when adding duration to timestamp") }
+    }
+}
+
+impl Sub<Duration> for Timestamp {
+    type Output = Timestamp;
+
+    /// # Panics
+    /// Panics if the result leaves the `i64` nanosecond range.
+    #[inline]
+    fn sub(self, rhs: Duration) -> Timestamp {
+        let nanos = self.nanos.checked_sub(rhs.nanos);
+        Timestamp { nanos: nanos.expect("overflow when subtracting duration from timestamp") }
+    }
+}
+
+impl Sub for Timestamp {
+    type Output = Duration;
+
+    /// # Panics
+    /// Panics if the difference does not fit in `i64` nanoseconds.
+    #[inline]
+    fn sub(self, rhs: Timestamp) -> Duration {
+        let nanos = self.nanos.checked_sub(rhs.nanos);
+        Duration { nanos: nanos.expect("overflow when subtracting timestamps") }
+    }
+}
+
+// --- Date - Date, Date +/- Period ---
+
+impl Sub for Date {
+    type Output = Duration;
+
+    /// Whole-day duration from `rhs` to `self`.
+    ///
+    /// # Panics
+    /// Panics if the span overflows i64 nanoseconds (about 292 years);
+    /// use [`Date::checked_sub`] to avoid it.
+    #[inline]
+    fn sub(self, rhs: Date) -> Duration {
+        self.checked_sub(rhs).expect("overflow when subtracting dates")
+    }
+}
+
+impl Date {
+
+    /// Whole-day duration from `rhs` to `self`, or `None` if the span
+    /// overflows i64 nanoseconds.
+    #[inline]
+    pub fn checked_sub(&self, rhs: Date) -> Option<Duration> {
+        Duration::checked_from_days(self.days as i64 - rhs.days as i64)
+    }
+}
+
+#[inline]
+fn add_period_days<C: Calendar>(days: i32, p: &Period, rule: MonthEndRule, cal: &C) -> i32 {
+    let mut d = days;
+    let months = p.total_months();
+    if months != 0 {
+        d = shift_months_days(d, months, rule);
+    }
+    d += p.days;
+    if p.business_days != 0 {
+        d = Date { days: d }.shift_business_days(cal, p.business_days).days;
+    }
+    d
+}
+
+impl Date {
+
+    /// Applies `period`: months (resolved with `rule`), then days,
+    /// then business days on `cal`.
+    #[inline]
+    pub fn add_period<C: Calendar>(&self, period: &Period, rule: MonthEndRule, cal: &C) -> Date {
+        Date { days: add_period_days(self.days, period, rule, cal) }
+    }
+}
+
+impl Add<Period> for Date {
+    type Output = Date;
+
+    /// Clamps to month length; business days skip Sat/Sun only.
+    /// Use [`Date::add_period`] for a holiday calendar.
+    #[inline]
+    fn add(self, rhs: Period) -> Date {
+        self.add_period(&rhs, MonthEndRule::Clamp, &WeekendRule::default())
+    }
+}
+
+impl Sub<Period> for Date {
+    type Output = Date;
+
+    #[inline]
+    fn sub(self, rhs: Period) -> Date {
+        self + (-rhs)
+    }
+}
+
+// --- Vectorized ---
+
+impl DateVector {
+
+    /// Elementwise `self - other` as whole-day durations.
+    /// Nulls on either side, and spans that overflow, yield nulls.
+    pub fn diff(&self, other: &DateVector) -> ErrorResult<DurationVector> {
+        let n = self.days.len();
+        if other.days.len() != n {
+            return Err(ErebusError::LengthMismatch {
+                expected: n,
+                found: other.days.len(),
+            });
+        }
+
+        let mut nanos = vec![0i64; n];
+        let mut validity = self.validity.clone() & other.validity.clone();
+
+        for (i, o) in nanos.iter_mut().enumerate() {
+            if validity[i] {
+                let days = self.days[i] as i64 - other.days[i] as i64;
+                match days.checked_mul(NANOS_PER_DAY) {
+                    Some(v) => *o = v,
+                    None => validity.set(i, false),
+                }
+            }
+        }
+
+        Ok(DurationVector { nanos, validity })
+    }
+
+    /// Applies the same `period` to every valid element.
+    pub fn add_period<C: Calendar>(&self, period: &Period, rule: MonthEndRule, cal: &C) -> DateVector {
+        self.map_valid_days(|d| add_period_days(d, period, rule, cal))
+    }
+
+    /// Applies each period to the matching date.
+    /// Nulls on either side yield nulls.
+    pub fn add_periods<C: Calendar>(
+        &self,
+        periods: &PeriodVector,
+        rule: MonthEndRule,
+        cal: &C,
+    ) -> ErrorResult<DateVector> {
+        let n = self.days.len();
+        if periods.len() != n {
+            return Err(ErebusError::LengthMismatch {
+                expected: n,
+                found: periods.len(),
+            });
+        }
+
+        let mut days = vec![0i32; n];
+        let validity = self.validity.clone() & periods.validity.clone();
+
+        for (i, o) in days.iter_mut().enumerate() {
+            if validity[i] {
+                let p = Period {
+                    years: periods.years[i],
+                    months: periods.months[i],
+                    days: periods.days[i],
+                    business_days: periods.business_days[i],
+                };
+                *o = add_period_days(self.days[i], &p, rule, cal);
+            }
+        }
+
+        Ok(DateVector { days, validity })
+    }
+}
+
+impl TimestampVector {
+
+    /// Shifts every valid instant by `d`. Overflow yields nulls.
+    pub fn add_duration(&self, d: Duration) -> TimestampVector {
+        let v = d.add_to_nanos(&VectorData {
+            data: self.nanos.clone(),
+            validity: self.validity.clone(),
+        });
+        TimestampVector { nanos: v.data, validity: v.validity }
+    }
+
+    /// Shifts each instant by the matching duration.
+    pub fn add_durations(&self, d: &DurationVector) -> ErrorResult<TimestampVector> {
+        let v = d.add_to_nanos(&VectorData {
+            data: self.nanos.clone(),
+            validity: self.validity.clone(),
+        })?;
+        Ok(TimestampVector { nanos: v.data, validity: v.validity })
+    }
+
+    /// Elementwise `self - other`. Nulls on either side yield nulls.
+    pub fn diff(&self, other: &TimestampVector) -> ErrorResult<DurationVector> {
+        let n = self.nanos.len();
+        if other.nanos.len() != n {
+            return Err(ErebusError::LengthMismatch {
+                expected: n,
+                found: other.nanos.len(),
+            });
+        }
+
+        let mut nanos = vec![0i64; n];
+        let mut validity = self.validity.clone() & other.validity.clone();
+
+        for (i, o) in nanos.iter_mut().enumerate() {
+            if validity[i] {
+                match self.nanos[i].checked_sub(other.nanos[i]) {
+                    Some(v) => *o = v,
+                    None => validity.set(i, false),
+                }
+            }
+        }
+
+        Ok(DurationVector { nanos, validity })
+    }
+}
@@ -0,0 +1,259 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// Exact elapsed time in nanoseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration {
+    pub(crate) nanos: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DurationVector {
+    pub(crate) nanos: Vec<i64>,
+    pub(crate) validity: BitVec,
+}
+
+// === Impls ===
+
+impl Duration {
+
+    #[inline]
+    pub fn from_nanos(nanos: i64) -> Self {
+        Self { nanos }
+    }
+
+    /// # Panics
+    /// Panics if `n` micros overflow i64 nanoseconds.
+    #[inline]
+    pub fn from_micros(n: i64) -> Self {
+        Self::checked_from_micros(n).expect("overflow when converting micros to a duration")
+    }
+
+    #[inline]
+    pub fn checked_from_micros(n: i64) -> Option<Self> {
+        n.checked_mul(NANOS_PER_MICRO).map(|nanos| Self { nanos })
+    }
+
+    /// # Panics
+    /// Panics if `n` millis overflow i64 nanoseconds.
+    #[inline]
+    pub fn from_millis(n: i64) -> Self {
+        Self::checked_from_millis(n).expect("overflow when converting millis to a duration")
+    }
+
+    #[inline]
+    pub fn checked_from_millis(n: i64) -> Option<Self> {
+        n.checked_mul(NANOS_PER_MILLI).map(|nanos| Self { nanos })
+    }
+
+    /// # Panics
+    /// Panics if `n` seconds overflow i64 nanoseconds.
+    #[inline]
+    pub fn from_seconds(n: i64) -> Self {
+        Self::checked_from_seconds(n).expect("overflow when converting seconds to a duration")
+    }
+
+    #[inline]
+    pub fn checked_from_seconds(n: i64) -> Option<Self> {
+        n.checked_mul(NANOS_PER_SECOND).map(|nanos| Self { nanos })
+    }
+
+    /// # Panics
+    /// Panics if `n` minutes overflow i64 nanoseconds.
+    #[inline]
+    pub fn from_minutes(n: i64) -> Self {
+        Self::checked_from_minutes(n).expect("overflow when converting minutes to a duration")
+    }
+
+    #[inline]
+    pub fn checked_from_minutes(n: i64) -> Option<Self> {
+        n.checked_mul(NANOS_PER_MINUTE).map(|nanos| Self { nanos })
+    }
+
+    /// # Panics
+    /// Panics if `n` hours overflow i64 nanoseconds.
+    #[inline]
+    pub fn from_hours(n: i64) -> Self {
+        Self::checked_from_hours(n).expect("overflow when converting hours to a duration")
+    }
+
+    #[inline]
+    pub fn checked_from_hours(n: i64) -> Option<Self> {
+        n.checked_mul(NANOS_PER_HOUR).map(|nanos| Self { nanos })
+    }
+
+    /// # Panics
+    /// Panics if `n` days overflow i64 nanoseconds.
+    #[inline]
+    pub fn from_days(n: i64) -> Self {
+        Self::checked_from_days(n).expect("overflow when converting days to a duration")
+    }
+
+    #[inline]
+    pub fn checked_from_days(n: i64) -> Option<Self> {
+        n.checked_mul(NANOS_PER_DAY).map(|nanos| Self { nanos })
+    }
+
+    #[inline]
+    pub fn nanos(&self) -> i64 {
+        self.nanos
+    }
+
+    /// Whole days, truncated towards zero.
+    #[inline]
+    pub fn whole_days(&self) -> i64 {
+        self.nanos / NANOS_PER_DAY
+    }
+
+    /// Whole seconds, truncated towards zero.
+    #[inline]
+    pub fn whole_seconds(&self) -> i64 {
+        self.nanos / NANOS_PER_SECOND
+    }
+
+    #[inline]
+    pub fn as_seconds_f64(&self) -> f64 {
+        self.nanos as f64 / NANOS_PER_SECOND as f64
+    }
+
+    #[inline]
+    pub fn abs(&self) -> Self {
+        Self { nanos: self.nanos.abs() }
+    }
+
+    #[inline]
+    pub fn checked_add(&self, other: Duration) -> Option<Self> {
+        self.nanos.checked_add(other.nanos).map(|nanos| Self { nanos })
+    }
+
+    #[inline]
+    pub fn checked_sub(&self, other: Duration) -> Option<Self> {
+        self.nanos.checked_sub(other.nanos).map(|nanos| Self { nanos })
+    }
+
+    #[inline]
+    pub fn checked_mul(&self, rhs: i64) -> Option<Self> {
+        self.nanos.checked_mul(rhs).map(|nanos| Self { nanos })
+    }
+
+    #[inline]
+    pub fn checked_neg(&self) -> Option<Self> {
+        self.nanos.checked_neg().map(|nanos| Self { nanos })
+    }
+
+    /// Adds this duration to every valid i64-nanosecond instant.
+    /// Results that overflow become nulls.
+    pub fn add_to_nanos(&self, instants: &VectorData<i64>) -> VectorData<i64> {
+        let n = instants.data.len();
+        let mut data = vec![0i64; n];
+        let mut validity = instants.validity.clone();
+
+        for (i, o) in data.iter_mut().enumerate() {
+            if validity[i] {
+                match instants.data[i].checked_add(self.nanos) {
+                    Some(v) => *o = v,
+                    None => validity.set(i, false),
+                }
+            }
+        }
+
+        VectorData { data, validity }
+    }
+}
+
+impl DurationVector {
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nanos.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nanos.is_empty()
+    }
+
+    /// Raw nanoseconds (placeholders at null rows).
+    #[inline]
+    pub fn nanos(&self) -> &[i64] {
+        &self.nanos
+    }
+
+    #[inline]
+    pub fn validity(&self) -> &BitVec {
+        &self.validity
+    }
+
+    /// Construct from raw nanoseconds; every row is valid.
+    #[inline]
+    pub fn from_nanos(nanos: Vec<i64>) -> Self {
+        let n = nanos.len();
+        Self { nanos, validity: bitvec![1; n] }
+    }
+
+    /// Construct from raw nanoseconds with an explicit validity bitmap.
+    #[inline]
+    pub fn from_nanos_with_validity(nanos: Vec<i64>, validity: BitVec) -> ErrorResult<Self> {
+        if nanos.len() != validity.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: nanos.len(),
+                found: validity.len(),
+            });
+        }
+        Ok(Self { nanos, validity })
+    }
+
+    /// Returns `Some(Duration)` if valid, `None` if null or out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<Duration> {
+        if i >= self.nanos.len() || !self.validity[i] {
+            return None;
+        }
+        Some(Duration { nanos: self.nanos[i] })
+    }
+
+    /// Whole days per element (0 at null rows).
+    pub fn whole_days(&self) -> Vec<i64> {
+        let mut out = vec![0i64; self.nanos.len()];
+        for (i, o) in out.iter_mut().enumerate() {
+            if self.validity[i] {
+                *o = self.nanos[i] / NANOS_PER_DAY;
+            }
+        }
+        out
+    }
+
+    /// Converts into `VectorData<i64>` nanoseconds.
+    #[inline]
+    pub fn to_nanos_vector_data(&self) -> VectorData<i64> {
+        VectorData { data: self.nanos.clone(), validity: self.validity.clone() }
+    }
+
+    /// Adds each duration to the matching i64-nanosecond instant.
+    /// Nulls on either side, or overflow, yield nulls.
+    pub fn add_to_nanos(&self, instants: &VectorData<i64>) -> ErrorResult<VectorData<i64>> {
+        let n = self.nanos.len();
+        if instants.data.len() != n {
+            return Err(ErebusError::LengthMismatch {
+                expected: n,
+                found: instants.data.len(),
+            });
+        }
+
+        let mut data = vec![0i64; n];
+        let mut validity = bitvec![0; n];
+
+        for (i, o) in data.iter_mut().enumerate() {
+            if self.validity[i] && instants.validity[i] {
+                if let Some(v) = instants.data[i].checked_add(self.nanos[i]) {
+                    *o = v;
+                    validity.set(i, true);
+                }
+            }
+        }
+
+        Ok(VectorData { data, validity })
+    }
+}
@@ -0,0 +1,7 @@
+mod arithmetic;
+mod duration;
+mod period;
+mod tenor;
+
+pub use duration::{Duration, DurationVector};
+pub use period::{Period, PeriodVector};
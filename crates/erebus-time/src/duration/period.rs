@@ -0,0 +1,143 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// Calendar period: years, months and days move along the calendar,
+/// business days move along a [`Calendar`].
+/// Components are applied in that order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Period {
+    pub years: i32,
+    pub months: i32,
+    pub days: i32,
+    pub business_days: i32,
+}
+
+/// Struct-of-arrays vector of periods with validity.
+#[derive(Debug, Clone)]
+pub struct PeriodVector {
+    pub(crate) years: Vec<i32>,
+    pub(crate) months: Vec<i32>,
+    pub(crate) days: Vec<i32>,
+    pub(crate) business_days: Vec<i32>,
+    pub(crate) validity: BitVec,
+}
+
+// === Impls ===
+
+impl Period {
+
+    #[inline]
+    pub fn new(years: i32, months: i32, days: i32, business_days: i32) -> Self {
+        Self { years, months, days, business_days }
+    }
+
+    #[inline]
+    pub fn years(n: i32) -> Self {
+        Self { years: n, ..Self::default() }
+    }
+
+    #[inline]
+    pub fn months(n: i32) -> Self {
+        Self { months: n, ..Self::default() }
+    }
+
+    #[inline]
+    pub fn weeks(n: i32) -> Self {
+        Self { days: 7 * n, ..Self::default() }
+    }
+
+    #[inline]
+    pub fn days(n: i32) -> Self {
+        Self { days: n, ..Self::default() }
+    }
+
+    #[inline]
+    pub fn business_days(n: i32) -> Self {
+        Self { business_days: n, ..Self::default() }
+    }
+
+    /// Total calendar months (`12 * years + months`).
+    #[inline]
+    pub fn total_months(&self) -> i32 {
+        12 * self.years + self.months
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl std::ops::Neg for Period {
+    type Output = Period;
+
+    #[inline]
+    fn neg(self) -> Period {
+        Period {
+            years: -self.years,
+            months: -self.months,
+            days: -self.days,
+            business_days: -self.business_days,
+        }
+    }
+}
+
+impl std::ops::Add for Period {
+    type Output = Period;
+
+    #[inline]
+    fn add(self, rhs: Period) -> Period {
+        Period {
+            years: self.years + rhs.years,
+            months: self.months + rhs.months,
+            days: self.days + rhs.days,
+            business_days: self.business_days + rhs.business_days,
+        }
+    }
+}
+
+impl PeriodVector {
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.years.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.years.is_empty()
+    }
+
+    #[inline]
+    pub fn validity(&self) -> &BitVec {
+        &self.validity
+    }
+
+    /// Builds a vector with every row valid.
+    pub fn from_periods(periods: &[Period]) -> Self {
+        let n = periods.len();
+        Self {
+            years: periods.iter().map(|p| p.years).collect(),
+            months: periods.iter().map(|p| p.months).collect(),
+            days: periods.iter().map(|p| p.days).collect(),
+            business_days: periods.iter().map(|p| p.business_days).collect(),
+            validity: bitvec![1; n],
+        }
+    }
+
+    /// Returns `Some(Period)` if valid, `None` if null or out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<Period> {
+        if i >= self.years.len() || !self.validity[i] {
+            return None;
+        }
+        Some(Period {
+            years: self.years[i],
+            months: self.months[i],
+            days: self.days[i],
+            business_days: self.business_days[i],
+        })
+    }
+}
@@ -0,0 +1,111 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impls ===
+
+// Tenor grammar (case-insensitive):
+//   tenor := ['-'] (number unit)+
+//   unit  := 'Y' | 'M' | 'W' | 'D' | 'BD'
+// Examples: "3M", "1Y6M", "2W", "5BD", "-1D"
+
+/// Parses a tenor string; returns `None` if malformed or out of `i32` range.
+pub(crate) fn parse_tenor(s: &str) -> Option<Period> {
+    let b = s.trim().as_bytes();
+    if b.is_empty() {
+        return None;
+    }
+
+    let (sign, mut i) = if b[0] == b'-' { (-1, 1) } else { (1, 0) };
+    if i >= b.len() {
+        return None;
+    }
+
+    let mut p = Period::default();
+
+    while i < b.len() {
+        // Number
+        let start = i;
+        let mut n: i32 = 0;
+        while i < b.len() && b[i].is_ascii_digit() {
+            n = n.checked_mul(10)?.checked_add((b[i] - b'0') as i32)?;
+            i += 1;
+        }
+        if i == start || i >= b.len() {
+            return None;
+        }
+
+        // Unit
+        let n = sign * n;
+        match b[i].to_ascii_uppercase() {
+            b'Y' => { p.years = p.years.checked_add(n)?; i += 1; }
+            b'M' => { p.months = p.months.checked_add(n)?; i += 1; }
+            b'W' => { p.days = p.days.checked_add(n.checked_mul(7)?)?; i += 1; }
+            b'D' => { p.days = p.days.checked_add(n)?; i += 1; }
+            b'B' if i + 1 < b.len() && b[i + 1].eq_ignore_ascii_case(&b'D') => {
+                p.business_days = p.business_days.checked_add(n)?;
+                i += 2;
+            }
+            _ => return None,
+        }
+    }
+
+    Some(p)
+}
+
+impl Period {
+
+    /// Parses a tenor such as "3M", "1Y6M", "2W" or "5BD".
+    pub fn parse_tenor(s: &str) -> ErrorResult<Period> {
+        parse_tenor(s).ok_or_else(|| {
+            ErebusError::InvalidOperation(format!("Invalid tenor: {:?}", s))
+        })
+    }
+}
+
+impl std::str::FromStr for Period {
+    type Err = ErebusError;
+
+    #[inline]
+    fn from_str(s: &str) -> ErrorResult<Period> {
+        Period::parse_tenor(s)
+    }
+}
+
+/// Writes the tenor form, e.g. "1Y6M", "2D5BD" or "0D".
+impl std::fmt::Display for Period {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0D");
+        }
+        if self.years != 0 { write!(f, "{}Y", self.years)?; }
+        if self.months != 0 { write!(f, "{}M", self.months)?; }
+        if self.days != 0 { write!(f, "{}D", self.days)?; }
+        if self.business_days != 0 { write!(f, "{}BD", self.business_days)?; }
+        Ok(())
+    }
+}
+
+impl PeriodVector {
+
+    /// Parses every tenor; malformed entries become nulls.
+    pub fn parse_tenors(values: &[&str]) -> PeriodVector {
+        let n = values.len();
+        let mut years = vec![0i32; n];
+        let mut months = vec![0i32; n];
+        let mut days = vec![0i32; n];
+        let mut business_days = vec![0i32; n];
+        let mut validity = bitvec![0; n];
+
+        for (i, s) in values.iter().enumerate() {
+            if let Some(p) = parse_tenor(s) {
+                years[i] = p.years;
+                months[i] = p.months;
+                days[i] = p.days;
+                business_days[i] = p.business_days;
+                validity.set(i, true);
+            }
+        }
+
+        PeriodVector { years, months, days, business_days, validity }
+    }
+}
@@ -3,8 +3,18 @@
 pub mod prelude;
 pub mod calendar;
 pub mod date;
-// pub mod duration;
-// pub mod timestamp;
+pub mod schedule;
+pub mod duration;
+pub mod timestamp;
+pub mod timezone;
 
-pub use date::{Date, DateVector, ymd_to_days, days_to_ymd};
-pub use calendar::*;
\ No newline at end of file
+pub use date::{
+    Date, DateVector, DateVectorView, ToDate, DateFreq, RangeBound, AutoParse, DateParseReport, ParseAutoOptions,
+    Aggregation, FillMethod, ResampleOptions, SerialEpoch,
+    ymd_to_days, days_to_ymd,
+};
+pub use calendar::*;
+pub use duration::*;
+pub use schedule::*;
+pub use timestamp::*;
+pub use timezone::*;
\ No newline at end of file
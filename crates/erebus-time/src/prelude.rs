@@ -10,8 +10,20 @@ pub use ahash::{AHashMap, AHashSet};
 // === Erebus core ===
 pub use erebus_core::prelude::*;
 
+// === Erebus io ===
+pub use erebus_io::{CompressionType, ErebusReader, ErebusWriter};
+
+// === Erebus vector ===
+pub use erebus_vector::VectorData;
+
 // Internal exports
-pub use crate::date::{Date, DateVector, ymd_to_days, days_to_ymd};
+pub use crate::date::{
+    Date, DateVector, DateVectorView, ToDate, DateFreq, RangeBound, AutoParse, DateParseReport, ParseAutoOptions,
+    Aggregation, FillMethod, ResampleOptions, SerialEpoch,
+    ymd_to_days, days_to_ymd,
+};
 pub use crate::calendar::*;
-// pub use crate::duration::;
-// pub use crate::timestamp::;
\ No newline at end of file
+pub use crate::schedule::*;
+pub use crate::duration::*;
+pub use crate::timestamp::*;
+pub use crate::timezone::*;
\ No newline at end of file
@@ -0,0 +1,43 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// Period between two regular schedule dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Monthly,
+    Quarterly,
+    Semiannual,
+    Annual,
+
+    /// Custom step expressed in months.
+    Months(u32),
+}
+
+// === Impls ===
+
+impl Frequency {
+
+    /// Number of months between two regular dates.
+    #[inline]
+    pub fn months(&self) -> u32 {
+        match *self {
+            Frequency::Monthly => 1,
+            Frequency::Quarterly => 3,
+            Frequency::Semiannual => 6,
+            Frequency::Annual => 12,
+            Frequency::Months(n) => n,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn validate(&self) -> ErrorResult<()> {
+        if self.months() == 0 {
+            return Err(ErebusError::InvalidOperation(
+                "Frequency must be at least one month".into(),
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+mod frequency;
+mod rule;
+mod schedule;
+
+pub use frequency::Frequency;
+pub use rule::{Direction, StubKind, RollConvention, ScheduleRule};
+pub use schedule::Schedule;
@@ -0,0 +1,67 @@
+// === Types ===
+
+/// Direction in which regular dates are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+
+    /// Step forward from the effective date.
+    /// Any irregular period ends up at the back.
+    Forward,
+
+    /// Step backward from the termination date.
+    /// Any irregular period ends up at the front.
+    Backward,
+}
+
+/// Length of the irregular (stub) period, if one is needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StubKind {
+
+    /// Keep the stub shorter than a regular period.
+    Short,
+
+    /// Merge the stub with its neighbouring regular period.
+    Long,
+}
+
+/// How generated regular dates are rolled within their month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollConvention {
+
+    /// Keep the day-of-month of the anchor date (clamped to month length).
+    None,
+
+    /// If the anchor date is a month end, every regular date is a month end.
+    EndOfMonth,
+
+    /// Every regular date is the IMM date (third Wednesday) of its month.
+    Imm,
+}
+
+/// Generation settings for a [`Schedule`](super::Schedule).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduleRule {
+    pub direction: Direction,
+    pub stub: StubKind,
+    pub roll: RollConvention,
+}
+
+// === Impls ===
+
+impl Default for ScheduleRule {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            direction: Direction::Backward,
+            stub: StubKind::Short,
+            roll: RollConvention::None,
+        }
+    }
+}
+
+impl ScheduleRule {
+    #[inline]
+    pub fn new(direction: Direction, stub: StubKind, roll: RollConvention) -> Self {
+        Self { direction, stub, roll }
+    }
+}
@@ -0,0 +1,145 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::date::shift_months_days;
+use super::frequency::Frequency;
+use super::rule::{Direction, StubKind, RollConvention, ScheduleRule};
+
+// === Types ===
+
+/// Coupon / payment schedule between an effective and a termination date.
+/// Both date vectors have the same length and include the two end points.
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    unadjusted: DateVector,
+    adjusted: DateVector,
+}
+
+// === Impls ===
+
+impl Schedule {
+
+    /// Generates a schedule from `effective` to `termination`.
+    /// Regular dates are stepped from the anchor (effective date when
+    /// going forward, termination date when going backward) by whole
+    /// multiples of `frequency`, so month-end clamping never drifts.
+    /// Adjusted dates are obtained by rolling each unadjusted date on `cal`.
+    pub fn generate<C: Calendar>(
+        effective: Date,
+        termination: Date,
+        frequency: Frequency,
+        rule: ScheduleRule,
+        cal: &C,
+        convention: BusinessDayConvention,
+    ) -> ErrorResult<Self> {
+        frequency.validate()?;
+
+        if effective >= termination {
+            return Err(ErebusError::InvalidOperation(
+                "Effective date must be before termination date".into(),
+            ));
+        }
+
+        let step = frequency.months() as i32;
+        let start = effective.days;
+        let end = termination.days;
+
+        let days = match rule.direction {
+            Direction::Forward => {
+                let mut regular = Vec::new();
+                let mut k = 1;
+                let next = loop {
+                    let d = regular_date(start, k * step, rule.roll);
+                    if d >= end {
+                        break d;
+                    }
+                    regular.push(d);
+                    k += 1;
+                };
+
+                if next != end && rule.stub == StubKind::Long {
+                    regular.pop();
+                }
+
+                let mut out = Vec::with_capacity(regular.len() + 2);
+                out.push(start);
+                out.extend_from_slice(&regular);
+                out.push(end);
+                out
+            }
+            Direction::Backward => {
+                let mut regular = Vec::new();
+                let mut k = 1;
+                let next = loop {
+                    let d = regular_date(end, -k * step, rule.roll);
+                    if d <= start {
+                        break d;
+                    }
+                    regular.push(d);
+                    k += 1;
+                };
+
+                if next != start && rule.stub == StubKind::Long {
+                    regular.pop();
+                }
+
+                let mut out = Vec::with_capacity(regular.len() + 2);
+                out.push(start);
+                out.extend(regular.iter().rev());
+                out.push(end);
+                out
+            }
+        };
+
+        let adjusted_days: Vec<i32> = days
+            .iter()
+            .map(|&d| convention.adjust(cal, Date { days: d }).map(|a| a.days))
+            .collect::<ErrorResult<_>>()?;
+
+        let n = days.len();
+        Ok(Self {
+            unadjusted: DateVector { days, validity: bitvec![1; n] },
+            adjusted: DateVector { days: adjusted_days, validity: bitvec![1; n] },
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.unadjusted.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.unadjusted.is_empty()
+    }
+
+    /// Schedule dates before business-day adjustment.
+    #[inline]
+    pub fn unadjusted(&self) -> &DateVector {
+        &self.unadjusted
+    }
+
+    /// Schedule dates after business-day adjustment.
+    #[inline]
+    pub fn adjusted(&self) -> &DateVector {
+        &self.adjusted
+    }
+
+    /// Consumes self and returns `(unadjusted, adjusted)`.
+    #[inline]
+    pub fn into_parts(self) -> (DateVector, DateVector) {
+        (self.unadjusted, self.adjusted)
+    }
+}
+
+/// Shifts `anchor` by `months` and applies the roll convention.
+#[inline]
+fn regular_date(anchor: i32, months: i32, roll: RollConvention) -> i32 {
+    match roll {
+        RollConvention::None => shift_months_days(anchor, months, MonthEndRule::Clamp),
+        RollConvention::EndOfMonth => shift_months_days(anchor, months, MonthEndRule::Preserve),
+        RollConvention::Imm => {
+            let (y, m, _) = days_to_ymd(shift_months_days(anchor, months, MonthEndRule::Clamp));
+            imm_date(y, m)
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// === Imports ===
+use crate::prelude::*;
+use super::units::{days_hms_to_nanos, NANOS_PER_DAY};
+
+// === Impls ===
+
+impl Timestamp {
+
+    /// Calendar date containing this instant.
+    #[inline]
+    pub fn date(&self) -> Date {
+        Date { days: self.nanos.div_euclid(NANOS_PER_DAY) as i32 }
+    }
+}
+
+impl TimestampVector {
+
+    /// Midnight of each date; nulls are carried through.
+    /// Dates outside the i64 nanosecond range become null.
+    pub fn from_date_vector(dates: &DateVector) -> Self {
+        let n = dates.days.len();
+        let mut nanos = vec![0i64; n];
+        let mut validity = dates.validity.clone();
+
+        for (i, o) in nanos.iter_mut().enumerate() {
+            unsafe {
+                if *dates.validity.get_unchecked(i) {
+                    match days_hms_to_nanos(*dates.days.get_unchecked(i), 0, 0, 0, 0) {
+                        Some(v) => *o = v,
+                        None => validity.set_unchecked(i, false),
+                    }
+                }
+            }
+        }
+
+        Self { nanos, validity }
+    }
+
+    /// Calendar date of each instant; nulls are carried through.
+    pub fn to_date_vector(&self) -> DateVector {
+        let n = self.nanos.len();
+        let mut days = vec![0i32; n];
+
+        for (i, d) in days.iter_mut().enumerate() {
+            unsafe {
+                if *self.validity.get_unchecked(i) {
+                    *d = self.nanos.get_unchecked(i).div_euclid(NANOS_PER_DAY) as i32;
+                }
+            }
+        }
+
+        DateVector { days, validity: self.validity.clone() }
+    }
+}
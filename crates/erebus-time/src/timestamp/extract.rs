@@ -0,0 +1,87 @@
+// === Imports ===
+use crate::prelude::*;
+use super::units::{split_nanos, nanos_to_hms};
+
+// === Impls ===
+
+impl Timestamp {
+
+    /// Extract (hour, minute, second, subsecond nanos).
+    #[inline]
+    pub fn hms(&self) -> (u8, u8, u8, u32) {
+        nanos_to_hms(split_nanos(self.nanos).1)
+    }
+
+    /// Extract hour component (0–23).
+    #[inline]
+    pub fn hour(&self) -> u8 {
+        self.hms().0
+    }
+
+    /// Extract minute component (0–59).
+    #[inline]
+    pub fn minute(&self) -> u8 {
+        self.hms().1
+    }
+
+    /// Extract second component (0–59).
+    #[inline]
+    pub fn second(&self) -> u8 {
+        self.hms().2
+    }
+
+    /// Extract subsecond nanoseconds (0–999_999_999).
+    #[inline]
+    pub fn nanosecond(&self) -> u32 {
+        self.hms().3
+    }
+
+    /// Nanoseconds elapsed since midnight.
+    #[inline]
+    pub fn nanos_of_day(&self) -> i64 {
+        split_nanos(self.nanos).1
+    }
+}
+
+impl TimestampVector {
+
+    #[inline]
+    fn extract_with<T: Copy + Default, F: Fn(i64) -> T>(&self, f: F) -> Vec<T> {
+        let n = self.nanos.len();
+        let mut out = vec![T::default(); n];
+
+        for (i, o) in out.iter_mut().enumerate() {
+            unsafe {
+                if *self.validity.get_unchecked(i) {
+                    *o = f(*self.nanos.get_unchecked(i));
+                }
+            }
+        }
+        out
+    }
+
+    /// Extract hours vector (0–23).
+    pub fn hours(&self) -> Vec<u8> {
+        self.extract_with(|v| nanos_to_hms(split_nanos(v).1).0)
+    }
+
+    /// Extract minutes vector (0–59).
+    pub fn minutes(&self) -> Vec<u8> {
+        self.extract_with(|v| nanos_to_hms(split_nanos(v).1).1)
+    }
+
+    /// Extract seconds vector (0–59).
+    pub fn seconds(&self) -> Vec<u8> {
+        self.extract_with(|v| nanos_to_hms(split_nanos(v).1).2)
+    }
+
+    /// Extract subsecond nanoseconds vector.
+    pub fn nanoseconds(&self) -> Vec<u32> {
+        self.extract_with(|v| nanos_to_hms(split_nanos(v).1).3)
+    }
+
+    /// Extract (hour, minute, second, subsecond nanos) tuples.
+    pub fn hms(&self) -> Vec<(u8, u8, u8, u32)> {
+        self.extract_with(|v| nanos_to_hms(split_nanos(v).1))
+    }
+}
@@ -0,0 +1,43 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::date::{compile_format, DateFormatter};
+use super::units::{split_nanos, nanos_to_hms};
+
+// === Impls ===
+
+impl Timestamp {
+
+    /// Formats with the same tokens accepted by [`Timestamp::parse`].
+    pub fn format(&self, fmt: &str) -> ErrorResult<String> {
+        let formatter = compile_format(fmt)?;
+        let mut out = String::new();
+        let (days, nod) = split_nanos(self.nanos);
+        formatter.write_datetime(&mut out, days, nanos_to_hms(nod));
+        Ok(out)
+    }
+}
+
+impl TimestampVector {
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _format(&self, formatter: &DateFormatter) -> VectorData<String> {
+        let n = self.nanos.len();
+        let mut out = vec![String::new(); n];
+
+        for (i, o) in out.iter_mut().enumerate() {
+            if self.validity[i] {
+                let (days, nod) = split_nanos(self.nanos[i]);
+                formatter.write_datetime(o, days, nanos_to_hms(nod));
+            }
+        }
+
+        VectorData { data: out, validity: self.validity.clone() }
+    }
+
+    /// Formats every element; null rows stay null.
+    pub fn format(&self, fmt: &str) -> ErrorResult<VectorData<String>> {
+        let formatter = compile_format(fmt)?;
+        Ok(self._format(&formatter))
+    }
+}
@@ -0,0 +1,29 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+
+impl TimestampVector {
+
+    /// Returns `Some(Timestamp)` if valid, `None` if null or out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<Timestamp> {
+        if i >= self.nanos.len() || !self.validity[i] {
+            return None;
+        }
+        Some(Timestamp { nanos: self.nanos[i] })
+    }
+
+    /// Returns whether the value at index `i` is valid (non-null).
+    /// Returns an error if `i` is out of bounds.
+    #[inline]
+    pub fn is_valid_at(&self, i: usize) -> ErrorResult<bool> {
+        if i >= self.validity.len() {
+            return Err(ErebusError::IndexOutOfBounds {
+                index: i,
+                size: self.validity.len(),
+            });
+        }
+        Ok(self.validity[i])
+    }
+}
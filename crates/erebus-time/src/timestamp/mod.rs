@@ -0,0 +1,13 @@
+mod convert;
+mod extract;
+mod format;
+mod indexing;
+mod parse;
+mod round;
+mod timestamp;
+mod units;
+
+pub use timestamp::{Timestamp, TimestampVector};
+pub(crate) use timestamp::validate_hms_strict;
+pub use round::TimeUnit;
+pub use units::*;
@@ -0,0 +1,60 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::date::{compile_ymd_format, extract_ymd_hms, YmdExtractor};
+use super::timestamp::validate_hms_lenient;
+use super::units::days_hms_to_nanos;
+
+// === Impls ===
+
+/// Parses a timestamp; returns `None` on any malformed or invalid field.
+#[inline]
+pub(crate) fn parse_timestamp_lenient(s: &str, ex: &YmdExtractor) -> Option<i64> {
+    let ((y, m, d), (hh, mm, ss, ns)) = extract_ymd_hms(s, ex)?;
+    if !validate_hms_lenient(hh, mm, ss, ns) {
+        return None;
+    }
+    Date::from_ymd(y, m, d)
+        .ok()
+        .and_then(|date| days_hms_to_nanos(date.days(), hh, mm, ss, ns))
+}
+
+impl Timestamp {
+
+    /// Parses with date tokens (`%Y %y %m %d %b %B`) and time tokens
+    /// (`%H %M %S %.f`). Missing time fields default to zero.
+    pub fn parse(s: &str, fmt: &str) -> ErrorResult<Self> {
+        let extractor = compile_ymd_format(fmt)?;
+        let ((y, m, d), (hh, mm, ss, ns)) = extract_ymd_hms(s, &extractor).ok_or_else(|| {
+            ErebusError::InvalidOperation("Failed to parse timestamp string".into())
+        })?;
+        Self::from_ymd_hms_nano(y, m, d, hh, mm, ss, ns)
+    }
+}
+
+impl TimestampVector {
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _parse(values: &[&str], extractor: &YmdExtractor) -> Self {
+        let n = values.len();
+
+        let mut nanos = vec![0i64; n];
+        let mut validity = bitvec![0; n];
+
+        for (i, s) in values.iter().enumerate() {
+            if let Some(v) = parse_timestamp_lenient(s, extractor) {
+                nanos[i] = v;
+                validity.set(i, true);
+            }
+        }
+
+        Self { nanos, validity }
+    }
+
+    /// Parses every element with one compiled format.
+    /// Unparseable elements are encoded as nulls.
+    pub fn parse(values: &[&str], fmt: &str) -> ErrorResult<Self> {
+        let extractor = compile_ymd_format(fmt)?;
+        Ok(Self::_parse(values, &extractor))
+    }
+}
@@ -0,0 +1,124 @@
+// === Imports ===
+use crate::prelude::*;
+use super::units::*;
+
+// === Types ===
+
+/// Resolution used when rounding timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+// === Impls ===
+
+impl TimeUnit {
+
+    /// Length of one unit in nanoseconds.
+    #[inline]
+    pub fn nanos(&self) -> i64 {
+        match *self {
+            TimeUnit::Day => NANOS_PER_DAY,
+            TimeUnit::Hour => NANOS_PER_HOUR,
+            TimeUnit::Minute => NANOS_PER_MINUTE,
+            TimeUnit::Second => NANOS_PER_SECOND,
+            TimeUnit::Millisecond => NANOS_PER_MILLI,
+            TimeUnit::Microsecond => NANOS_PER_MICRO,
+            TimeUnit::Nanosecond => 1,
+        }
+    }
+}
+
+// Each helper is `None` when the result leaves the i64 range.
+
+#[inline]
+fn floor_nanos(v: i64, u: i64) -> Option<i64> {
+    v.checked_sub(v.rem_euclid(u))
+}
+
+#[inline]
+fn ceil_nanos(v: i64, u: i64) -> Option<i64> {
+    let r = v.rem_euclid(u);
+    if r == 0 { Some(v) } else { v.checked_add(u - r) }
+}
+
+/// Round half up (ties go towards +inf).
+#[inline]
+fn round_nanos(v: i64, u: i64) -> Option<i64> {
+    let r = v.rem_euclid(u);
+    if r * 2 >= u { v.checked_add(u - r) } else { v.checked_sub(r) }
+}
+
+#[inline]
+fn rounded(nanos: Option<i64>) -> ErrorResult<Timestamp> {
+    nanos.map(|nanos| Timestamp { nanos }).ok_or_else(|| {
+        ErebusError::InvalidOperation("Rounded timestamp is outside the i64 range".into())
+    })
+}
+
+impl Timestamp {
+
+    /// Errors if the result is outside the i64 nanosecond range.
+    #[inline]
+    pub fn floor(&self, unit: TimeUnit) -> ErrorResult<Timestamp> {
+        rounded(floor_nanos(self.nanos, unit.nanos()))
+    }
+
+    /// Errors if the result is outside the i64 nanosecond range.
+    #[inline]
+    pub fn ceil(&self, unit: TimeUnit) -> ErrorResult<Timestamp> {
+        rounded(ceil_nanos(self.nanos, unit.nanos()))
+    }
+
+    /// Rounds to the nearest multiple of `unit`; ties round up.
+    /// Errors if the result is outside the i64 nanosecond range.
+    #[inline]
+    pub fn round(&self, unit: TimeUnit) -> ErrorResult<Timestamp> {
+        rounded(round_nanos(self.nanos, unit.nanos()))
+    }
+}
+
+impl TimestampVector {
+
+    /// Rows whose result leaves the i64 range become null.
+    #[inline]
+    fn map_valid_nanos<F: Fn(i64) -> Option<i64>>(&self, f: F) -> TimestampVector {
+        let n = self.nanos.len();
+        let mut out = vec![0i64; n];
+        let mut validity = self.validity.clone();
+
+        for (i, o) in out.iter_mut().enumerate() {
+            unsafe {
+                if *self.validity.get_unchecked(i) {
+                    match f(*self.nanos.get_unchecked(i)) {
+                        Some(v) => *o = v,
+                        None => validity.set_unchecked(i, false),
+                    }
+                }
+            }
+        }
+
+        TimestampVector { nanos: out, validity }
+    }
+
+    pub fn floor(&self, unit: TimeUnit) -> TimestampVector {
+        let u = unit.nanos();
+        self.map_valid_nanos(|v| floor_nanos(v, u))
+    }
+
+    pub fn ceil(&self, unit: TimeUnit) -> TimestampVector {
+        let u = unit.nanos();
+        self.map_valid_nanos(|v| ceil_nanos(v, u))
+    }
+
+    pub fn round(&self, unit: TimeUnit) -> TimestampVector {
+        let u = unit.nanos();
+        self.map_valid_nanos(|v| round_nanos(v, u))
+    }
+}
@@ -0,0 +1,208 @@
+// === Imports ===
+use crate::prelude::*;
+use super::units::days_hms_to_nanos;
+
+// === Types ===
+
+/// Instant stored as nanoseconds since the Unix epoch (UTC, no time zone).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Timestamp {
+    pub(crate) nanos: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimestampVector {
+    pub(crate) nanos: Vec<i64>,
+    pub(crate) validity: BitVec,
+}
+
+// === Impls ===
+
+/// Strict time-of-day validation.
+#[inline]
+pub(crate) fn validate_hms_strict(hour: u8, minute: u8, second: u8, nanos: u32) -> ErrorResult<()> {
+    if !validate_hms_lenient(hour, minute, second, nanos) {
+        return Err(ErebusError::InvalidTime { hour, minute, second, nanos });
+    }
+    Ok(())
+}
+
+/// Error for a date whose midnight does not fit in i64 nanoseconds.
+#[inline]
+pub(crate) fn out_of_timestamp_range(days: i32) -> ErebusError {
+    ErebusError::InvalidDate(format!(
+        "{} days since epoch is outside the timestamp range",
+        days
+    ))
+}
+
+/// Lenient time-of-day validation.
+#[inline]
+pub(crate) fn validate_hms_lenient(hour: u8, minute: u8, second: u8, nanos: u32) -> bool {
+    hour < 24 && minute < 60 && second < 60 && nanos < 1_000_000_000
+}
+
+impl Timestamp {
+
+    #[inline]
+    pub fn from_nanos(nanos: i64) -> Self {
+        Self { nanos }
+    }
+
+    #[inline]
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> ErrorResult<Self> {
+        Self::from_ymd_hms_nano(year, month, day, hour, minute, second, 0)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_ymd_hms_nano(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> ErrorResult<Self> {
+        let date = Date::from_ymd(year, month, day)?;
+        Self::from_date_hms(date, hour, minute, second, nanos)
+    }
+
+    /// Midnight of `date`.
+    /// Errors if the date is outside the i64 nanosecond range.
+    #[inline]
+    pub fn from_date(date: Date) -> ErrorResult<Self> {
+        Self::from_date_hms(date, 0, 0, 0, 0)
+    }
+
+    #[inline]
+    pub fn from_date_hms(
+        date: Date,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        nanos: u32,
+    ) -> ErrorResult<Self> {
+        validate_hms_strict(hour, minute, second, nanos)?;
+        days_hms_to_nanos(date.days(), hour, minute, second, nanos)
+            .map(|nanos| Self { nanos })
+            .ok_or_else(|| out_of_timestamp_range(date.days()))
+    }
+
+    #[inline]
+    pub fn nanos(&self) -> i64 {
+        self.nanos
+    }
+}
+
+impl TimestampVector {
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nanos.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nanos.is_empty()
+    }
+
+    /// Raw nanoseconds since epoch (placeholders at null rows).
+    #[inline]
+    pub fn nanos(&self) -> &[i64] {
+        &self.nanos
+    }
+
+    #[inline]
+    pub fn validity(&self) -> &BitVec {
+        &self.validity
+    }
+
+    /// Construct from raw nanoseconds; every row is valid.
+    #[inline]
+    pub fn from_nanos(nanos: Vec<i64>) -> Self {
+        let n = nanos.len();
+        Self { nanos, validity: bitvec![1; n] }
+    }
+
+    /// Construct from raw nanoseconds with an explicit validity bitmap.
+    #[inline]
+    pub fn from_nanos_with_validity(nanos: Vec<i64>, validity: BitVec) -> ErrorResult<Self> {
+        if nanos.len() != validity.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: nanos.len(),
+                found: validity.len(),
+            });
+        }
+        Ok(Self { nanos, validity })
+    }
+
+    /// - No bounds checks are performed
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) unsafe fn _from_date_hms_vectors(
+        dates: &DateVector,
+        hours: &[u8],
+        minutes: &[u8],
+        seconds: &[u8],
+        nanos_in: &[u32],
+    ) -> Self {
+        let n = dates.len();
+
+        let mut nanos = vec![0i64; n];
+        let mut validity = bitvec![0; n];
+
+        for i in 0..n {
+            if !*dates.validity.get_unchecked(i) {
+                continue;
+            }
+            let h = *hours.get_unchecked(i);
+            let m = *minutes.get_unchecked(i);
+            let s = *seconds.get_unchecked(i);
+            let ns = *nanos_in.get_unchecked(i);
+
+            if !validate_hms_lenient(h, m, s, ns) {
+                continue;
+            }
+            // Dates outside the i64 nanosecond range become null
+            if let Some(v) = days_hms_to_nanos(*dates.days.get_unchecked(i), h, m, s, ns) {
+                *nanos.get_unchecked_mut(i) = v;
+                validity.set_unchecked(i, true);
+            }
+        }
+
+        Self { nanos, validity }
+    }
+
+    /// Construct from a date vector plus time-of-day parts
+    /// (lenient per-element validation).
+    /// Null dates and invalid times are encoded as nulls.
+    #[inline]
+    pub fn from_date_hms_vectors(
+        dates: &DateVector,
+        hours: &[u8],
+        minutes: &[u8],
+        seconds: &[u8],
+        nanos: &[u32],
+    ) -> ErrorResult<Self> {
+        let n = dates.len();
+
+        for len in [hours.len(), minutes.len(), seconds.len(), nanos.len()] {
+            if len != n {
+                return Err(ErebusError::LengthMismatch { expected: n, found: len });
+            }
+        }
+
+        // SAFETY: lengths verified above
+        Ok(unsafe {
+            Self::_from_date_hms_vectors(dates, hours, minutes, seconds, nanos)
+        })
+    }
+}
@@ -0,0 +1,36 @@
+// === Impls ===
+
+pub const NANOS_PER_MICRO: i64 = 1_000;
+pub const NANOS_PER_MILLI: i64 = 1_000_000;
+pub const NANOS_PER_SECOND: i64 = 1_000_000_000;
+pub const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SECOND;
+pub const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
+pub const NANOS_PER_DAY: i64 = 24 * NANOS_PER_HOUR;
+
+/// Splits nanoseconds since epoch into (days since epoch, nanoseconds into day).
+#[inline]
+pub fn split_nanos(nanos: i64) -> (i32, i64) {
+    (nanos.div_euclid(NANOS_PER_DAY) as i32, nanos.rem_euclid(NANOS_PER_DAY))
+}
+
+/// Splits nanoseconds into a day into (hour, minute, second, subsecond nanos).
+#[inline]
+pub fn nanos_to_hms(nanos_of_day: i64) -> (u8, u8, u8, u32) {
+    let h = nanos_of_day / NANOS_PER_HOUR;
+    let m = (nanos_of_day % NANOS_PER_HOUR) / NANOS_PER_MINUTE;
+    let s = (nanos_of_day % NANOS_PER_MINUTE) / NANOS_PER_SECOND;
+    let ns = nanos_of_day % NANOS_PER_SECOND;
+    (h as u8, m as u8, s as u8, ns as u32)
+}
+
+/// Combines days since epoch and a time of day into nanoseconds since epoch.
+/// Inputs are assumed valid. `None` when the result does not fit in i64
+/// (outside roughly 1677-09-21 to 2262-04-11).
+#[inline]
+pub fn days_hms_to_nanos(days: i32, hour: u8, minute: u8, second: u8, nanos: u32) -> Option<i64> {
+    let time_of_day = hour as i64 * NANOS_PER_HOUR
+        + minute as i64 * NANOS_PER_MINUTE
+        + second as i64 * NANOS_PER_SECOND
+        + nanos as i64;
+    (days as i64).checked_mul(NANOS_PER_DAY)?.checked_add(time_of_day)
+}
@@ -0,0 +1,109 @@
+// === Imports ===
+use crate::prelude::*;
+use super::timezone::{local_nanos_to_utc, Disambiguate, Interval, TimeZone, MAX_OFFSET_SECS};
+
+// === Impls ===
+
+impl TimeZone {
+
+    /// Converts UTC nanoseconds to local wall-clock nanoseconds.
+    /// The current offset interval is cached, so sorted input costs
+    /// one lookup per transition crossed. Overflow yields null.
+    pub fn utc_to_local(&self, utc: &VectorData<i64>) -> VectorData<i64> {
+        let n = utc.data.len();
+        let mut data = vec![0i64; n];
+        let mut validity = utc.validity.clone();
+        let mut cache: Option<Interval> = None;
+
+        for (i, o) in data.iter_mut().enumerate() {
+            if !validity[i] {
+                continue;
+            }
+            let x = utc.data[i];
+            let secs = x.div_euclid(NANOS_PER_SECOND);
+
+            let iv = match cache {
+                Some(iv) if iv.contains(secs) => iv,
+                _ => {
+                    let iv = self.interval_at(secs);
+                    cache = Some(iv);
+                    iv
+                }
+            };
+
+            match x.checked_add(iv.offset as i64 * NANOS_PER_SECOND) {
+                Some(v) => *o = v,
+                None => validity.set(i, false),
+            }
+        }
+
+        VectorData { data, validity }
+    }
+
+    /// Converts local wall-clock nanoseconds to UTC, resolving
+    /// ambiguous and non-existent times with `choice`.
+    pub fn local_to_utc(
+        &self,
+        local: &VectorData<i64>,
+        choice: Disambiguate,
+    ) -> ErrorResult<VectorData<i64>> {
+        let n = local.data.len();
+        let mut data = vec![0i64; n];
+        let mut validity = local.validity.clone();
+        let mut cache: Option<Interval> = None;
+
+        for (i, o) in data.iter_mut().enumerate() {
+            if !validity[i] {
+                continue;
+            }
+            let x = local.data[i];
+            let secs = x.div_euclid(NANOS_PER_SECOND);
+
+            // Fast path: far enough from both interval ends that no
+            // other offset can map to the same local time
+            if let Some(iv) = cache {
+                let utc = secs - iv.offset as i64;
+                if utc >= iv.from.saturating_add(MAX_OFFSET_SECS)
+                    && utc < iv.until.saturating_sub(MAX_OFFSET_SECS)
+                {
+                    match x.checked_sub(iv.offset as i64 * NANOS_PER_SECOND) {
+                        Some(v) => *o = v,
+                        None => validity.set(i, false),
+                    }
+                    continue;
+                }
+            }
+
+            match local_nanos_to_utc(self, x, choice)? {
+                Some(v) => {
+                    *o = v;
+                    cache = Some(self.interval_at(v.div_euclid(NANOS_PER_SECOND)));
+                }
+                None => validity.set(i, false),
+            }
+        }
+
+        Ok(VectorData { data, validity })
+    }
+}
+
+impl TimestampVector {
+
+    /// Local wall-clock times in `tz` of these UTC instants.
+    pub fn to_local(&self, tz: &TimeZone) -> TimestampVector {
+        let v = tz.utc_to_local(&VectorData {
+            data: self.nanos.clone(),
+            validity: self.validity.clone(),
+        });
+        TimestampVector { nanos: v.data, validity: v.validity }
+    }
+
+    /// UTC instants of these local wall-clock times in `tz`.
+    pub fn to_utc(&self, tz: &TimeZone, choice: Disambiguate) -> ErrorResult<TimestampVector> {
+        let v = tz.local_to_utc(
+            &VectorData { data: self.nanos.clone(), validity: self.validity.clone() },
+            choice,
+        )?;
+        Ok(TimestampVector { nanos: v.data, validity: v.validity })
+    }
+}
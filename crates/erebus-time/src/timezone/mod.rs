@@ -0,0 +1,6 @@
+mod convert;
+mod posix;
+mod timezone;
+mod tzif;
+
+pub use timezone::{TimeZone, Disambiguate};
\ No newline at end of file
@@ -0,0 +1,244 @@
+// === Imports ===
+use crate::prelude::*;
+use super::timezone::Interval;
+
+// === Types ===
+
+// POSIX TZ string, as found in TZif footers:
+//   std offset [dst [offset] [,start[/time],end[/time]]]
+// Example: "EST5EDT,M3.2.0,M11.1.0"
+// POSIX offsets count hours *west* of UTC, so their sign is flipped here.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PosixTz {
+    pub(crate) std_offset: i32,
+    pub(crate) dst: Option<PosixDst>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PosixDst {
+    offset: i32,
+    start: RuleDate,
+    start_time: i32, // seconds after local midnight, standard time
+    end: RuleDate,
+    end_time: i32,   // seconds after local midnight, daylight time
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDate {
+    /// `Jn`: 1..=365, Feb 29 is never counted.
+    JulianNoLeap(u16),
+    /// `n`: 0..=365, Feb 29 is counted.
+    JulianZero(u16),
+    /// `Mm.w.d`: day `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    MonthWeekDay { month: u8, week: u8, weekday: u8 },
+}
+
+struct Scanner<'a> {
+    b: &'a [u8],
+    i: usize,
+}
+
+// === Impls ===
+
+impl Scanner<'_> {
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.b.get(self.i).copied()
+    }
+
+    #[inline]
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.i += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn number(&mut self) -> Option<i32> {
+        let start = self.i;
+        let mut v: i32 = 0;
+        while let Some(c) = self.peek().filter(u8::is_ascii_digit) {
+            v = v.checked_mul(10)?.checked_add((c - b'0') as i32)?;
+            self.i += 1;
+        }
+        if self.i == start { None } else { Some(v) }
+    }
+
+    fn name(&mut self) -> Option<()> {
+        if self.eat(b'<') {
+            while self.peek()? != b'>' {
+                self.i += 1;
+            }
+            self.i += 1;
+            return Some(());
+        }
+        let start = self.i;
+        while self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            self.i += 1;
+        }
+        if self.i - start >= 3 { Some(()) } else { None }
+    }
+
+    /// `[+-]hh[:mm[:ss]]` in seconds.
+    fn hms(&mut self) -> Option<i32> {
+        let sign = if self.eat(b'-') { -1 } else { self.eat(b'+'); 1 };
+        let h = self.number()?;
+        let mut secs = h * 3600;
+        if self.eat(b':') {
+            secs += self.number()? * 60;
+            if self.eat(b':') {
+                secs += self.number()?;
+            }
+        }
+        if h > 167 { None } else { Some(sign * secs) }
+    }
+
+    fn rule_date(&mut self) -> Option<RuleDate> {
+        if self.eat(b'M') {
+            let month = self.number()?;
+            if !self.eat(b'.') { return None; }
+            let week = self.number()?;
+            if !self.eat(b'.') { return None; }
+            let weekday = self.number()?;
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || !(0..=6).contains(&weekday) {
+                return None;
+            }
+            Some(RuleDate::MonthWeekDay { month: month as u8, week: week as u8, weekday: weekday as u8 })
+        } else if self.eat(b'J') {
+            let n = self.number()?;
+            if !(1..=365).contains(&n) { return None; }
+            Some(RuleDate::JulianNoLeap(n as u16))
+        } else {
+            let n = self.number()?;
+            if !(0..=365).contains(&n) { return None; }
+            Some(RuleDate::JulianZero(n as u16))
+        }
+    }
+
+    /// `date[/time]`, time defaults to 02:00:00.
+    fn rule(&mut self) -> Option<(RuleDate, i32)> {
+        let date = self.rule_date()?;
+        let time = if self.eat(b'/') { self.hms()? } else { 7200 };
+        Some((date, time))
+    }
+}
+
+impl RuleDate {
+    /// Days since epoch of this rule date in `year`.
+    fn days_in_year(&self, year: i32) -> i32 {
+        let jan1 = ymd_to_days(year, 1, 1);
+        match *self {
+            RuleDate::JulianNoLeap(n) => {
+                let n = n as i32;
+                jan1 + n - 1 + if is_leap_year(year) && n >= 60 { 1 } else { 0 }
+            }
+            RuleDate::JulianZero(n) => jan1 + n as i32,
+            RuleDate::MonthWeekDay { month, week, weekday } => {
+                let first = ymd_to_days(year, month, 1);
+                let first_wd = (days_to_weekday_iso(first) % 7) as i32; // Sunday = 0
+                let mut d = first + (weekday as i32 - first_wd).rem_euclid(7) + 7 * (week as i32 - 1);
+                let last = first + days_in_month(year, month) as i32 - 1;
+                while d > last {
+                    d -= 7;
+                }
+                d
+            }
+        }
+    }
+}
+
+impl PosixTz {
+
+    pub(crate) fn parse(s: &str) -> ErrorResult<Self> {
+        let err = || ErebusError::DecodeError(format!("Invalid POSIX TZ string: {:?}", s));
+        let mut sc = Scanner { b: s.as_bytes(), i: 0 };
+
+        sc.name().ok_or_else(err)?;
+        let std_offset = -sc.hms().ok_or_else(err)?;
+
+        if sc.peek().is_none() {
+            return Ok(Self { std_offset, dst: None });
+        }
+
+        sc.name().ok_or_else(err)?;
+        let offset = match sc.peek() {
+            Some(b',') | None => std_offset + 3600,
+            _ => -sc.hms().ok_or_else(err)?,
+        };
+
+        // US rules when none are given
+        let (start, end) = if sc.eat(b',') {
+            let start = sc.rule().ok_or_else(err)?;
+            if !sc.eat(b',') {
+                return Err(err());
+            }
+            (start, sc.rule().ok_or_else(err)?)
+        } else {
+            (
+                (RuleDate::MonthWeekDay { month: 3, week: 2, weekday: 0 }, 7200),
+                (RuleDate::MonthWeekDay { month: 11, week: 1, weekday: 0 }, 7200),
+            )
+        };
+
+        if sc.peek().is_some() {
+            return Err(err());
+        }
+
+        Ok(Self {
+            std_offset,
+            dst: Some(PosixDst {
+                offset,
+                start: start.0,
+                start_time: start.1,
+                end: end.0,
+                end_time: end.1,
+            }),
+        })
+    }
+
+    /// UTC seconds of the DST start and end in `year`,
+    /// each paired with the offset in effect afterwards.
+    fn transitions_in_year(dst: &PosixDst, std_offset: i32, year: i32) -> [(i64, i32, bool); 2] {
+        let start = dst.start.days_in_year(year) as i64 * 86_400
+            + dst.start_time as i64 - std_offset as i64;
+        let end = dst.end.days_in_year(year) as i64 * 86_400
+            + dst.end_time as i64 - dst.offset as i64;
+
+        let a = (start, dst.offset, true);
+        let b = (end, std_offset, false);
+        if start <= end { [a, b] } else { [b, a] }
+    }
+
+    /// Interval of constant offset containing UTC second `secs`.
+    pub(crate) fn interval_at(&self, secs: i64) -> Interval {
+        let dst = match &self.dst {
+            None => {
+                return Interval { offset: self.std_offset, is_dst: false, from: i64::MIN, until: i64::MAX };
+            }
+            Some(d) => d,
+        };
+
+        let year = days_to_ymd(secs.div_euclid(86_400).clamp(i32::MIN as i64, i32::MAX as i64) as i32).0;
+
+        let mut points = [(0i64, 0i32, false); 6];
+        for (k, y) in [year - 1, year, year + 1].into_iter().enumerate() {
+            let t = Self::transitions_in_year(dst, self.std_offset, y);
+            points[2 * k] = t[0];
+            points[2 * k + 1] = t[1];
+        }
+
+        let idx = points.partition_point(|p| p.0 <= secs);
+        if idx == 0 {
+            // Before last year's first transition: the state that ended the year before
+            let (t, offset, is_dst) = points[1];
+            return Interval { offset, is_dst, from: i64::MIN, until: t.min(points[0].0) };
+        }
+
+        let (from, offset, is_dst) = points[idx - 1];
+        let until = if idx < points.len() { points[idx].0 } else { i64::MAX };
+        Interval { offset, is_dst, from, until }
+    }
+}
@@ -0,0 +1,299 @@
+// === Imports ===
+use crate::prelude::*;
+use super::posix::PosixTz;
+use super::tzif::parse_tzif;
+use std::path::Path;
+
+// === Constants ===
+
+/// Bound on any UTC offset, used to bracket local -> UTC candidates.
+pub(crate) const MAX_OFFSET_SECS: i64 = 26 * 3600;
+
+// === Types ===
+
+/// IANA time zone: historical transitions plus an optional POSIX rule
+/// for instants after the last transition.
+#[derive(Debug, Clone)]
+pub struct TimeZone {
+    name: String,
+    transitions: Vec<i64>, // UTC seconds, ascending
+    offsets: Vec<i32>,     // offset in effect from transitions[i]
+    dst: Vec<bool>,
+    initial_offset: i32,   // before the first transition
+    initial_dst: bool,
+    rule: Option<PosixTz>,
+}
+
+/// How to resolve local times that occur twice (fall back)
+/// or never (spring forward).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Disambiguate {
+    /// Ambiguous: the first occurrence. Non-existent: the last instant before the gap.
+    #[default]
+    Earliest,
+    /// Ambiguous: the second occurrence. Non-existent: the first instant after the gap.
+    Latest,
+    /// Return an error.
+    Raise,
+    /// Produce a null.
+    Null,
+}
+
+/// Span of UTC seconds `[from, until)` with a constant offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Interval {
+    pub(crate) offset: i32,
+    pub(crate) is_dst: bool,
+    pub(crate) from: i64,
+    pub(crate) until: i64,
+}
+
+/// Result of mapping a local second to UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LocalResolution {
+    Unique(i64),
+    Ambiguous(i64, i64),
+    /// Local time falls in a gap opened at this UTC second.
+    Gap(i64),
+}
+
+// === Impls ===
+
+impl Interval {
+    #[inline]
+    pub(crate) fn contains(&self, secs: i64) -> bool {
+        secs >= self.from && secs < self.until
+    }
+}
+
+impl TimeZone {
+
+    /// Coordinated Universal Time.
+    pub fn utc() -> Self {
+        Self::fixed("UTC", 0)
+    }
+
+    /// Zone with a constant offset east of UTC, in seconds.
+    pub fn fixed(name: &str, offset_secs: i32) -> Self {
+        Self {
+            name: name.to_string(),
+            transitions: Vec::new(),
+            offsets: Vec::new(),
+            dst: Vec::new(),
+            initial_offset: offset_secs,
+            initial_dst: false,
+            rule: None,
+        }
+    }
+
+    /// Zone defined only by a POSIX TZ rule, e.g. "EST5EDT,M3.2.0,M11.1.0".
+    pub fn from_posix(name: &str, rule: &str) -> ErrorResult<Self> {
+        let rule = PosixTz::parse(rule)?;
+        Ok(Self {
+            name: name.to_string(),
+            transitions: Vec::new(),
+            offsets: Vec::new(),
+            dst: Vec::new(),
+            initial_offset: rule.std_offset,
+            initial_dst: false,
+            rule: Some(rule),
+        })
+    }
+
+    /// Builds a zone from the contents of a TZif file.
+    pub fn from_tzif_bytes(name: &str, bytes: &[u8]) -> ErrorResult<Self> {
+        let data = parse_tzif(bytes)?;
+
+        let offsets = data.type_idx.iter().map(|&i| data.types[i as usize].utoff).collect();
+        let dst = data.type_idx.iter().map(|&i| data.types[i as usize].is_dst).collect();
+        let rule = data.footer.as_deref().map(PosixTz::parse).transpose()?;
+
+        Ok(Self {
+            name: name.to_string(),
+            transitions: data.transitions,
+            offsets,
+            dst,
+            initial_offset: data.types[0].utoff,
+            initial_dst: data.types[0].is_dst,
+            rule,
+        })
+    }
+
+    /// Reads a TZif file from disk.
+    pub fn from_file<P: AsRef<Path>>(name: &str, path: P) -> ErrorResult<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::from_tzif_bytes(name, &bytes)
+    }
+
+    /// Loads `name` (e.g. "America/New_York") from the system zoneinfo
+    /// database: `$TZDIR` if set, otherwise the usual install locations.
+    pub fn from_system(name: &str) -> ErrorResult<Self> {
+        if name.is_empty() || name.starts_with('/') || name.split('/').any(|p| p == "..") {
+            return Err(ErebusError::InvalidOperation(format!("Invalid time zone name: {:?}", name)));
+        }
+
+        let mut dirs: Vec<String> = Vec::new();
+        if let Ok(dir) = std::env::var("TZDIR") {
+            dirs.push(dir);
+        }
+        dirs.extend(
+            ["/usr/share/zoneinfo", "/usr/lib/zoneinfo", "/usr/share/lib/zoneinfo"]
+                .iter()
+                .map(|s| s.to_string()),
+        );
+
+        for dir in dirs {
+            let path = Path::new(&dir).join(name);
+            if path.is_file() {
+                return Self::from_file(name, path);
+            }
+        }
+
+        Err(ErebusError::InvalidOperation(format!("Unknown time zone: {}", name)))
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Offset interval containing UTC second `secs`.
+    pub(crate) fn interval_at(&self, secs: i64) -> Interval {
+        let n = self.transitions.len();
+
+        if n == 0 {
+            return match &self.rule {
+                Some(rule) => rule.interval_at(secs),
+                None => Interval {
+                    offset: self.initial_offset,
+                    is_dst: self.initial_dst,
+                    from: i64::MIN,
+                    until: i64::MAX,
+                },
+            };
+        }
+
+        if secs < self.transitions[0] {
+            return Interval {
+                offset: self.initial_offset,
+                is_dst: self.initial_dst,
+                from: i64::MIN,
+                until: self.transitions[0],
+            };
+        }
+
+        let last = self.transitions[n - 1];
+        if secs >= last {
+            return match &self.rule {
+                Some(rule) => {
+                    let mut iv = rule.interval_at(secs);
+                    iv.from = iv.from.max(last);
+                    iv
+                }
+                None => Interval {
+                    offset: self.offsets[n - 1],
+                    is_dst: self.dst[n - 1],
+                    from: last,
+                    until: i64::MAX,
+                },
+            };
+        }
+
+        let i = self.transitions.partition_point(|&t| t <= secs) - 1;
+        Interval {
+            offset: self.offsets[i],
+            is_dst: self.dst[i],
+            from: self.transitions[i],
+            until: self.transitions[i + 1],
+        }
+    }
+
+    /// Maps local second `local` to UTC.
+    /// Walks every interval that can hold a UTC instant within
+    /// `MAX_OFFSET_SECS` of `local` and keeps the self-consistent ones.
+    pub(crate) fn resolve_local(&self, local: i64) -> LocalResolution {
+        let mut found = [0i64; 2];
+        let mut count = 0usize;
+        let mut gap = None;
+
+        let hi = local.saturating_add(MAX_OFFSET_SECS);
+        let mut prev: Option<Interval> = None;
+        let mut iv = self.interval_at(local.saturating_sub(MAX_OFFSET_SECS));
+
+        loop {
+            let utc = local - iv.offset as i64;
+            if iv.contains(utc) && count < 2 {
+                found[count] = utc;
+                count += 1;
+            }
+            if let Some(p) = prev {
+                if local - p.offset as i64 >= iv.from && utc < iv.from {
+                    gap = Some(iv.from);
+                }
+            }
+            if iv.until == i64::MAX || iv.until > hi {
+                break;
+            }
+            prev = Some(iv);
+            iv = self.interval_at(iv.until);
+        }
+
+        match count {
+            0 => LocalResolution::Gap(gap.unwrap_or(local)),
+            1 => LocalResolution::Unique(found[0]),
+            _ => LocalResolution::Ambiguous(found[0].min(found[1]), found[0].max(found[1])),
+        }
+    }
+
+    /// UTC offset in seconds at `utc`.
+    #[inline]
+    pub fn utc_offset_seconds(&self, utc: Timestamp) -> i32 {
+        self.interval_at(utc.nanos.div_euclid(NANOS_PER_SECOND)).offset
+    }
+
+    /// Whether daylight saving time is in effect at `utc`.
+    #[inline]
+    pub fn is_dst_at(&self, utc: Timestamp) -> bool {
+        self.interval_at(utc.nanos.div_euclid(NANOS_PER_SECOND)).is_dst
+    }
+
+    /// Local wall-clock time of the UTC instant `utc`.
+    #[inline]
+    pub fn to_local(&self, utc: Timestamp) -> Timestamp {
+        let offset = self.utc_offset_seconds(utc) as i64;
+        Timestamp { nanos: utc.nanos + offset * NANOS_PER_SECOND }
+    }
+
+    /// UTC instant of the local wall-clock time `local`.
+    /// Returns `Ok(None)` only with [`Disambiguate::Null`].
+    pub fn to_utc(&self, local: Timestamp, choice: Disambiguate) -> ErrorResult<Option<Timestamp>> {
+        Ok(local_nanos_to_utc(self, local.nanos, choice)?.map(Timestamp::from_nanos))
+    }
+}
+
+/// Resolves local nanoseconds to UTC nanoseconds using `choice`.
+pub(crate) fn local_nanos_to_utc(
+    tz: &TimeZone,
+    local: i64,
+    choice: Disambiguate,
+) -> ErrorResult<Option<i64>> {
+    let secs = local.div_euclid(NANOS_PER_SECOND);
+    let sub = local.rem_euclid(NANOS_PER_SECOND);
+
+    let utc = match tz.resolve_local(secs) {
+        LocalResolution::Unique(u) => u.checked_mul(NANOS_PER_SECOND).map(|x| x + sub),
+        LocalResolution::Ambiguous(early, late) => match choice {
+            Disambiguate::Earliest => early.checked_mul(NANOS_PER_SECOND).map(|x| x + sub),
+            Disambiguate::Latest => late.checked_mul(NANOS_PER_SECOND).map(|x| x + sub),
+            Disambiguate::Raise => return Err(ErebusError::AmbiguousLocalTime(local)),
+            Disambiguate::Null => None,
+        },
+        LocalResolution::Gap(t) => match choice {
+            Disambiguate::Earliest => t.checked_mul(NANOS_PER_SECOND).map(|x| x - 1),
+            Disambiguate::Latest => t.checked_mul(NANOS_PER_SECOND),
+            Disambiguate::Raise => return Err(ErebusError::NonExistentLocalTime(local)),
+            Disambiguate::Null => None,
+        },
+    };
+    Ok(utc)
+}
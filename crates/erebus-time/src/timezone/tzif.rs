@@ -0,0 +1,179 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+// TZif layout (RFC 8536):
+//   header: "TZif" | version | 15 reserved | 6 x u32 BE counts
+//   data:   transition times | type indices | ttinfo entries
+//           | abbreviation chars | leap records | std/wall | ut/local
+// Version 2+ files repeat header + data with 64-bit times, then a
+// newline-enclosed POSIX TZ footer describing times after the last transition.
+
+/// Local time type (`ttinfo`) of a TZif file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LocalTimeType {
+    pub(crate) utoff: i32,
+    pub(crate) is_dst: bool,
+    pub(crate) abbr: String,
+}
+
+/// Decoded contents of a TZif file.
+#[derive(Debug, Clone)]
+pub(crate) struct TzifData {
+    pub(crate) transitions: Vec<i64>, // UTC seconds, ascending
+    pub(crate) type_idx: Vec<u8>,     // local time type after each transition
+    pub(crate) types: Vec<LocalTimeType>,
+    pub(crate) footer: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Counts {
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+struct Cursor<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+// === Impls ===
+
+impl<'a> Cursor<'a> {
+    #[inline]
+    fn take(&mut self, n: usize) -> ErrorResult<&'a [u8]> {
+        let end = self.pos.checked_add(n).ok_or(ErebusError::UnexpectedEof)?;
+        if end > self.buf.len() {
+            return Err(ErebusError::UnexpectedEof);
+        }
+        let out = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    #[inline]
+    fn u32_be(&mut self) -> ErrorResult<u32> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+}
+
+impl Counts {
+    /// Size of the data block for the given transition time width.
+    #[inline]
+    fn block_len(&self, time_size: usize) -> usize {
+        self.timecnt * time_size
+            + self.timecnt
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+fn read_header(c: &mut Cursor<'_>) -> ErrorResult<(u8, Counts)> {
+    if c.take(4)? != b"TZif" {
+        return Err(ErebusError::DecodeError("Missing TZif magic".into()));
+    }
+    let version = c.take(1)?[0];
+    c.take(15)?;
+
+    let counts = Counts {
+        isutcnt: c.u32_be()? as usize,
+        isstdcnt: c.u32_be()? as usize,
+        leapcnt: c.u32_be()? as usize,
+        timecnt: c.u32_be()? as usize,
+        typecnt: c.u32_be()? as usize,
+        charcnt: c.u32_be()? as usize,
+    };
+
+    if counts.typecnt == 0 {
+        return Err(ErebusError::DecodeError("TZif file has no local time types".into()));
+    }
+    Ok((version, counts))
+}
+
+fn read_block(c: &mut Cursor<'_>, counts: Counts, time_size: usize) -> ErrorResult<TzifData> {
+    let mut transitions = Vec::with_capacity(counts.timecnt);
+    for _ in 0..counts.timecnt {
+        let b = c.take(time_size)?;
+        let t = if time_size == 8 {
+            i64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]])
+        } else {
+            i32::from_be_bytes([b[0], b[1], b[2], b[3]]) as i64
+        };
+        transitions.push(t);
+    }
+
+    let type_idx = c.take(counts.timecnt)?.to_vec();
+    if type_idx.iter().any(|&i| i as usize >= counts.typecnt) {
+        return Err(ErebusError::DecodeError("Transition type index out of range".into()));
+    }
+    if transitions.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(ErebusError::DecodeError("Transition times are not ascending".into()));
+    }
+
+    let mut raw_types = Vec::with_capacity(counts.typecnt);
+    for _ in 0..counts.typecnt {
+        let b = c.take(6)?;
+        let utoff = i32::from_be_bytes([b[0], b[1], b[2], b[3]]);
+        raw_types.push((utoff, b[4] != 0, b[5] as usize));
+    }
+
+    let chars = c.take(counts.charcnt)?;
+    let types = raw_types
+        .into_iter()
+        .map(|(utoff, is_dst, idx)| {
+            let tail = chars.get(idx..).unwrap_or(&[]);
+            let end = tail.iter().position(|&b| b == 0).unwrap_or(tail.len());
+            LocalTimeType {
+                utoff,
+                is_dst,
+                abbr: String::from_utf8_lossy(&tail[..end]).into_owned(),
+            }
+        })
+        .collect();
+
+    // Leap second records and std/wall + ut/local indicators are not needed
+    c.take(counts.leapcnt * (time_size + 4) + counts.isstdcnt + counts.isutcnt)?;
+
+    Ok(TzifData { transitions, type_idx, types, footer: None })
+}
+
+/// Decodes a TZif (v1-v4) byte buffer.
+pub(crate) fn parse_tzif(bytes: &[u8]) -> ErrorResult<TzifData> {
+    let mut c = Cursor { buf: bytes, pos: 0 };
+    let (version, v1_counts) = read_header(&mut c)?;
+
+    if version == 0 {
+        return read_block(&mut c, v1_counts, 4);
+    }
+
+    // Skip the 32-bit block and read the 64-bit one
+    c.take(v1_counts.block_len(4))?;
+    let (_, counts) = read_header(&mut c)?;
+    let mut data = read_block(&mut c, counts, 8)?;
+
+    // Footer: "\n" <POSIX TZ string> "\n"
+    if c.take(1)? != b"\n" {
+        return Err(ErebusError::DecodeError("Malformed TZif footer".into()));
+    }
+    let rest = &bytes[c.pos..];
+    let end = rest
+        .iter()
+        .position(|&b| b == b'\n')
+        .ok_or_else(|| ErebusError::DecodeError("Unterminated TZif footer".into()))?;
+    let footer = std::str::from_utf8(&rest[..end])
+        .map_err(|_| ErebusError::DecodeError("TZif footer is not UTF-8".into()))?;
+
+    if !footer.is_empty() {
+        data.footer = Some(footer.to_string());
+    }
+    Ok(data)
+}
@@ -1,2 +1,5 @@
 mod date;
-// mod ;
\ No newline at end of file
+mod duration;
+mod schedule;
+mod timestamp;
+mod timezone;
\ No newline at end of file
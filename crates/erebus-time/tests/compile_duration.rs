@@ -0,0 +1 @@
+mod duration;
\ No newline at end of file
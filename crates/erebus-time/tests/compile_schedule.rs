@@ -0,0 +1 @@
+mod schedule;
\ No newline at end of file
@@ -0,0 +1 @@
+mod timestamp;
\ No newline at end of file
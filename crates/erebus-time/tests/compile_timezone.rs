@@ -0,0 +1 @@
+mod timezone;
\ No newline at end of file
@@ -0,0 +1,106 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn d(y: i32, m: u8, day: u8) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+#[test]
+fn test_date_add_days_and_weeks() {
+    let x = d(2024, 2, 28);
+    assert_eq!(x.add_days(1).ymd(), (2024, 2, 29));
+    assert_eq!(x.add_days(2).ymd(), (2024, 3, 1));
+    assert_eq!(x.add_days(-59).ymd(), (2023, 12, 31));
+    assert_eq!(x.add_weeks(2).ymd(), (2024, 3, 13));
+}
+
+#[test]
+fn test_date_add_months_clamp() {
+    let x = d(2024, 1, 31);
+    assert_eq!(x.add_months(1, MonthEndRule::Clamp).ymd(), (2024, 2, 29));
+    assert_eq!(x.add_months(3, MonthEndRule::Clamp).ymd(), (2024, 4, 30));
+    assert_eq!(x.add_months(-2, MonthEndRule::Clamp).ymd(), (2023, 11, 30));
+    assert_eq!(d(2024, 2, 29).add_months(1, MonthEndRule::Clamp).ymd(), (2024, 3, 29));
+}
+
+#[test]
+fn test_date_add_months_preserve_end_of_month() {
+    assert_eq!(d(2024, 2, 29).add_months(1, MonthEndRule::Preserve).ymd(), (2024, 3, 31));
+    assert_eq!(d(2023, 4, 30).add_months(-2, MonthEndRule::Preserve).ymd(), (2023, 2, 28));
+    // Not a month end: behaves like clamp
+    assert_eq!(d(2024, 2, 28).add_months(1, MonthEndRule::Preserve).ymd(), (2024, 3, 28));
+}
+
+#[test]
+fn test_date_add_years_leap_day() {
+    let x = d(2024, 2, 29);
+    assert_eq!(x.add_years(1, MonthEndRule::Clamp).ymd(), (2025, 2, 28));
+    assert_eq!(x.add_years(4, MonthEndRule::Clamp).ymd(), (2028, 2, 29));
+    assert_eq!(d(2023, 2, 28).add_years(1, MonthEndRule::Preserve).ymd(), (2024, 2, 29));
+}
+
+#[test]
+fn test_date_days_between() {
+    assert_eq!(d(2024, 1, 1).days_between(d(2024, 12, 31)), 365);
+    assert_eq!(d(2024, 3, 1).days_between(d(2024, 2, 1)), -29);
+}
+
+#[test]
+fn test_date_vector_add_months_with_nulls() {
+    let dv = DateVector::from_ymd_vectors(
+        &[2024, 2024, 2023],
+        &[1,    2,    12],
+        &[31,   30,   31],
+    ).unwrap();
+
+    let out = dv.add_months(1, MonthEndRule::Clamp);
+    assert_eq!(out.ymd(), vec![(2024, 2, 29), (0, 0, 0), (2024, 1, 31)]);
+    assert!(!out.is_valid_at(1).unwrap());
+}
+
+#[test]
+fn test_date_vector_add_days_weeks_years() {
+    let dv = DateVector::from_ymd_vectors(&[2024], &[2], &[29]).unwrap();
+    assert_eq!(dv.add_days(1).ymd(), vec![(2024, 3, 1)]);
+    assert_eq!(dv.add_weeks(-1).ymd(), vec![(2024, 2, 22)]);
+    assert_eq!(dv.add_years(1, MonthEndRule::Clamp).ymd(), vec![(2025, 2, 28)]);
+}
+
+#[test]
+fn test_date_vector_days_between() {
+    let a = DateVector::from_ymd_vectors(
+        &[2024, 2024, 2024],
+        &[1,    2,    3],
+        &[1,    30,   1],
+    ).unwrap();
+    let b = DateVector::from_ymd_vectors(
+        &[2024, 2024, 2023],
+        &[1,    3,    3],
+        &[31,   1,    1],
+    ).unwrap();
+
+    // 2024-02-30 is invalid, so that row is null rather than 0
+    let out = a.days_between(&b).unwrap();
+    assert_eq!(out.data, vec![30, 0, -366]);
+    assert_eq!(out.validity, bitvec![1, 0, 1]);
+
+    let same = a.days_between(&a).unwrap();
+    assert_eq!(same.validity, bitvec![1, 0, 1]);
+    assert_eq!(same.data, vec![0, 0, 0]);
+}
+
+#[test]
+fn test_date_vector_days_between_length_mismatch() {
+    let a = DateVector::from_ymd_vectors(&[2024], &[1], &[1]).unwrap();
+    let b = DateVector::from_ymd_vectors(&[2024, 2024], &[1, 1], &[1, 2]).unwrap();
+
+    match a.days_between(&b).unwrap_err() {
+        ErebusError::LengthMismatch { expected, found } => {
+            assert_eq!(expected, 1);
+            assert_eq!(found, 2);
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
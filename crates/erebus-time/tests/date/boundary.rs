@@ -0,0 +1,93 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn d(y: i32, m: u8, day: u8) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+#[test]
+fn test_date_month_boundaries() {
+    let x = d(2024, 2, 14);
+    assert_eq!(x.month_start().ymd(), (2024, 2, 1));
+    assert_eq!(x.month_end().ymd(), (2024, 2, 29));
+}
+
+#[test]
+fn test_date_quarter_boundaries() {
+    assert_eq!(d(2024, 5, 20).quarter_start().ymd(), (2024, 4, 1));
+    assert_eq!(d(2024, 5, 20).quarter_end().ymd(), (2024, 6, 30));
+    assert_eq!(d(2024, 12, 31).quarter_start().ymd(), (2024, 10, 1));
+    assert_eq!(d(2024, 1, 1).quarter_end().ymd(), (2024, 3, 31));
+}
+
+#[test]
+fn test_date_year_boundaries() {
+    let x = d(1969, 7, 20);
+    assert_eq!(x.year_start().ymd(), (1969, 1, 1));
+    assert_eq!(x.year_end().ymd(), (1969, 12, 31));
+}
+
+#[test]
+fn test_date_first_last_business_day_of_month_nyse() {
+    let cal = get_nyse_calendar().unwrap();
+
+    // 2024-09-01 is Sunday, 2024-09-02 is Labor Day
+    assert_eq!(d(2024, 9, 15).first_business_day_of_month(&cal).unwrap().ymd(), (2024, 9, 3));
+    // 2024-03-31 is Sunday, 2024-03-29 is Good Friday
+    assert_eq!(d(2024, 3, 5).last_business_day_of_month(&cal).unwrap().ymd(), (2024, 3, 28));
+}
+
+#[test]
+fn test_date_vector_boundaries_with_nulls() {
+    let dv = DateVector::from_ymd_vectors(
+        &[2024, 2023, 2023],
+        &[2,    2,    11],
+        &[10,   29,   5],
+    ).unwrap();
+
+    assert_eq!(dv.month_start().ymd(), vec![(2024, 2, 1), (0, 0, 0), (2023, 11, 1)]);
+    assert_eq!(dv.month_end().ymd(), vec![(2024, 2, 29), (0, 0, 0), (2023, 11, 30)]);
+    assert_eq!(dv.quarter_end().ymd(), vec![(2024, 3, 31), (0, 0, 0), (2023, 12, 31)]);
+    assert_eq!(dv.year_start().ymd(), vec![(2024, 1, 1), (0, 0, 0), (2023, 1, 1)]);
+}
+
+#[test]
+fn test_date_vector_business_days_of_month_nyse() {
+    let cal = get_nyse_calendar().unwrap();
+
+    let dv = DateVector::from_ymd_vectors(
+        &[2024, 2024],
+        &[9,    3],
+        &[15,   5],
+    ).unwrap();
+
+    assert_eq!(
+        dv.first_business_day_of_month(&cal).ymd(),
+        vec![(2024, 9, 3), (2024, 3, 1)]
+    );
+    assert_eq!(
+        dv.last_business_day_of_month(&cal).ymd(),
+        vec![(2024, 9, 30), (2024, 3, 28)]
+    );
+}
+
+struct JuneOff;
+
+impl Calendar for JuneOff {
+    fn is_weekend(&self, date: Date) -> bool { date.is_weekend(WeekendRule::SaturdaySunday) }
+    fn is_holiday(&self, date: Date) -> bool { (date.ymd().0, date.ymd().1) == (2024, 6) }
+}
+
+#[test]
+fn test_business_day_of_month_without_business_days() {
+    assert_eq!(d(2024, 6, 15).first_business_day_of_month(&JuneOff), None);
+    assert_eq!(d(2024, 6, 15).last_business_day_of_month(&JuneOff), None);
+    assert_eq!(d(2024, 7, 15).first_business_day_of_month(&JuneOff), Some(d(2024, 7, 1)));
+    assert_eq!(d(2024, 5, 15).last_business_day_of_month(&JuneOff), Some(d(2024, 5, 31)));
+
+    let dv = DateVector::from_ymd_vectors(&[2024, 2024], &[6, 7], &[15, 15]).unwrap();
+    assert_eq!(dv.first_business_day_of_month(&JuneOff).get(0), None);
+    assert_eq!(dv.last_business_day_of_month(&JuneOff).get(1), Some(d(2024, 7, 31)));
+}
@@ -0,0 +1,117 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Helpers ===
+
+fn ymd(dv: &DateVector) -> Vec<Option<(i32, u8, u8)>> {
+    let text = dv.format("%Y-%m-%d").unwrap();
+    text.data
+        .iter()
+        .zip(text.validity.iter())
+        .map(|(s, v)| {
+            if *v {
+                Some(Date::from_ymd_str(s, "%Y-%m-%d").unwrap().ymd())
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+// === Tests ===
+
+#[test]
+fn test_parse_auto_iso() {
+    let out = DateVector::parse_auto(&["2024-03-05", "2024-12-31", ""]).unwrap();
+    assert_eq!(out.format, "%Y-%m-%d");
+    assert_eq!(ymd(&out.dates), vec![Some((2024, 3, 5)), Some((2024, 12, 31)), None]);
+    assert!(out.report.is_clean());
+}
+
+#[test]
+fn test_parse_auto_compact_and_names() {
+    let out = DateVector::parse_auto(&["20240305", "20231130"]).unwrap();
+    assert_eq!(out.format, "%Y%m%d");
+    assert_eq!(ymd(&out.dates)[0], Some((2024, 3, 5)));
+
+    let out = DateVector::parse_auto(&["5-Mar-2024", "15-Nov-2023"]).unwrap();
+    assert_eq!(out.format, "%-d-%b-%Y");
+    assert_eq!(ymd(&out.dates), vec![Some((2024, 3, 5)), Some((2023, 11, 15))]);
+
+    let out = DateVector::parse_auto(&["March 5, 2024", "December 25, 2023"]).unwrap();
+    assert_eq!(out.format, "%B %-d, %Y");
+    assert_eq!(ymd(&out.dates), vec![Some((2024, 3, 5)), Some((2023, 12, 25))]);
+}
+
+#[test]
+fn test_parse_auto_month_first_by_default() {
+    let out = DateVector::parse_auto(&["03/05/2024", "04/06/2024"]).unwrap();
+    assert_eq!(out.format, "%-m/%-d/%Y");
+    assert_eq!(ymd(&out.dates)[0], Some((2024, 3, 5)));
+
+    let opts = ParseAutoOptions { day_first: true, ..Default::default() };
+    let out = DateVector::parse_auto_with(&["03/05/2024", "04/06/2024"], opts).unwrap();
+    assert_eq!(out.format, "%-d/%-m/%Y");
+    assert_eq!(ymd(&out.dates)[0], Some((2024, 5, 3)));
+}
+
+#[test]
+fn test_parse_auto_day_first_detected() {
+    // 25/12 only fits day-first, so the ambiguous row follows suit
+    let out = DateVector::parse_auto(&["03/05/2024", "25/12/2024"]).unwrap();
+    assert_eq!(out.format, "%-d/%-m/%Y");
+    assert_eq!(ymd(&out.dates), vec![Some((2024, 5, 3)), Some((2024, 12, 25))]);
+    assert!(out.report.is_clean());
+}
+
+#[test]
+fn test_parse_auto_mixed_column() {
+    let values = ["2024-03-05", "03/05/2024", "5-Mar-2024", "20240305", "March 5, 2024", "garbage"];
+    let out = DateVector::parse_auto(&values).unwrap();
+
+    let got = ymd(&out.dates);
+    for v in &got[..5] {
+        assert_eq!(*v, Some((2024, 3, 5)));
+    }
+    assert_eq!(got[5], None);
+    assert_eq!(out.report.failed, vec![5]);
+}
+
+#[test]
+fn test_parse_auto_nothing_matches() {
+    assert!(DateVector::parse_auto(&["foo", "bar"]).is_err());
+}
+
+#[test]
+fn test_parse_any_in_order() {
+    let (dv, report) = DateVector::parse_any(
+        &["2024-01-02", " 02/01/2024 ", "2024-13-01", "", "x"],
+        &["%Y-%m-%d", "%d/%m/%Y"],
+    ).unwrap();
+
+    assert_eq!(
+        ymd(&dv),
+        vec![Some((2024, 1, 2)), Some((2024, 1, 2)), None, None, None],
+    );
+    assert_eq!(report.failed, vec![2, 4]);
+}
+
+#[test]
+fn test_parse_any_requires_full_match() {
+    let (_, report) = DateVector::parse_any(&["2024-01-02xyz", "03/05/2024"], &["%Y-%m-%d", "%m/%d/%y"]).unwrap();
+    assert_eq!(report.failed, vec![0, 1]);
+}
+
+#[test]
+fn test_parse_any_invalid_format() {
+    assert!(DateVector::parse_any(&["2024"], &["%Q"]).is_err());
+}
+
+#[test]
+fn test_unpadded_tokens_round_trip() {
+    let d = Date::from_ymd(2024, 3, 5).unwrap();
+    assert_eq!(d.format("%-m/%-d/%Y").unwrap(), "3/5/2024");
+    assert_eq!(Date::from_ymd_str("3/5/2024", "%-m/%-d/%Y").unwrap(), d);
+    assert_eq!(Date::from_ymd_str("03/05/2024", "%-m/%-d/%Y").unwrap(), d);
+    assert_eq!(Date::from_ymd_str("Tuesday, March 5 2024", "%A, %B %-d %Y").unwrap(), d);
+}
@@ -85,4 +85,59 @@ fn test_date_vector_ymd() {
             (1999, 12, 31),
         ]
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_date_quarter_and_day_of_year() {
+    let d = Date::from_ymd(2024, 12, 31).unwrap();
+    assert_eq!(d.quarter(), 4);
+    assert_eq!(d.day_of_year(), 366);
+    assert_eq!(d.days_in_year(), 366);
+
+    let d = Date::from_ymd(2023, 4, 1).unwrap();
+    assert_eq!(d.quarter(), 2);
+    assert_eq!(d.day_of_year(), 91);
+    assert_eq!(d.days_in_year(), 365);
+}
+
+#[test]
+fn test_date_week_of_year() {
+    // 2024-01-01 is a Monday
+    let sat = Date::from_ymd(2024, 1, 6).unwrap();
+    let sun = Date::from_ymd(2024, 1, 7).unwrap();
+    assert_eq!(sat.week_of_year(WeekConvention::US), 1);
+    assert_eq!(sun.week_of_year(WeekConvention::US), 2);
+    assert_eq!(sun.week_of_year(WeekConvention::ISO), 1);
+    assert_eq!(sun.week_of_year(WeekConvention::Custom { first_day: 1 }), 1);
+    assert_eq!(Date::from_ymd(2024, 1, 8).unwrap().week_of_year(WeekConvention::ISO), 2);
+
+    // 2021-01-03 belongs to ISO week 53 of 2020
+    let d = Date::from_ymd(2021, 1, 3).unwrap();
+    assert_eq!(d.iso_year_week(), (2020, 53));
+    assert_eq!(d.week_of_year(WeekConvention::US), 2);
+}
+
+#[test]
+fn test_date_is_month_and_quarter_end() {
+    assert!(Date::from_ymd(2024, 2, 29).unwrap().is_month_end());
+    assert!(!Date::from_ymd(2024, 2, 28).unwrap().is_month_end());
+    assert!(Date::from_ymd(2024, 6, 30).unwrap().is_quarter_end());
+    assert!(!Date::from_ymd(2024, 5, 31).unwrap().is_quarter_end());
+}
+
+#[test]
+fn test_date_vector_calendar_fields() {
+    let v = DateVector::from_ymd_vectors(
+        &[2024, 2023, 2020],
+        &[3,    2,    12],
+        &[31,   29,   31], // middle invalid
+    ).unwrap();
+
+    assert_eq!(v.quarters(), vec![1, 0, 4]);
+    assert_eq!(v.days_of_year(), vec![91, 0, 366]);
+    assert_eq!(v.days_in_year(), vec![366, 0, 366]);
+    assert_eq!(v.week_of_year(WeekConvention::ISO), vec![13, 0, 53]);
+    assert_eq!(v.iso_year_week(), vec![(2024, 13), (0, 0), (2020, 53)]);
+    assert_eq!(v.is_month_end(), vec![true, false, true]);
+    assert_eq!(v.is_quarter_end(), vec![true, false, true]);
+}
@@ -0,0 +1,81 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Helpers ===
+
+fn date(y: i32, m: u8, d: u8) -> Date {
+    Date::from_ymd(y, m, d).unwrap()
+}
+
+fn fd(year: i32, quarter: u8, period: u8, week: u8) -> FiscalDate {
+    FiscalDate { year, quarter, period, week }
+}
+
+// === Tests ===
+
+#[test]
+fn test_fiscal_months_october_start() {
+    let cal = FiscalCalendar::months(10).unwrap();
+    assert_eq!(date(2023, 10, 1).fiscal(&cal), fd(2024, 1, 1, 1));
+    assert_eq!(date(2024, 1, 15).fiscal(&cal), fd(2024, 2, 4, 16));
+    assert_eq!(date(2024, 9, 30).fiscal(&cal), fd(2024, 4, 12, 53));
+    assert_eq!(cal.year_bounds(2024), (date(2023, 10, 1), date(2024, 9, 30)));
+}
+
+#[test]
+fn test_fiscal_months_calendar_year() {
+    let cal = FiscalCalendar::months(1).unwrap();
+    assert_eq!(date(2024, 7, 1).fiscal(&cal), fd(2024, 3, 7, 27));
+}
+
+#[test]
+fn test_fiscal_445_last_saturday_of_september() {
+    let cal = FiscalCalendar::weeks(10, RetailPattern::W445, 6, YearEndRule::LastWeekday).unwrap();
+
+    assert_eq!(cal.year_bounds(2023), (date(2022, 9, 25), date(2023, 9, 30)));
+    assert_eq!(cal.weeks_in_year(2023), 53);
+    assert_eq!(cal.weeks_in_year(2024), 52);
+
+    assert_eq!(date(2022, 9, 25).fiscal(&cal), fd(2023, 1, 1, 1));
+    assert_eq!(date(2022, 10, 22).fiscal(&cal), fd(2023, 1, 1, 4));
+    assert_eq!(date(2022, 10, 23).fiscal(&cal), fd(2023, 1, 2, 5));
+    assert_eq!(date(2022, 11, 20).fiscal(&cal), fd(2023, 1, 3, 9));
+    assert_eq!(date(2022, 12, 24).fiscal(&cal), fd(2023, 1, 3, 13));
+    assert_eq!(date(2022, 12, 25).fiscal(&cal), fd(2023, 2, 4, 14));
+    // 53rd week is folded into the last period
+    assert_eq!(date(2023, 9, 30).fiscal(&cal), fd(2023, 4, 12, 53));
+    assert_eq!(date(2023, 10, 1).fiscal(&cal), fd(2024, 1, 1, 1));
+}
+
+#[test]
+fn test_fiscal_patterns_split_quarters() {
+    let start = date(2022, 9, 25);
+    let periods = |pattern| {
+        let cal = FiscalCalendar::weeks(10, pattern, 6, YearEndRule::LastWeekday).unwrap();
+        (0..13).map(|w| start.add_days(7 * w).fiscal(&cal).period).collect::<Vec<_>>()
+    };
+    assert_eq!(periods(RetailPattern::W445), vec![1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 3]);
+    assert_eq!(periods(RetailPattern::W454), vec![1, 1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 3, 3]);
+    assert_eq!(periods(RetailPattern::W544), vec![1, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3]);
+}
+
+#[test]
+fn test_fiscal_nearest_saturday_to_january_end() {
+    // Retail year ending on the Saturday nearest Jan 31
+    let cal = FiscalCalendar::weeks(2, RetailPattern::W454, 6, YearEndRule::NearestWeekday).unwrap();
+
+    assert_eq!(cal.year_bounds(2024), (date(2023, 1, 29), date(2024, 2, 3)));
+    assert_eq!(cal.weeks_in_year(2024), 53);
+    assert_eq!(date(2024, 2, 1).fiscal(&cal).year, 2024);
+    assert_eq!(date(2024, 2, 4).fiscal(&cal), fd(2025, 1, 1, 1));
+}
+
+#[test]
+fn test_fiscal_vector_and_validation() {
+    let cal = FiscalCalendar::months(4).unwrap();
+    let v = DateVector::from_ymd_vectors(&[2024, 2023], &[4, 2], &[1, 30]).unwrap();
+    assert_eq!(v.fiscal(&cal), vec![fd(2025, 1, 1, 1), FiscalDate::default()]);
+
+    assert!(FiscalCalendar::months(13).is_err());
+    assert!(FiscalCalendar::weeks(1, RetailPattern::W445, 0, YearEndRule::LastWeekday).is_err());
+}
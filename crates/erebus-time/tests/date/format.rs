@@ -0,0 +1,110 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+#[test]
+fn test_date_format_basic_tokens() {
+    let d = Date::from_ymd(2024, 3, 5).unwrap();
+    assert_eq!(d.format("%Y-%m-%d").unwrap(), "2024-03-05");
+    assert_eq!(d.format("%d/%m/%y").unwrap(), "05/03/24");
+    assert_eq!(d.format("%b %e, %Y").unwrap(), "Mar  5, 2024");
+    assert_eq!(d.format("%A %d %B %Y").unwrap(), "Tuesday 05 March 2024");
+    assert_eq!(d.format("%a %j %%").unwrap(), "Tue 065 %");
+}
+
+#[test]
+fn test_date_format_week_numbers() {
+    // 2021-01-01 is a Friday: week 0 for %U/%W, ISO week 53 of 2020
+    let d = Date::from_ymd(2021, 1, 1).unwrap();
+    assert_eq!(d.format("%U %W %V").unwrap(), "00 00 53");
+
+    // 2024-12-30 is a Monday in ISO week 1 of 2025
+    let d = Date::from_ymd(2024, 12, 30).unwrap();
+    assert_eq!(d.format("%U %W %V").unwrap(), "52 53 01");
+}
+
+#[test]
+fn test_date_format_invalid_token() {
+    let d = Date::from_ymd(2024, 3, 5).unwrap();
+    assert!(d.format("%Q").is_err());
+    assert!(d.format("%Y%").is_err());
+}
+
+#[test]
+fn test_date_vector_format_nulls() {
+    let dv = DateVector::from_ymd_str_vectors(&["2024-01-31", "bad", "1999-12-01"], "%Y-%m-%d").unwrap();
+    let out = dv.format("%d %b %Y").unwrap();
+    assert_eq!(out.validity, bitvec![1, 0, 1]);
+    assert_eq!(out.data[0], "31 Jan 2024");
+    assert_eq!(out.data[2], "01 Dec 1999");
+}
+
+#[test]
+fn test_date_format_round_trip() {
+    let fmts = [
+        "%Y-%m-%d",
+        "%d-%b-%Y",
+        "%B %d, %Y",
+        "%A, %d %B %Y",
+        "%a %e %b %Y",
+        "%Y.%j",
+        "%G-W%V-%A",
+        "%Y %U %A",
+        "%Y %W %a",
+    ];
+    let dv = DateVector::range(
+        Date::from_ymd(2019, 12, 20).unwrap(),
+        Date::from_ymd(2021, 1, 10).unwrap(),
+        DateFreq::Daily,
+        RangeBound::Inclusive,
+    ).unwrap();
+
+    for fmt in fmts {
+        let text = dv.format(fmt).unwrap();
+        let values: Vec<&str> = text.data.iter().map(|s| s.as_str()).collect();
+        let back = DateVector::from_ymd_str_vectors(&values, fmt).unwrap();
+        assert_eq!(back.format("%Y-%m-%d").unwrap(), dv.format("%Y-%m-%d").unwrap(), "format {}", fmt);
+    }
+}
+
+#[test]
+fn test_date_parse_day_of_year() {
+    assert_eq!(Date::from_ymd_str("2024-366", "%Y-%j").unwrap().ymd(), (2024, 12, 31));
+    assert!(Date::from_ymd_str("2023-366", "%Y-%j").is_err());
+    assert!(Date::from_ymd_str("2023-000", "%Y-%j").is_err());
+}
+
+#[test]
+fn test_date_parse_weekday_must_match() {
+    assert!(Date::from_ymd_str("Tue 2024-03-05", "%a %Y-%m-%d").is_ok());
+    assert!(Date::from_ymd_str("Wed 2024-03-05", "%a %Y-%m-%d").is_err());
+    assert!(Date::from_ymd_str("Wednesday 2024-03-05", "%A %Y-%m-%d").is_err());
+}
+
+#[test]
+fn test_date_parse_iso_week() {
+    // ISO week 1 of 2025 starts on 2024-12-30
+    assert_eq!(Date::from_ymd_str("2025-W01-Mon", "%G-W%V-%a").unwrap().ymd(), (2024, 12, 30));
+    assert!(Date::from_ymd_str("2025-W53-Mon", "%G-W%V-%a").is_err());
+    assert_eq!(Date::from_ymd_str("2020-W53-Sun", "%G-W%V-%a").unwrap().ymd(), (2021, 1, 3));
+
+    // With %Y the year is the calendar year of the date
+    assert_eq!(Date::from_ymd_str("2024-W01-Mon", "%Y-W%V-%a").unwrap().ymd(), (2024, 1, 1));
+    assert_eq!(Date::from_ymd_str("2021-W53-Sun", "%Y-W%V-%a").unwrap().ymd(), (2021, 1, 3));
+    assert_eq!(Date::from_ymd(2021, 1, 3).unwrap().format("%G %Y %V").unwrap(), "2020 2021 53");
+}
+
+#[test]
+fn test_date_parse_space_padded_day() {
+    assert_eq!(Date::from_ymd_str("Mar  5 2024", "%b %e %Y").unwrap().ymd(), (2024, 3, 5));
+    assert_eq!(Date::from_ymd_str("Mar 05 2024", "%b %e %Y").unwrap().ymd(), (2024, 3, 5));
+}
+
+#[test]
+fn test_date_parse_format_errors() {
+    assert!(DateVector::from_ymd_str_vectors(&[], "%Y-%V").is_err());
+    assert!(DateVector::from_ymd_str_vectors(&[], "%Y-%m-%j").is_err());
+    assert!(DateVector::from_ymd_str_vectors(&[], "%a %A %Y-%m-%d").is_err());
+    assert!(DateVector::from_ymd_str_vectors(&[], "%G-%m-%d").is_err());
+}
@@ -0,0 +1,84 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn dv() -> DateVector {
+    // 2024-02-30 is invalid -> null
+    DateVector::from_ymd_vectors(&[2024, 1969, 2024, 2024], &[3, 12, 2, 3], &[1, 31, 30, 1]).unwrap()
+}
+
+#[test]
+fn test_date_vector_to_vector_data_roundtrip() {
+    let v = dv();
+    let vd = v.to_vector_data();
+    assert_eq!(vd.validity, bitvec![1, 1, 0, 1]);
+    assert_eq!(vd.data[1], -1);
+    assert_eq!(vd.data[0], Date::from_ymd(2024, 3, 1).unwrap().days() as i64);
+
+    // VectorData reducers now apply to days since epoch
+    assert_eq!(vd.min(), Some(-1));
+    assert_eq!(vd.n_unique(), 2);
+
+    let back = DateVector::from_vector_data(&vd).unwrap();
+    assert_eq!(back.format("%Y-%m-%d").unwrap().data, v.format("%Y-%m-%d").unwrap().data);
+    assert_eq!(back.get(2), None);
+
+    let via_traits: VectorData<i64> = (&v).into();
+    assert_eq!(via_traits.data, vd.data);
+    assert!(DateVector::try_from(&via_traits).is_ok());
+}
+
+#[test]
+fn test_from_vector_data_out_of_range() {
+    let mut vd = VectorData { data: vec![0i64, i64::MAX], validity: bitvec![1, 1] };
+    assert!(DateVector::from_vector_data(&vd).is_err());
+
+    // Null rows are not checked
+    vd.validity.set(1, false);
+    let v = DateVector::from_vector_data(&vd).unwrap();
+    assert_eq!(v.get(0).unwrap().ymd(), (1970, 1, 1));
+    assert!(v.get(1).is_none());
+}
+
+#[test]
+fn test_to_text_and_to_date() {
+    let text = dv().to_text("%d/%m/%Y").unwrap();
+    assert_eq!(text.data[0], "01/03/2024");
+    assert_eq!(text.validity, bitvec![1, 1, 0, 1]);
+
+    let back = text.to_date("%d/%m/%Y").unwrap();
+    assert_eq!(back.get(1).unwrap().ymd(), (1969, 12, 31));
+    assert!(back.get(2).is_none());
+}
+
+#[test]
+fn test_to_date_respects_input_nulls() {
+    let mut s = VectorData {
+        data: vec!["2024-01-05".to_string(), "2024-01-06".to_string(), "nope".to_string()],
+        validity: bitvec![1, 1, 1],
+    };
+    s.validity.set(1, false);
+
+    let d = s.to_date("%Y-%m-%d").unwrap();
+    assert_eq!(d.get(0).unwrap().ymd(), (2024, 1, 5));
+    assert!(d.get(1).is_none());
+    assert!(d.get(2).is_none());
+}
+
+#[test]
+fn test_date_vector_view() {
+    let v = dv();
+    let view = v.view();
+    assert_eq!(view.len(), 4);
+    assert!(!view.is_valid_at(2));
+    assert_eq!(view.get(1).unwrap().ymd(), (1969, 12, 31));
+    assert_eq!(view.iter_valid().count(), 3);
+
+    let part = v.slice_view(1, 10);
+    assert_eq!(part.len(), 3);
+    assert_eq!(part.get(0).unwrap().ymd(), (1969, 12, 31));
+    assert_eq!(part.to_owned().len(), 3);
+
+    assert!(v.slice_view(3, 1).is_empty());
+}
@@ -0,0 +1,96 @@
+// === Imports ===
+use erebus_time::prelude::*;
+use std::io::{Cursor, Seek, SeekFrom};
+
+// === Tests ===
+
+fn d(y: i32, m: u8, day: u8) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+fn rewind(writer: &mut ErebusWriter<Cursor<Vec<u8>>>) -> ErebusReader<Cursor<Vec<u8>>> {
+    let mut cursor = writer.inner_mut().clone();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    ErebusReader::new(cursor)
+}
+
+#[test]
+fn test_date_vector_write_read_roundtrip() {
+    let v = DateVector::from_ymd_vectors(&[2024, 1969, 2024], &[2, 7, 2], &[29, 20, 30]).unwrap();
+
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new())).with_compression(CompressionType::Zstd);
+    v.write_to(&mut writer).unwrap();
+    let out = DateVector::read_from(&mut rewind(&mut writer)).unwrap();
+
+    assert_eq!(out.len(), 3);
+    assert_eq!(out.get(0).unwrap().ymd(), (2024, 2, 29));
+    assert_eq!(out.get(1).unwrap().ymd(), (1969, 7, 20));
+    assert!(out.get(2).is_none());
+}
+
+#[test]
+fn test_date_vector_file_roundtrip() {
+    let cal = get_nyse_calendar().unwrap();
+    let v = DateVector::range(d(2000, 1, 1), d(2024, 12, 31), DateFreq::BusinessDaily(&cal), RangeBound::Inclusive)
+        .unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nyse_days.erebus");
+    v.to_file(&path, CompressionType::Zstd).unwrap();
+
+    // Sorted business days compress to well under a byte per row
+    assert!((std::fs::metadata(&path).unwrap().len() as usize) < v.len() / 4);
+
+    let out = DateVector::from_file(&path).unwrap();
+    assert_eq!(out.format("%Y-%m-%d").unwrap().data, v.format("%Y-%m-%d").unwrap().data);
+}
+
+#[test]
+fn test_holiday_set_roundtrip() {
+    let holidays = HolidaySet::new(vec![d(2024, 12, 25).days(), d(2024, 1, 1).days()]);
+
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    holidays.write_to(&mut writer).unwrap();
+    let out = HolidaySet::read_from(&mut rewind(&mut writer)).unwrap();
+
+    assert_eq!(out.days(), holidays.days());
+    assert!(out.is_holiday(d(2024, 12, 25)));
+}
+
+#[test]
+fn test_market_calendar_file_roundtrip() {
+    let nyse = get_nyse_calendar().unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("nyse.erebus");
+    nyse.to_file(&path, CompressionType::Lz4).unwrap();
+    let out = MarketCalendar::from_file(&path).unwrap();
+
+    assert_eq!(out.weekend(), WeekendRule::SaturdaySunday);
+    assert_eq!(out.holidays().days(), nyse.holidays().days());
+    // 2024-07-04 Independence Day
+    assert!(!out.is_business_day(d(2024, 7, 4)));
+}
+
+#[test]
+fn test_weekend_rule_mask_roundtrip() {
+    for rule in [
+        WeekendRule::SaturdaySunday,
+        WeekendRule::FridaySaturday,
+        WeekendRule::Custom { fri: true, sat: false, sun: false },
+        WeekendRule::Custom { fri: false, sat: false, sun: false },
+    ] {
+        assert_eq!(WeekendRule::from_mask(rule.to_mask()).unwrap(), rule);
+    }
+    assert_eq!(WeekendRule::SaturdaySunday.to_mask(), 0b0110_0000);
+    // Monday cannot be a weekend day
+    assert!(WeekendRule::from_mask(0b0000_0001).is_err());
+}
+
+#[test]
+fn test_calendar_reader_rejects_date_vector() {
+    let v = DateVector::from_ymd_vectors(&[2024], &[1], &[1]).unwrap();
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new()));
+    v.write_to(&mut writer).unwrap();
+    assert!(MarketCalendar::read_from(&mut rewind(&mut writer)).is_err());
+}
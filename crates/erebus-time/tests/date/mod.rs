@@ -1,6 +1,19 @@
+mod arithmetic;
+mod boundary;
 mod date;
+mod detect;
 mod extract;
+mod fiscal;
+mod format;
 mod indexing;
+mod interop;
+mod io;
+mod range;
+mod resample;
+mod session;
+mod serial;
+mod set;
+mod sort;
 mod validity;
 mod weekday;
 mod weekend;
\ No newline at end of file
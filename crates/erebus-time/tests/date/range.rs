@@ -0,0 +1,181 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn d(y: i32, m: u8, day: u8) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+#[test]
+fn test_date_range_daily_inclusive_exclusive() {
+    let inc = DateVector::range(d(2024, 2, 27), d(2024, 3, 1), DateFreq::Daily, RangeBound::Inclusive).unwrap();
+    assert_eq!(
+        inc.ymd(),
+        vec![(2024, 2, 27), (2024, 2, 28), (2024, 2, 29), (2024, 3, 1)]
+    );
+
+    let exc = DateVector::range(d(2024, 2, 27), d(2024, 3, 1), DateFreq::Daily, RangeBound::Exclusive).unwrap();
+    assert_eq!(exc.len(), 3);
+}
+
+#[test]
+fn test_date_range_empty_when_start_after_end() {
+    let out = DateVector::range(d(2024, 3, 1), d(2024, 2, 1), DateFreq::Daily, RangeBound::Inclusive).unwrap();
+    assert!(out.is_empty());
+
+    let out = DateVector::range(d(2024, 3, 1), d(2024, 3, 1), DateFreq::Daily, RangeBound::Exclusive).unwrap();
+    assert!(out.is_empty());
+}
+
+#[test]
+fn test_date_range_business_daily_nyse() {
+    let cal = get_nyse_calendar().unwrap();
+
+    // 2024-03-29 Good Friday, 2024-03-30/31 weekend
+    let out = DateVector::range(
+        d(2024, 3, 27), d(2024, 4, 2),
+        DateFreq::BusinessDaily(&cal),
+        RangeBound::Inclusive,
+    ).unwrap();
+
+    assert_eq!(
+        out.ymd(),
+        vec![(2024, 3, 27), (2024, 3, 28), (2024, 4, 1), (2024, 4, 2)]
+    );
+}
+
+#[test]
+fn test_date_range_business_daily_matches_is_business_day() {
+    let cal = get_nyse_calendar().unwrap();
+
+    let all = DateVector::range(d(2020, 1, 1), d(2024, 12, 31), DateFreq::Daily, RangeBound::Inclusive).unwrap();
+    let bd = DateVector::range(
+        d(2020, 1, 1), d(2024, 12, 31),
+        DateFreq::BusinessDaily(&cal),
+        RangeBound::Inclusive,
+    ).unwrap();
+
+    assert_eq!(bd.len(), all.count_business_days(&cal));
+    assert_eq!(bd.count_business_days(&cal), bd.len());
+}
+
+#[test]
+fn test_date_range_weekly_on_weekday() {
+    // 2024-03-01 is Friday; first Monday is 2024-03-04
+    let out = DateVector::range(
+        d(2024, 3, 1), d(2024, 3, 25),
+        DateFreq::Weekly { weekday: 1 },
+        RangeBound::Inclusive,
+    ).unwrap();
+
+    assert_eq!(
+        out.ymd(),
+        vec![(2024, 3, 4), (2024, 3, 11), (2024, 3, 18), (2024, 3, 25)]
+    );
+}
+
+#[test]
+fn test_date_range_month_start_and_end() {
+    let ms = DateVector::range(d(2024, 1, 1), d(2024, 4, 15), DateFreq::MonthStart, RangeBound::Inclusive).unwrap();
+    assert_eq!(
+        ms.ymd(),
+        vec![(2024, 1, 1), (2024, 2, 1), (2024, 3, 1), (2024, 4, 1)]
+    );
+
+    let me = DateVector::range(d(2024, 1, 15), d(2024, 4, 30), DateFreq::MonthEnd, RangeBound::Exclusive).unwrap();
+    assert_eq!(
+        me.ymd(),
+        vec![(2024, 1, 31), (2024, 2, 29), (2024, 3, 31)]
+    );
+}
+
+#[test]
+fn test_date_range_quarter_and_year_end() {
+    let qe = DateVector::range(d(2023, 11, 1), d(2024, 12, 31), DateFreq::QuarterEnd, RangeBound::Inclusive).unwrap();
+    assert_eq!(
+        qe.ymd(),
+        vec![(2023, 12, 31), (2024, 3, 31), (2024, 6, 30), (2024, 9, 30), (2024, 12, 31)]
+    );
+
+    let ye = DateVector::range(d(2021, 12, 31), d(2024, 6, 1), DateFreq::YearEnd, RangeBound::Inclusive).unwrap();
+    assert_eq!(ye.ymd(), vec![(2021, 12, 31), (2022, 12, 31), (2023, 12, 31)]);
+}
+
+#[test]
+fn test_date_range_nth_weekday_and_imm() {
+    // Third Friday (options expiry)
+    let tf = DateVector::range(
+        d(2024, 1, 20), d(2024, 4, 30),
+        DateFreq::NthWeekday { weekday: 5, n: 3 },
+        RangeBound::Inclusive,
+    ).unwrap();
+    assert_eq!(tf.ymd(), vec![(2024, 2, 16), (2024, 3, 15), (2024, 4, 19)]);
+
+    let imm = DateVector::range(d(2024, 3, 1), d(2024, 6, 30), DateFreq::Imm, RangeBound::Inclusive).unwrap();
+    assert_eq!(
+        imm.ymd(),
+        vec![(2024, 3, 20), (2024, 4, 17), (2024, 5, 15), (2024, 6, 19)]
+    );
+}
+
+#[test]
+fn test_date_range_fifth_weekday_skips_months() {
+    // Fifth Friday exists in Mar 2024 and May 2024 only (Mar..Jun)
+    let out = DateVector::range(
+        d(2024, 3, 1), d(2024, 6, 30),
+        DateFreq::NthWeekday { weekday: 5, n: 5 },
+        RangeBound::Inclusive,
+    ).unwrap();
+    assert_eq!(out.ymd(), vec![(2024, 3, 29), (2024, 5, 31)]);
+}
+
+#[test]
+fn test_date_range_periods() {
+    let me = DateVector::range_periods(d(2024, 1, 31), 3, DateFreq::MonthEnd).unwrap();
+    assert_eq!(me.ymd(), vec![(2024, 1, 31), (2024, 2, 29), (2024, 3, 31)]);
+
+    let none = DateVector::range_periods(d(2024, 1, 31), 0, DateFreq::Daily).unwrap();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_date_range_periods_business_daily() {
+    let cal = get_nyse_calendar().unwrap();
+
+    let out = DateVector::range_periods(d(2024, 3, 28), 3, DateFreq::BusinessDaily(&cal)).unwrap();
+    assert_eq!(out.ymd(), vec![(2024, 3, 28), (2024, 4, 1), (2024, 4, 2)]);
+
+    let long = DateVector::range_periods(d(2024, 1, 1), 252, DateFreq::BusinessDaily(&cal)).unwrap();
+    assert_eq!(long.len(), 252);
+    assert_eq!(long.count_business_days(&cal), 252);
+}
+
+#[test]
+fn test_date_range_invalid_freq() {
+    let err = DateVector::range(
+        d(2024, 1, 1), d(2024, 2, 1),
+        DateFreq::Weekly { weekday: 8 },
+        RangeBound::Inclusive,
+    ).unwrap_err();
+    assert!(matches!(err, ErebusError::InvalidOperation(_)));
+
+    let err = DateVector::range_periods(d(2024, 1, 1), 2, DateFreq::NthWeekday { weekday: 1, n: 6 }).unwrap_err();
+    assert!(matches!(err, ErebusError::InvalidOperation(_)));
+}
+
+struct NoBusinessDays;
+
+impl Calendar for NoBusinessDays {
+    fn is_weekend(&self, _date: Date) -> bool { true }
+    fn is_holiday(&self, _date: Date) -> bool { false }
+}
+
+#[test]
+fn test_date_range_periods_errors_without_business_days() {
+    let res = DateVector::range_periods(d(2024, 1, 1), 5, DateFreq::BusinessDaily(&NoBusinessDays));
+    assert!(res.is_err());
+
+    assert_eq!(format!("{:?}", DateFreq::BusinessDaily(&NoBusinessDays)), "BusinessDaily(..)");
+    assert_eq!(format!("{:?}", DateFreq::Weekly { weekday: 5 }), "Weekly { weekday: 5 }");
+}
@@ -0,0 +1,111 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn d(y: i32, m: u8, day: u8) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+fn dv(ymd: &[(i32, u8, u8)]) -> DateVector {
+    let y: Vec<i32> = ymd.iter().map(|t| t.0).collect();
+    let m: Vec<u8> = ymd.iter().map(|t| t.1).collect();
+    let d: Vec<u8> = ymd.iter().map(|t| t.2).collect();
+    DateVector::from_ymd_vectors(&y, &m, &d).unwrap()
+}
+
+fn values(data: &[f64]) -> VectorData<f64> {
+    VectorData { data: data.to_vec(), validity: bitvec![1; data.len()] }
+}
+
+fn opts(fill: FillMethod, max_gap: Option<u32>, agg: Aggregation) -> ResampleOptions {
+    ResampleOptions { fill, max_gap, agg }
+}
+
+fn month_ends() -> DateVector {
+    DateVector::range(d(2024, 1, 1), d(2024, 3, 31), DateFreq::MonthEnd, RangeBound::Inclusive).unwrap()
+}
+
+#[test]
+fn test_downsample_to_month_end() {
+    let dates = dv(&[(2024, 1, 10), (2024, 1, 31), (2024, 2, 5), (2024, 2, 20), (2024, 3, 31)]);
+    let v = values(&[1.0, 2.0, 3.0, 5.0, 7.0]);
+    let grid = month_ends();
+
+    let last = dates.resample(&v, &grid, opts(FillMethod::Null, None, Aggregation::Last)).unwrap();
+    // First grid date only takes observations on that date
+    assert_eq!(last.data, vec![2.0, 5.0, 7.0]);
+
+    let sum = dates.resample(&v, &grid, opts(FillMethod::Null, None, Aggregation::Sum)).unwrap();
+    assert_eq!(sum.data, vec![2.0, 8.0, 7.0]);
+
+    let mean = dates.resample(&v, &grid, opts(FillMethod::Null, None, Aggregation::Mean)).unwrap();
+    assert_eq!(mean.data[1], 4.0);
+
+    let first = dates.resample(&v, &grid, opts(FillMethod::Null, None, Aggregation::First)).unwrap();
+    assert_eq!(first.data[1], 3.0);
+
+    let min = dates.resample(&v, &grid, opts(FillMethod::Null, None, Aggregation::Min)).unwrap();
+    let max = dates.resample(&v, &grid, opts(FillMethod::Null, None, Aggregation::Max)).unwrap();
+    assert_eq!((min.data[1], max.data[1]), (3.0, 5.0));
+}
+
+#[test]
+fn test_unsorted_input_and_nulls_ignored() {
+    let dates = dv(&[(2024, 2, 20), (2024, 2, 30), (2024, 2, 5), (2024, 2, 25)]);
+    let mut v = values(&[5.0, 100.0, 3.0, 9.0]);
+    v.validity.set(3, false);
+
+    let out = dates
+        .resample(&v, &month_ends(), opts(FillMethod::Null, None, Aggregation::Last))
+        .unwrap();
+    assert_eq!(out.validity, bitvec![0, 1, 0]);
+    assert_eq!(out.data[1], 5.0);
+}
+
+#[test]
+fn test_upsample_business_days_with_fill() {
+    let cal = get_nyse_calendar().unwrap();
+    // 2024-01-01 holiday; 2024-01-02 .. 2024-01-05 Tue-Fri, 2024-01-08 Mon
+    let grid = DateVector::range(
+        d(2024, 1, 1),
+        d(2024, 1, 8),
+        DateFreq::BusinessDaily(&cal),
+        RangeBound::Inclusive,
+    )
+    .unwrap();
+    assert_eq!(grid.len(), 5);
+
+    let dates = dv(&[(2024, 1, 2), (2024, 1, 6)]);
+    let v = values(&[1.0, 2.0]);
+
+    let ffill = dates.resample(&v, &grid, opts(FillMethod::Forward, None, Aggregation::Last)).unwrap();
+    assert_eq!(ffill.data, vec![1.0, 1.0, 1.0, 1.0, 2.0]);
+    assert_eq!(ffill.validity, bitvec![1; 5]);
+
+    let limited = dates.resample(&v, &grid, opts(FillMethod::Forward, Some(2), Aggregation::Last)).unwrap();
+    assert_eq!(limited.validity, bitvec![1, 1, 1, 0, 1]);
+
+    let bfill = dates.resample(&v, &grid, opts(FillMethod::Backward, None, Aggregation::Last)).unwrap();
+    assert_eq!(bfill.data, vec![1.0, 2.0, 2.0, 2.0, 2.0]);
+
+    // Jan 4: 2 days from Jan 2 and Jan 6 -> tie goes to the earlier
+    let nearest = dates.resample(&v, &grid, opts(FillMethod::Nearest, None, Aggregation::Last)).unwrap();
+    assert_eq!(nearest.data, vec![1.0, 1.0, 1.0, 2.0, 2.0]);
+
+    let null = dates.resample(&v, &grid, opts(FillMethod::Null, None, Aggregation::Last)).unwrap();
+    // Saturday's observation lands in Monday's bucket
+    assert_eq!(null.validity, bitvec![1, 0, 0, 0, 1]);
+}
+
+#[test]
+fn test_resample_rejects_bad_inputs() {
+    let dates = dv(&[(2024, 1, 2)]);
+    let grid = dv(&[(2024, 1, 2), (2024, 1, 2)]);
+    assert!(dates.resample(&values(&[1.0]), &grid, ResampleOptions::default()).is_err());
+
+    let grid = dv(&[(2024, 1, 2), (2024, 2, 30)]);
+    assert!(dates.resample(&values(&[1.0]), &grid, ResampleOptions::default()).is_err());
+
+    assert!(dates.resample(&values(&[1.0, 2.0]), &month_ends(), ResampleOptions::default()).is_err());
+}
@@ -0,0 +1,110 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn ints(data: &[i64]) -> VectorData<i64> {
+    VectorData { data: data.to_vec(), validity: bitvec![1; data.len()] }
+}
+
+fn floats(data: &[f64]) -> VectorData<f64> {
+    VectorData { data: data.to_vec(), validity: bitvec![1; data.len()] }
+}
+
+fn ymd(v: &DateVector, i: usize) -> Option<(i32, u8, u8)> {
+    v.get(i).map(|d| d.ymd())
+}
+
+#[test]
+fn test_excel_1900_serials() {
+    let v = DateVector::from_serial(&ints(&[1, 59, 60, 61, 45_292, 0, -5]), SerialEpoch::Excel1900);
+    assert_eq!(ymd(&v, 0), Some((1900, 1, 1)));
+    assert_eq!(ymd(&v, 1), Some((1900, 2, 28)));
+    // Fake 1900-02-29
+    assert_eq!(ymd(&v, 2), None);
+    assert_eq!(ymd(&v, 3), Some((1900, 3, 1)));
+    assert_eq!(ymd(&v, 4), Some((2024, 1, 1)));
+    assert_eq!(ymd(&v, 5), None);
+    assert_eq!(ymd(&v, 6), None);
+
+    let back = v.to_serial(SerialEpoch::Excel1900);
+    assert_eq!(&back.data[..5], &[1, 59, 0, 61, 45_292]);
+    assert_eq!(back.validity, bitvec![1, 1, 0, 1, 1, 0, 0]);
+}
+
+#[test]
+fn test_excel_1900_rejects_pre_1900_dates() {
+    let v = DateVector::from_ymd_vectors(&[1899, 1900], &[12, 1], &[31, 1]).unwrap();
+    let s = v.to_serial(SerialEpoch::Excel1900);
+    assert_eq!(s.validity, bitvec![0, 1]);
+    assert_eq!(s.data[1], 1);
+}
+
+#[test]
+fn test_excel_1904_serials() {
+    let v = DateVector::from_serial(&ints(&[0, 43_830, -1]), SerialEpoch::Excel1904);
+    assert_eq!(ymd(&v, 0), Some((1904, 1, 1)));
+    assert_eq!(ymd(&v, 1), Some((2024, 1, 1)));
+    assert_eq!(ymd(&v, 2), None);
+    assert_eq!(&v.to_serial(SerialEpoch::Excel1904).data[..2], &[0, 43_830]);
+}
+
+#[test]
+fn test_excel_fractional_serials_drop_time() {
+    let v = DateVector::from_serial_f64(&floats(&[45_292.75, f64::NAN, f64::INFINITY, 1e300]), SerialEpoch::Excel1900);
+    assert_eq!(ymd(&v, 0), Some((2024, 1, 1)));
+    assert_eq!(v.to_serial_f64(SerialEpoch::Excel1900).validity, bitvec![1, 0, 0, 0]);
+}
+
+#[test]
+fn test_julian_day_numbers() {
+    let v = DateVector::from_serial(&ints(&[2_440_588, 2_451_545, 0]), SerialEpoch::JulianDay);
+    assert_eq!(ymd(&v, 0), Some((1970, 1, 1)));
+    assert_eq!(ymd(&v, 1), Some((2000, 1, 1)));
+    // JDN 0 is 4714-11-24 BC (proleptic Gregorian), astronomical year -4713
+    assert_eq!(ymd(&v, 2), Some((-4713, 11, 24)));
+
+    // Julian Dates: JD 2451544.5 is 2000-01-01 00:00, JD 2451545.49 is still that day
+    let f = DateVector::from_serial_f64(&floats(&[2_451_544.5, 2_451_545.49, 2_451_544.49]), SerialEpoch::JulianDay);
+    assert_eq!(ymd(&f, 0), Some((2000, 1, 1)));
+    assert_eq!(ymd(&f, 1), Some((2000, 1, 1)));
+    assert_eq!(ymd(&f, 2), Some((1999, 12, 31)));
+
+    assert_eq!(v.to_serial(SerialEpoch::JulianDay).data, vec![2_440_588, 2_451_545, 0]);
+    assert_eq!(v.to_serial_f64(SerialEpoch::JulianDay).data[1], 2_451_545.0);
+}
+
+#[test]
+fn test_sas_stata_and_unix() {
+    for epoch in [SerialEpoch::Sas, SerialEpoch::Stata] {
+        let v = DateVector::from_serial(&ints(&[0, -1, 23_376]), epoch);
+        assert_eq!(ymd(&v, 0), Some((1960, 1, 1)));
+        assert_eq!(ymd(&v, 1), Some((1959, 12, 31)));
+        assert_eq!(ymd(&v, 2), Some((2024, 1, 1)));
+        assert_eq!(v.to_serial(epoch).data, vec![0, -1, 23_376]);
+    }
+
+    let u = DateVector::from_serial(&ints(&[19_723, i64::MAX]), SerialEpoch::Unix);
+    assert_eq!(ymd(&u, 0), Some((2024, 1, 1)));
+    assert_eq!(ymd(&u, 1), None);
+}
+
+#[test]
+fn test_yyyymmdd() {
+    let mut input = ints(&[20_240_229, 20_230_229, 19_691_231, 20_241_301, -20_240_101, 100_000_101, 20_240_101]);
+    input.validity.set(6, false);
+
+    let v = DateVector::from_yyyymmdd(&input);
+    assert_eq!(ymd(&v, 0), Some((2024, 2, 29)));
+    assert_eq!(ymd(&v, 1), None);
+    assert_eq!(ymd(&v, 2), Some((1969, 12, 31)));
+    assert_eq!(ymd(&v, 3), None);
+    assert_eq!(ymd(&v, 4), None);
+    assert_eq!(ymd(&v, 5), None);
+    assert_eq!(ymd(&v, 6), None);
+
+    let back = v.to_yyyymmdd();
+    assert_eq!(back.data[0], 20_240_229);
+    assert_eq!(back.data[2], 19_691_231);
+    assert_eq!(back.validity, bitvec![1, 0, 1, 0, 0, 0, 0]);
+}
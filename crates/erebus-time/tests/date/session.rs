@@ -0,0 +1,160 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn d(y: i32, m: u8, day: u8) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+/// UTC instant in nanoseconds.
+fn utc(y: i32, m: u8, day: u8, h: u8, mi: u8) -> i64 {
+    Timestamp::from_ymd_hms(y, m, day, h, mi, 0).unwrap().nanos()
+}
+
+/// Hong Kong style split session at a fixed UTC+8.
+fn split_calendar() -> MarketCalendar {
+    let session = TradingSession::new(vec![
+        SessionSegment::new((9, 30), (12, 0)).unwrap(),
+        SessionSegment::new((13, 0), (16, 0)).unwrap(),
+    ])
+    .unwrap();
+    let hours = MarketHours::new(TimeZone::fixed("HKT", 8 * 3600), session).with_half_day(d(2024, 12, 24));
+    MarketCalendar::new(WeekendRule::SaturdaySunday, HolidaySet::new(vec![])).with_hours(hours)
+}
+
+#[test]
+fn test_nyse_session_bounds_follow_dst() {
+    let nyse = get_nyse_calendar().unwrap();
+
+    // EST (UTC-5)
+    assert_eq!(nyse.session_bounds(d(2024, 3, 5)), vec![(utc(2024, 3, 5, 14, 30), utc(2024, 3, 5, 21, 0))]);
+    // EDT (UTC-4)
+    assert_eq!(nyse.session_bounds(d(2024, 7, 9)), vec![(utc(2024, 7, 9, 13, 30), utc(2024, 7, 9, 20, 0))]);
+    // Independence Day, Saturday
+    assert!(nyse.session_bounds(d(2024, 7, 4)).is_empty());
+    assert!(nyse.session_bounds(d(2024, 7, 6)).is_empty());
+}
+
+#[test]
+fn test_nyse_session_bounds_before_2007_dst_rules() {
+    let nyse = get_nyse_calendar().unwrap();
+
+    // DST ran from the first Sunday in April to the last Sunday in October
+    assert_eq!(nyse.session_bounds(d(2006, 3, 20))[0].0, utc(2006, 3, 20, 14, 30));
+    assert_eq!(nyse.session_bounds(d(2006, 4, 3))[0].0, utc(2006, 4, 3, 13, 30));
+    assert_eq!(nyse.session_bounds(d(2006, 10, 30))[0].0, utc(2006, 10, 30, 14, 30));
+}
+
+#[test]
+fn test_nyse_early_closes() {
+    let nyse = get_nyse_calendar().unwrap();
+
+    assert_eq!(nyse.session_bounds(d(2024, 11, 29))[0].1, utc(2024, 11, 29, 18, 0));
+    assert_eq!(nyse.session_bounds(d(2024, 7, 3))[0].1, utc(2024, 7, 3, 17, 0));
+    assert_eq!(nyse.session_bounds(d(2024, 12, 24))[0].1, utc(2024, 12, 24, 18, 0));
+    // 2020-07-03 was the observed holiday, not an early close
+    assert!(nyse.session_bounds(d(2020, 7, 3)).is_empty());
+    assert_eq!(nyse.hours().session_on(d(2024, 7, 2)), nyse.hours().regular());
+}
+
+#[test]
+fn test_nyse_is_open() {
+    let nyse = get_nyse_calendar().unwrap();
+
+    assert!(!nyse.is_open(utc(2024, 3, 5, 14, 29)));
+    assert!(nyse.is_open(utc(2024, 3, 5, 14, 30)));
+    assert!(nyse.is_open(utc(2024, 3, 5, 20, 59)));
+    assert!(!nyse.is_open(utc(2024, 3, 5, 21, 0)));
+    // After the 13:00 early close
+    assert!(!nyse.is_open(utc(2024, 11, 29, 18, 30)));
+    assert!(!nyse.is_open(utc(2024, 7, 4, 15, 0)));
+}
+
+#[test]
+fn test_nyse_next_open_previous_close() {
+    let nyse = get_nyse_calendar().unwrap();
+
+    // Friday evening -> Monday open; Monday pre-market -> Friday close
+    let fri_evening = utc(2024, 3, 8, 23, 0);
+    assert_eq!(nyse.next_open(fri_evening), Some(utc(2024, 3, 11, 13, 30)));
+    assert_eq!(nyse.previous_close(utc(2024, 3, 11, 12, 0)), Some(utc(2024, 3, 8, 21, 0)));
+
+    // At the open itself
+    let open = utc(2024, 3, 5, 14, 30);
+    assert_eq!(nyse.next_open(open), Some(open));
+    // Mid-session: the previous close is the prior day's
+    assert_eq!(nyse.previous_close(utc(2024, 3, 5, 16, 0)), Some(utc(2024, 3, 4, 21, 0)));
+}
+
+#[test]
+fn test_nyse_trading_minutes_between() {
+    let nyse = get_nyse_calendar().unwrap();
+
+    // Thanksgiving week: 3 full days, Thursday closed, Friday to 13:00
+    let start = utc(2024, 11, 25, 0, 0);
+    let end = utc(2024, 11, 30, 0, 0);
+    assert_eq!(nyse.trading_minutes_between(start, end), 3 * 390 + 210);
+
+    // Partial overlap with one session
+    assert_eq!(nyse.trading_minutes_between(utc(2024, 3, 5, 20, 0), utc(2024, 3, 6, 15, 0)), 60 + 30);
+    assert_eq!(nyse.trading_minutes_between(end, start), 0);
+}
+
+#[test]
+fn test_split_session_lunch_break_and_half_day() {
+    let cal = split_calendar();
+
+    // 10:00 and 13:30 local are open, 12:30 local (lunch) is not
+    assert!(cal.is_open(utc(2024, 3, 5, 2, 0)));
+    assert!(!cal.is_open(utc(2024, 3, 5, 4, 30)));
+    assert!(cal.is_open(utc(2024, 3, 5, 5, 30)));
+
+    assert_eq!(cal.session_bounds(d(2024, 3, 5)).len(), 2);
+    assert_eq!(cal.hours().regular().duration_seconds(), 330 * 60);
+    assert_eq!(cal.trading_minutes_between(utc(2024, 3, 4, 16, 0), utc(2024, 3, 5, 16, 0)), 330);
+
+    // Half day: morning session only
+    assert_eq!(cal.session_bounds(d(2024, 12, 24)), vec![(utc(2024, 12, 24, 1, 30), utc(2024, 12, 24, 4, 0))]);
+    // Next open from lunch is the afternoon segment
+    assert_eq!(cal.next_open(utc(2024, 3, 5, 4, 30)), Some(utc(2024, 3, 5, 5, 0)));
+}
+
+#[test]
+fn test_early_close_drops_later_segments() {
+    let session = TradingSession::new(vec![
+        SessionSegment::new((9, 30), (12, 0)).unwrap(),
+        SessionSegment::new((13, 0), (16, 0)).unwrap(),
+    ])
+    .unwrap();
+
+    let early = session.with_close((11, 0)).unwrap();
+    assert_eq!(early.segments().len(), 1);
+    assert_eq!(early.segments()[0].close_seconds(), 11 * 3600);
+
+    let late = session.with_close((14, 0)).unwrap();
+    assert_eq!(late.duration_seconds(), (150 + 60) * 60);
+
+    assert!(session.with_close((9, 0)).is_err());
+}
+
+#[test]
+fn test_default_hours_trade_all_business_day() {
+    let cal = MarketCalendar::new(WeekendRule::SaturdaySunday, HolidaySet::new(vec![]));
+    assert!(cal.is_open(utc(2024, 3, 5, 0, 0)));
+    assert!(!cal.is_open(utc(2024, 3, 9, 12, 0)));
+    assert_eq!(cal.session_bounds(d(2024, 3, 5)), vec![(utc(2024, 3, 5, 0, 0), utc(2024, 3, 6, 0, 0))]);
+}
+
+#[test]
+fn test_session_validation() {
+    assert!(SessionSegment::new((16, 0), (9, 30)).is_err());
+    assert!(SessionSegment::new((9, 60), (10, 0)).is_err());
+    assert!(SessionSegment::new((0, 0), (24, 0)).is_ok());
+    assert!(TradingSession::new(vec![]).is_err());
+    assert!(TradingSession::new(vec![
+        SessionSegment::new((9, 0), (12, 0)).unwrap(),
+        SessionSegment::new((11, 0), (15, 0)).unwrap(),
+    ])
+    .is_err());
+}
@@ -0,0 +1,40 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn dv(ymd: &[(i32, u8, u8)]) -> DateVector {
+    let y: Vec<i32> = ymd.iter().map(|t| t.0).collect();
+    let m: Vec<u8> = ymd.iter().map(|t| t.1).collect();
+    let d: Vec<u8> = ymd.iter().map(|t| t.2).collect();
+    DateVector::from_ymd_vectors(&y, &m, &d).unwrap()
+}
+
+fn fmt(v: &DateVector) -> Vec<String> {
+    v.format("%Y-%m-%d").unwrap().data
+}
+
+#[test]
+fn test_set_ops_portfolio_vs_trading_days() {
+    // Portfolio marks include a weekend date, a duplicate and a null
+    let portfolio = dv(&[(2024, 1, 6), (2024, 1, 2), (2024, 1, 3), (2024, 1, 2), (2024, 2, 30)]);
+    let trading = dv(&[(2024, 1, 2), (2024, 1, 3), (2024, 1, 4), (2024, 1, 5)]);
+
+    assert_eq!(
+        fmt(&portfolio.union(&trading)),
+        vec!["2024-01-02", "2024-01-03", "2024-01-04", "2024-01-05", "2024-01-06"]
+    );
+    assert_eq!(fmt(&portfolio.intersect(&trading)), vec!["2024-01-02", "2024-01-03"]);
+    assert_eq!(fmt(&portfolio.difference(&trading)), vec!["2024-01-06"]);
+    assert_eq!(fmt(&trading.difference(&portfolio)), vec!["2024-01-04", "2024-01-05"]);
+}
+
+#[test]
+fn test_set_ops_with_empty() {
+    let a = dv(&[(2024, 1, 2), (2024, 1, 1)]);
+    let empty = dv(&[]);
+
+    assert_eq!(fmt(&a.union(&empty)), vec!["2024-01-01", "2024-01-02"]);
+    assert!(a.intersect(&empty).is_empty());
+    assert!(empty.difference(&a).is_empty());
+}
@@ -0,0 +1,81 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn dv(ymd: &[(i32, u8, u8)]) -> DateVector {
+    let y: Vec<i32> = ymd.iter().map(|t| t.0).collect();
+    let m: Vec<u8> = ymd.iter().map(|t| t.1).collect();
+    let d: Vec<u8> = ymd.iter().map(|t| t.2).collect();
+    DateVector::from_ymd_vectors(&y, &m, &d).unwrap()
+}
+
+fn fmt(v: &DateVector) -> Vec<String> {
+    v.format("%Y-%m-%d").unwrap().data
+}
+
+#[test]
+fn test_sort_indices_nulls_and_direction() {
+    // Feb 30 is invalid -> null
+    let v = dv(&[(2024, 3, 1), (2024, 2, 30), (1969, 12, 31), (2024, 1, 15)]);
+
+    assert_eq!(v.sort_indices(true, true), vec![2, 3, 0, 1]);
+    assert_eq!(v.sort_indices(true, false), vec![1, 2, 3, 0]);
+    assert_eq!(v.sort_indices(false, true), vec![0, 3, 2, 1]);
+}
+
+#[test]
+fn test_sort_returns_dates_in_order() {
+    let v = dv(&[(2024, 3, 1), (2023, 12, 31), (2024, 1, 15)]);
+    let s = v.sort(true, true);
+    assert_eq!(fmt(&s), vec!["2023-12-31", "2024-01-15", "2024-03-01"]);
+    assert!(s.is_sorted());
+    assert!(!v.is_sorted());
+}
+
+#[test]
+fn test_min_max_skip_nulls() {
+    let v = dv(&[(2024, 2, 30), (2024, 5, 1), (1999, 1, 1)]);
+    assert_eq!(v.min().unwrap().ymd(), (1999, 1, 1));
+    assert_eq!(v.max().unwrap().ymd(), (2024, 5, 1));
+
+    let empty = dv(&[(2024, 2, 30)]);
+    assert!(empty.min().is_none());
+    assert!(empty.max().is_none());
+}
+
+#[test]
+fn test_unique_keeps_first_appearance() {
+    let v = dv(&[(2024, 1, 2), (2024, 1, 1), (2024, 1, 2), (2024, 2, 30), (2024, 1, 1)]);
+    assert_eq!(v.n_unique(), 2);
+    assert_eq!(fmt(&v.unique()), vec!["2024-01-02", "2024-01-01"]);
+}
+
+#[test]
+fn test_searchsorted_left_and_right() {
+    let idx = dv(&[(2024, 1, 1), (2024, 1, 2), (2024, 1, 2), (2024, 1, 5)]);
+    let q = dv(&[(2023, 12, 31), (2024, 1, 2), (2024, 1, 3), (2024, 2, 30), (2025, 1, 1)]);
+
+    assert_eq!(
+        idx.searchsorted(&q, false).unwrap(),
+        vec![Some(0), Some(1), Some(3), None, Some(4)]
+    );
+    assert_eq!(
+        idx.searchsorted(&q, true).unwrap(),
+        vec![Some(0), Some(3), Some(3), None, Some(4)]
+    );
+}
+
+#[test]
+fn test_searchsorted_rejects_unsorted_or_null() {
+    let q = dv(&[(2024, 1, 1)]);
+    assert!(dv(&[(2024, 1, 2), (2024, 1, 1)]).searchsorted(&q, false).is_err());
+    assert!(dv(&[(2024, 1, 1), (2024, 2, 30)]).searchsorted(&q, false).is_err());
+}
+
+#[test]
+fn test_is_in() {
+    let v = dv(&[(2024, 1, 1), (2024, 1, 2), (2024, 2, 30)]);
+    let other = dv(&[(2024, 1, 2), (2024, 2, 31)]);
+    assert_eq!(v.is_in(&other), vec![false, true, false]);
+}
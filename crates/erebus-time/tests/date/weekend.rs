@@ -418,4 +418,69 @@ fn test_date_vector_shift_business_days_with_nulls() {
             (0, 0, 0),    // null preserved
         ]
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_date_adjust_conventions_nyse() {
+    let cal = get_nyse_calendar().unwrap();
+
+    // 2024-03-30 is Saturday, 2024-03-29 is Good Friday
+    let sat = Date::from_ymd(2024, 3, 30).unwrap();
+
+    assert_eq!(sat.adjust(&cal, BusinessDayConvention::Unadjusted).unwrap().ymd(), (2024, 3, 30));
+    assert_eq!(sat.adjust(&cal, BusinessDayConvention::Following).unwrap().ymd(), (2024, 4, 1));
+    assert_eq!(sat.adjust(&cal, BusinessDayConvention::ModifiedFollowing).unwrap().ymd(), (2024, 3, 28));
+    assert_eq!(sat.adjust(&cal, BusinessDayConvention::Preceding).unwrap().ymd(), (2024, 3, 28));
+}
+
+#[test]
+fn test_date_adjust_modified_preceding_crosses_month() {
+    let cal = get_nyse_calendar().unwrap();
+
+    // 2024-06-01 is Saturday
+    let sat = Date::from_ymd(2024, 6, 1).unwrap();
+
+    assert_eq!(sat.adjust(&cal, BusinessDayConvention::Preceding).unwrap().ymd(), (2024, 5, 31));
+    assert_eq!(sat.adjust(&cal, BusinessDayConvention::ModifiedPreceding).unwrap().ymd(), (2024, 6, 3));
+}
+
+#[test]
+fn test_date_vector_adjust_with_nulls() {
+    let cal = get_nyse_calendar().unwrap();
+
+    let dv = DateVector::from_ymd_vectors(
+        &[2024, 2024, 2024],
+        &[3,    2,    3],
+        &[30,   30,   11],
+    ).unwrap();
+
+    let out = dv.adjust(&cal, BusinessDayConvention::Following).unwrap();
+
+    assert_eq!(
+        out.ymd(),
+        vec![
+            (2024, 4, 1),
+            (0, 0, 0),
+            (2024, 3, 11),
+        ]
+    );
+}
+
+struct NoBusinessDays;
+
+impl Calendar for NoBusinessDays {
+    fn is_weekend(&self, _date: Date) -> bool { true }
+    fn is_holiday(&self, _date: Date) -> bool { false }
+}
+
+#[test]
+fn test_date_adjust_errors_without_business_days() {
+    let d = Date::from_ymd(2024, 6, 1).unwrap();
+
+    assert!(d.adjust(&NoBusinessDays, BusinessDayConvention::Following).is_err());
+    assert!(d.adjust(&NoBusinessDays, BusinessDayConvention::ModifiedPreceding).is_err());
+    assert_eq!(d.adjust(&NoBusinessDays, BusinessDayConvention::Unadjusted).unwrap(), d);
+
+    let dv = DateVector::from_ymd_vectors(&[2024], &[6], &[1]).unwrap();
+    assert!(dv.adjust(&NoBusinessDays, BusinessDayConvention::Preceding).is_err());
+}
@@ -0,0 +1,136 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+#[test]
+fn test_duration_constructors() {
+    assert_eq!(Duration::from_days(1).nanos(), NANOS_PER_DAY);
+    assert_eq!(Duration::from_hours(2), Duration::from_minutes(120));
+    assert_eq!(Duration::from_seconds(1), Duration::from_millis(1_000));
+    assert_eq!(Duration::from_micros(1), Duration::from_nanos(1_000));
+}
+
+#[test]
+fn test_duration_accessors() {
+    let d = Duration::from_hours(36) + Duration::from_millis(500);
+    assert_eq!(d.whole_days(), 1);
+    assert_eq!(d.whole_seconds(), 36 * 3600);
+    assert_eq!(d.as_seconds_f64(), 36.0 * 3600.0 + 0.5);
+    assert_eq!((-d).whole_days(), -1);
+    assert_eq!((-d).abs(), d);
+}
+
+#[test]
+fn test_duration_ops() {
+    let a = Duration::from_seconds(10);
+    let b = Duration::from_seconds(4);
+    assert_eq!(a - b, Duration::from_seconds(6));
+    assert_eq!(b * 3, Duration::from_seconds(12));
+    assert_eq!(Duration::from_nanos(i64::MAX).checked_add(Duration::from_nanos(1)), None);
+}
+
+#[test]
+fn test_duration_checked_mul_and_neg() {
+    let d = Duration::from_days(1);
+    assert_eq!(d.checked_mul(-2), Some(Duration::from_days(-2)));
+    assert_eq!(d.checked_mul(i64::MAX), None);
+    assert_eq!(Duration::from_nanos(i64::MIN).checked_neg(), None);
+}
+
+#[test]
+#[should_panic(expected = "overflow when multiplying duration")]
+fn test_duration_mul_overflow_panics() {
+    let _ = Duration::from_days(1) * i64::MAX;
+}
+
+#[test]
+fn test_date_minus_date_is_days() {
+    let a = Date::from_ymd(2024, 3, 1).unwrap();
+    let b = Date::from_ymd(2024, 2, 1).unwrap();
+    assert_eq!((a - b).whole_days(), 29);
+    assert_eq!((b - a).whole_days(), -29);
+}
+
+#[test]
+fn test_timestamp_duration_ops() {
+    let t = Timestamp::from_ymd_hms(2024, 3, 15, 23, 0, 0).unwrap();
+    let u = t + Duration::from_hours(2);
+    assert_eq!(u, Timestamp::from_ymd_hms(2024, 3, 16, 1, 0, 0).unwrap());
+    assert_eq!(u - Duration::from_hours(2), t);
+    assert_eq!(u - t, Duration::from_hours(2));
+}
+
+#[test]
+fn test_duration_add_to_nanos() {
+    let v = VectorData::from_vec(vec![0i64, 5, i64::MAX], bitvec![1, 0, 1]).unwrap();
+    let out = Duration::from_nanos(10).add_to_nanos(&v);
+    assert_eq!(out.data[0], 10);
+    assert!(!out.validity[1]);
+    assert!(!out.validity[2]); // overflow
+}
+
+#[test]
+fn test_duration_vector_add_to_nanos() {
+    let dv = DurationVector::from_nanos_with_validity(vec![1, 2, 3], bitvec![1, 1, 0]).unwrap();
+    let v = VectorData::from_vec(vec![10i64, 20, 30], bitvec![1, 0, 1]).unwrap();
+
+    let out = dv.add_to_nanos(&v).unwrap();
+    assert_eq!(out.data[0], 11);
+    assert!(!out.validity[1]);
+    assert!(!out.validity[2]);
+
+    let short = VectorData::from_vec(vec![1i64], bitvec![1]).unwrap();
+    assert!(dv.add_to_nanos(&short).is_err());
+}
+
+#[test]
+fn test_date_vector_diff() {
+    let a = DateVector::from_ymd_vectors(&[2024, 2024, 2024], &[3, 2, 1], &[1, 30, 1]).unwrap();
+    let b = DateVector::from_ymd_vectors(&[2024, 2024, 2023], &[2, 2, 1], &[1, 1, 1]).unwrap();
+
+    let d = a.diff(&b).unwrap();
+    assert_eq!(d.get(0), Some(Duration::from_days(29)));
+    assert_eq!(d.get(1), None);
+    assert_eq!(d.whole_days(), vec![29, 0, 365]);
+}
+
+#[test]
+fn test_date_spans_beyond_292_years() {
+    let late = Date::from_ymd(9999, 12, 31).unwrap();
+    let early = Date::from_ymd(2024, 1, 1).unwrap();
+    assert_eq!(late.checked_sub(early), None);
+    assert_eq!(Duration::checked_from_days(300 * 365), None);
+    let near = Date::from_ymd(2200, 1, 1).unwrap();
+    assert_eq!(near.checked_sub(early), Some(Duration::from_days(near.days() as i64 - early.days() as i64)));
+
+    let a = DateVector::from_ymd_vectors(&[9999, 2024], &[12, 3], &[31, 1]).unwrap();
+    let b = DateVector::from_ymd_vectors(&[2024, 2024], &[1, 2], &[1, 1]).unwrap();
+    let d = a.diff(&b).unwrap();
+    assert_eq!(d.get(0), None);
+    assert_eq!(d.get(1), Some(Duration::from_days(29)));
+}
+
+#[test]
+#[should_panic(expected = "overflow when subtracting dates")]
+fn test_date_sub_overflow_panics() {
+    let _ = Date::from_ymd(9999, 12, 31).unwrap() - Date::from_ymd(2024, 1, 1).unwrap();
+}
+
+#[test]
+fn test_timestamp_vector_duration_ops() {
+    let t0 = Timestamp::from_ymd_hms(2024, 3, 15, 9, 30, 0).unwrap();
+    let tv = TimestampVector::from_nanos_with_validity(vec![t0.nanos(), 0], bitvec![1, 0]).unwrap();
+
+    let shifted = tv.add_duration(Duration::from_minutes(30));
+    assert_eq!(shifted.get(0), Some(Timestamp::from_ymd_hms(2024, 3, 15, 10, 0, 0).unwrap()));
+    assert_eq!(shifted.get(1), None);
+
+    let diff = shifted.diff(&tv).unwrap();
+    assert_eq!(diff.get(0), Some(Duration::from_minutes(30)));
+    assert_eq!(diff.get(1), None);
+
+    let per_row = DurationVector::from_nanos(vec![NANOS_PER_SECOND, NANOS_PER_SECOND]);
+    let out = tv.add_durations(&per_row).unwrap();
+    assert_eq!(out.get(0).unwrap().second(), 1);
+}
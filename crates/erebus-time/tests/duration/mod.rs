@@ -0,0 +1,3 @@
+mod duration;
+mod period;
+mod tenor;
\ No newline at end of file
@@ -0,0 +1,65 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn d(y: i32, m: u8, day: u8) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+#[test]
+fn test_date_plus_period_clamps_month_end() {
+    assert_eq!((d(2024, 1, 31) + Period::months(1)).ymd(), (2024, 2, 29));
+    assert_eq!((d(2024, 2, 29) + Period::years(1)).ymd(), (2025, 2, 28));
+    assert_eq!((d(2024, 3, 31) - Period::months(1)).ymd(), (2024, 2, 29));
+}
+
+#[test]
+fn test_date_plus_period_days_and_weeks() {
+    assert_eq!((d(2024, 2, 28) + Period::days(2)).ymd(), (2024, 3, 1));
+    assert_eq!((d(2024, 2, 28) + Period::weeks(1)).ymd(), (2024, 3, 6));
+}
+
+#[test]
+fn test_date_plus_period_business_days_default_weekend() {
+    // 2024-03-15 is Friday
+    assert_eq!((d(2024, 3, 15) + Period::business_days(1)).ymd(), (2024, 3, 18));
+}
+
+#[test]
+fn test_date_add_period_with_calendar_and_rule() {
+    let cal = get_nyse_calendar().unwrap();
+
+    // 2024-03-28 (Thu) + 1BD skips Good Friday
+    let p = Period::business_days(1);
+    assert_eq!(d(2024, 3, 28).add_period(&p, MonthEndRule::Clamp, &cal).ymd(), (2024, 4, 1));
+
+    let p = Period::months(1);
+    assert_eq!(d(2024, 2, 29).add_period(&p, MonthEndRule::Preserve, &cal).ymd(), (2024, 3, 31));
+}
+
+#[test]
+fn test_period_components_apply_in_order() {
+    // Months first (Jan 31 -> Feb 29), then days
+    let p = Period::new(0, 1, 1, 0);
+    assert_eq!((d(2024, 1, 31) + p).ymd(), (2024, 3, 1));
+}
+
+#[test]
+fn test_date_vector_add_period() {
+    let dv = DateVector::from_ymd_vectors(&[2024, 2024], &[1, 2], &[31, 30]).unwrap();
+    let out = dv.add_period(&Period::months(1), MonthEndRule::Clamp, &WeekendRule::default());
+    assert_eq!(out.ymd(), vec![(2024, 2, 29), (0, 0, 0)]);
+}
+
+#[test]
+fn test_date_vector_add_periods() {
+    let dv = DateVector::from_ymd_vectors(&[2024, 2024, 2024], &[1, 1, 1], &[31, 31, 31]).unwrap();
+    let pv = PeriodVector::parse_tenors(&["1M", "bad", "1Y"]);
+
+    let out = dv.add_periods(&pv, MonthEndRule::Clamp, &WeekendRule::default()).unwrap();
+    assert_eq!(out.ymd(), vec![(2024, 2, 29), (0, 0, 0), (2025, 1, 31)]);
+
+    let short = PeriodVector::from_periods(&[Period::days(1)]);
+    assert!(dv.add_periods(&short, MonthEndRule::Clamp, &WeekendRule::default()).is_err());
+}
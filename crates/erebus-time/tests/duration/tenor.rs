@@ -0,0 +1,44 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+#[test]
+fn test_tenor_parse_simple() {
+    assert_eq!(Period::parse_tenor("3M").unwrap(), Period::months(3));
+    assert_eq!(Period::parse_tenor("2W").unwrap(), Period::days(14));
+    assert_eq!(Period::parse_tenor("5BD").unwrap(), Period::business_days(5));
+    assert_eq!(Period::parse_tenor("10Y").unwrap(), Period::years(10));
+    assert_eq!(Period::parse_tenor("1D").unwrap(), Period::days(1));
+}
+
+#[test]
+fn test_tenor_parse_compound_and_case() {
+    assert_eq!(Period::parse_tenor("1Y6M").unwrap(), Period::new(1, 6, 0, 0));
+    assert_eq!(Period::parse_tenor(" 1y2bd ").unwrap(), Period::new(1, 0, 0, 2));
+    assert_eq!("-1M2D".parse::<Period>().unwrap(), Period::new(0, -1, -2, 0));
+}
+
+#[test]
+fn test_tenor_parse_invalid() {
+    for s in ["", "M", "3", "3X", "3B", "-", "1Y6", "99999999999M", "400000000W", "2000000000D2000000000D"] {
+        assert!(Period::parse_tenor(s).is_err(), "{:?} should fail", s);
+    }
+}
+
+#[test]
+fn test_tenor_display_round_trip() {
+    for s in ["1Y6M", "3M", "14D", "5BD", "0D", "-1Y"] {
+        let p = Period::parse_tenor(s).unwrap();
+        assert_eq!(p.to_string(), s);
+    }
+}
+
+#[test]
+fn test_period_vector_parse_tenors() {
+    let pv = PeriodVector::parse_tenors(&["3M", "oops", "1Y6M"]);
+    assert_eq!(pv.len(), 3);
+    assert_eq!(pv.get(0), Some(Period::months(3)));
+    assert_eq!(pv.get(1), None);
+    assert_eq!(pv.get(2).unwrap().total_months(), 18);
+}
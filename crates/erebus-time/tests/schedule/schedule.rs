@@ -0,0 +1,252 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn no_holidays() -> MarketCalendar {
+    MarketCalendar::new(WeekendRule::SaturdaySunday, HolidaySet::new(vec![]))
+}
+
+fn d(y: i32, m: u8, day: u8) -> Date {
+    Date::from_ymd(y, m, day).unwrap()
+}
+
+fn rule(direction: Direction, stub: StubKind, roll: RollConvention) -> ScheduleRule {
+    ScheduleRule::new(direction, stub, roll)
+}
+
+#[test]
+fn test_schedule_quarterly_forward_regular() {
+    let s = Schedule::generate(
+        d(2024, 1, 15), d(2025, 1, 15),
+        Frequency::Quarterly,
+        rule(Direction::Forward, StubKind::Short, RollConvention::None),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![
+            (2024, 1, 15), (2024, 4, 15), (2024, 7, 15),
+            (2024, 10, 15), (2025, 1, 15),
+        ]
+    );
+    assert_eq!(s.len(), 5);
+}
+
+#[test]
+fn test_schedule_forward_short_back_stub() {
+    let s = Schedule::generate(
+        d(2024, 1, 15), d(2024, 12, 1),
+        Frequency::Quarterly,
+        rule(Direction::Forward, StubKind::Short, RollConvention::None),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![
+            (2024, 1, 15), (2024, 4, 15), (2024, 7, 15),
+            (2024, 10, 15), (2024, 12, 1),
+        ]
+    );
+}
+
+#[test]
+fn test_schedule_forward_long_back_stub() {
+    let s = Schedule::generate(
+        d(2024, 1, 15), d(2024, 12, 1),
+        Frequency::Quarterly,
+        rule(Direction::Forward, StubKind::Long, RollConvention::None),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![(2024, 1, 15), (2024, 4, 15), (2024, 7, 15), (2024, 12, 1)]
+    );
+}
+
+#[test]
+fn test_schedule_backward_short_front_stub() {
+    let s = Schedule::generate(
+        d(2024, 2, 1), d(2025, 1, 15),
+        Frequency::Semiannual,
+        rule(Direction::Backward, StubKind::Short, RollConvention::None),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![(2024, 2, 1), (2024, 7, 15), (2025, 1, 15)]
+    );
+}
+
+#[test]
+fn test_schedule_backward_long_front_stub() {
+    let s = Schedule::generate(
+        d(2024, 2, 1), d(2025, 1, 15),
+        Frequency::Quarterly,
+        rule(Direction::Backward, StubKind::Long, RollConvention::None),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    // Short stub would be 2024-02-01 -> 2024-04-15; merged into the next period
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![(2024, 2, 1), (2024, 7, 15), (2024, 10, 15), (2025, 1, 15)]
+    );
+}
+
+#[test]
+fn test_schedule_long_stub_without_stub_is_regular() {
+    let s = Schedule::generate(
+        d(2024, 1, 15), d(2025, 1, 15),
+        Frequency::Semiannual,
+        rule(Direction::Backward, StubKind::Long, RollConvention::None),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![(2024, 1, 15), (2024, 7, 15), (2025, 1, 15)]
+    );
+}
+
+#[test]
+fn test_schedule_monthly_clamps_without_drift() {
+    let s = Schedule::generate(
+        d(2024, 1, 31), d(2024, 5, 31),
+        Frequency::Monthly,
+        rule(Direction::Forward, StubKind::Short, RollConvention::None),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![
+            (2024, 1, 31), (2024, 2, 29), (2024, 3, 31),
+            (2024, 4, 30), (2024, 5, 31),
+        ]
+    );
+}
+
+#[test]
+fn test_schedule_end_of_month_roll() {
+    let s = Schedule::generate(
+        d(2023, 2, 28), d(2023, 8, 31),
+        Frequency::Monthly,
+        rule(Direction::Forward, StubKind::Short, RollConvention::EndOfMonth),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![
+            (2023, 2, 28), (2023, 3, 31), (2023, 4, 30), (2023, 5, 31),
+            (2023, 6, 30), (2023, 7, 31), (2023, 8, 31),
+        ]
+    );
+}
+
+#[test]
+fn test_schedule_end_of_month_ignored_for_mid_month_anchor() {
+    let s = Schedule::generate(
+        d(2023, 2, 27), d(2023, 4, 27),
+        Frequency::Monthly,
+        rule(Direction::Forward, StubKind::Short, RollConvention::EndOfMonth),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![(2023, 2, 27), (2023, 3, 27), (2023, 4, 27)]
+    );
+}
+
+#[test]
+fn test_schedule_imm_roll() {
+    let s = Schedule::generate(
+        d(2024, 3, 20), d(2024, 12, 18),
+        Frequency::Quarterly,
+        rule(Direction::Forward, StubKind::Short, RollConvention::Imm),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![(2024, 3, 20), (2024, 6, 19), (2024, 9, 18), (2024, 12, 18)]
+    );
+}
+
+#[test]
+fn test_schedule_custom_month_step() {
+    let s = Schedule::generate(
+        d(2024, 1, 10), d(2025, 1, 10),
+        Frequency::Months(4),
+        rule(Direction::Forward, StubKind::Short, RollConvention::None),
+        &no_holidays(),
+        BusinessDayConvention::Unadjusted,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![(2024, 1, 10), (2024, 5, 10), (2024, 9, 10), (2025, 1, 10)]
+    );
+}
+
+#[test]
+fn test_schedule_adjusted_dates_nyse() {
+    let cal = get_nyse_calendar().unwrap();
+
+    let s = Schedule::generate(
+        d(2023, 12, 30), d(2024, 6, 30),
+        Frequency::Quarterly,
+        ScheduleRule::default(),
+        &cal,
+        BusinessDayConvention::ModifiedFollowing,
+    ).unwrap();
+
+    assert_eq!(
+        s.unadjusted().ymd(),
+        vec![(2023, 12, 30), (2024, 3, 30), (2024, 6, 30)]
+    );
+    // Sat -> Fri (month end), Sat + Good Friday -> Thu, Sun -> Fri
+    assert_eq!(
+        s.adjusted().ymd(),
+        vec![(2023, 12, 29), (2024, 3, 28), (2024, 6, 28)]
+    );
+}
+
+#[test]
+fn test_schedule_invalid_inputs() {
+    let cal = no_holidays();
+
+    let err = Schedule::generate(
+        d(2024, 1, 1), d(2024, 1, 1),
+        Frequency::Monthly,
+        ScheduleRule::default(),
+        &cal,
+        BusinessDayConvention::Unadjusted,
+    ).unwrap_err();
+    assert!(matches!(err, ErebusError::InvalidOperation(_)));
+
+    let err = Schedule::generate(
+        d(2024, 1, 1), d(2025, 1, 1),
+        Frequency::Months(0),
+        ScheduleRule::default(),
+        &cal,
+        BusinessDayConvention::Unadjusted,
+    ).unwrap_err();
+    assert!(matches!(err, ErebusError::InvalidOperation(_)));
+}
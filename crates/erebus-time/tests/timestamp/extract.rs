@@ -0,0 +1,31 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+#[test]
+fn test_timestamp_extract_parts() {
+    let ts = Timestamp::from_ymd_hms_nano(2024, 3, 15, 13, 45, 30, 123_456_789).unwrap();
+    assert_eq!(ts.hour(), 13);
+    assert_eq!(ts.minute(), 45);
+    assert_eq!(ts.second(), 30);
+    assert_eq!(ts.nanosecond(), 123_456_789);
+    assert_eq!(ts.date().ymd(), (2024, 3, 15));
+}
+
+#[test]
+fn test_timestamp_vector_extract_with_nulls() {
+    let a = Timestamp::from_ymd_hms(2024, 3, 15, 13, 45, 30).unwrap();
+    let b = Timestamp::from_ymd_hms_nano(1960, 1, 1, 1, 2, 3, 4).unwrap();
+
+    let tv = TimestampVector::from_nanos_with_validity(
+        vec![a.nanos(), 0, b.nanos()],
+        bitvec![1, 0, 1],
+    ).unwrap();
+
+    assert_eq!(tv.hours(), vec![13, 0, 1]);
+    assert_eq!(tv.minutes(), vec![45, 0, 2]);
+    assert_eq!(tv.seconds(), vec![30, 0, 3]);
+    assert_eq!(tv.nanoseconds(), vec![0, 0, 4]);
+    assert_eq!(tv.hms()[2], (1, 2, 3, 4));
+}
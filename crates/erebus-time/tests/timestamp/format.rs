@@ -0,0 +1,92 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+#[test]
+fn test_timestamp_parse_full() {
+    let t = Timestamp::parse("2024-03-15 09:30:01.25", "%Y-%m-%d %H:%M:%S%.f").unwrap();
+    assert_eq!(t, Timestamp::from_ymd_hms_nano(2024, 3, 15, 9, 30, 1, 250_000_000).unwrap());
+}
+
+#[test]
+fn test_timestamp_parse_fraction_optional() {
+    let t = Timestamp::parse("2024-03-15 09:30:01", "%Y-%m-%d %H:%M:%S%.f").unwrap();
+    assert_eq!(t.nanosecond(), 0);
+
+    let t = Timestamp::parse("2024-03-15T09:30:01.000000001", "%Y-%m-%dT%H:%M:%S%.f").unwrap();
+    assert_eq!(t.nanosecond(), 1);
+}
+
+#[test]
+fn test_timestamp_parse_month_name_and_time() {
+    let t = Timestamp::parse("March 15, 2024 16:00", "%B %d, %Y %H:%M").unwrap();
+    assert_eq!(t, Timestamp::from_ymd_hms(2024, 3, 15, 16, 0, 0).unwrap());
+}
+
+#[test]
+fn test_timestamp_parse_date_only() {
+    let t = Timestamp::parse("2024-03-15", "%Y-%m-%d").unwrap();
+    assert_eq!(t.nanos_of_day(), 0);
+}
+
+#[test]
+fn test_timestamp_parse_errors() {
+    assert!(Timestamp::parse("2024-03-15 24:00:00", "%Y-%m-%d %H:%M:%S").is_err());
+    assert!(Timestamp::parse("2024-03-15 10:61:00", "%Y-%m-%d %H:%M:%S").is_err());
+    assert!(Timestamp::parse("2024-03-15 10:00:00.", "%Y-%m-%d %H:%M:%S%.f").is_err());
+    assert!(Timestamp::parse("2024-03-15 10:00:00.1234567890", "%Y-%m-%d %H:%M:%S%.f").is_err());
+    // %.f must close the format
+    assert!(Timestamp::parse("x", "%Y-%m-%d %H:%M:%S%.f UTC").is_err());
+}
+
+#[test]
+fn test_timestamp_vector_parse_with_nulls() {
+    let tv = TimestampVector::parse(
+        &["2024-03-15 09:30:00.5", "bad", "2024-02-30 09:30:00"],
+        "%Y-%m-%d %H:%M:%S%.f",
+    ).unwrap();
+
+    assert_eq!(tv.get(0).unwrap().hms(), (9, 30, 0, 500_000_000));
+    assert_eq!(tv.get(1), None);
+    assert_eq!(tv.get(2), None);
+}
+
+#[test]
+fn test_timestamp_format() {
+    let t = Timestamp::from_ymd_hms_nano(2024, 3, 5, 9, 3, 7, 120_000_000).unwrap();
+    assert_eq!(t.format("%Y-%m-%d %H:%M:%S%.f").unwrap(), "2024-03-05 09:03:07.120");
+    assert_eq!(t.format("%d %b %y %H:%M").unwrap(), "05 Mar 24 09:03");
+    assert_eq!(t.format("%B %d, %Y 100%%").unwrap(), "March 05, 2024 100%");
+}
+
+#[test]
+fn test_timestamp_format_fraction_precision() {
+    let f = "%H:%M:%S%.f";
+    assert_eq!(Timestamp::from_ymd_hms(2024, 1, 1, 0, 0, 0).unwrap().format(f).unwrap(), "00:00:00");
+    assert_eq!(Timestamp::from_ymd_hms_nano(2024, 1, 1, 0, 0, 0, 1_000).unwrap().format(f).unwrap(), "00:00:00.000001");
+    assert_eq!(Timestamp::from_ymd_hms_nano(2024, 1, 1, 0, 0, 0, 7).unwrap().format(f).unwrap(), "00:00:00.000000007");
+}
+
+#[test]
+fn test_timestamp_vector_format_round_trip() {
+    let fmt = "%Y-%m-%d %H:%M:%S%.f";
+    let values = ["2024-03-15 09:30:00.5", "bad", "1969-12-31 23:59:59.000001"];
+    let tv = TimestampVector::parse(&values, fmt).unwrap();
+
+    let out = tv.format(fmt).unwrap();
+    assert_eq!(out.data[0], "2024-03-15 09:30:00.500");
+    assert!(!out.validity[1]);
+    assert_eq!(out.data[2], "1969-12-31 23:59:59.000001");
+
+    let strs: Vec<&str> = out.data.iter().map(|s| s.as_str()).collect();
+    let back = TimestampVector::parse(&strs, fmt).unwrap();
+    assert_eq!(back.nanos()[0], tv.nanos()[0]);
+    assert_eq!(back.nanos()[2], tv.nanos()[2]);
+}
+
+#[test]
+fn test_timestamp_format_unsupported_token() {
+    let t = Timestamp::from_nanos(0);
+    assert!(t.format("%Q").is_err());
+}
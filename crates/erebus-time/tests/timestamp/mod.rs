@@ -0,0 +1,4 @@
+mod extract;
+mod format;
+mod round;
+mod timestamp;
\ No newline at end of file
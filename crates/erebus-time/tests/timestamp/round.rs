@@ -0,0 +1,66 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+fn ts(h: u8, m: u8, s: u8, ns: u32) -> Timestamp {
+    Timestamp::from_ymd_hms_nano(2024, 3, 15, h, m, s, ns).unwrap()
+}
+
+#[test]
+fn test_timestamp_floor_ceil_round_minute() {
+    let t = ts(9, 30, 29, 999_999_999);
+    assert_eq!(t.floor(TimeUnit::Minute).unwrap(), ts(9, 30, 0, 0));
+    assert_eq!(t.ceil(TimeUnit::Minute).unwrap(), ts(9, 31, 0, 0));
+    assert_eq!(t.round(TimeUnit::Minute).unwrap(), ts(9, 30, 0, 0));
+    assert_eq!(ts(9, 30, 30, 0).round(TimeUnit::Minute).unwrap(), ts(9, 31, 0, 0));
+}
+
+#[test]
+fn test_timestamp_ceil_exact_is_identity() {
+    let t = ts(9, 30, 0, 0);
+    assert_eq!(t.ceil(TimeUnit::Hour).unwrap(), ts(10, 0, 0, 0));
+    assert_eq!(t.ceil(TimeUnit::Minute).unwrap(), t);
+    assert_eq!(t.floor(TimeUnit::Nanosecond).unwrap(), t);
+}
+
+#[test]
+fn test_timestamp_floor_before_epoch() {
+    let t = Timestamp::from_ymd_hms(1969, 12, 31, 18, 0, 0).unwrap();
+    let d = t.floor(TimeUnit::Day).unwrap();
+    assert_eq!(d.date().ymd(), (1969, 12, 31));
+    assert_eq!(d.hms(), (0, 0, 0, 0));
+}
+
+#[test]
+fn test_timestamp_vector_round_with_nulls() {
+    let tv = TimestampVector::from_nanos_with_validity(
+        vec![ts(9, 30, 0, 1_500_000).nanos(), 7],
+        bitvec![1, 0],
+    ).unwrap();
+
+    let out = tv.round(TimeUnit::Millisecond);
+    assert_eq!(out.get(0), Some(ts(9, 30, 0, 2_000_000)));
+    assert_eq!(out.get(1), None);
+
+    assert_eq!(tv.floor(TimeUnit::Second).get(0), Some(ts(9, 30, 0, 0)));
+    assert_eq!(tv.ceil(TimeUnit::Second).get(0), Some(ts(9, 30, 1, 0)));
+}
+
+#[test]
+fn test_timestamp_round_near_i64_limits() {
+    let hi = TimestampVector::from_nanos(vec![i64::MAX, i64::MIN, 0]);
+
+    let up = hi.ceil(TimeUnit::Day);
+    assert_eq!(up.validity().to_vec(), bitvec![0, 1, 1]);
+    let down = hi.floor(TimeUnit::Second);
+    assert_eq!(down.validity().to_vec(), bitvec![1, 0, 1]);
+    let near = hi.round(TimeUnit::Hour);
+    assert!(!near.validity()[0]);
+    assert!(near.validity()[2]);
+
+    let t = TimestampVector::from_nanos(vec![i64::MAX]).get(0).unwrap();
+    assert!(t.ceil(TimeUnit::Second).is_err());
+    assert!(t.round(TimeUnit::Day).is_err());
+    assert!(t.floor(TimeUnit::Day).is_ok());
+}
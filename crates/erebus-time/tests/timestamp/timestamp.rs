@@ -0,0 +1,120 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Tests ===
+
+#[test]
+fn test_timestamp_from_ymd_hms() {
+    let ts = Timestamp::from_ymd_hms(1970, 1, 2, 0, 0, 1).unwrap();
+    assert_eq!(ts.nanos(), NANOS_PER_DAY + NANOS_PER_SECOND);
+}
+
+#[test]
+fn test_timestamp_before_epoch() {
+    let ts = Timestamp::from_ymd_hms_nano(1969, 12, 31, 23, 59, 59, 500).unwrap();
+    assert_eq!(ts.nanos(), -NANOS_PER_SECOND + 500);
+    assert_eq!(ts.date().ymd(), (1969, 12, 31));
+    assert_eq!(ts.hms(), (23, 59, 59, 500));
+}
+
+#[test]
+fn test_timestamp_from_date() {
+    let d = Date::from_ymd(2024, 3, 15).unwrap();
+    let ts = Timestamp::from_date(d).unwrap();
+    assert_eq!(ts.date(), d);
+    assert_eq!(ts.nanos_of_day(), 0);
+}
+
+#[test]
+fn test_timestamp_invalid_time() {
+    let d = Date::from_ymd(2024, 3, 15).unwrap();
+    match Timestamp::from_date_hms(d, 24, 0, 0, 0).unwrap_err() {
+        ErebusError::InvalidTime { hour, .. } => assert_eq!(hour, 24),
+        other => panic!("Unexpected error: {:?}", other),
+    }
+    assert!(Timestamp::from_date_hms(d, 0, 0, 0, 1_000_000_000).is_err());
+}
+
+#[test]
+fn test_timestamp_invalid_date() {
+    let err = Timestamp::from_ymd_hms(2023, 2, 29, 0, 0, 0).unwrap_err();
+    assert!(matches!(err, ErebusError::InvalidDay { .. }));
+}
+
+#[test]
+fn test_timestamp_vector_from_date_hms_vectors_with_nulls() {
+    let dates = DateVector::from_ymd_vectors(
+        &[2024, 2023, 2024],
+        &[3,    2,    3],
+        &[15,   29,   15],
+    ).unwrap();
+
+    let tv = TimestampVector::from_date_hms_vectors(
+        &dates,
+        &[9, 9, 25],
+        &[30, 30, 0],
+        &[0, 0, 0],
+        &[0, 0, 0],
+    ).unwrap();
+
+    assert_eq!(tv.len(), 3);
+    assert!(tv.is_valid_at(0).unwrap());
+    assert!(!tv.is_valid_at(1).unwrap()); // invalid date
+    assert!(!tv.is_valid_at(2).unwrap()); // invalid hour
+    assert_eq!(tv.get(0).unwrap().hms(), (9, 30, 0, 0));
+    assert_eq!(tv.get(1), None);
+}
+
+#[test]
+fn test_timestamp_vector_length_mismatch() {
+    let dates = DateVector::from_ymd_vectors(&[2024], &[1], &[1]).unwrap();
+    let err = TimestampVector::from_date_hms_vectors(&dates, &[0, 0], &[0], &[0], &[0]).unwrap_err();
+    match err {
+        ErebusError::LengthMismatch { expected, found } => {
+            assert_eq!(expected, 1);
+            assert_eq!(found, 2);
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
+
+#[test]
+fn test_timestamp_vector_date_round_trip() {
+    let dates = DateVector::from_ymd_vectors(
+        &[2024, 2023, 1969],
+        &[3,    2,    12],
+        &[15,   30,   31],
+    ).unwrap();
+
+    let tv = TimestampVector::from_date_vector(&dates);
+    let back = tv.to_date_vector();
+
+    assert_eq!(back.ymd(), dates.ymd());
+    assert!(!back.is_valid_at(1).unwrap());
+}
+
+#[test]
+fn test_timestamp_vector_from_nanos_with_validity() {
+    let tv = TimestampVector::from_nanos_with_validity(vec![0, 1], bitvec![1, 0]).unwrap();
+    assert_eq!(tv.get(0), Some(Timestamp::from_nanos(0)));
+    assert_eq!(tv.get(1), None);
+
+    assert!(TimestampVector::from_nanos_with_validity(vec![0], bitvec![1, 0]).is_err());
+}
+
+#[test]
+fn test_timestamp_outside_i64_range() {
+    // i64 nanoseconds cover roughly 1677-09-21 to 2262-04-11
+    let far = Date::from_ymd(3000, 1, 1).unwrap();
+    assert!(Timestamp::from_date(far).is_err());
+    assert!(Timestamp::from_ymd_hms(3000, 1, 1, 0, 0, 0).is_err());
+    assert!(Timestamp::from_ymd_hms(1600, 1, 1, 0, 0, 0).is_err());
+    assert!(Timestamp::from_ymd_hms(2262, 4, 11, 0, 0, 0).is_ok());
+
+    let dates = DateVector::from_ymd_vectors(&[2024, 3000], &[1, 1], &[1, 1]).unwrap();
+    let tv = TimestampVector::from_date_vector(&dates);
+    assert_eq!(tv.validity().to_vec(), bitvec![1, 0]);
+
+    let tv = TimestampVector::from_date_hms_vectors(&dates, &[9, 9], &[0, 0], &[0, 0], &[0, 0]).unwrap();
+    assert_eq!(tv.validity().to_vec(), bitvec![1, 0]);
+}
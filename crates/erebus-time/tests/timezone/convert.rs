@@ -0,0 +1,68 @@
+// === Imports ===
+use erebus_time::prelude::*;
+use super::tzif::eastern;
+
+// === Helpers ===
+
+fn ns(y: i32, m: u8, d: u8, hh: u8, mm: u8, ss: u8) -> i64 {
+    Timestamp::from_ymd_hms(y, m, d, hh, mm, ss).unwrap().nanos()
+}
+
+// === Tests ===
+
+#[test]
+fn test_utc_to_local_vector() {
+    let tz = eastern();
+    let utc = VectorData {
+        data: vec![ns(2024, 1, 1, 12, 0, 0), 0, ns(2024, 7, 1, 12, 0, 0), ns(2024, 7, 2, 12, 0, 0)],
+        validity: bitvec![1, 0, 1, 1],
+    };
+    let local = tz.utc_to_local(&utc);
+    assert_eq!(local.validity, bitvec![1, 0, 1, 1]);
+    assert_eq!(local.data[0], ns(2024, 1, 1, 7, 0, 0));
+    assert_eq!(local.data[2], ns(2024, 7, 1, 8, 0, 0));
+    assert_eq!(local.data[3], ns(2024, 7, 2, 8, 0, 0));
+}
+
+#[test]
+fn test_local_to_utc_vector_across_transition() {
+    let tz = eastern();
+    let local = VectorData {
+        data: vec![
+            ns(2024, 3, 1, 16, 0, 0),
+            ns(2024, 3, 9, 16, 0, 0),
+            ns(2024, 3, 10, 2, 30, 0),
+            ns(2024, 3, 11, 16, 0, 0),
+            ns(2024, 11, 3, 1, 30, 0),
+        ],
+        validity: bitvec![1; 5],
+    };
+
+    let utc = tz.local_to_utc(&local, Disambiguate::Null).unwrap();
+    assert_eq!(utc.validity, bitvec![1, 1, 0, 1, 0]);
+    assert_eq!(utc.data[0], ns(2024, 3, 1, 21, 0, 0));
+    assert_eq!(utc.data[1], ns(2024, 3, 9, 21, 0, 0));
+    assert_eq!(utc.data[3], ns(2024, 3, 11, 20, 0, 0));
+
+    let utc = tz.local_to_utc(&local, Disambiguate::Latest).unwrap();
+    assert_eq!(utc.data[2], ns(2024, 3, 10, 7, 0, 0));
+    assert_eq!(utc.data[4], ns(2024, 11, 3, 6, 30, 0));
+
+    assert!(tz.local_to_utc(&local, Disambiguate::Raise).is_err());
+}
+
+#[test]
+fn test_timestamp_vector_round_trip() {
+    let tz = eastern();
+    let utc = TimestampVector::from_nanos((0..500).map(|h| ns(2024, 1, 1, 0, 0, 0) + h * 13 * NANOS_PER_HOUR).collect());
+    let back = utc.to_local(&tz).to_utc(&tz, Disambiguate::Earliest).unwrap();
+
+    // Every instant maps back except the repeated fall-back hour under Earliest
+    for i in 0..utc.len() {
+        let u = utc.nanos()[i];
+        let repeated = u >= ns(2024, 11, 3, 6, 0, 0) && u < ns(2024, 11, 3, 7, 0, 0);
+        if !repeated {
+            assert_eq!(back.nanos()[i], u);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod convert;
+mod timezone;
+mod tzif;
\ No newline at end of file
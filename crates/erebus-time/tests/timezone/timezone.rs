@@ -0,0 +1,140 @@
+// === Imports ===
+use erebus_time::prelude::*;
+use super::tzif::{build_tzif, eastern};
+
+// === Helpers ===
+
+fn ts(y: i32, m: u8, d: u8, hh: u8, mm: u8, ss: u8) -> Timestamp {
+    Timestamp::from_ymd_hms(y, m, d, hh, mm, ss).unwrap()
+}
+
+// === Tests ===
+
+#[test]
+fn test_timezone_utc_and_fixed() {
+    let t = ts(2024, 6, 1, 12, 0, 0);
+    assert_eq!(TimeZone::utc().to_local(t), t);
+
+    let tz = TimeZone::fixed("+05:30", 19800);
+    assert_eq!(tz.to_local(t), ts(2024, 6, 1, 17, 30, 0));
+    assert_eq!(tz.to_utc(ts(2024, 6, 1, 17, 30, 0), Disambiguate::Raise).unwrap(), Some(t));
+}
+
+#[test]
+fn test_timezone_offsets_around_transitions() {
+    let tz = eastern();
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 1, 15, 12, 0, 0)), -18000);
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 3, 10, 6, 59, 59)), -18000);
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 3, 10, 7, 0, 0)), -14400);
+    assert!(tz.is_dst_at(ts(2024, 7, 4, 0, 0, 0)));
+    assert!(!tz.is_dst_at(ts(2024, 11, 3, 6, 0, 0)));
+}
+
+#[test]
+fn test_timezone_before_first_transition() {
+    let tz = eastern();
+    assert_eq!(tz.utc_offset_seconds(ts(1900, 1, 1, 0, 0, 0)), -18000);
+}
+
+#[test]
+fn test_timezone_footer_rule_future_years() {
+    let tz = eastern();
+
+    // 2030: DST from Mar 10 to Nov 3; 2100: Mar 14 to Nov 7
+    assert_eq!(tz.utc_offset_seconds(ts(2030, 3, 10, 6, 59, 59)), -18000);
+    assert_eq!(tz.utc_offset_seconds(ts(2030, 3, 10, 7, 0, 0)), -14400);
+    assert_eq!(tz.utc_offset_seconds(ts(2030, 11, 3, 5, 59, 59)), -14400);
+    assert_eq!(tz.utc_offset_seconds(ts(2030, 11, 3, 6, 0, 0)), -18000);
+    assert_eq!(tz.utc_offset_seconds(ts(2100, 3, 14, 7, 0, 0)), -14400);
+    assert_eq!(tz.utc_offset_seconds(ts(2100, 12, 31, 23, 0, 0)), -18000);
+}
+
+#[test]
+fn test_timezone_southern_hemisphere_rule() {
+    // Sydney: DST from first Sunday of October to first Sunday of April
+    let bytes = build_tzif(&[(36000, false, "AEST")], &[], "AEST-10AEDT,M10.1.0,M4.1.0/3");
+    let tz = TimeZone::from_tzif_bytes("Test/Sydney", &bytes).unwrap();
+
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 1, 15, 0, 0, 0)), 39600);
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 6, 15, 0, 0, 0)), 36000);
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 12, 15, 0, 0, 0)), 39600);
+    // 2024-10-06 02:00 AEST = 2024-10-05 16:00 UTC
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 10, 5, 15, 59, 59)), 36000);
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 10, 5, 16, 0, 0)), 39600);
+}
+
+#[test]
+fn test_timezone_nonexistent_local_time() {
+    let tz = eastern();
+    let local = ts(2024, 3, 10, 2, 30, 0);
+    let gap = ts(2024, 3, 10, 7, 0, 0);
+
+    assert_eq!(tz.to_utc(local, Disambiguate::Latest).unwrap(), Some(gap));
+    assert_eq!(
+        tz.to_utc(local, Disambiguate::Earliest).unwrap(),
+        Some(Timestamp::from_nanos(gap.nanos() - 1)),
+    );
+    assert_eq!(tz.to_utc(local, Disambiguate::Null).unwrap(), None);
+    assert!(matches!(
+        tz.to_utc(local, Disambiguate::Raise),
+        Err(ErebusError::NonExistentLocalTime(_)),
+    ));
+}
+
+#[test]
+fn test_timezone_ambiguous_local_time() {
+    let tz = eastern();
+    let local = ts(2024, 11, 3, 1, 30, 0);
+
+    assert_eq!(tz.to_utc(local, Disambiguate::Earliest).unwrap(), Some(ts(2024, 11, 3, 5, 30, 0)));
+    assert_eq!(tz.to_utc(local, Disambiguate::Latest).unwrap(), Some(ts(2024, 11, 3, 6, 30, 0)));
+    assert_eq!(tz.to_utc(local, Disambiguate::Null).unwrap(), None);
+    assert!(matches!(
+        tz.to_utc(local, Disambiguate::Raise),
+        Err(ErebusError::AmbiguousLocalTime(_)),
+    ));
+}
+
+#[test]
+fn test_timezone_round_trip_keeps_subseconds() {
+    let tz = eastern();
+    let utc = Timestamp::from_nanos(ts(2024, 7, 1, 20, 0, 0).nanos() + 123_456_789);
+    let local = tz.to_local(utc);
+    assert_eq!(local.hms(), (16, 0, 0, 123_456_789));
+    assert_eq!(tz.to_utc(local, Disambiguate::Raise).unwrap(), Some(utc));
+}
+
+#[test]
+fn test_timezone_from_system_invalid_name() {
+    assert!(TimeZone::from_system("../etc/passwd").is_err());
+    assert!(TimeZone::from_system("No/Such_Zone").is_err());
+}
+
+#[test]
+fn test_timezone_system_new_york() {
+    let tz = match TimeZone::from_system("America/New_York") {
+        Ok(tz) => tz,
+        Err(_) => return, // no zoneinfo database installed
+    };
+
+    // NYSE close, 16:00 local, in winter and summer
+    let winter = tz.to_utc(ts(2024, 1, 16, 16, 0, 0), Disambiguate::Raise).unwrap().unwrap();
+    let summer = tz.to_utc(ts(2024, 7, 16, 16, 0, 0), Disambiguate::Raise).unwrap().unwrap();
+    assert_eq!(winter, ts(2024, 1, 16, 21, 0, 0));
+    assert_eq!(summer, ts(2024, 7, 16, 20, 0, 0));
+
+    assert!(tz.to_utc(ts(2024, 3, 10, 2, 30, 0), Disambiguate::Raise).is_err());
+    assert!(tz.to_utc(ts(2024, 11, 3, 1, 30, 0), Disambiguate::Raise).is_err());
+    assert_eq!(tz.utc_offset_seconds(ts(2200, 7, 1, 0, 0, 0)), -14400);
+}
+
+#[test]
+fn test_timezone_from_posix_rule() {
+    let tz = TimeZone::from_posix("America/New_York", "EST5EDT,M3.2.0,M11.1.0").unwrap();
+    assert_eq!(tz.name(), "America/New_York");
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 1, 15, 12, 0, 0)), -5 * 3600);
+    assert_eq!(tz.utc_offset_seconds(ts(2024, 7, 15, 12, 0, 0)), -4 * 3600);
+    assert!(tz.is_dst_at(ts(2024, 7, 15, 12, 0, 0)));
+
+    assert!(TimeZone::from_posix("bad", "not a rule").is_err());
+}
@@ -0,0 +1,99 @@
+// === Imports ===
+use erebus_time::prelude::*;
+
+// === Helpers ===
+
+/// Builds a TZif v2 file with the given `(utoff, is_dst, abbr)` types,
+/// `(utc_secs, type_index)` transitions and POSIX footer.
+pub(crate) fn build_tzif(types: &[(i32, bool, &str)], transitions: &[(i64, u8)], footer: &str) -> Vec<u8> {
+    let mut chars = Vec::new();
+    let mut abbr_idx = Vec::new();
+    for (_, _, abbr) in types {
+        abbr_idx.push(chars.len() as u8);
+        chars.extend_from_slice(abbr.as_bytes());
+        chars.push(0);
+    }
+
+    let header = |out: &mut Vec<u8>, timecnt: usize, typecnt: usize, charcnt: usize| {
+        out.extend_from_slice(b"TZif2");
+        out.extend_from_slice(&[0u8; 15]);
+        for c in [0, 0, 0, timecnt, typecnt, charcnt] {
+            out.extend_from_slice(&(c as u32).to_be_bytes());
+        }
+    };
+
+    let mut out = Vec::new();
+
+    // Minimal v1 block: a single UTC type
+    header(&mut out, 0, 1, 4);
+    out.extend_from_slice(&[0, 0, 0, 0, 0, 0]);
+    out.extend_from_slice(b"UTC\0");
+
+    header(&mut out, transitions.len(), types.len(), chars.len());
+    for (t, _) in transitions {
+        out.extend_from_slice(&t.to_be_bytes());
+    }
+    for (_, i) in transitions {
+        out.push(*i);
+    }
+    for (k, (utoff, is_dst, _)) in types.iter().enumerate() {
+        out.extend_from_slice(&utoff.to_be_bytes());
+        out.push(*is_dst as u8);
+        out.push(abbr_idx[k]);
+    }
+    out.extend_from_slice(&chars);
+
+    out.push(b'\n');
+    out.extend_from_slice(footer.as_bytes());
+    out.push(b'\n');
+    out
+}
+
+/// US Eastern with the 2024 transitions and the US footer rule.
+pub(crate) fn eastern() -> TimeZone {
+    let spring = Timestamp::from_ymd_hms(2024, 3, 10, 7, 0, 0).unwrap().nanos() / NANOS_PER_SECOND;
+    let fall = Timestamp::from_ymd_hms(2024, 11, 3, 6, 0, 0).unwrap().nanos() / NANOS_PER_SECOND;
+    let bytes = build_tzif(
+        &[(-18000, false, "EST"), (-14400, true, "EDT")],
+        &[(spring, 1), (fall, 0)],
+        "EST5EDT,M3.2.0,M11.1.0",
+    );
+    TimeZone::from_tzif_bytes("Test/Eastern", &bytes).unwrap()
+}
+
+// === Tests ===
+
+#[test]
+fn test_tzif_parse_valid() {
+    let tz = eastern();
+    assert_eq!(tz.name(), "Test/Eastern");
+}
+
+#[test]
+fn test_tzif_bad_magic() {
+    let mut bytes = build_tzif(&[(0, false, "UTC")], &[], "UTC0");
+    bytes[0] = b'X';
+    assert!(TimeZone::from_tzif_bytes("X", &bytes).is_err());
+}
+
+#[test]
+fn test_tzif_truncated() {
+    let bytes = build_tzif(&[(0, false, "UTC")], &[(0, 0)], "UTC0");
+    for cut in [10, 44, bytes.len() - 1] {
+        assert!(TimeZone::from_tzif_bytes("X", &bytes[..cut]).is_err());
+    }
+}
+
+#[test]
+fn test_tzif_bad_footer() {
+    let bytes = build_tzif(&[(0, false, "UTC")], &[], "EST5EDT,M3.2.0");
+    assert!(TimeZone::from_tzif_bytes("X", &bytes).is_err());
+}
+
+#[test]
+fn test_tzif_no_footer_keeps_last_offset() {
+    let bytes = build_tzif(&[(3600, false, "CET"), (7200, false, "EET")], &[(0, 1)], "");
+    let tz = TimeZone::from_tzif_bytes("X", &bytes).unwrap();
+    assert_eq!(tz.utc_offset_seconds(Timestamp::from_nanos(-1)), 3600);
+    assert_eq!(tz.utc_offset_seconds(Timestamp::from_nanos(NANOS_PER_DAY * 20_000)), 7200);
+}
@@ -0,0 +1,100 @@
+// === Imports ===
+use crate::prelude::*;
+use super::words::{bits_from_words, pack_word, WORD_BITS};
+
+// === Types ===
+
+/// Bit-packed boolean column: one bit per value plus a validity bitmap.
+/// Values under a null are unspecified; every operation masks them out.
+#[derive(Debug, Clone)]
+pub struct BitmaskData {
+    pub values: BitVec,    // 1 = true, 0 = false
+    pub validity: BitVec,  // 1 = valid, 0 = null
+}
+
+// === Impl ===
+
+impl BitmaskData {
+
+    /// Create an empty BitmaskData.
+    #[inline]
+    pub fn empty() -> Self {
+        Self { values: BitVec::new(), validity: BitVec::new() }
+    }
+
+    /// `n` valid copies of `value`.
+    #[inline]
+    pub fn full(value: bool, n: usize) -> Self {
+        Self { values: BitVec::repeat(value, n), validity: bitvec![1; n] }
+    }
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _from_bits(values: BitVec, validity: BitVec) -> Self {
+        debug_assert_eq!(values.len(), validity.len(), "Validity length mismatch");
+        Self { values, validity }
+    }
+
+    /// Creates a new [`BitmaskData`] safely.
+    /// Returns an error if values and validity lengths mismatch.
+    #[inline]
+    pub fn from_bits(values: BitVec, validity: BitVec) -> ErrorResult<Self> {
+        if values.len() != validity.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: values.len(),
+                found: validity.len(),
+            });
+        }
+        Ok(Self::_from_bits(values, validity))
+    }
+
+    /// All-valid mask from plain bools.
+    #[inline]
+    pub fn from_bools(values: &[bool]) -> Self {
+        let n = values.len();
+        let words = values.chunks(WORD_BITS).map(pack_word);
+        Self { values: bits_from_words(words, n), validity: bitvec![1; n] }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get null count in the mask
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        self.validity.count_zeros()
+    }
+
+    /// Value at `i`, `None` when null or out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<bool> {
+        match self.validity.get(i) {
+            Some(v) if *v => Some(self.values[i]),
+            _ => None,
+        }
+    }
+
+    /// Track memory usage
+    pub fn memory_usage(&self) -> usize {
+        std::mem::size_of_val(self)
+            + (self.values.as_raw_slice().len() + self.validity.as_raw_slice().len())
+                * std::mem::size_of::<usize>()
+    }
+}
+
+/// Equal when validity matches and every valid value matches;
+/// bits under nulls are ignored.
+impl PartialEq for BitmaskData {
+    fn eq(&self, other: &Self) -> bool {
+        self.validity == other.validity
+            && self.values.len() == other.values.len()
+            && self.validity.iter_ones().all(|i| self.values[i] == other.values[i])
+    }
+}
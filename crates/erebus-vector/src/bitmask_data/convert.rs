@@ -0,0 +1,53 @@
+// === Imports ===
+use crate::prelude::*;
+use super::words::{bits_from_words, pack_word, WORD_BITS};
+
+// === Impl ===
+
+impl From<&VectorData<bool>> for BitmaskData {
+    fn from(v: &VectorData<bool>) -> Self {
+        let n = v.data.len();
+        let words = v.data.chunks(WORD_BITS).map(pack_word);
+        BitmaskData {
+            values: bits_from_words(words, n),
+            validity: v.validity.clone(),
+        }
+    }
+}
+
+impl From<VectorData<bool>> for BitmaskData {
+    fn from(v: VectorData<bool>) -> Self {
+        BitmaskData::from(&v)
+    }
+}
+
+impl From<&BitmaskData> for VectorData<bool> {
+    fn from(m: &BitmaskData) -> Self {
+        VectorData {
+            data: m.values.iter().by_vals().collect(),
+            validity: m.validity.clone(),
+        }
+    }
+}
+
+impl From<BitmaskData> for VectorData<bool> {
+    fn from(m: BitmaskData) -> Self {
+        VectorData::from(&m)
+    }
+}
+
+impl BitmaskData {
+    /// Unpacks into a byte-per-value [`VectorData<bool>`].
+    #[inline]
+    pub fn to_vector_data(&self) -> VectorData<bool> {
+        VectorData::from(self)
+    }
+}
+
+impl VectorData<bool> {
+    /// Packs into a [`BitmaskData`].
+    #[inline]
+    pub fn to_bitmask(&self) -> BitmaskData {
+        BitmaskData::from(self)
+    }
+}
@@ -0,0 +1,32 @@
+// === Imports ===
+use crate::prelude::*;
+use super::words::{load_word, WORD_BITS};
+
+// === Impl ===
+
+impl BitmaskData {
+    /// Calls `f` with the index of every valid `true`, skipping empty words.
+    #[inline]
+    pub(crate) fn for_each_true(&self, mut f: impl FnMut(usize)) {
+        let chunks = self.values.chunks(WORD_BITS).zip(self.validity.chunks(WORD_BITS));
+        for (k, (v, m)) in chunks.enumerate() {
+            let mut word = load_word(v) & load_word(m);
+            while word != 0 {
+                f(k * WORD_BITS + word.trailing_zeros() as usize);
+                word &= word - 1;
+            }
+        }
+    }
+
+    /// Return indices where the value is true. Nulls are skipped.
+    pub fn arg_true(&self) -> Vec<usize> {
+        let mut out = Vec::with_capacity(self.count_true());
+        self.for_each_true(|i| out.push(i));
+        out
+    }
+
+    /// Return indices where the value is false. Nulls are skipped.
+    pub fn arg_false(&self) -> Vec<usize> {
+        self.not().arg_true()
+    }
+}
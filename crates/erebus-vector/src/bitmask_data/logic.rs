@@ -0,0 +1,165 @@
+// === Imports ===
+use crate::prelude::*;
+use super::words::{bits_from_words, load_word, WORD_BITS};
+
+// === Impl ===
+
+impl BitmaskData {
+    /// Applies `f(value_a, valid_a, value_b, valid_b) -> (value, valid)` 64 rows at a time.
+    /// Values are masked by validity before `f` sees them.
+    #[inline]
+    fn kleene_words(&self, other: &Self, f: impl Fn(u64, u64, u64, u64) -> (u64, u64)) -> Self {
+        let n = self.len();
+        let words: Vec<(u64, u64)> = self.values.chunks(WORD_BITS)
+            .zip(self.validity.chunks(WORD_BITS))
+            .zip(other.values.chunks(WORD_BITS).zip(other.validity.chunks(WORD_BITS)))
+            .map(|((va, ma), (vb, mb))| {
+                let (ma, mb) = (load_word(ma), load_word(mb));
+                let (v, m) = f(load_word(va) & ma, ma, load_word(vb) & mb, mb);
+                (v & m, m)
+            })
+            .collect();
+
+        BitmaskData {
+            values: bits_from_words(words.iter().map(|w| w.0), n),
+            validity: bits_from_words(words.iter().map(|w| w.1), n),
+        }
+    }
+
+    #[inline]
+    fn check_same_len(&self, other: &Self) -> ErrorResult<()> {
+        if self.len() != other.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: self.len(),
+                found: other.len(),
+            });
+        }
+        Ok(())
+    }
+
+    // -- And --
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _and(&self, other: &Self) -> Self {
+        // Known when both are valid, or either side is a valid false.
+        self.kleene_words(other, |va, ma, vb, mb| {
+            (va & vb, (ma & mb) | (ma & !va) | (mb & !vb))
+        })
+    }
+
+    /// Kleene AND: `false AND null = false`, `true AND null = null`.
+    #[inline]
+    pub fn and(&self, other: &Self) -> ErrorResult<Self> {
+        self.check_same_len(other)?;
+        Ok(self._and(other))
+    }
+
+    // -- Or --
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _or(&self, other: &Self) -> Self {
+        // Known when both are valid, or either side is a valid true.
+        self.kleene_words(other, |va, ma, vb, mb| {
+            (va | vb, (ma & mb) | va | vb)
+        })
+    }
+
+    /// Kleene OR: `true OR null = true`, `false OR null = null`.
+    #[inline]
+    pub fn or(&self, other: &Self) -> ErrorResult<Self> {
+        self.check_same_len(other)?;
+        Ok(self._or(other))
+    }
+
+    // -- Xor --
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _xor(&self, other: &Self) -> Self {
+        self.kleene_words(other, |va, ma, vb, mb| (va ^ vb, ma & mb))
+    }
+
+    /// Kleene XOR: null whenever either side is null.
+    #[inline]
+    pub fn xor(&self, other: &Self) -> ErrorResult<Self> {
+        self.check_same_len(other)?;
+        Ok(self._xor(other))
+    }
+
+    // -- And not --
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _and_not(&self, other: &Self) -> Self {
+        // a AND (NOT b): a valid true in `other` forces false.
+        self.kleene_words(other, |va, ma, vb, mb| {
+            (va & !vb & mb, (ma & mb) | (ma & !va) | vb)
+        })
+    }
+
+    /// Kleene `self AND NOT other`.
+    #[inline]
+    pub fn and_not(&self, other: &Self) -> ErrorResult<Self> {
+        self.check_same_len(other)?;
+        Ok(self._and_not(other))
+    }
+
+    // -- Not --
+
+    /// Flips every value; nulls stay null.
+    #[inline]
+    pub fn not(&self) -> Self {
+        BitmaskData {
+            values: !self.values.clone(),
+            validity: self.validity.clone(),
+        }
+    }
+}
+
+// -- Operator overloads --
+
+macro_rules! impl_bitmask_overload {
+    ($trait:ident, $method:ident, $inner:ident) => {
+        impl std::ops::$trait for BitmaskData {
+            type Output = BitmaskData;
+            /// # Panics
+            /// Panics if the lengths differ; use `and`/`or`/`xor` for a checked result.
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<'a> std::ops::$trait<&'a BitmaskData> for &'a BitmaskData {
+            type Output = BitmaskData;
+            /// # Panics
+            /// Panics if the lengths differ; use `and`/`or`/`xor` for a checked result.
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$inner(rhs).expect(concat!("BitmaskData ", stringify!($method), ": length mismatch"))
+            }
+        }
+    };
+}
+
+impl_bitmask_overload!(BitAnd, bitand, and);
+impl_bitmask_overload!(BitOr, bitor, or);
+impl_bitmask_overload!(BitXor, bitxor, xor);
+
+impl std::ops::Not for BitmaskData {
+    type Output = BitmaskData;
+    #[inline]
+    fn not(self) -> Self::Output {
+        BitmaskData::not(&self)
+    }
+}
+
+impl std::ops::Not for &BitmaskData {
+    type Output = BitmaskData;
+    #[inline]
+    fn not(self) -> Self::Output {
+        BitmaskData::not(self)
+    }
+}
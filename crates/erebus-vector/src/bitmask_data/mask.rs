@@ -0,0 +1,75 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Types ===
+
+/// Row selector accepted by `bool_index`.
+/// Plain bools select their `true` rows; [`BitmaskData`] selects its valid `true` rows.
+pub trait BoolMask {
+    /// Number of rows the mask covers.
+    fn mask_len(&self) -> usize;
+
+    /// Calls `f` with every selected row, in increasing order.
+    fn for_each_selected(&self, f: &mut dyn FnMut(usize));
+
+    /// Number of selected rows.
+    fn selected_count(&self) -> usize;
+}
+
+// === Impl ===
+
+impl BoolMask for [bool] {
+    #[inline]
+    fn mask_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn for_each_selected(&self, f: &mut dyn FnMut(usize)) {
+        for (i, &b) in self.iter().enumerate() {
+            if b {
+                f(i);
+            }
+        }
+    }
+
+    #[inline]
+    fn selected_count(&self) -> usize {
+        self.iter().filter(|&&b| b).count()
+    }
+}
+
+impl<const N: usize> BoolMask for [bool; N] {
+    #[inline]
+    fn mask_len(&self) -> usize { N }
+    #[inline]
+    fn for_each_selected(&self, f: &mut dyn FnMut(usize)) { self.as_slice().for_each_selected(f) }
+    #[inline]
+    fn selected_count(&self) -> usize { self.as_slice().selected_count() }
+}
+
+impl BoolMask for Vec<bool> {
+    #[inline]
+    fn mask_len(&self) -> usize { self.len() }
+    #[inline]
+    fn for_each_selected(&self, f: &mut dyn FnMut(usize)) { self.as_slice().for_each_selected(f) }
+    #[inline]
+    fn selected_count(&self) -> usize { self.as_slice().selected_count() }
+}
+
+impl BoolMask for BitmaskData {
+    #[inline]
+    fn mask_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn for_each_selected(&self, f: &mut dyn FnMut(usize)) {
+        self.for_each_true(f)
+    }
+
+    #[inline]
+    fn selected_count(&self) -> usize {
+        self.count_true()
+    }
+}
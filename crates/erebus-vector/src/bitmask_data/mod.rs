@@ -0,0 +1,11 @@
+pub mod bitmask_data;
+pub mod words;
+pub mod convert;
+pub mod logic;
+pub mod reduce;
+pub mod indexing;
+pub mod mask;
+
+// Re-export key types
+pub use bitmask_data::BitmaskData;
+pub use mask::BoolMask;
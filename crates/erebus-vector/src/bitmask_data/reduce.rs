@@ -0,0 +1,68 @@
+// === Imports ===
+use crate::prelude::*;
+use super::words::{load_word, low_mask, WORD_BITS};
+
+// === Impl ===
+
+impl BitmaskData {
+    /// Iterates `(values & validity, validity)` 64 rows at a time.
+    #[inline]
+    fn valid_words(&self) -> impl Iterator<Item = (u64, u64, usize)> + '_ {
+        self.values.chunks(WORD_BITS)
+            .zip(self.validity.chunks(WORD_BITS))
+            .map(|(v, m)| {
+                let m_word = load_word(m);
+                (load_word(v) & m_word, m_word, m.len())
+            })
+    }
+
+    /// Count valid true values.
+    #[inline]
+    pub fn count_true(&self) -> usize {
+        self.valid_words().map(|(v, _, _)| v.count_ones() as usize).sum()
+    }
+
+    /// Count valid false values.
+    #[inline]
+    pub fn count_false(&self) -> usize {
+        self.valid_words().map(|(v, m, _)| (!v & m).count_ones() as usize).sum()
+    }
+
+    /// All: true if ALL valid elements are true.
+    #[inline]
+    pub fn all(&self) -> bool {
+        self.valid_words().all(|(v, m, _)| v == m)
+    }
+
+    /// Any: true if ANY valid element is true.
+    #[inline]
+    pub fn any(&self) -> bool {
+        self.valid_words().any(|(v, _, _)| v != 0)
+    }
+
+    /// Kleene ANY: `Some(true)` if a valid value is true,
+    /// `None` if the answer depends on a null, `Some(false)` otherwise.
+    pub fn any_kleene(&self) -> Option<bool> {
+        let mut saw_null = false;
+        for (v, m, len) in self.valid_words() {
+            if v != 0 {
+                return Some(true);
+            }
+            saw_null |= m != low_mask(len);
+        }
+        if saw_null { None } else { Some(false) }
+    }
+
+    /// Kleene ALL: `Some(false)` if a valid value is false,
+    /// `None` if the answer depends on a null, `Some(true)` otherwise.
+    pub fn all_kleene(&self) -> Option<bool> {
+        let mut saw_null = false;
+        for (v, m, len) in self.valid_words() {
+            if v != m {
+                return Some(false);
+            }
+            saw_null |= m != low_mask(len);
+        }
+        if saw_null { None } else { Some(true) }
+    }
+}
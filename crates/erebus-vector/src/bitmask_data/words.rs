@@ -0,0 +1,36 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+
+pub(crate) const WORD_BITS: usize = 64;
+
+/// Mask with the low `len` bits set.
+#[inline]
+pub(crate) fn low_mask(len: usize) -> u64 {
+    if len >= WORD_BITS { u64::MAX } else { (1u64 << len) - 1 }
+}
+
+/// Packs up to 64 bools into the low bits of a word.
+#[inline]
+pub(crate) fn pack_word(bits: &[bool]) -> u64 {
+    bits.iter()
+        .enumerate()
+        .fold(0u64, |w, (j, &b)| w | ((b as u64) << j))
+}
+
+/// Loads a chunk of at most 64 bits, with bits past its end cleared.
+#[inline]
+pub(crate) fn load_word(chunk: &BitSlice) -> u64 {
+    chunk.load_le::<u64>() & low_mask(chunk.len())
+}
+
+/// Builds an `n`-bit `BitVec` from little-endian 64-bit words.
+#[inline]
+pub(crate) fn bits_from_words(words: impl IntoIterator<Item = u64>, n: usize) -> BitVec {
+    let mut bits = bitvec![0; n];
+    for (chunk, w) in bits.chunks_mut(WORD_BITS).zip(words) {
+        chunk.store_le(w);
+    }
+    bits
+}
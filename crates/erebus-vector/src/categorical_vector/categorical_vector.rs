@@ -0,0 +1,225 @@
+// === Imports ===
+use crate::prelude::*;
+use super::codes::{with_codes, CategoricalCodes, CodeInt};
+
+// === Types ===
+
+/// Dictionary-encoded string column: each distinct string is stored once
+/// in `dictionary` and rows hold a code into it, in the narrowest of
+/// u8/u16/u32. Codes under nulls are 0 and never looked up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CategoricalVector {
+    pub(crate) dictionary: Vec<String>,
+    pub(crate) codes: CategoricalCodes,
+    pub(crate) validity: BitVec,   // 1 = valid, 0 = null
+    pub(crate) lookup: AHashMap<String, u32>,
+}
+
+// === Impl ===
+
+#[inline]
+fn build_lookup(dictionary: &[String]) -> ErrorResult<AHashMap<String, u32>> {
+    if dictionary.len() > u32::MAX as usize {
+        return Err(ErebusError::InvalidOperation(
+            "Categorical dictionary exceeds u32 codes".into(),
+        ));
+    }
+    let mut lookup = AHashMap::with_capacity(dictionary.len());
+    for (i, s) in dictionary.iter().enumerate() {
+        if lookup.insert(s.clone(), i as u32).is_some() {
+            return Err(ErebusError::InvalidOperation(format!(
+                "Duplicate category '{}' in dictionary",
+                s
+            )));
+        }
+    }
+    Ok(lookup)
+}
+
+impl CategoricalVector {
+
+    /// Create an empty CategoricalVector.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            dictionary: Vec::new(),
+            codes: CategoricalCodes::U8(Vec::new()),
+            validity: BitVec::new(),
+            lookup: AHashMap::new(),
+        }
+    }
+
+    /// Empty column with a fixed initial dictionary, in category order.
+    /// Returns an error on duplicate categories.
+    pub fn with_categories(dictionary: Vec<String>) -> ErrorResult<Self> {
+        let lookup = build_lookup(&dictionary)?;
+        Ok(Self {
+            codes: CategoricalCodes::for_categories(dictionary.len()),
+            dictionary,
+            validity: BitVec::new(),
+            lookup,
+        })
+    }
+
+    /// Builds from a dictionary and one u32 code per row.
+    /// Returns an error on length mismatch, duplicate categories, or a
+    /// valid row whose code is outside the dictionary.
+    pub fn from_parts(dictionary: Vec<String>, codes: Vec<u32>, validity: BitVec) -> ErrorResult<Self> {
+        if codes.len() != validity.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: codes.len(),
+                found: validity.len(),
+            });
+        }
+        if let Some(i) = validity.iter_ones().find(|&i| codes[i] as usize >= dictionary.len()) {
+            return Err(ErebusError::IndexOutOfBounds {
+                index: codes[i] as usize,
+                size: dictionary.len(),
+            });
+        }
+        let lookup = build_lookup(&dictionary)?;
+
+        // Normalise codes under nulls so they stay addressable after narrowing.
+        let mut codes = codes;
+        for i in validity.iter_zeros() {
+            codes[i] = 0;
+        }
+
+        Ok(Self {
+            codes: CategoricalCodes::from_u32(codes, dictionary.len()),
+            dictionary,
+            validity,
+            lookup,
+        })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.validity.is_empty()
+    }
+
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        self.validity.count_zeros()
+    }
+
+    /// Number of dictionary entries, used or not.
+    #[inline]
+    pub fn n_categories(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    #[inline]
+    pub fn dictionary(&self) -> &[String] {
+        &self.dictionary
+    }
+
+    #[inline]
+    pub fn codes(&self) -> &CategoricalCodes {
+        &self.codes
+    }
+
+    #[inline]
+    pub fn validity(&self) -> &BitVec {
+        &self.validity
+    }
+
+    /// Code of `category`, `None` if it is not in the dictionary.
+    #[inline]
+    pub fn category_code(&self, category: &str) -> Option<u32> {
+        self.lookup.get(category).copied()
+    }
+
+    /// Code at `i`, `None` when null or out of bounds.
+    #[inline]
+    pub fn code(&self, i: usize) -> Option<u32> {
+        match self.validity.get(i) {
+            Some(v) if *v => self.codes.get(i),
+            _ => None,
+        }
+    }
+
+    /// Value at `i`, `None` when null or out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<&str> {
+        self.code(i).map(|c| self.dictionary[c as usize].as_str())
+    }
+
+    /// Code of `category`, adding it to the dictionary (and widening the
+    /// codes if needed) when new.
+    pub fn get_or_insert_category(&mut self, category: &str) -> u32 {
+        if let Some(&code) = self.lookup.get(category) {
+            return code;
+        }
+        let code = self.dictionary.len() as u32;
+        self.dictionary.push(category.to_string());
+        self.lookup.insert(category.to_string(), code);
+        self.codes.widen_for(self.dictionary.len());
+        code
+    }
+
+    /// Appends a value, or a null for `None`.
+    pub fn push(&mut self, value: Option<&str>) {
+        match value {
+            Some(s) => {
+                let code = self.get_or_insert_category(s);
+                self.codes.push(code);
+                self.validity.push(true);
+            }
+            None => {
+                self.codes.push(0);
+                self.validity.push(false);
+            }
+        }
+    }
+
+    /// Rows at `indices`, sharing the same dictionary.
+    pub(crate) fn take(&self, indices: &[usize]) -> Self {
+        Self {
+            dictionary: self.dictionary.clone(),
+            codes: self.codes.take(indices),
+            validity: indices.iter().map(|&i| self.validity[i]).collect(),
+            lookup: self.lookup.clone(),
+        }
+    }
+
+    /// Approximate heap bytes: codes, validity, dictionary strings and the
+    /// lookup map (its own key copies plus one control byte per bucket).
+    pub fn memory_usage(&self) -> usize {
+        let dict: usize = self.dictionary.iter().map(|s| s.capacity()).sum();
+        let keys: usize = self.lookup.keys().map(|s| s.capacity()).sum();
+        self.codes.memory_usage()
+            + self.validity.capacity() / 8
+            + dict
+            + self.dictionary.capacity() * std::mem::size_of::<String>()
+            + keys
+            + self.lookup.capacity() * (std::mem::size_of::<(String, u32)>() + 1)
+    }
+
+    /// Calls `f(row, code)` for every valid row.
+    #[inline]
+    pub(crate) fn for_each_valid_code<F: FnMut(usize, u32)>(&self, mut f: F) {
+        if self.validity.not_any() {
+            return;
+        }
+        let all_valid = self.validity.all();
+        with_codes!(&self.codes, v => {
+            if all_valid {
+                v.iter().enumerate().for_each(|(i, &c)| f(i, c.to_u32()));
+            } else {
+                self.validity.iter_ones().for_each(|i| f(i, v[i].to_u32()));
+            }
+        })
+    }
+}
+
+impl Default for CategoricalVector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,132 @@
+// === Types ===
+
+/// Dictionary codes stored in the narrowest unsigned width that can
+/// address every category. Widens in place as categories are added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CategoricalCodes {
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+// === Impl ===
+
+/// Runs `$body` with `$v` bound to the inner code vector, whatever its width.
+macro_rules! with_codes {
+    ($codes:expr, $v:ident => $body:expr) => {
+        match $codes {
+            CategoricalCodes::U8($v) => $body,
+            CategoricalCodes::U16($v) => $body,
+            CategoricalCodes::U32($v) => $body,
+        }
+    };
+}
+pub(crate) use with_codes;
+
+/// Code element types, widened losslessly to u32.
+pub(crate) trait CodeInt: Copy {
+    fn to_u32(self) -> u32;
+}
+
+macro_rules! impl_code_int {
+    ($($t:ty),*) => {
+        $(impl CodeInt for $t {
+            #[inline]
+            fn to_u32(self) -> u32 { self.into() }
+        })*
+    };
+}
+
+impl_code_int!(u8, u16, u32);
+
+impl CategoricalCodes {
+    /// Empty codes sized for a dictionary of `n_categories` entries.
+    #[inline]
+    pub fn for_categories(n_categories: usize) -> Self {
+        Self::from_u32(Vec::new(), n_categories)
+    }
+
+    /// Narrows `codes` to the width required by `n_categories`.
+    pub fn from_u32(codes: Vec<u32>, n_categories: usize) -> Self {
+        match Self::width_for(n_categories) {
+            1 => Self::U8(codes.into_iter().map(|c| c as u8).collect()),
+            2 => Self::U16(codes.into_iter().map(|c| c as u16).collect()),
+            _ => Self::U32(codes),
+        }
+    }
+
+    /// Bytes per code needed to address `n_categories` entries.
+    #[inline]
+    pub fn width_for(n_categories: usize) -> usize {
+        if n_categories <= 1 << 8 {
+            1
+        } else if n_categories <= 1 << 16 {
+            2
+        } else {
+            4
+        }
+    }
+
+    /// Bytes per code.
+    #[inline]
+    pub fn width(&self) -> usize {
+        match self {
+            Self::U8(_) => 1,
+            Self::U16(_) => 2,
+            Self::U32(_) => 4,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        with_codes!(self, v => v.len())
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<u32> {
+        with_codes!(self, v => v.get(i).map(|&c| c.to_u32()))
+    }
+
+    pub fn to_u32(&self) -> Vec<u32> {
+        with_codes!(self, v => v.iter().map(|&c| c.to_u32()).collect())
+    }
+
+    /// Widens in place so that a dictionary of `n_categories` fits. Never narrows.
+    pub fn widen_for(&mut self, n_categories: usize) {
+        if Self::width_for(n_categories) <= self.width() {
+            return;
+        }
+        let codes = std::mem::replace(self, Self::U8(Vec::new())).to_u32();
+        *self = Self::from_u32(codes, n_categories);
+    }
+
+    /// Appends `code`; the caller must have widened for it already.
+    #[inline]
+    pub(crate) fn push(&mut self, code: u32) {
+        match self {
+            Self::U8(v) => v.push(code as u8),
+            Self::U16(v) => v.push(code as u16),
+            Self::U32(v) => v.push(code),
+        }
+    }
+
+    /// Codes picked at `indices`, keeping the current width.
+    pub(crate) fn take(&self, indices: &[usize]) -> Self {
+        match self {
+            Self::U8(v) => Self::U8(indices.iter().map(|&i| v[i]).collect()),
+            Self::U16(v) => Self::U16(indices.iter().map(|&i| v[i]).collect()),
+            Self::U32(v) => Self::U32(indices.iter().map(|&i| v[i]).collect()),
+        }
+    }
+
+    /// Heap bytes used by the codes.
+    #[inline]
+    pub fn memory_usage(&self) -> usize {
+        self.len() * self.width()
+    }
+}
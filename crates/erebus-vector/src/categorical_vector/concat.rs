@@ -0,0 +1,34 @@
+// === Imports ===
+use crate::prelude::*;
+use super::codes::with_codes;
+
+// === Impl ===
+
+impl CategoricalVector {
+    /// Appends `other`, merging its dictionary into ours: shared categories
+    /// reuse their code, new ones are added at the end.
+    pub fn append(&mut self, other: &Self) {
+        let remap: Vec<u32> = other.dictionary.iter()
+            .map(|s| self.get_or_insert_category(s))
+            .collect();
+
+        with_codes!(&other.codes, v => {
+            for (&c, valid) in v.iter().zip(other.validity.iter().by_vals()) {
+                self.codes.push(if valid { remap[c as usize] } else { 0 });
+            }
+        });
+        self.validity.extend_from_bitslice(&other.validity);
+    }
+
+    /// Concatenates `parts` in order under a merged dictionary.
+    pub fn concat(parts: &[&Self]) -> Self {
+        let mut out = match parts.first() {
+            Some(first) => (*first).clone(),
+            None => return Self::new(),
+        };
+        for part in &parts[1..] {
+            out.append(part);
+        }
+        out
+    }
+}
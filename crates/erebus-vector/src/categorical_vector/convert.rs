@@ -0,0 +1,52 @@
+// === Imports ===
+use crate::prelude::*;
+use super::codes::with_codes;
+
+// === Impl ===
+
+impl CategoricalVector {
+    /// Dictionary-encodes `values`; categories are numbered in order of
+    /// first appearance.
+    pub fn from_vector_data(values: &VectorData<String>) -> Self {
+        let mut out = Self::new();
+        for (s, valid) in values.data.iter().zip(values.validity.iter().by_vals()) {
+            out.push(valid.then_some(s.as_str()));
+        }
+        out
+    }
+
+    /// Decodes back to one `String` per row; nulls become empty strings.
+    pub fn to_vector_data(&self) -> VectorData<String> {
+        let data = with_codes!(&self.codes, v => {
+            v.iter()
+                .zip(self.validity.iter().by_vals())
+                .map(|(&c, valid)| if valid { self.dictionary[c as usize].clone() } else { String::new() })
+                .collect()
+        });
+        VectorData {
+            data,
+            validity: self.validity.clone(),
+        }
+    }
+}
+
+impl VectorData<String> {
+    #[inline]
+    pub fn to_categorical(&self) -> CategoricalVector {
+        CategoricalVector::from_vector_data(self)
+    }
+}
+
+impl From<&VectorData<String>> for CategoricalVector {
+    #[inline]
+    fn from(values: &VectorData<String>) -> Self {
+        Self::from_vector_data(values)
+    }
+}
+
+impl From<&CategoricalVector> for VectorData<String> {
+    #[inline]
+    fn from(values: &CategoricalVector) -> Self {
+        values.to_vector_data()
+    }
+}
@@ -0,0 +1,11 @@
+pub mod codes;
+pub mod categorical_vector;
+pub mod convert;
+pub mod reduce;
+pub mod sort;
+pub mod concat;
+pub mod io;
+
+// Re-export key types
+pub use codes::CategoricalCodes;
+pub use categorical_vector::CategoricalVector;
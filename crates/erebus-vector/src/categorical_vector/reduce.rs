@@ -0,0 +1,78 @@
+// === Imports ===
+use crate::prelude::*;
+use super::codes::CategoricalCodes;
+
+// === Impl ===
+// All reductions run over the codes; strings are only touched for output.
+
+impl CategoricalVector {
+    /// Number of valid rows per dictionary entry, indexed by code.
+    pub fn category_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0usize; self.dictionary.len()];
+        self.for_each_valid_code(|_, c| counts[c as usize] += 1);
+        counts
+    }
+
+    /// Distinct non-null values.
+    #[inline]
+    pub fn n_unique(&self) -> usize {
+        self.category_counts().iter().filter(|&&n| n > 0).count()
+    }
+
+    #[inline]
+    pub fn n_distinct(&self) -> usize {
+        self.n_unique()
+    }
+
+    /// One row per distinct non-null value, in order of first appearance,
+    /// with the dictionary trimmed to those values.
+    pub fn unique(&self) -> Self {
+        let mut seen = vec![false; self.dictionary.len()];
+        let mut order = Vec::new();
+        self.for_each_valid_code(|_, c| {
+            if !std::mem::replace(&mut seen[c as usize], true) {
+                order.push(c);
+            }
+        });
+
+        let dictionary: Vec<String> = order.iter().map(|&c| self.dictionary[c as usize].clone()).collect();
+        let codes = (0..order.len() as u32).collect();
+        let validity = bitvec![1; order.len()];
+        Self::from_parts(dictionary, codes, validity).expect("unique categories are distinct")
+    }
+
+    /// `(value, count)` for every value present, most frequent first;
+    /// ties keep dictionary order.
+    pub fn value_counts(&self) -> Vec<(String, usize)> {
+        let counts = self.category_counts();
+        let mut present: Vec<usize> = (0..counts.len()).filter(|&c| counts[c] > 0).collect();
+        present.sort_by(|&a, &b| counts[b].cmp(&counts[a]));
+        present.into_iter().map(|c| (self.dictionary[c].clone(), counts[c])).collect()
+    }
+
+    /// Drops dictionary entries no valid row refers to, keeping the order
+    /// of the rest, and narrows the codes if possible.
+    pub fn remove_unused_categories(&self) -> Self {
+        let counts = self.category_counts();
+        let mut remap = vec![0u32; counts.len()];
+        let mut dictionary = Vec::new();
+        for (c, &n) in counts.iter().enumerate() {
+            if n > 0 {
+                remap[c] = dictionary.len() as u32;
+                dictionary.push(self.dictionary[c].clone());
+            }
+        }
+
+        let mut codes = vec![0u32; self.len()];
+        self.for_each_valid_code(|i, c| codes[i] = remap[c as usize]);
+
+        let n = dictionary.len();
+        let lookup = dictionary.iter().enumerate().map(|(i, s)| (s.clone(), i as u32)).collect();
+        Self {
+            dictionary,
+            codes: CategoricalCodes::from_u32(codes, n),
+            validity: self.validity.clone(),
+            lookup,
+        }
+    }
+}
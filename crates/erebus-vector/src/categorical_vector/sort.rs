@@ -0,0 +1,63 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+// Counting sorts over the codes: O(n + categories), stable, nulls last.
+
+impl CategoricalVector {
+    /// Stable counting sort of rows by `rank[code]`.
+    fn argsort_by_rank(&self, rank: &[u32], descending: bool) -> Vec<usize> {
+        let k = rank.len();
+        let key = |c: u32| {
+            let r = rank[c as usize] as usize;
+            if descending { k - 1 - r } else { r }
+        };
+
+        let mut offsets = vec![0usize; k + 1];
+        self.for_each_valid_code(|_, c| offsets[key(c) + 1] += 1);
+        for r in 0..k {
+            offsets[r + 1] += offsets[r];
+        }
+
+        let mut out = vec![0usize; self.len()];
+        self.for_each_valid_code(|i, c| {
+            let slot = &mut offsets[key(c)];
+            out[*slot] = i;
+            *slot += 1;
+        });
+
+        let valid = self.len() - self.null_count();
+        for (slot, i) in out[valid..].iter_mut().zip(self.validity.iter_zeros()) {
+            *slot = i;
+        }
+        out
+    }
+
+    /// Row order by dictionary (category) order.
+    pub fn argsort_by_category(&self, descending: bool) -> Vec<usize> {
+        let rank: Vec<u32> = (0..self.dictionary.len() as u32).collect();
+        self.argsort_by_rank(&rank, descending)
+    }
+
+    /// Row order by byte-wise string order, ranking the dictionary once.
+    pub fn argsort_lexical(&self, descending: bool) -> Vec<usize> {
+        let mut by_value: Vec<u32> = (0..self.dictionary.len() as u32).collect();
+        by_value.sort_unstable_by(|&a, &b| self.dictionary[a as usize].cmp(&self.dictionary[b as usize]));
+
+        let mut rank = vec![0u32; by_value.len()];
+        for (r, &c) in by_value.iter().enumerate() {
+            rank[c as usize] = r as u32;
+        }
+        self.argsort_by_rank(&rank, descending)
+    }
+
+    #[inline]
+    pub fn sort_by_category(&self, descending: bool) -> Self {
+        self.take(&self.argsort_by_category(descending))
+    }
+
+    #[inline]
+    pub fn sort_lexical(&self, descending: bool) -> Self {
+        self.take(&self.argsort_lexical(descending))
+    }
+}
@@ -0,0 +1,140 @@
+// === Imports ===
+use crate::prelude::*;
+use super::values::{map_valid, widen, with_ints, CompactInts};
+
+// === Types ===
+
+/// Integer column stored in the narrowest of i8/i16/i32/i64 that holds
+/// its valid values. Appends that overflow re-widen transparently, and
+/// compute widens each value to i64 on the fly. Values under nulls are 0.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactIntVector {
+    pub(crate) values: CompactInts,
+    pub(crate) validity: BitVec,   // 1 = valid, 0 = null
+}
+
+// === Impl ===
+
+impl CompactIntVector {
+
+    /// Create an empty CompactIntVector.
+    #[inline]
+    pub fn new() -> Self {
+        Self { values: CompactInts::I8(Vec::new()), validity: BitVec::new() }
+    }
+
+    /// Builds from i64 values, choosing the narrowest width for the valid ones.
+    /// Returns an error if values and validity lengths mismatch.
+    pub fn from_vec(values: Vec<i64>, validity: BitVec) -> ErrorResult<Self> {
+        if values.len() != validity.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: values.len(),
+                found: validity.len(),
+            });
+        }
+
+        let mut values = values;
+        let mut width = 1;
+        for (x, valid) in values.iter_mut().zip(validity.iter().by_vals()) {
+            if valid {
+                width = width.max(CompactInts::width_for(*x));
+            } else {
+                *x = 0;
+            }
+        }
+
+        Ok(Self { values: CompactInts::from_i64(values, width), validity })
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.validity.is_empty()
+    }
+
+    #[inline]
+    pub fn null_count(&self) -> usize {
+        self.validity.count_zeros()
+    }
+
+    /// Bytes per stored value.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.values.width()
+    }
+
+    #[inline]
+    pub fn values(&self) -> &CompactInts {
+        &self.values
+    }
+
+    #[inline]
+    pub fn validity(&self) -> &BitVec {
+        &self.validity
+    }
+
+    /// Value at `i`, `None` when null or out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<i64> {
+        match self.validity.get(i) {
+            Some(v) if *v => self.values.get(i),
+            _ => None,
+        }
+    }
+
+    /// Appends a value, or a null for `None`, widening first if it does not fit.
+    pub fn push(&mut self, value: Option<i64>) {
+        match value {
+            Some(x) => {
+                self.values.widen_to(CompactInts::width_for(x));
+                self.values.push(x);
+                self.validity.push(true);
+            }
+            None => {
+                self.values.push(0);
+                self.validity.push(false);
+            }
+        }
+    }
+
+    /// Appends `other`, widening to the larger of the two widths.
+    pub fn append(&mut self, other: &Self) {
+        self.values.widen_to(other.width());
+        with_ints!(&other.values, v => v.iter().for_each(|&x| self.values.push(widen(x))));
+        self.validity.extend_from_bitslice(&other.validity);
+    }
+
+    /// Rows at `indices`, keeping the current width.
+    pub(crate) fn take(&self, indices: &[usize]) -> Self {
+        Self {
+            values: self.values.take(indices),
+            validity: indices.iter().map(|&i| self.validity[i]).collect(),
+        }
+    }
+
+    /// Maps valid values (widened to i64) to `(value, is_valid)`.
+    #[inline]
+    pub(crate) fn map_to_vector_data<U, F>(&self, f: F) -> VectorData<U>
+    where
+        U: Clone + Default + Send + Sync,
+        F: Fn(i64) -> (U, bool) + Sync + Send,
+    {
+        with_ints!(&self.values, v => map_valid(v, &self.validity, f))
+    }
+
+    /// Approximate heap bytes: values and validity.
+    #[inline]
+    pub fn memory_usage(&self) -> usize {
+        self.values.memory_usage() + self.validity.capacity() / 8
+    }
+}
+
+impl Default for CompactIntVector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,57 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+
+impl CompactIntVector {
+    #[inline]
+    pub fn from_vector_data(values: &VectorData<i64>) -> Self {
+        Self::from_vec(values.data.clone(), values.validity.clone())
+            .expect("VectorData keeps data and validity aligned")
+    }
+
+    /// Widens back to i64.
+    #[inline]
+    pub fn to_vector_data(&self) -> VectorData<i64> {
+        self.map_to_vector_data(|x| (x, true))
+    }
+
+    /// → f64 (lossless below 2^53)
+    #[inline]
+    pub fn to_float(&self) -> VectorData<f64> {
+        self.map_to_vector_data(|x| (x as f64, true))
+    }
+
+    /// → bool (nonzero → true)
+    #[inline]
+    pub fn to_bool(&self) -> VectorData<bool> {
+        self.map_to_vector_data(|x| (x != 0, true))
+    }
+
+    /// → String
+    #[inline]
+    pub fn to_text(&self) -> VectorData<String> {
+        self.map_to_vector_data(|x| (x.to_string(), true))
+    }
+}
+
+impl VectorData<i64> {
+    #[inline]
+    pub fn to_compact(&self) -> CompactIntVector {
+        CompactIntVector::from_vector_data(self)
+    }
+}
+
+impl From<&VectorData<i64>> for CompactIntVector {
+    #[inline]
+    fn from(values: &VectorData<i64>) -> Self {
+        Self::from_vector_data(values)
+    }
+}
+
+impl From<&CompactIntVector> for VectorData<i64> {
+    #[inline]
+    fn from(values: &CompactIntVector) -> Self {
+        values.to_vector_data()
+    }
+}
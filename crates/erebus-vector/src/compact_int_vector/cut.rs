@@ -0,0 +1,34 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+
+impl CompactIntVector {
+
+    // -- Cut --
+    /// Bin index per row; the result is itself compact (bin counts are small).
+    pub fn cut(
+        &self,
+        bins: &[f64],
+        right: bool,
+        bounded: bool,
+    ) -> ErrorResult<CompactIntVector> {
+        validate_cut_bins(bins)?;
+        let out = self.map_to_vector_data(|x| cut_value(x as f64, bins, right, bounded, |idx| idx));
+        CompactIntVector::from_vec(out.data, out.validity)
+    }
+
+    // -- Cut with Labels --
+    pub fn cut_labels(
+        &self,
+        bins: &[f64],
+        labels: &[String],
+        right: bool,
+        bounded: bool,
+    ) -> ErrorResult<VectorData<String>> {
+        validate_cut_inputs(bins, labels, bounded)?;
+        Ok(self.map_to_vector_data(|x| {
+            cut_value(x as f64, bins, right, bounded, |idx| labels[idx as usize].clone())
+        }))
+    }
+}
@@ -0,0 +1,39 @@
+// === Imports ===
+use crate::prelude::*;
+use super::values::with_ints;
+use erebus_io::prelude::{CompressionType, ErebusReader, ErebusWriter};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::path::Path;
+
+// === Impl ===
+
+impl CompactIntVector {
+
+    /// Writes this column as a compact `.erebus` integer object,
+    /// using the writer's compression.
+    #[inline]
+    pub fn write_to<W: Write + Seek>(&self, writer: &mut ErebusWriter<W>) -> ErrorResult<()> {
+        with_ints!(&self.values, v => writer.compact_int().write(v, &self.validity))
+    }
+
+    /// Reads a compact `.erebus` integer object.
+    #[inline]
+    pub fn read_from<R: Read + Seek>(reader: &mut ErebusReader<R>) -> ErrorResult<Self> {
+        let (values, validity) = reader.compact_int().read()?;
+        Self::from_vec(values, validity)
+    }
+
+    pub fn to_file<P: AsRef<Path>>(&self, path: P, compression: CompressionType) -> ErrorResult<()> {
+        let file = BufWriter::new(File::create(path)?);
+        let mut writer = ErebusWriter::new(file).with_compression(compression);
+        self.write_to(&mut writer)?;
+        writer.inner_mut().flush()?;
+        Ok(())
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> ErrorResult<Self> {
+        let mut reader = ErebusReader::new(BufReader::new(File::open(path)?));
+        Self::read_from(&mut reader)
+    }
+}
@@ -0,0 +1,11 @@
+pub mod values;
+pub mod compact_int_vector;
+pub mod convert;
+pub mod reduce;
+pub mod cut;
+pub mod sort;
+pub mod io;
+
+// Re-export key types
+pub use values::CompactInts;
+pub use compact_int_vector::CompactIntVector;
@@ -0,0 +1,38 @@
+// === Imports ===
+use crate::prelude::*;
+use super::values::{fold_valid, with_ints};
+
+// === Impl ===
+// Reductions widen each value to i64 as they go; nothing is materialised.
+
+impl CompactIntVector {
+    #[inline]
+    pub fn sum(&self) -> i64 {
+        with_ints!(&self.values, v => fold_valid(v, &self.validity, 0i64, |a, x| a + x, |a, b| a + b))
+    }
+
+    #[inline]
+    pub fn min(&self) -> Option<i64> {
+        with_ints!(&self.values, v => fold_valid(
+            v, &self.validity, None,
+            |a: Option<i64>, x| Some(a.map_or(x, |a| a.min(x))),
+            |a, b| match (a, b) { (Some(a), Some(b)) => Some(a.min(b)), (a, b) => a.or(b) },
+        ))
+    }
+
+    #[inline]
+    pub fn max(&self) -> Option<i64> {
+        with_ints!(&self.values, v => fold_valid(
+            v, &self.validity, None,
+            |a: Option<i64>, x| Some(a.map_or(x, |a| a.max(x))),
+            |a, b| match (a, b) { (Some(a), Some(b)) => Some(a.max(b)), (a, b) => a.or(b) },
+        ))
+    }
+
+    /// Mean of valid values, NaN when there are none.
+    pub fn mean(&self) -> f64 {
+        let count = self.validity.count_ones();
+        if count == 0 { return f64::NAN; }
+        self.sum() as f64 / count as f64
+    }
+}
@@ -0,0 +1,24 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+
+impl CompactIntVector {
+    /// Sorted row indices, computed on the values widened to i64.
+    /// algo: "auto", "stable", "introsort", "heapsort", "radix"
+    #[inline]
+    pub fn sort_indices(&self, ascending: bool, nulls_last: bool, algo: Option<&str>) -> Vec<usize> {
+        self.to_vector_data().sort_indices(ascending, nulls_last, algo)
+    }
+
+    /// Sorted copy, keeping the current width.
+    #[inline]
+    pub fn sort(&self, ascending: bool, nulls_last: bool, algo: Option<&str>) -> Self {
+        self.take(&self.sort_indices(ascending, nulls_last, algo))
+    }
+
+    #[inline]
+    pub fn sort_inplace(&mut self, ascending: bool, nulls_last: bool, algo: Option<&str>) {
+        *self = self.sort(ascending, nulls_last, algo);
+    }
+}
@@ -0,0 +1,197 @@
+// === Imports ===
+use crate::prelude::*;
+use rayon::prelude::*;
+use std::time::Instant;
+
+// === Types ===
+
+/// Integer storage in the narrowest signed width that holds every value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompactInts {
+    I8(Vec<i8>),
+    I16(Vec<i16>),
+    I32(Vec<i32>),
+    I64(Vec<i64>),
+}
+
+// === Impl ===
+
+/// Runs `$body` with `$v` bound to the inner vector, whatever its width.
+macro_rules! with_ints {
+    ($values:expr, $v:ident => $body:expr) => {
+        match $values {
+            CompactInts::I8($v) => $body,
+            CompactInts::I16($v) => $body,
+            CompactInts::I32($v) => $body,
+            CompactInts::I64($v) => $body,
+        }
+    };
+}
+pub(crate) use with_ints;
+
+/// Lossless widening of any lane element to i64.
+#[inline]
+pub(crate) fn widen<C: Copy + Into<i64>>(x: C) -> i64 {
+    x.into()
+}
+
+impl CompactInts {
+    /// Bytes per value needed to hold `x`.
+    #[inline]
+    pub fn width_for(x: i64) -> usize {
+        if i8::try_from(x).is_ok() {
+            1
+        } else if i16::try_from(x).is_ok() {
+            2
+        } else if i32::try_from(x).is_ok() {
+            4
+        } else {
+            8
+        }
+    }
+
+    /// Stores `values` at `width` bytes; every value must fit.
+    pub fn from_i64(values: Vec<i64>, width: usize) -> Self {
+        match width {
+            1 => Self::I8(values.into_iter().map(|x| x as i8).collect()),
+            2 => Self::I16(values.into_iter().map(|x| x as i16).collect()),
+            4 => Self::I32(values.into_iter().map(|x| x as i32).collect()),
+            _ => Self::I64(values),
+        }
+    }
+
+    /// Bytes per value.
+    #[inline]
+    pub fn width(&self) -> usize {
+        match self {
+            Self::I8(_) => 1,
+            Self::I16(_) => 2,
+            Self::I32(_) => 4,
+            Self::I64(_) => 8,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        with_ints!(self, v => v.len())
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[inline]
+    pub fn get(&self, i: usize) -> Option<i64> {
+        with_ints!(self, v => v.get(i).map(|&x| widen(x)))
+    }
+
+    pub fn to_i64(&self) -> Vec<i64> {
+        with_ints!(self, v => v.iter().map(|&x| widen(x)).collect())
+    }
+
+    /// Widens in place to at least `width` bytes. Never narrows.
+    pub fn widen_to(&mut self, width: usize) {
+        if width <= self.width() {
+            return;
+        }
+        let values = std::mem::replace(self, Self::I8(Vec::new())).to_i64();
+        *self = Self::from_i64(values, width);
+    }
+
+    /// Appends `x`; the caller must have widened for it already.
+    #[inline]
+    pub(crate) fn push(&mut self, x: i64) {
+        match self {
+            Self::I8(v) => v.push(x as i8),
+            Self::I16(v) => v.push(x as i16),
+            Self::I32(v) => v.push(x as i32),
+            Self::I64(v) => v.push(x),
+        }
+    }
+
+    /// Values picked at `indices`, keeping the current width.
+    pub(crate) fn take(&self, indices: &[usize]) -> Self {
+        match self {
+            Self::I8(v) => Self::I8(indices.iter().map(|&i| v[i]).collect()),
+            Self::I16(v) => Self::I16(indices.iter().map(|&i| v[i]).collect()),
+            Self::I32(v) => Self::I32(indices.iter().map(|&i| v[i]).collect()),
+            Self::I64(v) => Self::I64(indices.iter().map(|&i| v[i]).collect()),
+        }
+    }
+
+    /// Heap bytes used by the values.
+    #[inline]
+    pub fn memory_usage(&self) -> usize {
+        self.len() * self.width()
+    }
+}
+
+/// Folds the valid entries of one lane, widened to i64, chunked in
+/// parallel for large inputs.
+#[inline]
+pub(crate) fn fold_valid<C, A, F, G>(values: &[C], validity: &BitVec, init: A, f: F, combine: G) -> A
+where
+    C: Copy + Into<i64> + Sync,
+    A: Copy + Send + Sync,
+    F: Fn(A, i64) -> A + Sync,
+    G: Fn(A, A) -> A + Sync + Send,
+{
+    let run = |start: usize, chunk: &[C]| {
+        chunk.iter().enumerate().fold(init, |acc, (i, &x)| {
+            if validity[start + i] { f(acc, x.into()) } else { acc }
+        })
+    };
+
+    let n = values.len();
+    let (use_parallel, chunk) = should_parallelize(n);
+    if !use_parallel {
+        return run(0, values);
+    }
+
+    let start = Instant::now();
+    let out = values
+        .par_chunks(chunk)
+        .enumerate()
+        .map(|(k, c)| run(k * chunk, c))
+        .reduce(|| init, &combine);
+    record_chunk_stats(n, start.elapsed().as_micros());
+    out
+}
+
+/// Maps the valid entries of one lane, widened to i64, to `(value, is_valid)`.
+/// Null rows yield `U::default()` without calling `f`.
+#[inline]
+pub(crate) fn map_valid<C, U, F>(values: &[C], validity: &BitVec, f: F) -> VectorData<U>
+where
+    C: Copy + Into<i64> + Sync,
+    U: Clone + Default + Send + Sync,
+    F: Fn(i64) -> (U, bool) + Sync + Send,
+{
+    let run = |start: usize, chunk: &[C]| -> Vec<(U, bool)> {
+        chunk.iter().enumerate().map(|(i, &x)| {
+            if validity[start + i] { f(x.into()) } else { (U::default(), false) }
+        }).collect()
+    };
+
+    let n = values.len();
+    let (use_parallel, chunk) = should_parallelize(n);
+    let pairs: Vec<(U, bool)> = if use_parallel {
+        let start = Instant::now();
+        let out = values
+            .par_chunks(chunk)
+            .enumerate()
+            .flat_map_iter(|(k, c)| run(k * chunk, c))
+            .collect();
+        record_chunk_stats(n, start.elapsed().as_micros());
+        out
+    } else {
+        run(0, values)
+    };
+
+    let (data, valid): (Vec<U>, Vec<bool>) = pairs.into_iter().unzip();
+    VectorData {
+        data,
+        validity: valid.into_iter().collect(),
+    }
+}
@@ -9,6 +9,12 @@ pub mod vector;
 pub mod vector_view;
 pub mod vector_data;
 pub mod vector_data_view;
+pub mod bitmask_data;
+pub mod categorical_vector;
+pub mod compact_int_vector;
 
 pub use vector::Vector;
-pub use vector_data::VectorData;
\ No newline at end of file
+pub use vector_data::VectorData;
+pub use bitmask_data::BitmaskData;
+pub use categorical_vector::CategoricalVector;
+pub use compact_int_vector::CompactIntVector;
\ No newline at end of file
@@ -13,5 +13,8 @@ pub use erebus_core::prelude::*;
 // Vector-level internal exports
 pub use crate::vector::Vector;
 pub use crate::vector_view::VectorView;
-pub use crate::vector_data::VectorData;
-pub use crate::vector_data_view::VectorDataView;
\ No newline at end of file
+pub use crate::vector_data::{VectorData, FillStrategy, InterpolateMethod};
+pub use crate::vector_data_view::VectorDataView;
+pub use crate::bitmask_data::{BitmaskData, BoolMask};
+pub use crate::categorical_vector::{CategoricalVector, CategoricalCodes};
+pub use crate::compact_int_vector::{CompactIntVector, CompactInts};
\ No newline at end of file
@@ -7,8 +7,12 @@ use std::any::TypeId;
 impl<T: 'static> Vector<T> {
     #[inline]
     pub fn is_numeric(&self) -> bool {
-        TypeId::of::<T>() == TypeId::of::<i64>()
-            || TypeId::of::<T>() == TypeId::of::<f64>()
+        [
+            TypeId::of::<i64>(), TypeId::of::<f64>(),
+            TypeId::of::<f32>(), TypeId::of::<i32>(), TypeId::of::<i16>(), TypeId::of::<i128>(),
+            TypeId::of::<u8>(), TypeId::of::<u32>(), TypeId::of::<u64>(),
+        ]
+        .contains(&TypeId::of::<T>())
     }
 }
 
@@ -71,34 +71,21 @@ impl<T: Clone + Send + Sync + 'static> Vector<T> {
 
     #[cfg_attr(feature = "internal", visibility::make(pub))]
     #[inline]
-    pub(crate) fn _bool_index(&self, mask: &[bool]) -> Self {
-        let n = self.data.len();
-
-        let mut out = Vec::<T>::with_capacity(n);
-
-        unsafe {
-            let out_ptr = out.as_mut_ptr();
-            let mut j = 0usize;
-
-            for i in 0..n {
-                if *mask.get_unchecked(i) {
-                    std::ptr::write(out_ptr.add(j), self.data.get_unchecked(i).clone());
-                    j += 1;
-                }
-            }
-            out.set_len(j);
-        }
-
+    pub(crate) fn _bool_index<M: BoolMask + ?Sized>(&self, mask: &M) -> Self {
+        let mut out = Vec::<T>::with_capacity(mask.selected_count());
+        mask.for_each_selected(&mut |i| unsafe {
+            out.push(self.data.get_unchecked(i).clone());
+        });
         Vector { data: out }
     }
 
     #[inline]
-    pub fn bool_index(&self, mask: &[bool]) -> ErrorResult<Self> {
+    pub fn bool_index<M: BoolMask + ?Sized>(&self, mask: &M) -> ErrorResult<Self> {
         let n = self.data.len();
-        if mask.len() != n {
+        if mask.mask_len() != n {
             return Err(ErebusError::LengthMismatch {
                 expected: n,
-                found: mask.len(),
+                found: mask.mask_len(),
             });
         }
         Ok(self._bool_index(mask))
@@ -12,13 +12,13 @@ pub struct SumAcc<T: Numeric> {
 pub struct SumReducer;
 
 impl<T: Numeric> Reducer<T> for SumReducer {
-    type Acc = SumAcc<T>;
-    type Output = T;
+    type Acc = SumAcc<T::Wide>;
+    type Output = T::Wide;
 
     #[inline]
     fn accumulate(&mut self, acc: &mut Self::Acc, value: &T, _is_valid: bool) {
         // Vector assumes everything is valid
-        acc.sum += *value;
+        acc.sum += value.to_wide();
     }
 
     #[inline]
@@ -33,8 +33,9 @@ impl<T: Numeric> Reducer<T> for SumReducer {
 }
 
 impl<T: Numeric> Vector<T> {
+    /// Sum, accumulated in `T::Wide` (i64/u64 for narrow ints).
     #[inline]
-    pub fn sum(&self) -> T {
+    pub fn sum(&self) -> T::Wide {
         self._reduce(SumReducer)
     }
 }
\ No newline at end of file
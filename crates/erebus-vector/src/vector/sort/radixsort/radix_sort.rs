@@ -33,7 +33,7 @@ where
 
         const BITS: usize = 8;
         const RADIX: usize = 1 << BITS;
-        let passes = (std::mem::size_of::<u64>() * 8) / BITS;
+        let passes = (std::mem::size_of::<T::Key>() * 8) / BITS;
 
         for pass in 0..passes {
             let shift = pass * BITS;
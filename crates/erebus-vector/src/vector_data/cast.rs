@@ -7,45 +7,84 @@ use std::any::TypeId;
 impl<T: 'static> VectorData<T> {
     #[inline]
     pub fn is_numeric(&self) -> bool {
-        TypeId::of::<T>() == TypeId::of::<i64>()
-            || TypeId::of::<T>() == TypeId::of::<f64>()
+        [
+            TypeId::of::<i64>(), TypeId::of::<f64>(),
+            TypeId::of::<f32>(), TypeId::of::<i32>(), TypeId::of::<i16>(), TypeId::of::<i128>(),
+            TypeId::of::<u8>(), TypeId::of::<u32>(), TypeId::of::<u64>(),
+        ]
+        .contains(&TypeId::of::<T>())
     }
 }
 
-impl VectorData<i64> {
-    /// i64 → f64 (lossless widening)
+impl<T: Copy + Send + Sync + 'static> VectorData<T> {
+    /// Numeric cast; values not representable in `U` become null.
     #[inline]
-    pub fn to_float(&self) -> VectorData<f64> {
-        self.map_unary_owned(|&x| x as f64)
+    pub fn cast_checked<U>(&self) -> VectorData<U>
+    where
+        T: NumericCast<U>,
+        U: Clone + Default + Send + Sync,
+    {
+        self.map_unary_owned_with_validity(|&x| match x.cast_checked() {
+            Some(v) => (v, true),
+            None => (U::default(), false),
+        })
     }
 
-    /// i64 → bool (nonzero → true)
+    /// Numeric cast clamped to the range of `U`.
     #[inline]
-    pub fn to_bool(&self) -> VectorData<bool> {
-        self.map_unary_owned(|&x| x != 0)
+    pub fn cast_saturating<U>(&self) -> VectorData<U>
+    where
+        T: NumericCast<U>,
+        U: Clone + Send + Sync,
+    {
+        self.map_unary_owned(|&x| x.cast_saturating())
     }
 
-    /// i64 → String
+    /// Numeric cast with `as`-style wrapping.
     #[inline]
-    pub fn to_text(&self) -> VectorData<String> {
-        self.map_unary_owned(|x| x.to_string())
+    pub fn cast_wrapping<U>(&self) -> VectorData<U>
+    where
+        T: NumericCast<U>,
+        U: Clone + Send + Sync,
+    {
+        self.map_unary_owned(|&x| x.cast_wrapping())
     }
 }
 
-impl VectorData<f64> {
-    /// f64 → i64 (truncating)
+/// Numeric element types with `to_*` conversions. Local to this crate so
+/// the generic impl below can sit beside the `bool`/`String` ones.
+pub trait CastElement: Numeric + ToString + NumericCast<i64> {}
+
+macro_rules! impl_cast_element {
+    ($($t:ty),*) => {
+        $(impl CastElement for $t {})*
+    };
+}
+
+impl_cast_element!(i64, i32, i16, i128, u8, u32, u64, f64, f32);
+
+// A single generic impl, rather than one per element type, so `to_bool`
+// etc. on a vector of unsuffixed literals still infers f64/i64.
+impl<T: CastElement> VectorData<T> {
+    /// numeric → f64 (large ints round to nearest)
+    #[inline]
+    pub fn to_float(&self) -> VectorData<f64> {
+        self.map_unary_owned(|&x| x.to_f64())
+    }
+
+    /// numeric → i64 (truncating; clamped to the i64 range, NaN → 0)
     #[inline]
     pub fn to_int(&self) -> VectorData<i64> {
-        self.map_unary_owned(|&x| x as i64)
+        self.map_unary_owned(|&x| x.cast_saturating())
     }
 
-    /// f64 → bool (nonzero → true)
+    /// numeric → bool (nonzero → true)
     #[inline]
     pub fn to_bool(&self) -> VectorData<bool> {
-        self.map_unary_owned(|&x| x != 0.0)
+        self.map_unary_owned(|&x| x != T::zero())
     }
 
-    /// f64 → String
+    /// numeric → String
     #[inline]
     pub fn to_text(&self) -> VectorData<String> {
         self.map_unary_owned(|x| x.to_string())
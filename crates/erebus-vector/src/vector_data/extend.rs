@@ -40,15 +40,11 @@ impl<T: Clone + Send + Sync> VectorData<T> {
         let mut data = Vec::with_capacity(total_len);
         let mut validity = BitVec::with_capacity(total_len);
 
-        let mut ptr = data.as_mut_ptr();
+        // Clone rather than bit-copy: a raw copy of non-`Copy` values
+        // (e.g. `String`) would double free.
         for v in vectors {
-            let len = v.data.len();
-            unsafe { std::ptr::copy_nonoverlapping(v.data.as_ptr(), ptr, len) };
-            ptr = unsafe { ptr.add(len) };
-        }
-        unsafe { data.set_len(total_len) };
-        for v in vectors {
-            validity.extend(v.validity.iter());
+            data.extend_from_slice(&v.data);
+            validity.extend_from_bitslice(&v.validity);
         }
         Self { data, validity }
     }
@@ -113,42 +113,30 @@ impl<T: Clone + Send + Sync + 'static> VectorData<T> {
 
     #[cfg_attr(feature = "internal", visibility::make(pub))]
     #[inline]
-    pub(crate) fn _bool_index(&self, mask: &[bool]) -> Self {
-        let n = self.data.len();
-        debug_assert_eq!(mask.len(), n);
-
-        let mut out = Vec::<T>::with_capacity(n);
-        let mut out_validity = bitvec![0; n];
-
-        unsafe {
-            let mut j = 0usize;
-            let out_ptr = out.as_mut_ptr();
-
-            for i in 0..n {
-                if *mask.get_unchecked(i) {
-                    // copy data
-                    std::ptr::write(out_ptr.add(j), self.data.get_unchecked(i).clone());
-                    // copy validity
-                    out_validity.set_unchecked(j, *self.validity.get_unchecked(i));
-                    j += 1;
-                }
-            }
+    pub(crate) fn _bool_index<M: BoolMask + ?Sized>(&self, mask: &M) -> Self {
+        debug_assert_eq!(mask.mask_len(), self.data.len());
 
-            out.set_len(j);
-            out_validity.truncate(j);
-        }
+        let k = mask.selected_count();
+        let mut out = Vec::<T>::with_capacity(k);
+        let mut out_validity = BitVec::with_capacity(k);
+
+        mask.for_each_selected(&mut |i| unsafe {
+            out.push(self.data.get_unchecked(i).clone());
+            out_validity.push(*self.validity.get_unchecked(i));
+        });
 
         VectorData { data: out, validity: out_validity }
     }
 
-    /// Public safe version
+    /// Public safe version. Accepts plain bools or a packed [`BitmaskData`]
+    /// (nulls in a `BitmaskData` do not select their row).
     #[inline]
-    pub fn bool_index(&self, mask: &[bool]) -> ErrorResult<Self> {
+    pub fn bool_index<M: BoolMask + ?Sized>(&self, mask: &M) -> ErrorResult<Self> {
         let n = self.data.len();
-        if mask.len() != n {
+        if mask.mask_len() != n {
             return Err(ErebusError::LengthMismatch {
                 expected: n,
-                found: mask.len(),
+                found: mask.mask_len(),
             });
         }
         Ok(self._bool_index(mask))
@@ -8,6 +8,8 @@ pub mod indexing;
 pub mod reduce;
 pub mod reorder;
 pub mod sort;
+pub mod nulls;
 
 // Re-export key types
-pub use vector_data::VectorData;
\ No newline at end of file
+pub use vector_data::VectorData;
+pub use nulls::{FillStrategy, InterpolateMethod};
\ No newline at end of file
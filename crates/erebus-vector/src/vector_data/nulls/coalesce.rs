@@ -0,0 +1,35 @@
+// === Imports ===
+use crate::prelude::*;
+use super::scan::null_indices;
+
+// === Impl ===
+
+impl<T: Clone> VectorData<T> {
+    /// Takes, row by row, the first valid value across `vectors`.
+    /// Rows that are null in every input stay null.
+    pub fn coalesce(vectors: &[&Self]) -> ErrorResult<Self> {
+        let Some((first, rest)) = vectors.split_first() else {
+            return Err(ErebusError::EmptyVector);
+        };
+        let n = first.data.len();
+        for v in rest {
+            if v.data.len() != n {
+                return Err(ErebusError::LengthMismatch { expected: n, found: v.data.len() });
+            }
+        }
+
+        let mut out = (*first).clone();
+        for v in rest {
+            if out.validity.all() {
+                break;
+            }
+            for i in null_indices(&out.validity) {
+                if v.validity[i] {
+                    out.data[i] = v.data[i].clone();
+                    out.validity.set(i, true);
+                }
+            }
+        }
+        Ok(out)
+    }
+}
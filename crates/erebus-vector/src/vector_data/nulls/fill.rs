@@ -0,0 +1,136 @@
+// === Imports ===
+use crate::prelude::*;
+use super::scan::{for_each_null, null_indices};
+
+// === Types ===
+
+/// How `fill_null_with` picks replacement values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillStrategy {
+    /// Last valid value before the null.
+    Forward,
+    /// Next valid value after the null.
+    Backward,
+    /// Mean of the valid values.
+    Mean,
+    /// Median of the valid values.
+    Median,
+    /// Zero.
+    Zero,
+}
+
+// === Impl ===
+
+impl<T: Clone> VectorData<T> {
+    /// Replaces every null with `value`.
+    pub fn fill_null(&self, value: T) -> Self {
+        let mut out = self.clone();
+        for_each_null(&self.validity, |i| out.data[i] = value.clone());
+        out.validity.fill(true);
+        out
+    }
+
+    /// Carries the last valid value forward over nulls.
+    /// `limit` caps how many consecutive nulls are filled after each valid value.
+    pub fn ffill(&self, limit: Option<usize>) -> Self {
+        let limit = limit.unwrap_or(usize::MAX);
+        let mut out = self.clone();
+        let mut src: Option<usize> = None;
+        let mut prev_null: Option<usize> = None;
+        for_each_null(&self.validity, |i| {
+            if i > 0 && prev_null != Some(i - 1) {
+                src = Some(i - 1);
+            }
+            prev_null = Some(i);
+            if let Some(s) = src {
+                if i - s <= limit {
+                    out.data[i] = self.data[s].clone();
+                    out.validity.set(i, true);
+                }
+            }
+        });
+        out
+    }
+
+    /// Carries the next valid value backward over nulls.
+    /// `limit` caps how many consecutive nulls are filled before each valid value.
+    pub fn bfill(&self, limit: Option<usize>) -> Self {
+        let limit = limit.unwrap_or(usize::MAX);
+        let n = self.data.len();
+        let mut out = self.clone();
+        let mut src: Option<usize> = None;
+        let mut prev_null: Option<usize> = None;
+        for &i in null_indices(&self.validity).iter().rev() {
+            if i + 1 < n && prev_null != Some(i + 1) {
+                src = Some(i + 1);
+            }
+            prev_null = Some(i);
+            if let Some(s) = src {
+                if s - i <= limit {
+                    out.data[i] = self.data[s].clone();
+                    out.validity.set(i, true);
+                }
+            }
+        }
+        out
+    }
+}
+
+fn valid_f64<T: Numeric>(v: &VectorData<T>) -> Vec<f64> {
+    v.data.iter()
+        .zip(v.validity.iter().by_vals())
+        .filter(|(_, ok)| *ok)
+        .map(|(x, _)| x.to_f64())
+        .collect()
+}
+
+fn valid_mean<T: Numeric>(v: &VectorData<T>) -> Option<f64> {
+    let vals = valid_f64(v);
+    if vals.is_empty() { return None; }
+    Some(vals.iter().sum::<f64>() / vals.len() as f64)
+}
+
+fn valid_median<T: Numeric>(v: &VectorData<T>) -> Option<f64> {
+    let mut vals = valid_f64(v);
+    if vals.is_empty() { return None; }
+    vals.sort_by(f64::total_cmp);
+    let mid = vals.len() / 2;
+    if vals.len() % 2 == 1 {
+        Some(vals[mid])
+    } else {
+        Some((vals[mid - 1] + vals[mid]) / 2.0)
+    }
+}
+
+macro_rules! impl_fill_null_with {
+    ($t:ty, $from_f64:expr) => {
+        impl VectorData<$t> {
+            /// Replaces nulls according to `strategy`.
+            /// Mean and median leave the vector unchanged when it has no valid values.
+            pub fn fill_null_with(&self, strategy: FillStrategy) -> Self {
+                let stat = match strategy {
+                    FillStrategy::Forward => return self.ffill(None),
+                    FillStrategy::Backward => return self.bfill(None),
+                    FillStrategy::Zero => return self.fill_null(<$t as Numeric>::zero()),
+                    FillStrategy::Mean => valid_mean(self),
+                    FillStrategy::Median => valid_median(self),
+                };
+                match stat {
+                    Some(m) => self.fill_null(($from_f64)(m)),
+                    None => self.clone(),
+                }
+            }
+        }
+    };
+}
+
+// Integer statistics are rounded to the nearest value.
+impl_fill_null_with!(i64, |m: f64| m.round() as i64);
+impl_fill_null_with!(i32, |m: f64| m.round() as i32);
+impl_fill_null_with!(i16, |m: f64| m.round() as i16);
+impl_fill_null_with!(i128, |m: f64| m.round() as i128);
+impl_fill_null_with!(u8, |m: f64| m.round() as u8);
+impl_fill_null_with!(u32, |m: f64| m.round() as u32);
+impl_fill_null_with!(u64, |m: f64| m.round() as u64);
+impl_fill_null_with!(f64, |m: f64| m);
+impl_fill_null_with!(f32, |m: f64| m as f32);
@@ -0,0 +1,76 @@
+// === Imports ===
+use crate::prelude::*;
+use super::scan::null_indices;
+
+// === Types ===
+
+/// How `interpolate` fills a null between two valid values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterpolateMethod {
+    /// Straight line between the surrounding valid values.
+    Linear,
+    /// Closest surrounding valid value; ties go to the earlier one.
+    Nearest,
+}
+
+// === Impl ===
+
+impl VectorData<f64> {
+    /// Fills interior nulls from the valid values on either side.
+    /// Positions come from `x` when given (null-free, increasing), otherwise the row index.
+    /// Leading and trailing nulls stay null.
+    pub fn interpolate(&self, method: InterpolateMethod, x: Option<&VectorData<f64>>) -> ErrorResult<Self> {
+        if let Some(x) = x {
+            if x.len() != self.data.len() {
+                return Err(ErebusError::LengthMismatch {
+                    expected: self.data.len(),
+                    found: x.len(),
+                });
+            }
+            if x.null_count() > 0 {
+                return Err(ErebusError::InvalidOperation(
+                    "interpolate: x must not contain nulls".into(),
+                ));
+            }
+        }
+        Ok(self._interpolate(method, x.map(|x| x.data.as_slice())))
+    }
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    pub(crate) fn _interpolate(&self, method: InterpolateMethod, x: Option<&[f64]>) -> Self {
+        let n = self.data.len();
+        let pos = |i: usize| x.map_or(i as f64, |x| x[i]);
+        let nulls = null_indices(&self.validity);
+        let mut out = self.clone();
+
+        let mut k = 0;
+        while k < nulls.len() {
+            // Consecutive nulls [start, end] share the same neighbours.
+            let start = nulls[k];
+            let mut end = start;
+            while k + 1 < nulls.len() && nulls[k + 1] == end + 1 {
+                k += 1;
+                end += 1;
+            }
+            k += 1;
+            if start == 0 || end + 1 >= n {
+                continue;
+            }
+
+            let (lo, hi) = (start - 1, end + 1);
+            let (x0, x1) = (pos(lo), pos(hi));
+            let (y0, y1) = (self.data[lo], self.data[hi]);
+            for i in start..=end {
+                let xi = pos(i);
+                out.data[i] = match method {
+                    InterpolateMethod::Linear if x1 == x0 => y0,
+                    InterpolateMethod::Linear => y0 + (y1 - y0) * (xi - x0) / (x1 - x0),
+                    InterpolateMethod::Nearest if xi - x0 <= x1 - xi => y0,
+                    InterpolateMethod::Nearest => y1,
+                };
+                out.validity.set(i, true);
+            }
+        }
+        out
+    }
+}
@@ -0,0 +1,36 @@
+// === Imports ===
+use crate::prelude::*;
+use super::scan::for_each_null;
+
+// === Impl ===
+
+impl<T: Clone> VectorData<T> {
+    /// `true` where the value is null. The result has no nulls.
+    pub fn is_null(&self) -> VectorData<bool> {
+        let n = self.data.len();
+        let mut data = vec![false; n];
+        for_each_null(&self.validity, |i| data[i] = true);
+        VectorData { data, validity: bitvec![1; n] }
+    }
+
+    /// `true` where the value is valid. The result has no nulls.
+    pub fn is_not_null(&self) -> VectorData<bool> {
+        let n = self.data.len();
+        let mut data = vec![true; n];
+        for_each_null(&self.validity, |i| data[i] = false);
+        VectorData { data, validity: bitvec![1; n] }
+    }
+
+    /// Removes null rows, keeping the order of the valid values.
+    pub fn drop_nulls(&self) -> Self {
+        let mut data = Vec::with_capacity(self.validity.count_ones());
+        let mut start = 0;
+        for_each_null(&self.validity, |i| {
+            data.extend_from_slice(&self.data[start..i]);
+            start = i + 1;
+        });
+        data.extend_from_slice(&self.data[start..]);
+        let n = data.len();
+        VectorData { data, validity: bitvec![1; n] }
+    }
+}
@@ -0,0 +1,9 @@
+pub mod scan;
+pub mod fill;
+pub mod interpolate;
+pub mod mask;
+pub mod coalesce;
+
+// Re-export key types
+pub use fill::FillStrategy;
+pub use interpolate::InterpolateMethod;
@@ -0,0 +1,29 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+
+const WORD_BITS: usize = 64;
+
+/// Calls `f` with the index of every null in `validity`, in increasing order.
+/// Loads 64 validity bits at a time and skips fully valid words.
+#[inline]
+pub(crate) fn for_each_null(validity: &BitSlice, mut f: impl FnMut(usize)) {
+    for (k, chunk) in validity.chunks(WORD_BITS).enumerate() {
+        let word: u64 = chunk.load_le();
+        let mask = if chunk.len() == WORD_BITS { u64::MAX } else { (1u64 << chunk.len()) - 1 };
+        let mut nulls = !word & mask;
+        while nulls != 0 {
+            f(k * WORD_BITS + nulls.trailing_zeros() as usize);
+            nulls &= nulls - 1;
+        }
+    }
+}
+
+/// Indices of every null in `validity`, in increasing order.
+#[inline]
+pub(crate) fn null_indices(validity: &BitSlice) -> Vec<usize> {
+    let mut out = Vec::with_capacity(validity.count_zeros());
+    for_each_null(validity, |i| out.push(i));
+    out
+}
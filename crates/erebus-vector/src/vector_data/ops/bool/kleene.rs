@@ -0,0 +1,80 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+// Kleene logic runs on the packed representation, 64 rows per word.
+
+impl VectorData<bool> {
+
+    // -- And --
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _and(&self, other: &Self) -> Self {
+        self.to_bitmask()._and(&other.to_bitmask()).into()
+    }
+
+    /// Kleene AND: `false AND null = false`, `true AND null = null`.
+    #[inline]
+    pub fn and(&self, other: &Self) -> ErrorResult<Self> {
+        Ok(self.to_bitmask().and(&other.to_bitmask())?.into())
+    }
+
+    // -- Or --
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _or(&self, other: &Self) -> Self {
+        self.to_bitmask()._or(&other.to_bitmask()).into()
+    }
+
+    /// Kleene OR: `true OR null = true`, `false OR null = null`.
+    #[inline]
+    pub fn or(&self, other: &Self) -> ErrorResult<Self> {
+        Ok(self.to_bitmask().or(&other.to_bitmask())?.into())
+    }
+
+    // -- Xor --
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _xor(&self, other: &Self) -> Self {
+        self.to_bitmask()._xor(&other.to_bitmask()).into()
+    }
+
+    /// Kleene XOR: null whenever either side is null.
+    #[inline]
+    pub fn xor(&self, other: &Self) -> ErrorResult<Self> {
+        Ok(self.to_bitmask().xor(&other.to_bitmask())?.into())
+    }
+
+    // -- And not --
+
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _and_not(&self, other: &Self) -> Self {
+        self.to_bitmask()._and_not(&other.to_bitmask()).into()
+    }
+
+    /// Kleene `self AND NOT other`.
+    #[inline]
+    pub fn and_not(&self, other: &Self) -> ErrorResult<Self> {
+        Ok(self.to_bitmask().and_not(&other.to_bitmask())?.into())
+    }
+
+    // -- Null-aware reductions --
+
+    /// Kleene ANY: `Some(true)` if a valid value is true,
+    /// `None` if the answer depends on a null, `Some(false)` otherwise.
+    #[inline]
+    pub fn any_kleene(&self) -> Option<bool> {
+        self.to_bitmask().any_kleene()
+    }
+
+    /// Kleene ALL: `Some(false)` if a valid value is false,
+    /// `None` if the answer depends on a null, `Some(true)` otherwise.
+    #[inline]
+    pub fn all_kleene(&self) -> Option<bool> {
+        self.to_bitmask().all_kleene()
+    }
+}
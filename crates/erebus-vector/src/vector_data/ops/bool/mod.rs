@@ -1 +1,2 @@
-mod ops;
\ No newline at end of file
+mod ops;
+mod kleene;
\ No newline at end of file
@@ -0,0 +1,69 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+// Elementwise comparisons between two columns of the same type.
+// Output validity = self.validity & other.validity.
+
+impl<T> VectorData<T>
+where
+    T: PartialOrd + Clone + Send + Sync + 'static,
+{
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn _zip_cmp<F>(&self, other: &Self, f: F) -> VectorData<bool>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        debug_assert_eq!(self.data.len(), other.data.len());
+        VectorData {
+            data: self.data.iter().zip(other.data.iter()).map(|(a, b)| f(a, b)).collect(),
+            validity: self.validity.clone() & &other.validity,
+        }
+    }
+
+    /// Checked `_zip_cmp`: errors when lengths differ.
+    #[inline]
+    pub(crate) fn zip_cmp<F>(&self, other: &Self, f: F) -> ErrorResult<VectorData<bool>>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        if self.data.len() != other.data.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: self.data.len(),
+                found: other.data.len(),
+            });
+        }
+        Ok(self._zip_cmp(other, f))
+    }
+
+    #[inline]
+    pub fn lt_vector(&self, other: &Self) -> ErrorResult<VectorData<bool>> {
+        self.zip_cmp(other, |a, b| a < b)
+    }
+
+    #[inline]
+    pub fn lte_vector(&self, other: &Self) -> ErrorResult<VectorData<bool>> {
+        self.zip_cmp(other, |a, b| a <= b)
+    }
+
+    #[inline]
+    pub fn gt_vector(&self, other: &Self) -> ErrorResult<VectorData<bool>> {
+        self.zip_cmp(other, |a, b| a > b)
+    }
+
+    #[inline]
+    pub fn gte_vector(&self, other: &Self) -> ErrorResult<VectorData<bool>> {
+        self.zip_cmp(other, |a, b| a >= b)
+    }
+
+    #[inline]
+    pub fn eq_vector(&self, other: &Self) -> ErrorResult<VectorData<bool>> {
+        self.zip_cmp(other, |a, b| a == b)
+    }
+
+    #[inline]
+    pub fn neq_vector(&self, other: &Self) -> ErrorResult<VectorData<bool>> {
+        self.zip_cmp(other, |a, b| a != b)
+    }
+}
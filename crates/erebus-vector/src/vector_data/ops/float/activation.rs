@@ -0,0 +1,88 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+use super::special_fns::{finite, finite_inplace, expit, gelu, logit, softplus, SpecialFloat};
+
+// === Impl ===
+
+macro_rules! impl_float_activation {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            // -- Logit: ln(x / (1 - x)), NA outside (0, 1) --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                logit, logit_inplace, logit_range,
+                $t,
+                |x: &$t| finite(logit(x.widen())),
+                |x: &mut $t| finite_inplace(x, logit(x.widen()))
+            );
+
+            // -- Expit (logistic sigmoid) --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                expit, expit_inplace, expit_range,
+                $t,
+                |x: &$t| finite(expit(x.widen())),
+                |x: &mut $t| finite_inplace(x, expit(x.widen()))
+            );
+
+            // -- Softplus: ln(1 + e^x) --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                softplus, softplus_inplace, softplus_range,
+                $t,
+                |x: &$t| finite(softplus(x.widen())),
+                |x: &mut $t| finite_inplace(x, softplus(x.widen()))
+            );
+
+            // -- ReLU: max(x, 0) --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                relu, relu_inplace, relu_range,
+                $t,
+                |x: &$t| finite(x.widen().max(0.0)),
+                |x: &mut $t| finite_inplace(x, x.widen().max(0.0))
+            );
+
+            // -- ReLU6: min(max(x, 0), 6) --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                relu6, relu6_inplace, relu6_range,
+                $t,
+                |x: &$t| finite(x.widen().clamp(0.0, 6.0)),
+                |x: &mut $t| finite_inplace(x, x.widen().clamp(0.0, 6.0))
+            );
+
+            // -- ReLU-N: min(max(x, 0), n) --
+            impl_unary_op!(
+                params_valid, inplace,
+                relu_n, relu_n_inplace, relu_n_range,
+                (n: $t) -> (n),
+                $t,
+                |x: &$t, n: $t| finite(x.widen().clamp(0.0, n.widen().max(0.0))),
+                |x: &mut $t, n: $t| finite_inplace(x, x.widen().clamp(0.0, n.widen().max(0.0)))
+            );
+
+            // -- Leaky ReLU: x if x >= 0, else alpha * x --
+            impl_unary_op!(
+                params_valid, inplace,
+                leaky_relu, leaky_relu_inplace, leaky_relu_range,
+                (alpha: $t) -> (alpha),
+                $t,
+                |x: &$t, alpha: $t| finite(if *x >= 0.0 { x.widen() } else { (alpha * *x).widen() }),
+                |x: &mut $t, alpha: $t| finite_inplace(x, if *x >= 0.0 { x.widen() } else { (alpha * *x).widen() })
+            );
+
+            // -- GELU (exact, erf form) --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                gelu, gelu_inplace, gelu_range,
+                $t,
+                |x: &$t| finite(gelu(x.widen())),
+                |x: &mut $t| finite_inplace(x, gelu(x.widen()))
+            );
+        }
+    )*};
+}
+
+impl_float_activation!(f64, f32);
@@ -4,111 +4,117 @@ use super::super::macros::*;
 
 // === Impl ===
 
-impl VectorData<f64> {
-    // -- Multiply then Add (x * a + b) --
-    impl_unary_op!(
-        params, inplace,
-        mul_add, mul_add_inplace, mul_add_range,
-        (a: f64, b: f64) -> (a, b),
-        f64,
-        |x: &f64, a: f64, b: f64| a * (*x) + b,
-        |x: &mut f64, a: f64, b: f64| { *x = a * (*x) + b; }
-    );
+macro_rules! impl_float_affine {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            // -- Multiply then Add (x * a + b) --
+            impl_unary_op!(
+                params, inplace,
+                mul_add, mul_add_inplace, mul_add_range,
+                (a: $t, b: $t) -> (a, b),
+                $t,
+                |x: &$t, a: $t, b: $t| a * (*x) + b,
+                |x: &mut $t, a: $t, b: $t| { *x = a * (*x) + b; }
+            );
 
-    // -- Multiply Scalar --
-    impl_unary_op!(
-        params, inplace,
-        mul_scalar, mul_scalar_inplace, mul_scalar_range,
-        (c: f64) -> (c),
-        f64,
-        |x: &f64, c: f64| (*x) * c,
-        |x: &mut f64, c: f64| { *x *= c; }
-    );
+            // -- Multiply Scalar --
+            impl_unary_op!(
+                params, inplace,
+                mul_scalar, mul_scalar_inplace, mul_scalar_range,
+                (c: $t) -> (c),
+                $t,
+                |x: &$t, c: $t| (*x) * c,
+                |x: &mut $t, c: $t| { *x *= c; }
+            );
 
-    // -- Add Scalar --
-    impl_unary_op!(
-        params, inplace,
-        add_scalar, add_scalar_inplace, add_scalar_range,
-        (c: f64) -> (c),
-        f64,
-        |x: &f64, c: f64| (*x) + c,
-        |x: &mut f64, c: f64| { *x += c; }
-    );
+            // -- Add Scalar --
+            impl_unary_op!(
+                params, inplace,
+                add_scalar, add_scalar_inplace, add_scalar_range,
+                (c: $t) -> (c),
+                $t,
+                |x: &$t, c: $t| (*x) + c,
+                |x: &mut $t, c: $t| { *x += c; }
+            );
 
-    // -- Subtract Scalar --
-    impl_unary_op!(
-        params, inplace,
-        sub_scalar, sub_scalar_inplace, sub_scalar_range,
-        (c: f64) -> (c),
-        f64,
-        |x: &f64, c: f64| (*x) - c,
-        |x: &mut f64, c: f64| { *x -= c; }
-    );
+            // -- Subtract Scalar --
+            impl_unary_op!(
+                params, inplace,
+                sub_scalar, sub_scalar_inplace, sub_scalar_range,
+                (c: $t) -> (c),
+                $t,
+                |x: &$t, c: $t| (*x) - c,
+                |x: &mut $t, c: $t| { *x -= c; }
+            );
 
-    // -- Divide Scalar --
-    impl_unary_op!(
-        params_valid, inplace,
-        div_scalar, div_scalar_inplace, div_scalar_range,
-        (c: f64) -> (c),
-        f64,
-        |x: &f64, c: f64| {
-            let y = *x / c;
-            if y.is_nan() { (0_f64, false) } else { (y, true) }
-        },
-        |x: &mut f64, c: f64| {
-            let y = *x / c;
-            if y.is_nan() {
-                false
-            } else {
-                *x = y;
-                true
-            }
-        }
-    );
+            // -- Divide Scalar --
+            impl_unary_op!(
+                params_valid, inplace,
+                div_scalar, div_scalar_inplace, div_scalar_range,
+                (c: $t) -> (c),
+                $t,
+                |x: &$t, c: $t| {
+                    let y = *x / c;
+                    if y.is_nan() { (0.0, false) } else { (y, true) }
+                },
+                |x: &mut $t, c: $t| {
+                    let y = *x / c;
+                    if y.is_nan() {
+                        false
+                    } else {
+                        *x = y;
+                        true
+                    }
+                }
+            );
 
-    // -- Inverse Divide Scalar --
-    impl_unary_op!(
-        params_valid, inplace,
-        inv_div_scalar, inv_div_scalar_inplace, inv_div_scalar_range,
-        (c: f64) -> (c),
-        f64,
-        |x: &f64, c: f64| {
-            let y = c / *x;
-            if y.is_nan() { (0_f64, false) } else { (y, true) }
-        },
-        |x: &mut f64, c: f64| {
-            let y = c / *x;
-            if y.is_nan() {
-                false
-            } else {
-                *x = y;
-                true
-            }
-        }
-    );
+            // -- Inverse Divide Scalar --
+            impl_unary_op!(
+                params_valid, inplace,
+                inv_div_scalar, inv_div_scalar_inplace, inv_div_scalar_range,
+                (c: $t) -> (c),
+                $t,
+                |x: &$t, c: $t| {
+                    let y = c / *x;
+                    if y.is_nan() { (0.0, false) } else { (y, true) }
+                },
+                |x: &mut $t, c: $t| {
+                    let y = c / *x;
+                    if y.is_nan() {
+                        false
+                    } else {
+                        *x = y;
+                        true
+                    }
+                }
+            );
 
-    // -- Reciprocal --
-    impl_unary_op!(
-        noparams_valid, inplace,
-        reciprocal, reciprocal_inplace, reciprocal_range,
-        f64,
-        |x: &f64| {
-            let y = 1.0 / *x;
-            if y.is_finite() {
-                (y, true)
-            } else {
-                (0.0, false)
-            }
-        },
-        |x: &mut f64| {
-            let y = 1.0 / *x;
-            if y.is_finite() {
-                *x = y;
-                true
-            } else {
-                *x = 0.0;
-                false
-            }
+            // -- Reciprocal --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                reciprocal, reciprocal_inplace, reciprocal_range,
+                $t,
+                |x: &$t| {
+                    let y = 1.0 / *x;
+                    if y.is_finite() {
+                        (y, true)
+                    } else {
+                        (0.0, false)
+                    }
+                },
+                |x: &mut $t| {
+                    let y = 1.0 / *x;
+                    if y.is_finite() {
+                        *x = y;
+                        true
+                    } else {
+                        *x = 0.0;
+                        false
+                    }
+                }
+            );
         }
-    );
-}
\ No newline at end of file
+    )*};
+}
+
+impl_float_affine!(f64, f32);
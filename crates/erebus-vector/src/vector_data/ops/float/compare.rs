@@ -4,94 +4,217 @@ use super::super::macros::*;
 
 // === Impl ===
 
-impl VectorData<f64> {
-
-    // -- Less Than --
-    impl_numeric_cmp_op!(
-        params,
-        lt, lt_range,
-        (value: f64) -> (value),
-        bool,
-        |x: &f64| *x < value
-    );
-
-    // -- Less Than Equal --
-    impl_numeric_cmp_op!(
-        params,
-        lte, lte_range,
-        (value: f64) -> (value),
-        bool,
-        |x: &f64| *x <= value
-    );
-
-    // -- Greater Than --
-    impl_numeric_cmp_op!(
-        params,
-        gt, gt_range,
-        (value: f64) -> (value),
-        bool,
-        |x: &f64| *x > value
-    );
-
-    // -- Greater Than Equal --
-    impl_numeric_cmp_op!(
-        params,
-        gte, gte_range,
-        (value: f64) -> (value),
-        bool,
-        |x: &f64| *x >= value
-    );
-
-    // -- Threshold --
-    impl_numeric_cmp_op!(
-        params,
-        threshold, threshold_range,
-        (value: f64) -> (value),
-        bool,
-        |x: &f64| *x > value
-    );
-
-    // -- Threshold Equal --
-    impl_numeric_cmp_op!(
-        params,
-        threshold_eq, threshold_eq_range,
-        (value: f64) -> (value),
-        bool,
-        |x: &f64| *x >= value
-    );
-
-    // -- Binarize --
-    impl_numeric_cmp_op!(
-        params,
-        binarize, binarize_range,
-        (value: f64) -> (value),
-        i64,
-        |x: &f64| if *x > value { 1 } else { 0 }
-    );
-
-    // -- Binarize Equal --
-    impl_numeric_cmp_op!(
-        params,
-        binarize_eq, binarize_eq_range,
-        (value: f64) -> (value),
-        i64,
-        |x: &f64| if *x >= value { 1 } else { 0 }
-    );
-
-    // -- Between (lower ≤ x ≤ upper) -> bool --
-    impl_numeric_cmp_op!(
-        params_valid,
-        between, between_range,
-        (lo: f64, hi: f64) -> (lo, hi),
-        bool,
-        |x: &f64, lo: f64, hi: f64| {
-            if lo > hi {
-                (false, false)
-            } else {
-                let v = *x >= lo && *x <= hi;
-                (v, true)
+macro_rules! impl_float_compare {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+
+            // -- Less Than --
+            impl_numeric_cmp_op!(
+                params,
+                lt, lt_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x < value
+            );
+
+            // -- Less Than Equal --
+            impl_numeric_cmp_op!(
+                params,
+                lte, lte_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x <= value
+            );
+
+            // -- Greater Than --
+            impl_numeric_cmp_op!(
+                params,
+                gt, gt_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x > value
+            );
+
+            // -- Greater Than Equal --
+            impl_numeric_cmp_op!(
+                params,
+                gte, gte_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x >= value
+            );
+
+            // -- Equal --
+            impl_numeric_cmp_op!(
+                params,
+                eq, eq_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x == value
+            );
+
+            // -- Not Equal --
+            impl_numeric_cmp_op!(
+                params,
+                neq, neq_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x != value
+            );
+
+            // -- Equal within tolerance (|x - value| <= tol) --
+            impl_numeric_cmp_op!(
+                params,
+                eq_tol, eq_tol_range,
+                (value: $t, tol: $t) -> (value, tol),
+                bool,
+                |x: &$t| (*x - value).abs() <= tol
+            );
+
+            // -- Not Equal within tolerance --
+            impl_numeric_cmp_op!(
+                params,
+                neq_tol, neq_tol_range,
+                (value: $t, tol: $t) -> (value, tol),
+                bool,
+                |x: &$t| (*x - value).abs() > tol
+            );
+
+            // -- Threshold --
+            impl_numeric_cmp_op!(
+                params,
+                threshold, threshold_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x > value
+            );
+
+            // -- Threshold Equal --
+            impl_numeric_cmp_op!(
+                params,
+                threshold_eq, threshold_eq_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x >= value
+            );
+
+            // -- Binarize --
+            impl_numeric_cmp_op!(
+                params,
+                binarize, binarize_range,
+                (value: $t) -> (value),
+                i64,
+                |x: &$t| if *x > value { 1 } else { 0 }
+            );
+
+            // -- Binarize Equal --
+            impl_numeric_cmp_op!(
+                params,
+                binarize_eq, binarize_eq_range,
+                (value: $t) -> (value),
+                i64,
+                |x: &$t| if *x >= value { 1 } else { 0 }
+            );
+
+            // -- Between (lower ≤ x ≤ upper) -> bool --
+            impl_numeric_cmp_op!(
+                params_valid,
+                between, between_range,
+                (lo: $t, hi: $t) -> (lo, hi),
+                bool,
+                |x: &$t, lo: $t, hi: $t| {
+                    if lo > hi {
+                        (false, false)
+                    } else {
+                        let v = *x >= lo && *x <= hi;
+                        (v, true)
+                    }
+                }
+            );
+
+            // -- Packed masks --
+
+            // -- Less Than (mask) --
+            impl_numeric_mask_op!(
+                params,
+                lt_mask,
+                (value: $t),
+                |x: &$t| *x < value
+            );
+
+            // -- Less Than Equal (mask) --
+            impl_numeric_mask_op!(
+                params,
+                lte_mask,
+                (value: $t),
+                |x: &$t| *x <= value
+            );
+
+            // -- Greater Than (mask) --
+            impl_numeric_mask_op!(
+                params,
+                gt_mask,
+                (value: $t),
+                |x: &$t| *x > value
+            );
+
+            // -- Greater Than Equal (mask) --
+            impl_numeric_mask_op!(
+                params,
+                gte_mask,
+                (value: $t),
+                |x: &$t| *x >= value
+            );
+
+            // -- Threshold (mask) --
+            impl_numeric_mask_op!(
+                params,
+                threshold_mask,
+                (value: $t),
+                |x: &$t| *x > value
+            );
+
+            // -- Threshold Equal (mask) --
+            impl_numeric_mask_op!(
+                params,
+                threshold_eq_mask,
+                (value: $t),
+                |x: &$t| *x >= value
+            );
+
+            // -- Between (mask) --
+            impl_numeric_mask_op!(
+                params_valid,
+                between_mask,
+                (lo: $t, hi: $t) -> (lo, hi),
+                |x: &$t, lo: $t, hi: $t| {
+                    if lo > hi {
+                        (false, false)
+                    } else {
+                        let v = *x >= lo && *x <= hi;
+                        (v, true)
+                    }
+                }
+            );
+        }
+
+        impl VectorData<$t> {
+            /// Elementwise equality within `tol`, against another column.
+            #[inline]
+            pub fn eq_vector_tol(&self, other: &Self, tol: $t) -> ErrorResult<VectorData<bool>> {
+                self.zip_cmp(other, |a, b| (a - b).abs() <= tol)
+            }
+
+            /// Elementwise `|a - b| <= atol + rtol * |b|`, as in numpy's `isclose`.
+            /// Equal infinities are close; NaN is never close (numpy's default
+            /// `equal_nan=False`). Nulls on either side yield nulls.
+            pub fn is_close(&self, other: &Self, rtol: $t, atol: $t) -> ErrorResult<VectorData<bool>> {
+                self.zip_cmp(other, |a, b| {
+                    a == b || (a - b).abs() <= atol + rtol * b.abs()
+                })
             }
         }
-    );
+    )*};
+}
 
-}
\ No newline at end of file
+impl_float_compare!(f64, f32);
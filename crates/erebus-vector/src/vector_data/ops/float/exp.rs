@@ -1,25 +1,41 @@
 // === Imports ===
 use crate::prelude::*;
 use super::super::macros::*;
+use super::special_fns::{finite, finite_inplace, SpecialFloat};
 
 // === Impl ===
 
-impl VectorData<f64> {
-    // -- Exponential --
-    impl_unary_op!(
-        noparams, inplace,
-        exp, exp_inplace, exp_range,
-        f64,
-        |x: &f64| x.exp(),
-        |x: &mut f64| *x = x.exp()
-    );
+macro_rules! impl_float_exp {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            // -- Exponential --
+            impl_unary_op!(
+                noparams, inplace,
+                exp, exp_inplace, exp_range,
+                $t,
+                |x: &$t| x.exp(),
+                |x: &mut $t| *x = x.exp()
+            );
 
-    // -- Exponential Minus 1 --
-    impl_unary_op!(
-        noparams, inplace,
-        exp_m1, exp_m1_inplace, exp_m1_range,
-        f64,
-        |x: &f64| x.exp_m1(),
-        |x: &mut f64| *x = x.exp_m1()
-    );
-}
\ No newline at end of file
+            // -- Exponential Minus 1 --
+            impl_unary_op!(
+                noparams, inplace,
+                exp_m1, exp_m1_inplace, exp_m1_range,
+                $t,
+                |x: &$t| x.exp_m1(),
+                |x: &mut $t| *x = x.exp_m1()
+            );
+
+            // -- Exponential Base 10 (Inf becomes NA) --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                exp10, exp10_inplace, exp10_range,
+                $t,
+                |x: &$t| finite(10_f64.powf(x.widen())),
+                |x: &mut $t| finite_inplace(x, 10_f64.powf(x.widen()))
+            );
+        }
+    )*};
+}
+
+impl_float_exp!(f64, f32);
@@ -7,4 +7,8 @@ mod power;
 mod logs;
 mod trig;
 mod cut;
-mod compare;
\ No newline at end of file
+mod compare;
+mod special_fns;
+mod activation;
+mod special;
+mod softmax;
\ No newline at end of file
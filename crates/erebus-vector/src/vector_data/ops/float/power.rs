@@ -4,56 +4,62 @@ use super::super::macros::*;
 
 // === Impl ===
 
-impl VectorData<f64> {
-    // -- Power Float --
-    impl_unary_op!(
-        params_valid, inplace,
-        powf, powf_inplace, powf_range,
-        (power: f64) -> (power),
-        f64,
-        |x: &f64, power: f64| {
-            let y = x.powf(power);
-            if y.is_nan() { (0_f64, false) } else { (y, true) }
-        },
-        |x: &mut f64, power: f64| {
-            let y = x.powf(power);
-            if y.is_nan() {
-                false
-            } else {
-                *x = y;
-                true
-            }
-        }
-    );
+macro_rules! impl_float_power {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            // -- Power Float --
+            impl_unary_op!(
+                params_valid, inplace,
+                powf, powf_inplace, powf_range,
+                (power: $t) -> (power),
+                $t,
+                |x: &$t, power: $t| {
+                    let y = x.powf(power);
+                    if y.is_nan() { (0.0, false) } else { (y, true) }
+                },
+                |x: &mut $t, power: $t| {
+                    let y = x.powf(power);
+                    if y.is_nan() {
+                        false
+                    } else {
+                        *x = y;
+                        true
+                    }
+                }
+            );
+
+            // -- Power Integer --
+            impl_unary_op!(
+                params_valid, inplace,
+                powi, powi_inplace, powi_range,
+                (power: i64) -> (power),
+                $t,
+                |x: &$t, power: i64| {
+                    let y = x.powi(power as i32);
+                    if y.is_nan() { (0.0, false) } else { (y, true) }
+                },
+                |x: &mut $t, power: i64| {
+                    let y = x.powi(power as i32);
+                    if y.is_nan() {
+                        false
+                    } else {
+                        *x = y;
+                        true
+                    }
+                }
+            );
 
-    // -- Power Integer --
-    impl_unary_op!(
-        params_valid, inplace,
-        powi, powi_inplace, powi_range,
-        (power: i64) -> (power),
-        f64,
-        |x: &f64, power: i64| {
-            let y = x.powi(power as i32);
-            if y.is_nan() { (0_f64, false) } else { (y, true) }
-        },
-        |x: &mut f64, power: i64| {
-            let y = x.powi(power as i32);
-            if y.is_nan() {
-                false
-            } else {
-                *x = y;
-                true
-            }
+            // -- Power Integer Positive --
+            impl_unary_op!(
+                params, inplace,
+                powi_pos, powi_pos_inplace, powi_pos_range,
+                (power: u64) -> (power),
+                $t,
+                |x: &$t, power: u64| x.powi(power as i32),
+                |x: &mut $t, power: u64| *x = x.powi(power as i32)
+            );
         }
-    );
+    )*};
+}
 
-    // -- Power Integer Positive --
-    impl_unary_op!(
-        params, inplace,
-        powi_pos, powi_pos_inplace, powi_pos_range,
-        (power: u64) -> (power),
-        f64,
-        |x: &f64, power: u64| x.powi(power as i32),
-        |x: &mut f64, power: u64| *x = x.powi(power as i32)
-    );
-}
\ No newline at end of file
+impl_float_power!(f64, f32);
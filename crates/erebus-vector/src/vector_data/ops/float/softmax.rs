@@ -0,0 +1,94 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::vector_data::reduce::log_sum_exp::LogSumExpReducer;
+use super::special_fns::{finite_inplace, SpecialFloat};
+
+// === Impl ===
+// Whole-vector ops: each value is normalised by the log-sum-exp of all
+// valid values, so large inputs never overflow. Nulls stay null and are
+// left out of the normaliser.
+
+macro_rules! impl_float_softmax {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            /// Applies `f(x - max, sum)` to every valid value, where `max` and `sum`
+            /// come from the log-sum-exp reducer. Non-finite inputs are left out
+            /// of the normaliser; they and any non-finite results become null.
+            #[inline]
+            fn normalise_by_lse(&mut self, f: impl Fn(f64, f64) -> f64) {
+                let acc = self._reduce(LogSumExpReducer);
+                for (i, x) in self.data.iter_mut().enumerate() {
+                    if !self.validity[i] {
+                        continue;
+                    }
+                    if !x.is_finite() || !finite_inplace(x, f(x.widen() - acc.max, acc.sum)) {
+                        self.validity.set(i, false);
+                    }
+                }
+            }
+
+            /// Splices `f` applied to `[start, end)` back into a full copy when `full`.
+            #[inline]
+            fn normalise_range(&self, start: usize, end: usize, full: bool, f: fn(&mut Self)) -> Self {
+                let n = self.data.len();
+                if start >= end || start >= n {
+                    return VectorData::empty();
+                }
+                let end = end.min(n);
+
+                let mut mid = self.slice(start, end);
+                f(&mut mid);
+                if !full {
+                    return mid;
+                }
+
+                let mut out = self.clone();
+                out.data[start..end].copy_from_slice(&mid.data);
+                out.validity[start..end].copy_from_bitslice(&mid.validity);
+                out
+            }
+
+            // -- Softmax --
+
+            #[inline]
+            pub fn softmax(&self) -> VectorData<$t> {
+                let mut out = self.clone();
+                out.softmax_inplace();
+                out
+            }
+
+            #[inline]
+            pub fn softmax_inplace(&mut self) {
+                self.normalise_by_lse(|shifted, sum| shifted.exp() / sum);
+            }
+
+            /// Softmax over `[start, end)` only; `full` keeps the values outside untouched.
+            #[inline]
+            pub fn softmax_range(&self, start: usize, end: usize, full: bool) -> VectorData<$t> {
+                self.normalise_range(start, end, full, Self::softmax_inplace)
+            }
+
+            // -- Log Softmax --
+
+            #[inline]
+            pub fn log_softmax(&self) -> VectorData<$t> {
+                let mut out = self.clone();
+                out.log_softmax_inplace();
+                out
+            }
+
+            #[inline]
+            pub fn log_softmax_inplace(&mut self) {
+                self.normalise_by_lse(|shifted, sum| shifted - sum.ln());
+            }
+
+            /// Log-softmax over `[start, end)` only; `full` keeps the values outside untouched.
+            #[inline]
+            pub fn log_softmax_range(&self, start: usize, end: usize, full: bool) -> VectorData<$t> {
+                self.normalise_range(start, end, full, Self::log_softmax_inplace)
+            }
+        }
+    )*};
+}
+
+impl_float_softmax!(f64, f32);
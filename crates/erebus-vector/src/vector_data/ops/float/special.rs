@@ -0,0 +1,50 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+use super::special_fns::{finite, finite_inplace, erf, erfc, gamma, lgamma, SpecialFloat};
+
+// === Impl ===
+
+macro_rules! impl_float_special {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            // -- Error Function --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                erf, erf_inplace, erf_range,
+                $t,
+                |x: &$t| finite(erf(x.widen())),
+                |x: &mut $t| finite_inplace(x, erf(x.widen()))
+            );
+
+            // -- Complementary Error Function --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                erfc, erfc_inplace, erfc_range,
+                $t,
+                |x: &$t| finite(erfc(x.widen())),
+                |x: &mut $t| finite_inplace(x, erfc(x.widen()))
+            );
+
+            // -- Gamma, NA at poles and on overflow --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                gamma, gamma_inplace, gamma_range,
+                $t,
+                |x: &$t| finite(gamma(x.widen())),
+                |x: &mut $t| finite_inplace(x, gamma(x.widen()))
+            );
+
+            // -- Log Gamma: ln|Gamma(x)| --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                lgamma, lgamma_inplace, lgamma_range,
+                $t,
+                |x: &$t| finite(lgamma(x.widen())),
+                |x: &mut $t| finite_inplace(x, lgamma(x.widen()))
+            );
+        }
+    )*};
+}
+
+impl_float_special!(f64, f32);
@@ -0,0 +1,194 @@
+// === Imports ===
+use std::f64::consts::{PI, SQRT_2};
+
+// === Impl ===
+// Scalar special functions used by the float ops. Implemented in-crate
+// to avoid a libm dependency; accuracy is around 1e-14 relative.
+
+/// Float element types. Special functions evaluate in f64 and narrow back.
+pub(crate) trait SpecialFloat: Copy {
+    fn widen(self) -> f64;
+    fn narrow(y: f64) -> Self;
+}
+
+impl SpecialFloat for f64 {
+    #[inline] fn widen(self) -> f64 { self }
+    #[inline] fn narrow(y: f64) -> Self { y }
+}
+
+impl SpecialFloat for f32 {
+    #[inline] fn widen(self) -> f64 { self as f64 }
+    #[inline] fn narrow(y: f64) -> Self { y as f32 }
+}
+
+/// Inf becomes NA: every float op routes its result through here, so
+/// overflow (including on narrowing to f32) yields a null rather than Inf.
+#[inline]
+pub(crate) fn finite<T: SpecialFloat>(y: f64) -> (T, bool) {
+    let y = T::narrow(y);
+    if y.widen().is_finite() { (y, true) } else { (T::narrow(0.0), false) }
+}
+
+/// In-place counterpart of [`finite`].
+#[inline]
+pub(crate) fn finite_inplace<T: SpecialFloat>(x: &mut T, y: f64) -> bool {
+    let y = T::narrow(y);
+    if y.widen().is_finite() {
+        *x = y;
+        true
+    } else {
+        false
+    }
+}
+
+/// Logistic sigmoid, evaluated on the side that cannot overflow.
+#[inline]
+pub(crate) fn expit(x: f64) -> f64 {
+    if x >= 0.0 {
+        1.0 / (1.0 + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (1.0 + e)
+    }
+}
+
+/// Inverse of [`expit`]; infinite at 0 and 1, NaN outside.
+#[inline]
+pub(crate) fn logit(x: f64) -> f64 {
+    (x / (1.0 - x)).ln()
+}
+
+/// `ln(1 + exp(x))` without overflow for large `x`.
+#[inline]
+pub(crate) fn softplus(x: f64) -> f64 {
+    x.max(0.0) + (-x.abs()).exp().ln_1p()
+}
+
+/// Exact GELU: `x * Phi(x)`, via `erfc` so large negative `x` keeps
+/// its tiny tail instead of cancelling to zero.
+#[inline]
+pub(crate) fn gelu(x: f64) -> f64 {
+    0.5 * x * erfc(-x / SQRT_2)
+}
+
+const FRAC_2_SQRT_PI: f64 = std::f64::consts::FRAC_2_SQRT_PI;
+
+/// Maclaurin series, accurate for |x| < 2.
+fn erf_series(x: f64) -> f64 {
+    let x2 = x * x;
+    let mut term = x;
+    let mut sum = x;
+    for n in 1..100 {
+        term *= -x2 / n as f64;
+        let add = term / (2 * n + 1) as f64;
+        sum += add;
+        if add.abs() < 1e-17 * sum.abs() {
+            break;
+        }
+    }
+    FRAC_2_SQRT_PI * sum
+}
+
+/// Continued fraction (modified Lentz), accurate for x >= 2.
+fn erfc_cf(x: f64) -> f64 {
+    let tiny = 1e-300;
+    let mut f = x;
+    let mut c = f;
+    let mut d = 0.0;
+    for k in 1..300 {
+        let a = k as f64 / 2.0;
+        d = x + a * d;
+        if d.abs() < tiny { d = tiny; }
+        d = 1.0 / d;
+        c = x + a / c;
+        if c.abs() < tiny { c = tiny; }
+        let delta = c * d;
+        f *= delta;
+        if (delta - 1.0).abs() < 1e-16 {
+            break;
+        }
+    }
+    (-x * x).exp() / (f * PI.sqrt())
+}
+
+/// Error function.
+pub(crate) fn erf(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x.abs() < 2.0 {
+        erf_series(x)
+    } else {
+        x.signum() * (1.0 - erfc_cf(x.abs()))
+    }
+}
+
+/// Complementary error function, `1 - erf(x)` without cancellation for large `x`.
+pub(crate) fn erfc(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x >= 2.0 {
+        erfc_cf(x)
+    } else if x <= -2.0 {
+        2.0 - erfc_cf(-x)
+    } else {
+        1.0 - erf_series(x)
+    }
+}
+
+// Lanczos approximation, g = 7, n = 9.
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+#[inline]
+fn is_pole(x: f64) -> bool {
+    x <= 0.0 && x.fract() == 0.0
+}
+
+#[inline]
+fn lanczos_sum(x: f64) -> f64 {
+    LANCZOS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS[0], |a, (i, c)| a + c / (x + (i + 1) as f64))
+}
+
+/// Gamma function; NaN at the poles (0, -1, -2, ...).
+pub(crate) fn gamma(x: f64) -> f64 {
+    if x.is_nan() || is_pole(x) {
+        return f64::NAN;
+    }
+    if x < 0.5 {
+        // Reflection: Γ(x) Γ(1 - x) = π / sin(πx)
+        return PI / ((PI * x).sin() * gamma(1.0 - x));
+    }
+    let x = x - 1.0;
+    let t = x + LANCZOS_G + 0.5;
+    // Split t^(x + 0.5) so it doesn't overflow before e^-t scales it down.
+    let p = t.powf((x + 0.5) / 2.0);
+    (2.0 * PI).sqrt() * p * (-t).exp() * p * lanczos_sum(x)
+}
+
+/// `ln|Γ(x)|`; NaN at the poles.
+pub(crate) fn lgamma(x: f64) -> f64 {
+    if x.is_nan() || is_pole(x) {
+        return f64::NAN;
+    }
+    if x < 0.5 {
+        return (PI / (PI * x).sin().abs()).ln() - lgamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + LANCZOS_G + 0.5;
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + lanczos_sum(x).ln()
+}
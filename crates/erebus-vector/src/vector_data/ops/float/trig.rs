@@ -4,191 +4,197 @@ use super::super::macros::*;
 
 // === Impl ===
 
-impl VectorData<f64> {
+macro_rules! impl_float_trig {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
 
-    // -- Sin --
-    impl_unary_op!(
-        noparams, inplace,
-        sin, sin_inplace, sin_range,
-        f64,
-        |x: &f64| x.sin(),
-        |x: &mut f64| { *x = x.sin(); }
-    );
+            // -- Sin --
+            impl_unary_op!(
+                noparams, inplace,
+                sin, sin_inplace, sin_range,
+                $t,
+                |x: &$t| x.sin(),
+                |x: &mut $t| { *x = x.sin(); }
+            );
 
-    // -- Cos --
-    impl_unary_op!(
-        noparams, inplace,
-        cos, cos_inplace, cos_range,
-        f64,
-        |x: &f64| x.cos(),
-        |x: &mut f64| { *x = x.cos(); }
-    );
+            // -- Cos --
+            impl_unary_op!(
+                noparams, inplace,
+                cos, cos_inplace, cos_range,
+                $t,
+                |x: &$t| x.cos(),
+                |x: &mut $t| { *x = x.cos(); }
+            );
 
-    // -- Tan --
-    impl_unary_op!(
-        noparams_valid, inplace,
-        tan, tan_inplace, tan_range,
-        f64,
-        |x: &f64| {
-            let y = x.tan();
-            if y.is_finite() { (y, true) } else { (0.0, false) }
-        },
-        |x: &mut f64| {
-            let y = x.tan();
-            if y.is_finite() {
-                *x = y;
-                true
-            } else {
-                *x = 0.0;
-                false
-            }
-        }
-    );
+            // -- Tan --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                tan, tan_inplace, tan_range,
+                $t,
+                |x: &$t| {
+                    let y = x.tan();
+                    if y.is_finite() { (y, true) } else { (0.0, false) }
+                },
+                |x: &mut $t| {
+                    let y = x.tan();
+                    if y.is_finite() {
+                        *x = y;
+                        true
+                    } else {
+                        *x = 0.0;
+                        false
+                    }
+                }
+            );
 
-    // -- Asin --
-    impl_unary_op!(
-        noparams_valid, inplace,
-        asin, asin_inplace, asin_range,
-        f64,
-        |x: &f64| {
-            let y = x.asin();
-            if y.is_finite() { (y, true) } else { (0.0, false) }
-        },
-        |x: &mut f64| {
-            let y = x.asin();
-            if y.is_finite() {
-                *x = y;
-                true
-            } else {
-                false
-            }
-        }
-    );
+            // -- Asin --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                asin, asin_inplace, asin_range,
+                $t,
+                |x: &$t| {
+                    let y = x.asin();
+                    if y.is_finite() { (y, true) } else { (0.0, false) }
+                },
+                |x: &mut $t| {
+                    let y = x.asin();
+                    if y.is_finite() {
+                        *x = y;
+                        true
+                    } else {
+                        false
+                    }
+                }
+            );
 
-    // -- Acos --
-    impl_unary_op!(
-        noparams_valid, inplace,
-        acos, acos_inplace, acos_range,
-        f64,
-        |x: &f64| {
-            let y = x.acos();
-            if y.is_finite() { (y, true) } else { (0.0, false) }
-        },
-        |x: &mut f64| {
-            let y = x.acos();
-            if y.is_finite() {
-                *x = y;
-                true
-            } else {
-                false
-            }
-        }
-    );
+            // -- Acos --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                acos, acos_inplace, acos_range,
+                $t,
+                |x: &$t| {
+                    let y = x.acos();
+                    if y.is_finite() { (y, true) } else { (0.0, false) }
+                },
+                |x: &mut $t| {
+                    let y = x.acos();
+                    if y.is_finite() {
+                        *x = y;
+                        true
+                    } else {
+                        false
+                    }
+                }
+            );
 
-    // -- Atan --
-    impl_unary_op!(
-        noparams, inplace,
-        atan, atan_inplace, atan_range,
-        f64,
-        |x: &f64| x.atan(),
-        |x: &mut f64| *x = x.atan()
-    );
+            // -- Atan --
+            impl_unary_op!(
+                noparams, inplace,
+                atan, atan_inplace, atan_range,
+                $t,
+                |x: &$t| x.atan(),
+                |x: &mut $t| *x = x.atan()
+            );
 
-    // -- Sinh --
-    impl_unary_op!(
-        noparams_valid, inplace,
-        sinh, sinh_inplace, sinh_range,
-        f64,
-        |x: &f64| {
-            let y = x.sinh();
-            if y.is_finite() { (y, true) } else { (0.0, false) }
-        },
-        |x: &mut f64| {
-            let y = x.sinh();
-            if y.is_finite() {
-                *x = y;
-                true
-            } else {
-                false
-            }
-        }
-    );
+            // -- Sinh --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                sinh, sinh_inplace, sinh_range,
+                $t,
+                |x: &$t| {
+                    let y = x.sinh();
+                    if y.is_finite() { (y, true) } else { (0.0, false) }
+                },
+                |x: &mut $t| {
+                    let y = x.sinh();
+                    if y.is_finite() {
+                        *x = y;
+                        true
+                    } else {
+                        false
+                    }
+                }
+            );
 
-    // -- Cosh --
-    impl_unary_op!(
-        noparams_valid, inplace,
-        cosh, cosh_inplace, cosh_range,
-        f64,
-        |x: &f64| {
-            let y = x.cosh();
-            if y.is_finite() { (y, true) } else { (0.0, false) }
-        },
-        |x: &mut f64| {
-            let y = x.cosh();
-            if y.is_finite() {
-                *x = y;
-                true
-            } else {
-                false
-            }
-        }
-    );
+            // -- Cosh --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                cosh, cosh_inplace, cosh_range,
+                $t,
+                |x: &$t| {
+                    let y = x.cosh();
+                    if y.is_finite() { (y, true) } else { (0.0, false) }
+                },
+                |x: &mut $t| {
+                    let y = x.cosh();
+                    if y.is_finite() {
+                        *x = y;
+                        true
+                    } else {
+                        false
+                    }
+                }
+            );
 
-    // -- Tanh --
-    impl_unary_op!(
-        noparams, inplace,
-        tanh, tanh_inplace, tanh_range,
-        f64,
-        |x: &f64| x.tanh(),
-        |x: &mut f64| *x = x.tanh()
-    );
+            // -- Tanh --
+            impl_unary_op!(
+                noparams, inplace,
+                tanh, tanh_inplace, tanh_range,
+                $t,
+                |x: &$t| x.tanh(),
+                |x: &mut $t| *x = x.tanh()
+            );
 
-    // -- Asinh --
-    impl_unary_op!(
-        noparams, inplace,
-        asinh, asinh_inplace, asinh_range,
-        f64,
-        |x: &f64| x.asinh(),
-        |x: &mut f64| *x = x.asinh()
-    );
+            // -- Asinh --
+            impl_unary_op!(
+                noparams, inplace,
+                asinh, asinh_inplace, asinh_range,
+                $t,
+                |x: &$t| x.asinh(),
+                |x: &mut $t| *x = x.asinh()
+            );
 
-    // -- Acosh --
-    impl_unary_op!(
-        noparams_valid, inplace,
-        acosh, acosh_inplace, acosh_range,
-        f64,
-        |x: &f64| {
-            let y = x.acosh();
-            if y.is_finite() { (y, true) } else { (0.0, false) }
-        },
-        |x: &mut f64| {
-            let y = x.acosh();
-            if y.is_finite() {
-                *x = y;
-                true
-            } else {
-                false
-            }
-        }
-    );
+            // -- Acosh --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                acosh, acosh_inplace, acosh_range,
+                $t,
+                |x: &$t| {
+                    let y = x.acosh();
+                    if y.is_finite() { (y, true) } else { (0.0, false) }
+                },
+                |x: &mut $t| {
+                    let y = x.acosh();
+                    if y.is_finite() {
+                        *x = y;
+                        true
+                    } else {
+                        false
+                    }
+                }
+            );
 
-    // -- Atanh --
-    impl_unary_op!(
-        noparams_valid, inplace,
-        atanh, atanh_inplace, atanh_range,
-        f64,
-        |x: &f64| {
-            let y = x.atanh();
-            if y.is_finite() { (y, true) } else { (0.0, false) }
-        },
-        |x: &mut f64| {
-            let y = x.atanh();
-            if y.is_finite() {
-                *x = y;
-                true
-            } else {
-                false
-            }
+            // -- Atanh --
+            impl_unary_op!(
+                noparams_valid, inplace,
+                atanh, atanh_inplace, atanh_range,
+                $t,
+                |x: &$t| {
+                    let y = x.atanh();
+                    if y.is_finite() { (y, true) } else { (0.0, false) }
+                },
+                |x: &mut $t| {
+                    let y = x.atanh();
+                    if y.is_finite() {
+                        *x = y;
+                        true
+                    } else {
+                        false
+                    }
+                }
+            );
         }
-    );
-}
\ No newline at end of file
+    )*};
+}
+
+impl_float_trig!(f64, f32);
@@ -4,93 +4,182 @@ use super::super::macros::*;
 
 // === Impl ===
 
-impl VectorData<i64> {
-
-    // -- Less Than --
-    impl_numeric_cmp_op!(
-        params,
-        lt, lt_range,
-        (value: i64) -> (value),
-        bool,
-        |x: &i64| *x < value
-    );
-
-    // -- Less Than Equal --
-    impl_numeric_cmp_op!(
-        params,
-        lte, lte_range,
-        (value: i64) -> (value),
-        bool,
-        |x: &i64| *x <= value
-    );
-
-    // -- Greater Than --
-    impl_numeric_cmp_op!(
-        params,
-        gt, gt_range,
-        (value: i64) -> (value),
-        bool,
-        |x: &i64| *x > value
-    );
-
-    // -- Greater Than Equal --
-    impl_numeric_cmp_op!(
-        params,
-        gte, gte_range,
-        (value: i64) -> (value),
-        bool,
-        |x: &i64| *x >= value
-    );
-
-    // -- Threshold --
-    impl_numeric_cmp_op!(
-        params,
-        threshold, threshold_range,
-        (value: f64) -> (value),
-        bool,
-        |x: &i64| (*x as f64) > value
-    );
-
-    // -- Threshold Equal --
-    impl_numeric_cmp_op!(
-        params,
-        threshold_eq, threshold_eq_range,
-        (value: f64) -> (value),
-        bool,
-        |x: &i64| (*x as f64) >= value
-    );
-
-    // -- Binarize --
-    impl_numeric_cmp_op!(
-        params,
-        binarize, binarize_range,
-        (value: f64) -> (value),
-        i64,
-        |x: &i64| if (*x as f64) > value { 1 } else { 0 }
-    );
-
-    // -- Binarize Equal --
-    impl_numeric_cmp_op!(
-        params,
-        binarize_eq, binarize_eq_range,
-        (value: f64) -> (value),
-        i64,
-        |x: &i64| if (*x as f64) >= value { 1 } else { 0 }
-    );
-
-    // -- Between (lower ≤ x ≤ upper) -> bool --
-    impl_numeric_cmp_op!(
-        params_valid,
-        between, between_range,
-        (lo: i64, hi: i64) -> (lo, hi),
-        bool,
-        |x: &i64, lo: i64, hi: i64| {
-            if lo > hi {
-                (false, false)
-            } else {
-                let v = *x >= lo && *x <= hi;
-                (v, true)
-            }
+macro_rules! impl_int_compare {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+
+            // -- Less Than --
+            impl_numeric_cmp_op!(
+                params,
+                lt, lt_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x < value
+            );
+
+            // -- Less Than Equal --
+            impl_numeric_cmp_op!(
+                params,
+                lte, lte_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x <= value
+            );
+
+            // -- Greater Than --
+            impl_numeric_cmp_op!(
+                params,
+                gt, gt_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x > value
+            );
+
+            // -- Greater Than Equal --
+            impl_numeric_cmp_op!(
+                params,
+                gte, gte_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x >= value
+            );
+
+            // -- Equal --
+            impl_numeric_cmp_op!(
+                params,
+                eq, eq_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x == value
+            );
+
+            // -- Not Equal --
+            impl_numeric_cmp_op!(
+                params,
+                neq, neq_range,
+                (value: $t) -> (value),
+                bool,
+                |x: &$t| *x != value
+            );
+
+            // -- Threshold --
+            impl_numeric_cmp_op!(
+                params,
+                threshold, threshold_range,
+                (value: f64) -> (value),
+                bool,
+                |x: &$t| (*x as f64) > value
+            );
+
+            // -- Threshold Equal --
+            impl_numeric_cmp_op!(
+                params,
+                threshold_eq, threshold_eq_range,
+                (value: f64) -> (value),
+                bool,
+                |x: &$t| (*x as f64) >= value
+            );
+
+            // -- Binarize --
+            impl_numeric_cmp_op!(
+                params,
+                binarize, binarize_range,
+                (value: f64) -> (value),
+                $t,
+                |x: &$t| if (*x as f64) > value { 1 } else { 0 }
+            );
+
+            // -- Binarize Equal --
+            impl_numeric_cmp_op!(
+                params,
+                binarize_eq, binarize_eq_range,
+                (value: f64) -> (value),
+                $t,
+                |x: &$t| if (*x as f64) >= value { 1 } else { 0 }
+            );
+
+            // -- Between (lower ≤ x ≤ upper) -> bool --
+            impl_numeric_cmp_op!(
+                params_valid,
+                between, between_range,
+                (lo: $t, hi: $t) -> (lo, hi),
+                bool,
+                |x: &$t, lo: $t, hi: $t| {
+                    if lo > hi {
+                        (false, false)
+                    } else {
+                        let v = *x >= lo && *x <= hi;
+                        (v, true)
+                    }
+                }
+            );
+
+            // -- Packed masks --
+
+            // -- Less Than (mask) --
+            impl_numeric_mask_op!(
+                params,
+                lt_mask,
+                (value: $t),
+                |x: &$t| *x < value
+            );
+
+            // -- Less Than Equal (mask) --
+            impl_numeric_mask_op!(
+                params,
+                lte_mask,
+                (value: $t),
+                |x: &$t| *x <= value
+            );
+
+            // -- Greater Than (mask) --
+            impl_numeric_mask_op!(
+                params,
+                gt_mask,
+                (value: $t),
+                |x: &$t| *x > value
+            );
+
+            // -- Greater Than Equal (mask) --
+            impl_numeric_mask_op!(
+                params,
+                gte_mask,
+                (value: $t),
+                |x: &$t| *x >= value
+            );
+
+            // -- Threshold (mask) --
+            impl_numeric_mask_op!(
+                params,
+                threshold_mask,
+                (value: f64),
+                |x: &$t| (*x as f64) > value
+            );
+
+            // -- Threshold Equal (mask) --
+            impl_numeric_mask_op!(
+                params,
+                threshold_eq_mask,
+                (value: f64),
+                |x: &$t| (*x as f64) >= value
+            );
+
+            // -- Between (mask) --
+            impl_numeric_mask_op!(
+                params_valid,
+                between_mask,
+                (lo: $t, hi: $t) -> (lo, hi),
+                |x: &$t, lo: $t, hi: $t| {
+                    if lo > hi {
+                        (false, false)
+                    } else {
+                        let v = *x >= lo && *x <= hi;
+                        (v, true)
+                    }
+                }
+            );
         }
-    );
-}
\ No newline at end of file
+    )*};
+}
+
+impl_int_compare!(i64, i32, i16, i128, u8, u32, u64);
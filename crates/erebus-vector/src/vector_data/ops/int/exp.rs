@@ -4,21 +4,27 @@ use super::super::macros::*;
 
 // === Impl ===
 
-impl VectorData<i64> {
+macro_rules! impl_int_exp {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
 
-    // -- Exponential --
-    impl_unary_op!(
-        noparams, noinplace,
-        exp, exp_range,
-        f64,
-        |x: &i64| (*x as f64).exp()
-    );
+            // -- Exponential --
+            impl_unary_op!(
+                noparams, noinplace,
+                exp, exp_range,
+                f64,
+                |x: &$t| (*x as f64).exp()
+            );
 
-    // -- Exponentnial Minus 1 --
-    impl_unary_op!(
-        noparams, noinplace,
-        exp_m1, exp_m1_range,
-        f64,
-        |x: &i64| (*x as f64).exp_m1()
-    );
-}
\ No newline at end of file
+            // -- Exponentnial Minus 1 --
+            impl_unary_op!(
+                noparams, noinplace,
+                exp_m1, exp_m1_range,
+                f64,
+                |x: &$t| (*x as f64).exp_m1()
+            );
+        }
+    )*};
+}
+
+impl_int_exp!(i64, i32, i16, i128, u8, u32, u64);
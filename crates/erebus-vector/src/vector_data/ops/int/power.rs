@@ -4,38 +4,55 @@ use super::super::macros::*;
 
 // === Impl ===
 
-impl VectorData<i64> {
-    // -- Power Float --
-    impl_unary_op!(
-        params_valid, noinplace,
-        powf, powf_range,
-        (power: f64) -> (power),
-        f64,
-        |x: &i64, power: f64| {
-            let y = (*x as f64).powf(power);
-            if y.is_nan() { (0_f64, false) } else { (y, true) }
-        }
-    );
+macro_rules! impl_int_power {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            // -- Power Float --
+            impl_unary_op!(
+                params_valid, noinplace,
+                powf, powf_range,
+                (power: f64) -> (power),
+                f64,
+                |x: &$t, power: f64| {
+                    let y = (*x as f64).powf(power);
+                    if y.is_nan() { (0_f64, false) } else { (y, true) }
+                }
+            );
+
+            // -- Power Integer --
+            impl_unary_op!(
+                params_valid, noinplace,
+                powi, powi_range,
+                (power: i64) -> (power),
+                f64,
+                |x: &$t, power: i64| {
+                    let y = (*x as f64).powi(power as i32);
+                    if y.is_nan() { (0_f64, false) } else { (y, true) }
+                }
+            );
 
-    // -- Power Integer --
-    impl_unary_op!(
-        params_valid, noinplace,
-        powi, powi_range,
-        (power: i64) -> (power),
-        f64,
-        |x: &i64, power: i64| {
-            let y = (*x as f64).powi(power as i32);
-            if y.is_nan() { (0_f64, false) } else { (y, true) }
+            // -- Power Integer Positive --
+            // Results that overflow `$t` become null.
+            impl_unary_op!(
+                params_valid, inplace,
+                powi_pos, powi_pos_inplace, powi_pos_range,
+                (power: u64) -> (power),
+                $t,
+                |x: &$t, power: u64| {
+                    match u32::try_from(power).ok().and_then(|p| x.checked_pow(p)) {
+                        Some(y) => (y, true),
+                        None => (0, false),
+                    }
+                },
+                |x: &mut $t, power: u64| {
+                    match u32::try_from(power).ok().and_then(|p| x.checked_pow(p)) {
+                        Some(y) => { *x = y; true }
+                        None => { *x = 0; false }
+                    }
+                }
+            );
         }
-    );
+    )*};
+}
 
-    // -- Power Integer Positive --
-    impl_unary_op!(
-        params, inplace,
-        powi_pos, powi_pos_inplace, powi_pos_range,
-        (power: u64) -> (power),
-        i64,
-        |x: &i64, power: u64| x.pow(power as u32),
-        |x: &mut i64, power: u64| *x = x.pow(power as u32)
-    );
-}
\ No newline at end of file
+impl_int_power!(i64, i32, i16, i128, u8, u32, u64);
@@ -4,77 +4,83 @@ use super::super::macros::*;
 
 // === Impl ===
 
-impl VectorData<i64> {
+macro_rules! impl_int_roots {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
 
-    // -- Square Root --
-    impl_unary_op!(
-        noparams_valid, noinplace,
-        sqrt, sqrt_range,
-        f64,
-        |x: &i64| {
-            if *x < 0 {
-                (f64::NAN, false)
-            } else {
-                ((*x as f64).sqrt(), true)
-            }
-        }
-    );
+            // -- Square Root --
+            impl_unary_op!(
+                noparams_valid, noinplace,
+                sqrt, sqrt_range,
+                f64,
+                |x: &$t| {
+                    if (*x as f64) < 0.0 {
+                        (f64::NAN, false)
+                    } else {
+                        ((*x as f64).sqrt(), true)
+                    }
+                }
+            );
 
-    // -- Cube Root --
-    impl_unary_op!(
-        noparams, noinplace,
-        cbrt, cbrt_range,
-        f64,
-        |x: &i64| (*x as f64).cbrt()
-    );
+            // -- Cube Root --
+            impl_unary_op!(
+                noparams, noinplace,
+                cbrt, cbrt_range,
+                f64,
+                |x: &$t| (*x as f64).cbrt()
+            );
 
-    // -- Nth Root --
-    impl_unary_op!(
-        params_valid,
-        noinplace,
-        nth_root, nth_root_range,
-        (n: f64) -> (n),
-        f64,
-        |x: &i64, n: f64| {
-            let y = (*x as f64).powf(1.0 / n);
-            if y.is_nan() {
-                (0_f64, false)
-            } else {
-                (y, true)
-            }
-        }
-    );
+            // -- Nth Root --
+            impl_unary_op!(
+                params_valid,
+                noinplace,
+                nth_root, nth_root_range,
+                (n: f64) -> (n),
+                f64,
+                |x: &$t, n: f64| {
+                    let y = (*x as f64).powf(1.0 / n);
+                    if y.is_nan() {
+                        (0_f64, false)
+                    } else {
+                        (y, true)
+                    }
+                }
+            );
 
-    // -- Reciprocal Square Root --
-    impl_unary_op!(
-        noparams_valid, noinplace,
-        rsqrt, rsqrt_range,
-        f64,
-        |x: &i64| {
-            if *x < 0 {
-                (0_f64, false)
-            } else {
-                let root = (*x as f64).sqrt();
-                if root == 0.0 {
-                    (f64::INFINITY, true)
-                } else {
-                    (1.0 / root, true)
+            // -- Reciprocal Square Root --
+            impl_unary_op!(
+                noparams_valid, noinplace,
+                rsqrt, rsqrt_range,
+                f64,
+                |x: &$t| {
+                    if (*x as f64) < 0.0 {
+                        (0_f64, false)
+                    } else {
+                        let root = (*x as f64).sqrt();
+                        if root == 0.0 {
+                            (f64::INFINITY, true)
+                        } else {
+                            (1.0 / root, true)
+                        }
+                    }
                 }
-            }
-        }
-    );
+            );
 
-    // -- Reciprocal Cube Root --
-    impl_unary_op!(
-        noparams, noinplace, rcbrt, rcbrt_range,
-        f64,
-        |x: &i64| {
-            let root = (*x as f64).cbrt();
-            if root == 0.0 {
-                f64::INFINITY
-            } else {
-                1.0 / root
-            }
+            // -- Reciprocal Cube Root --
+            impl_unary_op!(
+                noparams, noinplace, rcbrt, rcbrt_range,
+                f64,
+                |x: &$t| {
+                    let root = (*x as f64).cbrt();
+                    if root == 0.0 {
+                        f64::INFINITY
+                    } else {
+                        1.0 / root
+                    }
+                }
+            );
         }
-    );
-}
\ No newline at end of file
+    )*};
+}
+
+impl_int_roots!(i64, i32, i16, i128, u8, u32, u64);
@@ -324,5 +324,33 @@ macro_rules! impl_numeric_cmp_op {
     };
 }
 
+#[macro_export]
+macro_rules! impl_numeric_mask_op {
+
+    // Packed counterpart of `impl_numeric_cmp_op!`: writes results into a `BitmaskData`.
+    (params,
+        $name:ident,
+        ($($param_decl:tt)*),
+        $expr_owned:expr
+    ) => {
+        #[inline]
+        pub fn $name(&self, $($param_decl)*) -> BitmaskData {
+            self.map_to_mask($expr_owned)
+        }
+    };
+
+    (params_valid,
+        $name:ident,
+        ($($param_decl:tt)*) -> ($($param_pass:tt)*),
+        $expr_owned:expr
+    ) => {
+        #[inline]
+        pub fn $name(&self, $($param_decl)*) -> BitmaskData {
+            self.map_to_mask_with_validity(|x| $expr_owned(x, $($param_pass)*))
+        }
+    };
+}
+
 pub(crate) use impl_unary_op;
-pub(crate) use impl_numeric_cmp_op;
\ No newline at end of file
+pub(crate) use impl_numeric_cmp_op;
+pub(crate) use impl_numeric_mask_op;
\ No newline at end of file
@@ -0,0 +1,74 @@
+// === Imports ===
+use crate::prelude::*;
+use crate::bitmask_data::words::{bits_from_words, load_word, WORD_BITS};
+use rayon::prelude::*;
+
+// === Impl ===
+
+impl<T> VectorData<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// Evaluates `f` on every row and packs the results straight into a [`BitmaskData`].
+    /// Validity is copied from `self`.
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn map_to_mask<F>(&self, f: F) -> BitmaskData
+    where
+        F: Fn(&T) -> bool + Sync + Send,
+    {
+        let n = self.data.len();
+        let (use_parallel, _) = should_parallelize(n);
+        let pack = |chunk: &[T]| {
+            chunk.iter()
+                .enumerate()
+                .fold(0u64, |w, (j, x)| w | ((f(x) as u64) << j))
+        };
+
+        let words: Vec<u64> = if use_parallel {
+            self.data.par_chunks(WORD_BITS).map(pack).collect()
+        } else {
+            self.data.chunks(WORD_BITS).map(pack).collect()
+        };
+
+        BitmaskData {
+            values: bits_from_words(words, n),
+            validity: self.validity.clone(),
+        }
+    }
+
+    /// Like `map_to_mask`, but `f(&T) -> (value, valid)` can also null a row.
+    /// Final validity = old_validity & new_validity.
+    #[cfg_attr(feature = "internal", visibility::make(pub))]
+    #[inline]
+    pub(crate) fn map_to_mask_with_validity<F>(&self, f: F) -> BitmaskData
+    where
+        F: Fn(&T) -> (bool, bool) + Sync + Send,
+    {
+        let n = self.data.len();
+        let (use_parallel, _) = should_parallelize(n);
+        let pack = |chunk: &[T]| {
+            chunk.iter()
+                .enumerate()
+                .fold((0u64, 0u64), |(v, m), (j, x)| {
+                    let (val, ok) = f(x);
+                    (v | ((val as u64) << j), m | ((ok as u64) << j))
+                })
+        };
+
+        let words: Vec<(u64, u64)> = if use_parallel {
+            self.data.par_chunks(WORD_BITS).map(pack).collect()
+        } else {
+            self.data.chunks(WORD_BITS).map(pack).collect()
+        };
+
+        let validity = words.iter()
+            .zip(self.validity.chunks(WORD_BITS))
+            .map(|(w, old)| w.1 & load_word(old));
+
+        BitmaskData {
+            values: bits_from_words(words.iter().map(|w| w.0), n),
+            validity: bits_from_words(validity, n),
+        }
+    }
+}
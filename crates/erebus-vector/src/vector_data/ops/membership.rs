@@ -0,0 +1,40 @@
+// === Imports ===
+use crate::prelude::*;
+
+// === Impl ===
+// Membership tests backed by a hash set. Nulls in `self` stay null;
+// nulls in the lookup vector are ignored.
+
+macro_rules! impl_is_in {
+    ($t:ty, |$x:ident| $key:expr) => {
+        impl VectorData<$t> {
+            /// `true` where the value appears in `values`.
+            pub fn is_in(&self, values: &[$t]) -> VectorData<bool> {
+                let set: AHashSet<_> = values.iter().map(|$x| $key).collect();
+                self.map_unary_owned(|$x| set.contains(&$key))
+            }
+
+            /// `true` where the value appears among the valid values of `other`.
+            pub fn is_in_vector(&self, other: &VectorData<$t>) -> VectorData<bool> {
+                let set: AHashSet<_> = other.data.iter()
+                    .zip(other.validity.iter().by_vals())
+                    .filter(|(_, ok)| *ok)
+                    .map(|($x, _)| $key)
+                    .collect();
+                self.map_unary_owned(|$x| set.contains(&$key))
+            }
+        }
+    };
+}
+
+// Keys borrow from the needles, so String lookups don't allocate.
+impl_is_in!(i64, |x| *x);
+impl_is_in!(i32, |x| *x);
+impl_is_in!(i16, |x| *x);
+impl_is_in!(i128, |x| *x);
+impl_is_in!(u8, |x| *x);
+impl_is_in!(u32, |x| *x);
+impl_is_in!(u64, |x| *x);
+impl_is_in!(f64, |x| OrderedFloat(*x));
+impl_is_in!(f32, |x| OrderedFloat(*x));
+impl_is_in!(String, |x| x.as_str());
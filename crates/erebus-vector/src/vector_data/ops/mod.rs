@@ -1,8 +1,12 @@
 pub mod map;
+pub mod mask;
 pub mod macros;
 pub mod int;
 pub mod float;
 pub mod bool;
+pub mod string;
+pub mod compare;
+pub mod membership;
 pub mod overloads;
 
 pub use macros::*;
\ No newline at end of file
@@ -4,180 +4,150 @@ use std::ops::{Neg,Add, Sub, Mul, Div};
 
 // === Impl ===
 
-impl Neg for VectorData<i64> {
-    type Output = VectorData<i64>;
-
+macro_rules! impl_neg_overload {
+    ($($t:ty),*) => {$(
+        impl Neg for VectorData<$t> {
+            type Output = VectorData<$t>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                (&self).neg()   // calls unary op
+            }
+        }
+
+        impl<'a> Neg for &'a VectorData<$t> {
+            type Output = VectorData<$t>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                (&self).neg()
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_scalar_overload {
+    ($scalar:ty => $out:ty; $($t:ty),*) => {$(
+        impl Add<$scalar> for VectorData<$t> {
+            type Output = VectorData<$out>;
+            fn add(self, rhs: $scalar) -> Self::Output {
+                self.add_scalar(rhs)
+            }
+        }
+
+        impl<'a> Add<$scalar> for &'a VectorData<$t> {
+            type Output = VectorData<$out>;
+            fn add(self, rhs: $scalar) -> Self::Output {
+                self.add_scalar(rhs)
+            }
+        }
+
+        impl Sub<$scalar> for VectorData<$t> {
+            type Output = VectorData<$out>;
+            fn sub(self, rhs: $scalar) -> Self::Output {
+                self.sub_scalar(rhs)
+            }
+        }
+
+        impl<'a> Sub<$scalar> for &'a VectorData<$t> {
+            type Output = VectorData<$out>;
+            fn sub(self, rhs: $scalar) -> Self::Output {
+                self.sub_scalar(rhs)
+            }
+        }
+
+        impl Mul<$scalar> for VectorData<$t> {
+            type Output = VectorData<$out>;
+            fn mul(self, rhs: $scalar) -> Self::Output {
+                self.mul_scalar(rhs)
+            }
+        }
+
+        impl<'a> Mul<$scalar> for &'a VectorData<$t> {
+            type Output = VectorData<$out>;
+            fn mul(self, rhs: $scalar) -> Self::Output {
+                self.mul_scalar(rhs)
+            }
+        }
+
+        impl Div<$scalar> for VectorData<$t> {
+            type Output = VectorData<$out>;
+            fn div(self, rhs: $scalar) -> Self::Output {
+                self.div_scalar(rhs)
+            }
+        }
+
+        impl<'a> Div<$scalar> for &'a VectorData<$t> {
+            type Output = VectorData<$out>;
+            fn div(self, rhs: $scalar) -> Self::Output {
+                self.div_scalar(rhs)
+            }
+        }
+
+        // Equivalent to inv div
+        impl Div<VectorData<$t>> for $scalar {
+            type Output = VectorData<$out>;
+            fn div(self, rhs: VectorData<$t>) -> Self::Output {
+                rhs.inv_div_scalar(self)
+            }
+        }
+
+        impl<'a> Div<&'a VectorData<$t>> for $scalar {
+            type Output = VectorData<$out>;
+            fn div(self, rhs: &'a VectorData<$t>) -> Self::Output {
+                rhs.inv_div_scalar(self)
+            }
+        }
+    )*};
+}
+
+// Integer columns promote to f64; float columns keep their own width.
+impl_neg_overload!(i64, i32, i16, i128, f64, f32);
+impl_scalar_overload!(f64 => f64; i64, i32, i16, i128, u8, u32, u64, f64);
+impl_scalar_overload!(f32 => f32; f32);
+
+// -- Kleene logic on VectorData<bool> --
+
+macro_rules! impl_kleene_overload {
+    ($trait:ident, $method:ident, $inner:ident) => {
+        impl std::ops::$trait for VectorData<bool> {
+            type Output = VectorData<bool>;
+            /// # Panics
+            /// Panics if the lengths differ; use `and`/`or`/`xor` for a checked result.
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<'a> std::ops::$trait<&'a VectorData<bool>> for &'a VectorData<bool> {
+            type Output = VectorData<bool>;
+            /// # Panics
+            /// Panics if the lengths differ; use `and`/`or`/`xor` for a checked result.
+            #[inline]
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$inner(rhs).expect(concat!("VectorData<bool> ", stringify!($method), ": length mismatch"))
+            }
+        }
+    };
+}
+
+impl_kleene_overload!(BitAnd, bitand, and);
+impl_kleene_overload!(BitOr, bitor, or);
+impl_kleene_overload!(BitXor, bitxor, xor);
+
+impl std::ops::Not for VectorData<bool> {
+    type Output = VectorData<bool>;
     #[inline]
-    fn neg(self) -> Self::Output {
-        (&self).neg()   // calls unary op
+    fn not(self) -> Self::Output {
+        VectorData::<bool>::not(&self)
     }
 }
 
-impl<'a> Neg for &'a VectorData<i64> {
-    type Output = VectorData<i64>;
-
+impl std::ops::Not for &VectorData<bool> {
+    type Output = VectorData<bool>;
     #[inline]
-    fn neg(self) -> Self::Output {
-        (&self).neg()
-    }
-}
-
-impl Add<f64> for VectorData<i64> {
-    type Output = VectorData<f64>;
-    fn add(self, rhs: f64) -> Self::Output {
-        self.add_scalar(rhs)
-    }
-}
-
-impl<'a> Add<f64> for &'a VectorData<i64> {
-    type Output = VectorData<f64>;
-    fn add(self, rhs: f64) -> Self::Output {
-        self.add_scalar(rhs)
-    }
-}
-
-impl Sub<f64> for VectorData<i64> {
-    type Output = VectorData<f64>;
-    fn sub(self, rhs: f64) -> Self::Output {
-        self.sub_scalar(rhs)
-    }
-}
-
-impl<'a> Sub<f64> for &'a VectorData<i64> {
-    type Output = VectorData<f64>;
-    fn sub(self, rhs: f64) -> Self::Output {
-        self.sub_scalar(rhs)
-    }
-}
-
-impl Mul<f64> for VectorData<i64> {
-    type Output = VectorData<f64>;
-    fn mul(self, rhs: f64) -> Self::Output {
-        self.mul_scalar(rhs)
-    }
-}
-
-impl<'a> Mul<f64> for &'a VectorData<i64> {
-    type Output = VectorData<f64>;
-    fn mul(self, rhs: f64) -> Self::Output {
-        self.mul_scalar(rhs)
-    }
-}
-
-impl Div<f64> for VectorData<i64> {
-    type Output = VectorData<f64>;
-    fn div(self, rhs: f64) -> Self::Output {
-        self.div_scalar(rhs)
-    }
-}
-
-impl<'a> Div<f64> for &'a VectorData<i64> {
-    type Output = VectorData<f64>;
-    fn div(self, rhs: f64) -> Self::Output {
-        self.div_scalar(rhs)
-    }
-}
-
-// Equivalent to inv div
-impl Div<VectorData<i64>> for f64 {
-    type Output = VectorData<f64>;
-    fn div(self, rhs: VectorData<i64>) -> Self::Output {
-        rhs.inv_div_scalar(self)
-    }
-}
-
-impl<'a> Div<&'a VectorData<i64>> for f64 {
-    type Output = VectorData<f64>;
-    fn div(self, rhs: &'a VectorData<i64>) -> Self::Output {
-        rhs.inv_div_scalar(self)
+    fn not(self) -> Self::Output {
+        VectorData::<bool>::not(self)
     }
 }
-
-impl Neg for VectorData<f64> {
-    type Output = VectorData<f64>;
-
-    #[inline]
-    fn neg(self) -> Self::Output {
-        (&self).neg()
-    }
-}
-
-impl<'a> Neg for &'a VectorData<f64> {
-    type Output = VectorData<f64>;
-
-    #[inline]
-    fn neg(self) -> Self::Output {
-        (&self).neg()
-    }
-}
-
-impl Add<f64> for VectorData<f64> {
-    type Output = VectorData<f64>;
-    fn add(self, rhs: f64) -> Self::Output {
-        self.add_scalar(rhs)
-    }
-}
-
-impl<'a> Add<f64> for &'a VectorData<f64> {
-    type Output = VectorData<f64>;
-    fn add(self, rhs: f64) -> Self::Output {
-        self.add_scalar(rhs)
-    }
-}
-
-impl Sub<f64> for VectorData<f64> {
-    type Output = VectorData<f64>;
-    fn sub(self, rhs: f64) -> Self::Output {
-        self.sub_scalar(rhs)
-    }
-}
-
-impl<'a> Sub<f64> for &'a VectorData<f64> {
-    type Output = VectorData<f64>;
-    fn sub(self, rhs: f64) -> Self::Output {
-        self.sub_scalar(rhs)
-    }
-}
-
-impl Mul<f64> for VectorData<f64> {
-    type Output = VectorData<f64>;
-    fn mul(self, rhs: f64) -> Self::Output {
-        self.mul_scalar(rhs)
-    }
-}
-
-impl<'a> Mul<f64> for &'a VectorData<f64> {
-    type Output = VectorData<f64>;
-    fn mul(self, rhs: f64) -> Self::Output {
-        self.mul_scalar(rhs)
-    }
-}
-
-impl Div<f64> for VectorData<f64> {
-    type Output = VectorData<f64>;
-    fn div(self, rhs: f64) -> Self::Output {
-        self.div_scalar(rhs)
-    }
-}
-
-impl<'a> Div<f64> for &'a VectorData<f64> {
-    type Output = VectorData<f64>;
-    fn div(self, rhs: f64) -> Self::Output {
-        self.div_scalar(rhs)
-    }
-}
-
-// Equivalent to inv div
-impl Div<VectorData<f64>> for f64 {
-    type Output = VectorData<f64>;
-    fn div(self, rhs: VectorData<f64>) -> Self::Output {
-        rhs.inv_div_scalar(self)
-    }
-}
-
-impl<'a> Div<&'a VectorData<f64>> for f64 {
-    type Output = VectorData<f64>;
-    fn div(self, rhs: &'a VectorData<f64>) -> Self::Output {
-        rhs.inv_div_scalar(self)
-    }
-}
\ No newline at end of file
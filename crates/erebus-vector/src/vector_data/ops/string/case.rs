@@ -0,0 +1,74 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+
+// === Impl ===
+
+impl VectorData<String> {
+    // -- Upper Case --
+    impl_unary_op!(
+        noparams, noinplace,
+        to_upper, to_upper_range,
+        String,
+        |x: &String| x.to_uppercase()
+    );
+
+    // -- Lower Case --
+    impl_unary_op!(
+        noparams, noinplace,
+        to_lower, to_lower_range,
+        String,
+        |x: &String| x.to_lowercase()
+    );
+
+    // -- Capitalize: first character upper, rest lower --
+    impl_unary_op!(
+        noparams, noinplace,
+        capitalize, capitalize_range,
+        String,
+        |x: &String| capitalize_word(x)
+    );
+
+    // -- Title: capitalize every whitespace-separated word --
+    impl_unary_op!(
+        noparams, noinplace,
+        title, title_range,
+        String,
+        |x: &String| title_case(x)
+    );
+
+    // -- Reverse (by char) --
+    impl_unary_op!(
+        noparams, noinplace,
+        reverse, reverse_range,
+        String,
+        |x: &String| x.chars().rev().collect::<String>()
+    );
+}
+
+/// Upper-cases the first character and lower-cases the rest.
+fn capitalize_word(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+/// Capitalizes each word, keeping the original whitespace.
+fn title_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut at_word_start = true;
+    for c in s.chars() {
+        if c.is_whitespace() {
+            out.push(c);
+            at_word_start = true;
+        } else if at_word_start {
+            out.extend(c.to_uppercase());
+            at_word_start = false;
+        } else {
+            out.extend(c.to_lowercase());
+        }
+    }
+    out
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+
+// === Impl ===
+// Lexicographic (byte-wise) comparisons against a scalar.
+
+impl VectorData<String> {
+
+    // -- Equal --
+    impl_numeric_cmp_op!(
+        params,
+        eq, eq_range,
+        (value: &str) -> (value),
+        bool,
+        |x: &String| x == value
+    );
+
+    // -- Not Equal --
+    impl_numeric_cmp_op!(
+        params,
+        neq, neq_range,
+        (value: &str) -> (value),
+        bool,
+        |x: &String| x != value
+    );
+
+    // -- Less Than --
+    impl_numeric_cmp_op!(
+        params,
+        lt, lt_range,
+        (value: &str) -> (value),
+        bool,
+        |x: &String| x.as_str() < value
+    );
+
+    // -- Less Than Equal --
+    impl_numeric_cmp_op!(
+        params,
+        lte, lte_range,
+        (value: &str) -> (value),
+        bool,
+        |x: &String| x.as_str() <= value
+    );
+
+    // -- Greater Than --
+    impl_numeric_cmp_op!(
+        params,
+        gt, gt_range,
+        (value: &str) -> (value),
+        bool,
+        |x: &String| x.as_str() > value
+    );
+
+    // -- Greater Than Equal --
+    impl_numeric_cmp_op!(
+        params,
+        gte, gte_range,
+        (value: &str) -> (value),
+        bool,
+        |x: &String| x.as_str() >= value
+    );
+}
@@ -0,0 +1,63 @@
+// === Imports ===
+use crate::prelude::*;
+use rayon::prelude::*;
+
+// === Impl ===
+
+impl VectorData<String> {
+    /// Row-wise `self + sep + other`; null where either side is null.
+    /// Not `concat`, which already appends rows (see `extend.rs`).
+    pub fn concat_str(&self, other: &Self, sep: &str) -> ErrorResult<Self> {
+        if self.data.len() != other.data.len() {
+            return Err(ErebusError::LengthMismatch {
+                expected: self.data.len(),
+                found: other.data.len(),
+            });
+        }
+
+        let data = self.data.par_iter()
+            .zip(other.data.par_iter())
+            .map(|(a, b)| {
+                let mut s = String::with_capacity(a.len() + sep.len() + b.len());
+                s.push_str(a);
+                s.push_str(sep);
+                s.push_str(b);
+                s
+            })
+            .collect();
+
+        Ok(VectorData {
+            data,
+            validity: self.validity.clone() & &other.validity,
+        })
+    }
+
+    /// `concat_str` over rows `[start, end)`. With `full`, rows outside
+    /// the range keep `self`'s values; otherwise only the range is returned.
+    pub fn concat_str_range(
+        &self,
+        other: &Self,
+        sep: &str,
+        start: usize,
+        end: usize,
+        full: bool,
+    ) -> ErrorResult<Self> {
+        let n = self.data.len();
+        if other.data.len() != n {
+            return Err(ErebusError::LengthMismatch {
+                expected: n,
+                found: other.data.len(),
+            });
+        }
+        if start >= end || start >= n {
+            return Ok(VectorData::empty());
+        }
+        let end = end.min(n);
+
+        let mid = self.slice(start, end).concat_str(&other.slice(start, end), sep)?;
+        if !full {
+            return Ok(mid);
+        }
+        Ok(VectorData::stack(&[&self.slice(0, start), &mid, &self.slice(end, n)]))
+    }
+}
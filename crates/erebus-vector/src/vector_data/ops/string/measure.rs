@@ -0,0 +1,23 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+
+// === Impl ===
+
+impl VectorData<String> {
+    // -- Length in chars --
+    impl_unary_op!(
+        noparams, noinplace,
+        len_chars, len_chars_range,
+        i64,
+        |x: &String| x.chars().count() as i64
+    );
+
+    // -- Length in bytes --
+    impl_unary_op!(
+        noparams, noinplace,
+        len_bytes, len_bytes_range,
+        i64,
+        |x: &String| x.len() as i64
+    );
+}
\ No newline at end of file
@@ -0,0 +1,9 @@
+mod compare;
+mod case;
+mod measure;
+mod trim;
+mod predicate;
+mod transform;
+mod split;
+mod concat;
+mod pattern;
\ No newline at end of file
@@ -0,0 +1,78 @@
+// === Imports ===
+use crate::prelude::*;
+use erebus_core::algorithms::regex::{glob_to_regex, like_to_regex};
+
+// === Impl ===
+// Regex, glob and LIKE matching. Each pattern is compiled once and the
+// compiled `Regex` is shared by every chunk of the parallel map.
+
+impl VectorData<String> {
+    /// Whether each value contains a match of `pattern`.
+    pub fn matches(&self, pattern: &str) -> ErrorResult<VectorData<bool>> {
+        let re = Regex::new(pattern)?;
+        Ok(self.map_unary_owned(|x| re.is_match(x)))
+    }
+
+    /// Group `group` of the first match; null where there is no match or
+    /// the group did not participate.
+    pub fn extract(&self, pattern: &str, group: usize) -> ErrorResult<Self> {
+        let re = Self::compile_with_group(pattern, group)?;
+        Ok(self.map_unary_owned_with_validity(|x| {
+            match re.captures(x).and_then(|c| c.get(group)) {
+                Some(m) => (m.to_string(), true),
+                None => (String::new(), false),
+            }
+        }))
+    }
+
+    /// Group `group` of every non-overlapping match.
+    pub fn extract_all(&self, pattern: &str, group: usize) -> ErrorResult<VectorData<Vec<String>>> {
+        let re = Self::compile_with_group(pattern, group)?;
+        Ok(self.map_unary_owned(|x| {
+            re.captures_iter(x)
+                .iter()
+                .filter_map(|c| c.get(group))
+                .map(str::to_string)
+                .collect()
+        }))
+    }
+
+    /// Replaces every match of `pattern` with `rep` (`$N` / `${N}` expand groups).
+    pub fn replace_regex(&self, pattern: &str, rep: &str) -> ErrorResult<Self> {
+        let re = Regex::new(pattern)?;
+        Ok(self.map_unary_owned(|x| re.replace_all(x, rep)))
+    }
+
+    /// Splits each value on matches of `pattern`.
+    pub fn split_regex(&self, pattern: &str) -> ErrorResult<VectorData<Vec<String>>> {
+        let re = Regex::new(pattern)?;
+        Ok(self.map_unary_owned(|x| re.split(x).into_iter().map(str::to_string).collect()))
+    }
+
+    /// Whole-value shell glob match (`*`, `?`, `[...]`).
+    pub fn glob(&self, pattern: &str) -> ErrorResult<VectorData<bool>> {
+        self.matches(&glob_to_regex(pattern)?)
+    }
+
+    /// Whole-value SQL `LIKE` match (`%`, `_`).
+    pub fn like(&self, pattern: &str) -> ErrorResult<VectorData<bool>> {
+        self.matches(&like_to_regex(pattern))
+    }
+
+    /// Case-insensitive [`like`](Self::like).
+    pub fn ilike(&self, pattern: &str) -> ErrorResult<VectorData<bool>> {
+        self.matches(&format!("(?i){}", like_to_regex(pattern)))
+    }
+
+    fn compile_with_group(pattern: &str, group: usize) -> ErrorResult<Regex> {
+        let re = Regex::new(pattern)?;
+        if group > re.group_count() {
+            return Err(ErebusError::InvalidPattern(format!(
+                "group {} out of range, pattern has {} groups",
+                group,
+                re.group_count()
+            )));
+        }
+        Ok(re)
+    }
+}
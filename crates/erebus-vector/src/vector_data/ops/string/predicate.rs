@@ -0,0 +1,34 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+
+// === Impl ===
+
+impl VectorData<String> {
+    // -- Starts With --
+    impl_unary_op!(
+        params, noinplace,
+        starts_with, starts_with_range,
+        (pat: &str) -> (pat),
+        bool,
+        |x: &String, pat: &str| x.starts_with(pat)
+    );
+
+    // -- Ends With --
+    impl_unary_op!(
+        params, noinplace,
+        ends_with, ends_with_range,
+        (pat: &str) -> (pat),
+        bool,
+        |x: &String, pat: &str| x.ends_with(pat)
+    );
+
+    // -- Contains --
+    impl_unary_op!(
+        params, noinplace,
+        contains, contains_range,
+        (pat: &str) -> (pat),
+        bool,
+        |x: &String, pat: &str| x.contains(pat)
+    );
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+
+// === Impl ===
+
+impl VectorData<String> {
+    // -- Split: the `n`-th piece after splitting on `sep`, NA when missing --
+    impl_unary_op!(
+        params_valid, noinplace,
+        split, split_range,
+        (sep: &str, n: usize) -> (sep, n),
+        String,
+        |x: &String, sep: &str, n: usize| match x.split(sep).nth(n) {
+            Some(part) => (part.to_string(), true),
+            None => (String::new(), false),
+        }
+    );
+}
\ No newline at end of file
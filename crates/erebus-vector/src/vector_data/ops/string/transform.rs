@@ -0,0 +1,62 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+
+// === Impl ===
+
+impl VectorData<String> {
+    // -- Replace every occurrence of `from` with `to` --
+    impl_unary_op!(
+        params, noinplace,
+        replace, replace_range,
+        (from: &str, to: &str) -> (from, to),
+        String,
+        |x: &String, from: &str, to: &str| x.replace(from, to)
+    );
+
+    // -- Substring: `len` chars starting at char `start` --
+    impl_unary_op!(
+        params, noinplace,
+        substring, substring_range,
+        (start: usize, len: usize) -> (start, len),
+        String,
+        |x: &String, start: usize, len: usize| x.chars().skip(start).take(len).collect::<String>()
+    );
+
+    // -- Pad Left to `width` chars with `fill` --
+    impl_unary_op!(
+        params, noinplace,
+        pad_left, pad_left_range,
+        (width: usize, fill: char) -> (width, fill),
+        String,
+        |x: &String, width: usize, fill: char| pad(x, width, fill, true)
+    );
+
+    // -- Pad Right to `width` chars with `fill` --
+    impl_unary_op!(
+        params, noinplace,
+        pad_right, pad_right_range,
+        (width: usize, fill: char) -> (width, fill),
+        String,
+        |x: &String, width: usize, fill: char| pad(x, width, fill, false)
+    );
+
+    // -- Repeat `n` times --
+    impl_unary_op!(
+        params, noinplace,
+        repeat, repeat_range,
+        (n: usize) -> (n),
+        String,
+        |x: &String, n: usize| x.repeat(n)
+    );
+}
+
+/// Pads `s` with `fill` up to `width` chars; longer strings are unchanged.
+fn pad(s: &str, width: usize, fill: char, left: bool) -> String {
+    let n = s.chars().count();
+    if n >= width {
+        return s.to_string();
+    }
+    let padding: String = std::iter::repeat_n(fill, width - n).collect();
+    if left { padding + s } else { s.to_string() + &padding }
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+// === Imports ===
+use crate::prelude::*;
+use super::super::macros::*;
+
+// === Impl ===
+
+impl VectorData<String> {
+    // -- Trim whitespace on both sides --
+    impl_unary_op!(
+        noparams, noinplace,
+        trim, trim_range,
+        String,
+        |x: &String| x.trim().to_string()
+    );
+
+    // -- Strip leading whitespace --
+    impl_unary_op!(
+        noparams, noinplace,
+        lstrip, lstrip_range,
+        String,
+        |x: &String| x.trim_start().to_string()
+    );
+
+    // -- Strip trailing whitespace --
+    impl_unary_op!(
+        noparams, noinplace,
+        rstrip, rstrip_range,
+        String,
+        |x: &String| x.trim_end().to_string()
+    );
+}
\ No newline at end of file
@@ -1,4 +1,90 @@
 // === Imports ===
 use crate::prelude::*;
 
-// === Impl ===
\ No newline at end of file
+// === Impl ===
+
+/// Running max and `sum(exp(x - max))`, so no term ever overflows.
+#[derive(Clone)]
+pub struct LogSumExpAcc {
+    pub max: f64,
+    pub sum: f64,
+}
+
+impl Default for LogSumExpAcc {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            max: f64::NEG_INFINITY,
+            sum: 0.0,
+        }
+    }
+}
+
+impl LogSumExpAcc {
+    /// `max + ln(sum)`; `-Inf` when nothing was accumulated.
+    #[inline]
+    pub fn value(&self) -> f64 {
+        if self.sum == 0.0 {
+            return f64::NEG_INFINITY; // log(0), as for an empty sum
+        }
+        self.max + self.sum.ln()
+    }
+}
+
+/// `exp(from - to)`, with equal infinities scaling by 1.
+#[inline]
+fn rescale(from: f64, to: f64) -> f64 {
+    if from == to { 1.0 } else { (from - to).exp() }
+}
+
+#[derive(Clone)]
+pub struct LogSumExpReducer;
+
+impl<T: Numeric> Reducer<T> for LogSumExpReducer {
+    type Acc = LogSumExpAcc;
+    type Output = LogSumExpAcc;
+
+    #[inline]
+    fn accumulate(&mut self, acc: &mut Self::Acc, value: &T, is_valid: bool) {
+        let x = (*value).to_f64();
+        // NaN and ±Inf are treated as NA
+        if !is_valid || !x.is_finite() {
+            return;
+        }
+
+        if x > acc.max {
+            acc.sum = acc.sum * rescale(acc.max, x) + 1.0;
+            acc.max = x;
+        } else {
+            acc.sum += rescale(x, acc.max);
+        }
+    }
+
+    #[inline]
+    fn combine(&self, a: &mut Self::Acc, b: Self::Acc) {
+        if b.sum == 0.0 {
+            return;
+        }
+        if a.sum == 0.0 {
+            *a = b;
+            return;
+        }
+
+        let m = a.max.max(b.max);
+        a.sum = a.sum * rescale(a.max, m) + b.sum * rescale(b.max, m);
+        a.max = m;
+    }
+
+    #[inline]
+    fn finalize(&self, acc: Self::Acc) -> Self::Output {
+        acc
+    }
+}
+
+impl<T: Numeric> VectorData<T> {
+    /// `ln(sum(exp(x)))` over valid finite values, computed without overflow.
+    #[inline]
+    pub fn log_sum_exp(&self) -> f64 {
+        self._reduce(LogSumExpReducer).value()
+    }
+}
@@ -3,20 +3,46 @@ use crate::prelude::*;
 
 // === Impl ===
 
-impl VectorData<i64> {
-    pub fn mean(&self) -> f64 {
-        let sum = self.sum() as f64;
-        let count = self.validity.count_ones() as usize;
-        if count == 0 { return f64::NAN; }
-        sum / count as f64
+#[derive(Clone, Default)]
+pub struct MeanAcc {
+    pub sum: f64,
+    pub count: usize,
+}
+
+#[derive(Clone)]
+pub struct MeanReducer;
+
+impl<T: Numeric> Reducer<T> for MeanReducer {
+    type Acc = MeanAcc;
+    type Output = f64;
+
+    #[inline]
+    fn accumulate(&mut self, acc: &mut Self::Acc, value: &T, is_valid: bool) {
+        if is_valid {
+            acc.sum += value.to_f64();
+            acc.count += 1;
+        }
+    }
+
+    #[inline]
+    fn combine(&self, a: &mut Self::Acc, b: Self::Acc) {
+        a.sum += b.sum;
+        a.count += b.count;
+    }
+
+    #[inline]
+    fn finalize(&self, acc: Self::Acc) -> f64 {
+        if acc.count == 0 {
+            return f64::NAN;
+        }
+        acc.sum / acc.count as f64
     }
 }
 
-impl VectorData<f64> {
+impl<T: Numeric> VectorData<T> {
+    /// Mean of valid values, accumulated in f64 so narrow ints can't overflow.
+    #[inline]
     pub fn mean(&self) -> f64 {
-        let sum = self.sum();
-        let count = self.validity.count_ones() as usize;
-        if count == 0 { return f64::NAN; }
-        sum / count as f64
+        self._reduce(MeanReducer)
     }
-}
\ No newline at end of file
+}
@@ -12,13 +12,13 @@ pub struct SumAcc<T: Numeric> {
 pub struct SumReducer;
 
 impl<T: Numeric> Reducer<T> for SumReducer {
-    type Acc = SumAcc<T>;
-    type Output = T;
+    type Acc = SumAcc<T::Wide>;
+    type Output = T::Wide;
 
     #[inline]
     fn accumulate(&mut self, acc: &mut Self::Acc, value: &T, is_valid: bool) {
         if is_valid {
-            acc.sum += *value;
+            acc.sum += value.to_wide();
         }
     }
 
@@ -34,8 +34,9 @@ impl<T: Numeric> Reducer<T> for SumReducer {
 }
 
 impl<T: Numeric> VectorData<T> {
+    /// Sum of valid values, accumulated in `T::Wide` (i64/u64 for narrow ints).
     #[inline]
-    pub fn sum(&self) -> T {
+    pub fn sum(&self) -> T::Wide {
         self._reduce(SumReducer)
     }
 }
\ No newline at end of file
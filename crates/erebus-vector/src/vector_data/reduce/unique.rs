@@ -44,94 +44,72 @@ fn unique_hash<T: Eq + Hash + Clone>(data: &[T], validity: &BitSlice) -> VectorD
     }
 }
 
-impl VectorData<i64> {
-    #[inline]
-    pub fn n_unique(&self) -> usize {
-        n_unique_hash(&self.data, &self.validity)
-    }
-
-    #[inline]
-    pub fn n_distinct(&self) -> usize {
-        self.n_unique()
-    }
-
-    #[inline]
-    pub fn unique(&self) -> Self {
-        unique_hash(&self.data, &self.validity)
-    }
-}
-
-impl VectorData<bool> {
-    #[inline]
-    pub fn n_unique(&self) -> usize {
-        n_unique_hash(&self.data, &self.validity)
-    }
-
-    #[inline]
-    pub fn n_distinct(&self) -> usize {
-        self.n_unique()
-    }
-
-    #[inline]
-    pub fn unique(&self) -> Self {
-        unique_hash(&self.data, &self.validity)
-    }
-}
-
-impl VectorData<String> {
-    #[inline]
-    pub fn n_unique(&self) -> usize {
-        n_unique_hash(&self.data, &self.validity)
-    }
+macro_rules! impl_unique_hash {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            #[inline]
+            pub fn n_unique(&self) -> usize {
+                n_unique_hash(&self.data, &self.validity)
+            }
 
-    #[inline]
-    pub fn n_distinct(&self) -> usize {
-        self.n_unique()
-    }
+            #[inline]
+            pub fn n_distinct(&self) -> usize {
+                self.n_unique()
+            }
 
-    #[inline]
-    pub fn unique(&self) -> Self {
-        unique_hash(&self.data, &self.validity)
-    }
+            #[inline]
+            pub fn unique(&self) -> Self {
+                unique_hash(&self.data, &self.validity)
+            }
+        }
+    )*};
 }
 
-impl VectorData<f64> {
-    #[inline]
-    pub fn n_unique(&self) -> usize {
-        let mut seen: HashSet<OrderedFloat<f64>> = HashSet::new();
-        for i in 0..self.data.len() {
-            if unsafe { *self.validity.get_unchecked(i) } {
-                seen.insert(OrderedFloat(unsafe { *self.data.get_unchecked(i) }));
+impl_unique_hash!(i64, i32, i16, i128, u8, u32, u64, bool, String);
+
+macro_rules! impl_unique_float {
+    ($($t:ty),*) => {$(
+        impl VectorData<$t> {
+            #[inline]
+            pub fn n_unique(&self) -> usize {
+                let mut seen: HashSet<OrderedFloat<$t>> = HashSet::new();
+                for i in 0..self.data.len() {
+                    if unsafe { *self.validity.get_unchecked(i) } {
+                        seen.insert(OrderedFloat(unsafe { *self.data.get_unchecked(i) }));
+                    }
+                }
+                seen.len()
             }
-        }
-        seen.len()
-    }
 
-    #[inline]
-    pub fn n_distinct(&self) -> usize {
-        self.n_unique()
-    }
+            #[inline]
+            pub fn n_distinct(&self) -> usize {
+                self.n_unique()
+            }
 
-    #[inline]
-    pub fn unique(&self) -> Self {
-        let n = self.data.len();
-        let mut seen: HashSet<OrderedFloat<f64>> = HashSet::with_capacity(n);
-        let mut out = Vec::new();
-        let mut n_out = 0usize;
+            #[inline]
+            pub fn unique(&self) -> Self {
+                let n = self.data.len();
+                let mut seen: HashSet<OrderedFloat<$t>> = HashSet::with_capacity(n);
+                let mut out = Vec::new();
+                let mut n_out = 0usize;
+
+                for i in 0..n {
+                    if unsafe { *self.validity.get_unchecked(i) } {
+                        let v = unsafe { *self.data.get_unchecked(i) };
+                        if seen.insert(OrderedFloat(v)) {
+                            out.push(v);
+                            n_out += 1usize;
+                        }
+                    }
+                }
 
-        for i in 0..n {
-            if unsafe { *self.validity.get_unchecked(i) } {
-                let v = unsafe { *self.data.get_unchecked(i) };
-                if seen.insert(OrderedFloat(v)) {
-                    out.push(v);
-                    n_out += 1usize;
+                VectorData {
+                    data: out,
+                    validity: bitvec![1; n_out],
                 }
             }
         }
+    )*};
+}
 
-        VectorData {
-            data: out,
-            validity: bitvec![1; n_out],
-        }
-    }
-}
\ No newline at end of file
+impl_unique_float!(f64, f32);
@@ -57,6 +57,59 @@ impl RadixKey for f64 {
     }
 }
 
+// Narrower and wider ints use a key of their own width, so the sort
+// makes one pass per byte of the type.
+
+macro_rules! impl_radix_key_signed {
+    ($($t:ty => $k:ty),*) => {
+        $(impl RadixKey for $t {
+            type Key = $k;
+            #[inline]
+            fn to_radix_key(v: $t) -> $k { (v as $k) ^ (1 << (<$k>::BITS - 1)) }
+            #[inline]
+            fn ff_mask() -> $k { 0xFF }
+            #[inline]
+            fn extract_byte(k: $k, shift: usize) -> usize { ((k >> shift) & 0xFF) as usize }
+        })*
+    };
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty),*) => {
+        $(impl RadixKey for $t {
+            type Key = $t;
+            #[inline]
+            fn to_radix_key(v: $t) -> $t { v }
+            #[inline]
+            fn ff_mask() -> $t { 0xFF }
+            #[inline]
+            fn extract_byte(k: $t, shift: usize) -> usize { ((k >> shift) & 0xFF) as usize }
+        })*
+    };
+}
+
+impl_radix_key_signed!(i16 => u16, i32 => u32, i128 => u128);
+impl_radix_key_unsigned!(u8, u32, u64);
+
+impl RadixKey for f32 {
+    type Key = u32;
+
+    #[inline]
+    fn to_radix_key(v: f32) -> u32 {
+        // Same IEEE-754 trick as f64
+        let bits = v.to_bits();
+        if bits & 0x8000_0000 == 0 { bits ^ 0x8000_0000 } else { !bits }
+    }
+
+    #[inline]
+    fn ff_mask() -> u32 { 0xFF }
+
+    #[inline]
+    fn extract_byte(k: u32, shift: usize) -> usize {
+        ((k >> shift) & 0xFF) as usize
+    }
+}
+
 // Simply defining these so that we can implement trait RadixKey on the sort functions and call it
 // These will not be used as of yet
 
@@ -51,7 +51,7 @@ where
 
         const BITS_PER_PASS: usize = 8;
         const RADIX: usize = 1 << BITS_PER_PASS;
-        let passes = (std::mem::size_of::<u64>() * 8) / BITS_PER_PASS;
+        let passes = (std::mem::size_of::<T::Key>() * 8) / BITS_PER_PASS;
 
         // Radix passes
         for pass in 0..passes {
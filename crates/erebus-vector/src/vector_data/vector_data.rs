@@ -5,6 +5,11 @@ use crate::prelude::*;
 // === Types ===
 
 /// Vector-backed data with validity bitmap (Dense only for now).
+///
+/// Element-wise ops (`sin`, `abs`, `gt`, ...) are implemented once per
+/// element type, so calling one on a vector of unsuffixed literals is
+/// ambiguous; suffix a literal (`vec![1.0_f64]`) or annotate the type.
+/// The `to_*` casts and the reductions are generic and infer f64/i64.
 #[derive(Debug, Clone, PartialEq)]
 pub struct VectorData<T> {
     pub data: Vec<T>,      // All rows, including placeholders for nulls
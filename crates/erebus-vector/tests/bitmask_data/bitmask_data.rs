@@ -0,0 +1,73 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd_bool(v: Vec<bool>, valid: Vec<bool>) -> VectorData<bool> {
+    VectorData::from_vec(v, BitVec::from_iter(valid)).unwrap()
+}
+
+#[test]
+fn test_from_bits_checks_length() {
+    assert!(BitmaskData::from_bits(bitvec![1, 0], bitvec![1]).is_err());
+    let m = BitmaskData::from_bits(bitvec![1, 0], bitvec![1, 0]).unwrap();
+    assert_eq!(m.len(), 2);
+    assert_eq!(m.get(0), Some(true));
+    assert_eq!(m.get(1), None);
+    assert_eq!(m.get(2), None);
+}
+
+#[test]
+fn test_round_trip_with_vector_data() {
+    let n = 130;
+    let v = vd_bool((0..n).map(|i| i % 3 == 0).collect(), (0..n).map(|i| i % 4 != 0).collect());
+    let m = v.to_bitmask();
+    assert_eq!(m.len(), n);
+    for i in 0..n {
+        let expected = if i % 4 != 0 { Some(i % 3 == 0) } else { None };
+        assert_eq!(m.get(i), expected);
+    }
+    assert_eq!(m.to_vector_data(), v);
+}
+
+#[test]
+fn test_eq_ignores_values_under_nulls() {
+    let a = BitmaskData::from_bits(bitvec![1, 0, 1], bitvec![1, 0, 1]).unwrap();
+    let b = BitmaskData::from_bits(bitvec![1, 1, 1], bitvec![1, 0, 1]).unwrap();
+    assert_eq!(a, b);
+
+    let c = BitmaskData::from_bits(bitvec![1, 0, 0], bitvec![1, 0, 1]).unwrap();
+    assert_ne!(a, c);
+    let d = BitmaskData::from_bits(bitvec![1, 0, 1], bitvec![1, 1, 1]).unwrap();
+    assert_ne!(a, d);
+}
+
+#[test]
+fn test_counts_use_validity() {
+    let m = vd_bool(vec![true, true, false, false, true], vec![true, false, true, false, true]).to_bitmask();
+    assert_eq!(m.count_true(), 2);
+    assert_eq!(m.count_false(), 1);
+    assert_eq!(m.null_count(), 2);
+}
+
+#[test]
+fn test_any_all_skip_nulls() {
+    let m = vd_bool(vec![true, false], vec![true, false]).to_bitmask();
+    assert!(m.all());
+    assert!(m.any());
+    assert_eq!(m.all_kleene(), None);
+    assert_eq!(m.any_kleene(), Some(true));
+
+    let none = BitmaskData::full(false, 100);
+    assert!(!none.any());
+    assert_eq!(none.any_kleene(), Some(false));
+    assert!(BitmaskData::full(true, 100).all());
+}
+
+#[test]
+fn test_memory_is_packed() {
+    let n = 1 << 16;
+    let m = BitmaskData::full(true, n);
+    let v = m.to_vector_data();
+    assert!(m.memory_usage() * 4 < v.memory_usage());
+}
@@ -0,0 +1,40 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd_i64(data: Vec<i64>, valid: Vec<bool>) -> VectorData<i64> {
+    VectorData::from_vec(data, valid.into_iter().collect()).unwrap()
+}
+
+fn vd_f64(data: Vec<f64>, valid: Vec<bool>) -> VectorData<f64> {
+    VectorData::from_vec(data, valid.into_iter().collect()).unwrap()
+}
+
+#[test]
+fn test_mask_ops_match_unpacked_ops() {
+    let n = 200;
+    let v = vd_i64((0..n as i64).map(|i| (i * 37) % 101).collect(), (0..n).map(|i| i % 9 != 0).collect());
+    assert_eq!(v.lt_mask(50).to_vector_data(), v.lt(50).to_bitmask().to_vector_data());
+    assert_eq!(v.gte_mask(50).values, v.gte(50).to_bitmask().values);
+    assert_eq!(v.threshold_mask(20.5).count_true(), v.threshold(20.5).count_true());
+    assert_eq!(v.lt_mask(50).validity, v.validity);
+}
+
+#[test]
+fn test_f64_mask_ops() {
+    let v = vd_f64(vec![1.0, 2.0, 3.0, 4.0], vec![true, true, false, true]);
+    let m = v.lte_mask(2.0);
+    assert_eq!((0..4).map(|i| m.get(i)).collect::<Vec<_>>(), vec![Some(true), Some(true), None, Some(false)]);
+    assert_eq!(v.gt_mask(1.5).arg_true(), vec![1, 3]);
+    assert_eq!(v.threshold_eq_mask(4.0).arg_true(), vec![3]);
+}
+
+#[test]
+fn test_between_mask() {
+    let v = vd_i64(vec![1, 5, 10, 15], vec![true, true, true, false]);
+    let m = v.between_mask(5, 10);
+    assert_eq!((0..4).map(|i| m.get(i)).collect::<Vec<_>>(), vec![Some(false), Some(true), Some(true), None]);
+    // An inverted range nulls every row, like `between`.
+    assert_eq!(v.between_mask(10, 5).null_count(), 4);
+}
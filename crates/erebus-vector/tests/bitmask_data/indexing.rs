@@ -0,0 +1,39 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+#[test]
+fn test_arg_true_and_arg_false_skip_nulls() {
+    let m = BitmaskData::from_bits(bitvec![1, 0, 1, 0, 1], bitvec![1, 1, 0, 0, 1]).unwrap();
+    assert_eq!(m.arg_true(), vec![0, 4]);
+    assert_eq!(m.arg_false(), vec![1]);
+}
+
+#[test]
+fn test_arg_true_spans_multiple_words() {
+    let n = 300;
+    let m = BitmaskData::from_bools(&(0..n).map(|i| i % 64 == 63).collect::<Vec<_>>());
+    assert_eq!(m.arg_true(), vec![63, 127, 191, 255]);
+}
+
+#[test]
+fn test_vector_data_bool_index_with_bitmask() {
+    let v = VectorData::from_vec(vec![10i64, 20, 30, 40], bitvec![1, 0, 1, 1]).unwrap();
+    let m = v.gt_mask(15);
+    let out = v.bool_index(&m).unwrap();
+    // Row 1 is null in the mask, so it is not selected.
+    assert_eq!(out.data, vec![30, 40]);
+    assert_eq!(out.validity, bitvec![1, 1]);
+
+    let short = BitmaskData::full(true, 3);
+    assert!(v.bool_index(&short).is_err());
+}
+
+#[test]
+fn test_vector_bool_index_with_bitmask() {
+    let v = Vector::from_vec(vec![1i64, 2, 3]).unwrap();
+    let m = BitmaskData::from_bools(&[true, false, true]);
+    assert_eq!(v.bool_index(&m).unwrap().data, vec![1, 3]);
+    assert_eq!(v.bool_index(&[false, true, false]).unwrap().data, vec![2]);
+}
@@ -0,0 +1,52 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn mask(v: &[Option<bool>]) -> BitmaskData {
+    let values: BitVec = v.iter().map(|x| x.unwrap_or(false)).collect();
+    let validity: BitVec = v.iter().map(|x| x.is_some()).collect();
+    BitmaskData::from_bits(values, validity).unwrap()
+}
+
+fn opts(m: &BitmaskData) -> Vec<Option<bool>> {
+    (0..m.len()).map(|i| m.get(i)).collect()
+}
+
+const T: Option<bool> = Some(true);
+const F: Option<bool> = Some(false);
+const N: Option<bool> = None;
+
+fn pairs() -> (BitmaskData, BitmaskData) {
+    (
+        mask(&[T, T, T, F, F, F, N, N, N]),
+        mask(&[T, F, N, T, F, N, T, F, N]),
+    )
+}
+
+#[test]
+fn test_bitmask_kleene_truth_tables() {
+    let (a, b) = pairs();
+    assert_eq!(opts(&(&a & &b)), vec![T, F, N, F, F, F, N, F, N]);
+    assert_eq!(opts(&(&a | &b)), vec![T, T, T, T, F, N, T, N, N]);
+    assert_eq!(opts(&(&a ^ &b)), vec![F, T, N, T, F, N, N, N, N]);
+    assert_eq!(opts(&a.and_not(&b).unwrap()), vec![F, T, N, F, F, F, F, N, N]);
+    assert_eq!(opts(&!a), vec![F, F, F, T, T, T, N, N, N]);
+}
+
+#[test]
+fn test_bitmask_logic_length_mismatch() {
+    let a = BitmaskData::full(true, 3);
+    let b = BitmaskData::full(true, 2);
+    assert!(a.and(&b).is_err());
+    assert!(a.xor(&b).is_err());
+}
+
+#[test]
+fn test_bitmask_logic_ignores_values_under_nulls() {
+    // Value bits under a null must not leak into the result.
+    let a = BitmaskData::from_bits(bitvec![1, 1], bitvec![0, 0]).unwrap();
+    let b = BitmaskData::full(false, 2);
+    assert_eq!(opts(&(&a | &b)), vec![N, N]);
+    assert_eq!(opts(&(&a & &b)), vec![F, F]);
+}
@@ -0,0 +1,4 @@
+mod bitmask_data;
+mod logic;
+mod compare;
+mod indexing;
\ No newline at end of file
@@ -0,0 +1,93 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd_str(data: &[&str], valid: Vec<bool>) -> VectorData<String> {
+    VectorData::from_vec(data.iter().map(|s| s.to_string()).collect(), valid.into_iter().collect()).unwrap()
+}
+
+fn values(v: &CategoricalVector) -> Vec<Option<&str>> {
+    (0..v.len()).map(|i| v.get(i)).collect()
+}
+
+#[test]
+fn test_roundtrip_vector_data() {
+    let vd = vd_str(&["AAPL", "MSFT", "", "AAPL", "GOOG"], vec![true, true, false, true, true]);
+    let cat = vd.to_categorical();
+
+    assert_eq!(cat.len(), 5);
+    assert_eq!(cat.null_count(), 1);
+    assert_eq!(cat.dictionary(), &["AAPL", "MSFT", "GOOG"]);
+    assert_eq!(cat.codes(), &CategoricalCodes::U8(vec![0, 1, 0, 0, 2]));
+    assert_eq!(values(&cat), vec![Some("AAPL"), Some("MSFT"), None, Some("AAPL"), Some("GOOG")]);
+    assert_eq!(cat.to_vector_data(), vd);
+}
+
+#[test]
+fn test_codes_widen_on_insert() {
+    let mut cat = CategoricalVector::new();
+    for i in 0..256 {
+        cat.push(Some(&format!("c{i}")));
+    }
+    assert_eq!(cat.codes().width(), 1);
+
+    cat.push(Some("c256"));
+    assert_eq!(cat.codes().width(), 2);
+    assert_eq!(cat.get(0), Some("c0"));
+    assert_eq!(cat.code(256), Some(256));
+
+    for i in 257..=65_536 {
+        cat.push(Some(&format!("c{i}")));
+    }
+    assert_eq!(cat.codes().width(), 4);
+    assert_eq!(cat.get(65_536), Some("c65536"));
+    assert_eq!(cat.get(300), Some("c300"));
+
+    // Repeats reuse existing codes
+    cat.push(Some("c7"));
+    assert_eq!(cat.n_categories(), 65_537);
+    assert_eq!(cat.code(65_537), Some(7));
+}
+
+#[test]
+fn test_from_parts_validation() {
+    let dict = vec!["a".to_string(), "b".to_string()];
+    let ok = CategoricalVector::from_parts(dict.clone(), vec![1, 99, 0], bitvec![1, 0, 1]).unwrap();
+    assert_eq!(values(&ok), vec![Some("b"), None, Some("a")]);
+
+    assert!(matches!(
+        CategoricalVector::from_parts(dict.clone(), vec![2], bitvec![1]),
+        Err(ErebusError::IndexOutOfBounds { index: 2, size: 2 })
+    ));
+    assert!(CategoricalVector::from_parts(dict, vec![0], bitvec![1, 1]).is_err());
+    assert!(CategoricalVector::with_categories(vec!["x".into(), "x".into()]).is_err());
+}
+
+#[test]
+fn test_concat_merges_dictionaries() {
+    let a = vd_str(&["x", "y"], vec![true, true]).to_categorical();
+    let b = vd_str(&["z", "x", "n/a"], vec![true, true, false]).to_categorical();
+
+    let out = CategoricalVector::concat(&[&a, &b]);
+    assert_eq!(out.dictionary(), &["x", "y", "z"]);
+    assert_eq!(values(&out), vec![Some("x"), Some("y"), Some("z"), Some("x"), None]);
+    assert_eq!(CategoricalVector::concat(&[]).len(), 0);
+}
+
+#[test]
+fn test_memory_smaller_than_strings() {
+    let data: Vec<&str> = (0..10_000).map(|i| ["SPY", "QQQ", "IWM"][i % 3]).collect();
+    let cat = vd_str(&data, vec![true; data.len()]).to_categorical();
+    assert_eq!(cat.codes().memory_usage(), 10_000);
+    assert!(cat.memory_usage() < 10_000 * std::mem::size_of::<String>());
+}
+
+#[test]
+fn test_memory_counts_lookup_map() {
+    let long: Vec<String> = (0..100).map(|i| format!("{:0>64}", i)).collect();
+    let refs: Vec<&str> = long.iter().map(String::as_str).collect();
+    let cat = vd_str(&refs, vec![true; refs.len()]).to_categorical();
+    // Dictionary and lookup each hold their own copy of every string.
+    assert!(cat.memory_usage() >= cat.codes().memory_usage() + 2 * 100 * 64);
+}
@@ -0,0 +1,40 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+use erebus_io::prelude::{CompressionType, ErebusReader, ErebusWriter};
+use std::io::{Cursor, Seek, SeekFrom};
+
+// === Tests ===
+
+fn roundtrip(v: &CategoricalVector, compression: CompressionType) -> CategoricalVector {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new())).with_compression(compression);
+    v.write_to(&mut writer).unwrap();
+
+    let mut cursor = writer.inner_mut().clone();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    CategoricalVector::read_from(&mut ErebusReader::new(cursor)).unwrap()
+}
+
+#[test]
+fn test_categorical_write_read_roundtrip() {
+    let mut v = CategoricalVector::new();
+    for i in 0..1_000 {
+        v.push(if i % 7 == 0 { None } else { Some(["Tech", "Energy", "Ünïcode"][i % 3]) });
+    }
+
+    for c in [CompressionType::None, CompressionType::Zstd, CompressionType::Lz4] {
+        let out = roundtrip(&v, c);
+        assert_eq!(out, v);
+    }
+}
+
+#[test]
+fn test_categorical_wide_codes_roundtrip() {
+    let mut v = CategoricalVector::new();
+    for i in 0..70_000 {
+        v.push(Some(&i.to_string()));
+    }
+    assert_eq!(v.codes().width(), 4);
+    let out = roundtrip(&v, CompressionType::Zstd);
+    assert_eq!(out.get(69_999), Some("69999"));
+    assert_eq!(out.codes().width(), 4);
+}
@@ -0,0 +1,4 @@
+mod categorical_vector;
+mod reduce;
+mod sort;
+mod io;
\ No newline at end of file
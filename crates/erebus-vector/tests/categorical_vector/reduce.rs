@@ -0,0 +1,50 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn cat(data: &[Option<&str>]) -> CategoricalVector {
+    let mut out = CategoricalVector::new();
+    data.iter().for_each(|v| out.push(*v));
+    out
+}
+
+#[test]
+fn test_unique_and_counts() {
+    let v = cat(&[Some("b"), Some("a"), None, Some("b"), Some("c"), Some("b"), Some("a")]);
+    assert_eq!(v.n_unique(), 3);
+    assert_eq!(v.category_counts(), vec![3, 2, 1]);
+    assert_eq!(
+        v.value_counts(),
+        vec![("b".to_string(), 3), ("a".to_string(), 2), ("c".to_string(), 1)]
+    );
+
+    let u = v.unique();
+    assert_eq!(u.dictionary(), &["b", "a", "c"]);
+    assert_eq!(u.len(), 3);
+    assert_eq!(u.null_count(), 0);
+}
+
+#[test]
+fn test_unused_categories() {
+    let mut v = CategoricalVector::with_categories(vec!["x".into(), "y".into(), "z".into()]).unwrap();
+    v.push(Some("z"));
+    v.push(None);
+    v.push(Some("x"));
+    assert_eq!(v.n_categories(), 3);
+    assert_eq!(v.n_unique(), 2);
+    assert_eq!(v.value_counts(), vec![("x".to_string(), 1), ("z".to_string(), 1)]);
+
+    let trimmed = v.remove_unused_categories();
+    assert_eq!(trimmed.dictionary(), &["x", "z"]);
+    assert_eq!((0..3).map(|i| trimmed.get(i)).collect::<Vec<_>>(), vec![Some("z"), None, Some("x")]);
+}
+
+#[test]
+fn test_all_null() {
+    let v = cat(&[None, None]);
+    assert_eq!(v.n_unique(), 0);
+    assert!(v.value_counts().is_empty());
+    assert_eq!(v.unique().len(), 0);
+    assert_eq!(v.argsort_lexical(true), vec![0, 1]);
+}
@@ -0,0 +1,36 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn sample() -> CategoricalVector {
+    // Dictionary order: "mid", "low", "high"
+    let mut v = CategoricalVector::with_categories(vec!["mid".into(), "low".into(), "high".into()]).unwrap();
+    for x in [Some("high"), None, Some("low"), Some("mid"), Some("low"), Some("high")] {
+        v.push(x);
+    }
+    v
+}
+
+#[test]
+fn test_sort_by_category_is_stable_nulls_last() {
+    let v = sample();
+    assert_eq!(v.argsort_by_category(false), vec![3, 2, 4, 0, 5, 1]);
+    assert_eq!(v.argsort_by_category(true), vec![0, 5, 2, 4, 3, 1]);
+
+    let sorted = v.sort_by_category(false);
+    let out: Vec<_> = (0..sorted.len()).map(|i| sorted.get(i)).collect();
+    assert_eq!(out, vec![Some("mid"), Some("low"), Some("low"), Some("high"), Some("high"), None]);
+    assert_eq!(sorted.dictionary(), v.dictionary());
+}
+
+#[test]
+fn test_sort_lexical() {
+    let v = sample();
+    assert_eq!(v.argsort_lexical(false), vec![0, 5, 2, 4, 3, 1]);
+    assert_eq!(v.argsort_lexical(true), vec![3, 2, 4, 0, 5, 1]);
+
+    let sorted = v.sort_lexical(false);
+    let out: Vec<_> = (0..sorted.len()).map(|i| sorted.get(i)).collect();
+    assert_eq!(out, vec![Some("high"), Some("high"), Some("low"), Some("low"), Some("mid"), None]);
+}
@@ -0,0 +1,100 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd_i64(data: Vec<i64>, valid: Vec<bool>) -> VectorData<i64> {
+    VectorData::from_vec(data, valid.into_iter().collect()).unwrap()
+}
+
+fn values(v: &CompactIntVector) -> Vec<Option<i64>> {
+    (0..v.len()).map(|i| v.get(i)).collect()
+}
+
+#[test]
+fn test_picks_narrowest_width() {
+    assert_eq!(vd_i64(vec![-128, 127], vec![true, true]).to_compact().width(), 1);
+    assert_eq!(vd_i64(vec![-129, 0], vec![true, true]).to_compact().width(), 2);
+    assert_eq!(vd_i64(vec![40_000], vec![true]).to_compact().width(), 4);
+    assert_eq!(vd_i64(vec![i64::MIN], vec![true]).to_compact().width(), 8);
+
+    // Values under nulls do not count
+    let v = vd_i64(vec![1, i64::MAX, 3], vec![true, false, true]).to_compact();
+    assert_eq!(v.width(), 1);
+    assert_eq!(values(&v), vec![Some(1), None, Some(3)]);
+    assert!(matches!(v.values(), CompactInts::I8(_)));
+}
+
+#[test]
+fn test_roundtrip_and_casts() {
+    let vd = vd_i64(vec![5, -300, 0, 7], vec![true, true, false, true]);
+    let v = CompactIntVector::from(&vd);
+    assert_eq!(v.width(), 2);
+    assert_eq!(v.to_vector_data(), vd_i64(vec![5, -300, 0, 7], vec![true, true, false, true]));
+    assert_eq!(v.to_float().data, vec![5.0, -300.0, 0.0, 7.0]);
+    assert_eq!(v.to_bool().data, vec![true, true, false, true]);
+    assert_eq!(v.to_text().data[1], "-300");
+    assert_eq!(v.to_text().validity, vd.validity);
+}
+
+#[test]
+fn test_push_rewidens() {
+    let mut v = CompactIntVector::new();
+    v.push(Some(1));
+    v.push(None);
+    assert_eq!(v.width(), 1);
+
+    v.push(Some(1_000));
+    assert_eq!(v.width(), 2);
+    v.push(Some(-5_000_000_000));
+    assert_eq!(v.width(), 8);
+    assert_eq!(values(&v), vec![Some(1), None, Some(1_000), Some(-5_000_000_000)]);
+}
+
+#[test]
+fn test_append_widens() {
+    let mut a = vd_i64(vec![1, 2], vec![true, true]).to_compact();
+    let b = vd_i64(vec![70_000, 3], vec![true, false]).to_compact();
+    a.append(&b);
+    assert_eq!(a.width(), 4);
+    assert_eq!(values(&a), vec![Some(1), Some(2), Some(70_000), None]);
+
+    assert!(CompactIntVector::from_vec(vec![1], bitvec![1, 1]).is_err());
+}
+
+#[test]
+fn test_memory_usage_shrinks() {
+    let vd = vd_i64((0..10_000).map(|i| i % 100).collect(), vec![true; 10_000]);
+    let v = vd.to_compact();
+    assert_eq!(v.values().memory_usage(), 10_000);
+    assert!(v.memory_usage() * 6 < vd.data.len() * 8);
+}
+
+#[test]
+fn test_sort() {
+    let v = CompactIntVector::from_vec(vec![3, -1, 0, 200, 7], bitvec![1, 1, 0, 1, 1]).unwrap();
+    let sorted = v.sort(true, true, None);
+    assert_eq!(values(&sorted), vec![Some(-1), Some(3), Some(7), Some(200), None]);
+    assert_eq!(sorted.width(), v.width());
+
+    let desc = v.sort(false, false, Some("radix"));
+    assert_eq!(values(&desc), vec![None, Some(200), Some(7), Some(3), Some(-1)]);
+    assert_eq!(v.sort_indices(true, true, Some("stable")), vec![1, 0, 4, 3, 2]);
+}
+
+#[test]
+fn test_cut() {
+    let v = CompactIntVector::from_vec(vec![1, 5, 10, 50, 0], bitvec![1, 1, 1, 1, 0]).unwrap();
+    let bins = [0.0, 5.0, 20.0];
+
+    let out = v.cut(&bins, true, true).unwrap();
+    assert_eq!(out.width(), 1);
+    assert_eq!(values(&out), vec![Some(0), Some(0), Some(1), None, None]);
+
+    let labels = vec!["low".to_string(), "high".to_string()];
+    let named = v.cut_labels(&bins, &labels, true, true).unwrap();
+    assert_eq!(named.data[..3], ["low", "low", "high"]);
+    assert_eq!(named.validity, bitvec![1, 1, 1, 0, 0]);
+
+    assert!(v.cut(&[1.0, 0.0], true, true).is_err());
+}
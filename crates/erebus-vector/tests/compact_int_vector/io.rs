@@ -0,0 +1,34 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+use erebus_io::prelude::{CompressionType, ErebusReader, ErebusWriter};
+use std::io::{Cursor, Seek, SeekFrom};
+
+// === Tests ===
+
+fn roundtrip(v: &CompactIntVector, compression: CompressionType) -> CompactIntVector {
+    let mut writer = ErebusWriter::new(Cursor::new(Vec::new())).with_compression(compression);
+    v.write_to(&mut writer).unwrap();
+
+    let mut cursor = writer.inner_mut().clone();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    CompactIntVector::read_from(&mut ErebusReader::new(cursor)).unwrap()
+}
+
+#[test]
+fn test_compact_int_write_read_roundtrip() {
+    let mut v = CompactIntVector::new();
+    for i in 0..1_000i64 {
+        v.push(if i % 9 == 0 { None } else { Some(i * i - 500) });
+    }
+    assert_eq!(v.width(), 4);
+
+    for c in [CompressionType::None, CompressionType::Zstd, CompressionType::Lz4] {
+        assert_eq!(roundtrip(&v, c), v);
+    }
+}
+
+#[test]
+fn test_compact_int_extremes_roundtrip() {
+    let v = CompactIntVector::from_vec(vec![i64::MIN, 0, i64::MAX], bitvec![1, 1, 1]).unwrap();
+    assert_eq!(roundtrip(&v, CompressionType::None), v);
+}
@@ -0,0 +1,3 @@
+mod compact_int_vector;
+mod reduce;
+mod io;
\ No newline at end of file
@@ -0,0 +1,36 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+#[test]
+fn test_reductions_match_i64() {
+    let data: Vec<i64> = (0..100_000).map(|i| (i * 37) % 2_001 - 1_000).collect();
+    let validity: BitVec = (0..100_000).map(|i| i % 11 != 0).collect();
+    let vd = VectorData::from_vec(data, validity).unwrap();
+    let v = vd.to_compact();
+
+    assert_eq!(v.width(), 2);
+    assert_eq!(v.sum(), vd.sum());
+    assert_eq!(v.min(), vd.min());
+    assert_eq!(v.max(), vd.max());
+    assert!((v.mean() - vd.mean()).abs() < 1e-12);
+}
+
+#[test]
+fn test_reductions_do_not_overflow_narrow_width() {
+    let v = CompactIntVector::from_vec(vec![100; 1_000], bitvec![1; 1_000]).unwrap();
+    assert_eq!(v.width(), 1);
+    assert_eq!(v.sum(), 100_000);
+    assert_eq!(v.mean(), 100.0);
+}
+
+#[test]
+fn test_reductions_empty_and_all_null() {
+    let v = CompactIntVector::from_vec(vec![4, 5], bitvec![0, 0]).unwrap();
+    assert_eq!(v.sum(), 0);
+    assert_eq!(v.min(), None);
+    assert_eq!(v.max(), None);
+    assert!(v.mean().is_nan());
+    assert_eq!(CompactIntVector::new().max(), None);
+}
@@ -1,2 +1,5 @@
 mod vector;
-mod vector_data;
\ No newline at end of file
+mod vector_data;
+mod bitmask_data;
+mod categorical_vector;
+mod compact_int_vector;
\ No newline at end of file
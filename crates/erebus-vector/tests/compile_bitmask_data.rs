@@ -0,0 +1 @@
+mod bitmask_data;
\ No newline at end of file
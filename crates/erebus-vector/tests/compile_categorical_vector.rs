@@ -0,0 +1 @@
+mod categorical_vector;
\ No newline at end of file
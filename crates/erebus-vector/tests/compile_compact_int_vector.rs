@@ -0,0 +1 @@
+mod compact_int_vector;
\ No newline at end of file
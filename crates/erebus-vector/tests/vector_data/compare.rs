@@ -0,0 +1,105 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd_i64(data: Vec<i64>, valid: Vec<bool>) -> VectorData<i64> {
+    VectorData::from_vec(data, valid.into_iter().collect()).unwrap()
+}
+
+fn vd_f64(data: Vec<f64>, valid: Vec<bool>) -> VectorData<f64> {
+    VectorData::from_vec(data, valid.into_iter().collect()).unwrap()
+}
+
+fn vd_str(data: &[&str], valid: Vec<bool>) -> VectorData<String> {
+    VectorData::from_vec(data.iter().map(|s| s.to_string()).collect(), valid.into_iter().collect()).unwrap()
+}
+
+fn opts(v: &VectorData<bool>) -> Vec<Option<bool>> {
+    v.data.iter().zip(v.validity.iter().by_vals())
+        .map(|(x, ok)| ok.then_some(*x))
+        .collect()
+}
+
+#[test]
+fn test_eq_neq_scalar() {
+    let v = vd_i64(vec![1, 2, 2, 3], vec![true, true, false, true]);
+    assert_eq!(opts(&v.eq(2)), vec![Some(false), Some(true), None, Some(false)]);
+    assert_eq!(opts(&v.neq(2)), vec![Some(true), Some(false), None, Some(true)]);
+
+    let f = vd_f64(vec![0.1 + 0.2, 0.5], vec![true, true]);
+    assert_eq!(f.eq(0.3).data, vec![false, false]);
+    assert_eq!(f.eq_tol(0.3, 1e-12).data, vec![true, false]);
+    assert_eq!(f.neq_tol(0.3, 1e-12).data, vec![false, true]);
+}
+
+#[test]
+fn test_eq_range_na_outside() {
+    let v = vd_i64(vec![1, 2, 1, 2], vec![true; 4]);
+    let out = v.eq_range(2, 1, 3, true);
+    assert_eq!(opts(&out), vec![None, Some(true), Some(false), None]);
+}
+
+#[test]
+fn test_vector_comparisons_combine_validity() {
+    let a = vd_i64(vec![1, 5, 3, 4], vec![true, true, false, true]);
+    let b = vd_i64(vec![2, 5, 1, 0], vec![true, true, true, false]);
+    assert_eq!(opts(&a.lt_vector(&b).unwrap()), vec![Some(true), Some(false), None, None]);
+    assert_eq!(opts(&a.lte_vector(&b).unwrap()), vec![Some(true), Some(true), None, None]);
+    assert_eq!(opts(&a.gt_vector(&b).unwrap()), vec![Some(false), Some(false), None, None]);
+    assert_eq!(opts(&a.gte_vector(&b).unwrap()), vec![Some(false), Some(true), None, None]);
+    assert_eq!(opts(&a.eq_vector(&b).unwrap()), vec![Some(false), Some(true), None, None]);
+    assert_eq!(opts(&a.neq_vector(&b).unwrap()), vec![Some(true), Some(false), None, None]);
+
+    let short = vd_i64(vec![1], vec![true]);
+    assert!(a.lt_vector(&short).is_err());
+}
+
+#[test]
+fn test_f64_vector_tolerance_and_is_close() {
+    let a = vd_f64(vec![1.0, 100.0, f64::INFINITY, f64::NAN], vec![true; 4]);
+    let b = vd_f64(vec![1.0 + 1e-10, 101.0, f64::INFINITY, 1.0], vec![true; 4]);
+    assert_eq!(a.eq_vector_tol(&b, 1e-9).unwrap().data[..2], [true, false]);
+
+    let close = a.is_close(&b, 0.02, 0.0).unwrap();
+    assert_eq!(opts(&close), vec![Some(true), Some(true), Some(true), Some(false)]);
+    let strict = a.is_close(&b, 0.0, 1e-12).unwrap();
+    assert_eq!(opts(&strict), vec![Some(false), Some(false), Some(true), Some(false)]);
+}
+
+#[test]
+fn test_is_in_and_is_in_vector() {
+    let v = vd_i64(vec![1, 2, 3, 4], vec![true, true, false, true]);
+    assert_eq!(opts(&v.is_in(&[2, 3, 4])), vec![Some(false), Some(true), None, Some(true)]);
+
+    let lookup = vd_i64(vec![1, 4], vec![true, false]);
+    assert_eq!(opts(&v.is_in_vector(&lookup)), vec![Some(true), Some(false), None, Some(false)]);
+
+    let f = vd_f64(vec![0.5, 1.5], vec![true, true]);
+    assert_eq!(f.is_in(&[1.5]).data, vec![false, true]);
+
+    let u = VectorData::<u8>::from_vec(vec![7, 200, 0], bitvec![1, 1, 0]).unwrap();
+    assert_eq!(opts(&u.is_in(&[200, 0])), vec![Some(false), Some(true), None]);
+
+    let g = VectorData::<f32>::from_vec(vec![0.25, 2.0], bitvec![1, 1]).unwrap();
+    let lookup = VectorData::<f32>::from_vec(vec![2.0, 0.25], bitvec![1, 0]).unwrap();
+    assert_eq!(g.is_in_vector(&lookup).data, vec![false, true]);
+}
+
+#[test]
+fn test_string_comparisons() {
+    let s = vd_str(&["apple", "banana", "cherry", "x"], vec![true, true, true, false]);
+    assert_eq!(opts(&s.eq("banana")), vec![Some(false), Some(true), Some(false), None]);
+    assert_eq!(opts(&s.neq("banana")), vec![Some(true), Some(false), Some(true), None]);
+    assert_eq!(s.lt("banana").data[..3], [true, false, false]);
+    assert_eq!(s.gte("banana").data[..3], [false, true, true]);
+
+    let t = vd_str(&["apple", "blueberry", "cherry", "x"], vec![true; 4]);
+    assert_eq!(opts(&s.eq_vector(&t).unwrap()), vec![Some(true), Some(false), Some(true), None]);
+    assert_eq!(opts(&s.lt_vector(&t).unwrap()), vec![Some(false), Some(true), Some(false), None]);
+
+    let names = vec!["cherry".to_string(), "apple".to_string()];
+    assert_eq!(opts(&s.is_in(&names)), vec![Some(true), Some(false), Some(true), None]);
+    let lookup = vd_str(&["banana", "apple"], vec![true, false]);
+    assert_eq!(opts(&s.is_in_vector(&lookup)), vec![Some(false), Some(true), Some(false), None]);
+}
@@ -6,4 +6,10 @@ mod extend;
 mod indexing;
 mod reduce;
 mod sort;
-mod ops;
\ No newline at end of file
+mod ops;
+mod nulls;
+mod compare;
+mod string;
+
+mod regex;
+mod numeric_types;
\ No newline at end of file
@@ -0,0 +1,133 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd_i64(v: Vec<i64>, valid: Vec<bool>) -> VectorData<i64> {
+    VectorData::from_vec(v, valid.into_iter().collect()).unwrap()
+}
+fn vd_f64(v: Vec<f64>, valid: Vec<bool>) -> VectorData<f64> {
+    VectorData::from_vec(v, valid.into_iter().collect()).unwrap()
+}
+
+fn opts<T: Clone>(v: &VectorData<T>) -> Vec<Option<T>> {
+    v.data.iter().zip(v.validity.iter().by_vals())
+        .map(|(x, ok)| ok.then(|| x.clone()))
+        .collect()
+}
+
+#[test]
+fn test_fill_null_value() {
+    let v = vd_i64(vec![1, 0, 3, 0], vec![true, false, true, false]);
+    let f = v.fill_null(9);
+    assert_eq!(f.data, vec![1, 9, 3, 9]);
+    assert_eq!(f.null_count(), 0);
+}
+
+#[test]
+fn test_fill_null_spans_multiple_words() {
+    let n = 200;
+    let valid: Vec<bool> = (0..n).map(|i| i % 3 != 0).collect();
+    let v = vd_i64((0..n as i64).collect(), valid);
+    let f = v.fill_null(-1);
+    for i in 0..n {
+        let expected = if i % 3 == 0 { -1 } else { i as i64 };
+        assert_eq!(f.data[i], expected);
+    }
+    assert_eq!(f.null_count(), 0);
+}
+
+#[test]
+fn test_ffill_and_limit() {
+    let v = vd_i64(vec![0, 1, 0, 0, 0, 5, 0], vec![false, true, false, false, false, true, false]);
+    assert_eq!(opts(&v.ffill(None)), vec![None, Some(1), Some(1), Some(1), Some(1), Some(5), Some(5)]);
+    assert_eq!(opts(&v.ffill(Some(2))), vec![None, Some(1), Some(1), Some(1), None, Some(5), Some(5)]);
+}
+
+#[test]
+fn test_bfill_and_limit() {
+    let v = vd_i64(vec![0, 1, 0, 0, 0, 5, 0], vec![false, true, false, false, false, true, false]);
+    assert_eq!(opts(&v.bfill(None)), vec![Some(1), Some(1), Some(5), Some(5), Some(5), Some(5), None]);
+    assert_eq!(opts(&v.bfill(Some(1))), vec![Some(1), Some(1), None, None, Some(5), Some(5), None]);
+}
+
+#[test]
+fn test_fill_null_with_strategies() {
+    let v = vd_f64(vec![1.0, 0.0, 2.0, 10.0, 0.0], vec![true, false, true, true, false]);
+    assert_eq!(v.fill_null_with(FillStrategy::Zero).data, vec![1.0, 0.0, 2.0, 10.0, 0.0]);
+    assert_eq!(v.fill_null_with(FillStrategy::Mean).data, vec![1.0, 13.0 / 3.0, 2.0, 10.0, 13.0 / 3.0]);
+    assert_eq!(v.fill_null_with(FillStrategy::Median).data, vec![1.0, 2.0, 2.0, 10.0, 2.0]);
+    assert_eq!(opts(&v.fill_null_with(FillStrategy::Forward)),
+        vec![Some(1.0), Some(1.0), Some(2.0), Some(10.0), Some(10.0)]);
+    assert_eq!(opts(&v.fill_null_with(FillStrategy::Backward)),
+        vec![Some(1.0), Some(2.0), Some(2.0), Some(10.0), None]);
+}
+
+#[test]
+fn test_fill_null_with_i64_rounds_and_all_null() {
+    let v = vd_i64(vec![1, 0, 2, 0], vec![true, false, true, true]);
+    assert_eq!(v.fill_null_with(FillStrategy::Mean).data, vec![1, 1, 2, 0]);
+    let even = vd_i64(vec![1, 0, 4], vec![true, false, true]);
+    assert_eq!(even.fill_null_with(FillStrategy::Median).data, vec![1, 3, 4]);
+
+    let empty = vd_i64(vec![0, 0], vec![false, false]);
+    assert_eq!(empty.fill_null_with(FillStrategy::Mean).null_count(), 2);
+}
+
+#[test]
+fn test_interpolate_linear_by_index() {
+    let v = vd_f64(vec![0.0, 1.0, 0.0, 0.0, 4.0, 0.0], vec![false, true, false, false, true, false]);
+    let out = v.interpolate(InterpolateMethod::Linear, None).unwrap();
+    assert_eq!(opts(&out), vec![None, Some(1.0), Some(2.0), Some(3.0), Some(4.0), None]);
+}
+
+#[test]
+fn test_interpolate_with_x_and_nearest() {
+    let v = vd_f64(vec![0.0, 0.0, 10.0], vec![true, false, true]);
+    let x = vd_f64(vec![0.0, 1.0, 4.0], vec![true; 3]);
+    let lin = v.interpolate(InterpolateMethod::Linear, Some(&x)).unwrap();
+    assert_eq!(lin.data, vec![0.0, 2.5, 10.0]);
+    let near = v.interpolate(InterpolateMethod::Nearest, Some(&x)).unwrap();
+    assert_eq!(near.data, vec![0.0, 0.0, 10.0]);
+
+    let tie = vd_f64(vec![1.0, 0.0, 3.0], vec![true, false, true]);
+    assert_eq!(tie.interpolate(InterpolateMethod::Nearest, None).unwrap().data, vec![1.0, 1.0, 3.0]);
+}
+
+#[test]
+fn test_interpolate_rejects_bad_x() {
+    let v = vd_f64(vec![0.0, 0.0, 1.0], vec![true, false, true]);
+    let short = vd_f64(vec![0.0, 1.0], vec![true; 2]);
+    assert!(v.interpolate(InterpolateMethod::Linear, Some(&short)).is_err());
+    let with_null = vd_f64(vec![0.0, 1.0, 2.0], vec![true, false, true]);
+    assert!(v.interpolate(InterpolateMethod::Linear, Some(&with_null)).is_err());
+}
+
+#[test]
+fn test_drop_nulls() {
+    let v = vd_i64(vec![1, 2, 3, 4, 5], vec![false, true, false, true, true]);
+    let d = v.drop_nulls();
+    assert_eq!(d.data, vec![2, 4, 5]);
+    assert_eq!(d.null_count(), 0);
+}
+
+#[test]
+fn test_is_null_and_is_not_null() {
+    let v = vd_i64(vec![1, 2, 3], vec![true, false, true]);
+    assert_eq!(v.is_null().data, vec![false, true, false]);
+    assert_eq!(v.is_not_null().data, vec![true, false, true]);
+    assert_eq!(v.is_null().null_count(), 0);
+}
+
+#[test]
+fn test_coalesce() {
+    let a = vd_i64(vec![1, 0, 0, 0], vec![true, false, false, false]);
+    let b = vd_i64(vec![9, 2, 0, 0], vec![true, true, false, false]);
+    let c = vd_i64(vec![9, 9, 3, 0], vec![true, true, true, false]);
+    let out = VectorData::coalesce(&[&a, &b, &c]).unwrap();
+    assert_eq!(opts(&out), vec![Some(1), Some(2), Some(3), None]);
+
+    let short = vd_i64(vec![1], vec![true]);
+    assert!(VectorData::coalesce(&[&a, &short]).is_err());
+    assert!(VectorData::<i64>::coalesce(&[]).is_err());
+}
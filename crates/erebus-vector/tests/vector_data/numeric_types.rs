@@ -0,0 +1,184 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd<T: Clone + Default>(v: Vec<T>, valid: Vec<bool>) -> VectorData<T> {
+    VectorData::from_vec(v, valid.into_iter().collect()).unwrap()
+}
+
+fn opts<T: Clone>(v: &VectorData<T>) -> Vec<Option<T>> {
+    v.data.iter().zip(v.validity.iter().by_vals())
+        .map(|(x, ok)| ok.then(|| x.clone()))
+        .collect()
+}
+
+fn sort_algos_with_radix() -> Vec<Option<&'static str>> {
+    vec![None, Some("introsort"), Some("stable"), Some("heapsort"), Some("radix")]
+}
+
+#[test]
+fn test_numeric_cast_scalar_modes() {
+    assert_eq!(NumericCast::<u8>::cast_checked(300i64), None);
+    assert_eq!(NumericCast::<u8>::cast_saturating(300i64), 255);
+    assert_eq!(NumericCast::<u8>::cast_wrapping(300i64), 44);
+    assert_eq!(NumericCast::<u32>::cast_saturating(-5i32), 0);
+    assert_eq!(NumericCast::<i16>::cast_checked(u64::MAX), None);
+    assert_eq!(NumericCast::<i128>::cast_checked(u64::MAX), Some(u64::MAX as i128));
+
+    assert_eq!(NumericCast::<i32>::cast_checked(2.9f64), Some(2));
+    assert_eq!(NumericCast::<i32>::cast_checked(f64::NAN), None);
+    assert_eq!(NumericCast::<i32>::cast_checked(2_147_483_648.0f64), None);
+    assert_eq!(NumericCast::<i32>::cast_saturating(f64::INFINITY), i32::MAX);
+    assert_eq!(NumericCast::<u8>::cast_saturating(f32::NAN), 0);
+    assert_eq!(NumericCast::<u8>::cast_wrapping(257.5f64), 1);
+
+    assert_eq!(NumericCast::<f32>::cast_checked(1e300f64), None);
+    assert_eq!(NumericCast::<f32>::cast_checked(f64::INFINITY), Some(f32::INFINITY));
+    assert_eq!(NumericCast::<f64>::cast_checked(1.5f32), Some(1.5));
+}
+
+#[test]
+fn test_vector_data_casts_between_types() {
+    let v = vd(vec![-1i64, 7, 300, 0], vec![true, true, true, false]);
+
+    assert_eq!(opts(&v.cast_checked::<u8>()), vec![None, Some(7), None, None]);
+    assert_eq!(opts(&v.cast_saturating::<u8>()), vec![Some(0), Some(7), Some(255), None]);
+    assert_eq!(opts(&v.cast_wrapping::<u8>()), vec![Some(255), Some(7), Some(44), None]);
+    assert_eq!(opts(&v.cast_checked::<f32>()), vec![Some(-1.0), Some(7.0), Some(300.0), None]);
+
+    let f = vd(vec![1.5f32, -2.5, f32::NAN], vec![true, true, true]);
+    assert_eq!(opts(&f.cast_checked::<i16>()), vec![Some(1), Some(-2), None]);
+    assert_eq!(opts(&f.cast_checked::<u32>()), vec![Some(1), None, None]);
+
+    let u = vd(vec![u64::MAX, 1], vec![true, true]);
+    assert_eq!(opts(&u.cast_checked::<i64>()), vec![None, Some(1)]);
+    assert_eq!(opts(&u.cast_checked::<i128>()), vec![Some(u64::MAX as i128), Some(1)]);
+
+    assert_eq!(opts(&vd(vec![0u8, 3], vec![true, true]).to_bool()), vec![Some(false), Some(true)]);
+    assert_eq!(opts(&vd(vec![-3i16], vec![true]).to_float()), vec![Some(-3.0)]);
+    assert_eq!(opts(&vd(vec![2.75f32], vec![true]).to_int()), vec![Some(2)]);
+    assert_eq!(opts(&vd(vec![42u32], vec![true]).to_text()), vec![Some("42".to_string())]);
+}
+
+#[test]
+fn test_ops_on_new_int_types() {
+    let v = vd(vec![-4i32, 9, 2], vec![true, true, false]);
+    assert_eq!(opts(&v.abs()), vec![Some(4), Some(9), None]);
+    assert_eq!(opts(&v.sqrt()), vec![None, Some(3.0), None]);
+    assert_eq!(opts(&v.clip(0, 5)), vec![Some(0), Some(5), None]);
+    assert_eq!(opts(&v.gt(0)), vec![Some(false), Some(true), None]);
+    assert_eq!(opts(&(&v * 0.5)), vec![Some(-2.0), Some(4.5), None]);
+    assert_eq!(opts(&-&v), vec![Some(4), Some(-9), None]);
+
+    let u = vd(vec![0u8, 1, 255], vec![true, true, true]);
+    assert_eq!(opts(&u.ln()), vec![None, Some(0.0), Some(255f64.ln())]);
+    assert_eq!(opts(&u.ln_1p()), vec![Some(0.0), Some(2f64.ln()), Some(256f64.ln())]);
+    assert_eq!(opts(&u.powi_pos(1)), vec![Some(0), Some(1), Some(255)]);
+    assert_eq!(opts(&u.cut(&[0.5, 100.0], true, false).unwrap()), vec![Some(0), Some(1), Some(2)]);
+
+    let big = vd(vec![i128::MAX, -1], vec![true, true]);
+    assert_eq!(opts(&big.signum()), vec![Some(1), Some(-1)]);
+    assert_eq!(opts(&big.lt(0)), vec![Some(false), Some(true)]);
+}
+
+#[test]
+fn test_ops_on_f32() {
+    let v = vd(vec![-1.5f32, 4.0, 0.0], vec![true, true, true]);
+    assert_eq!(opts(&v.abs()), vec![Some(1.5), Some(4.0), Some(0.0)]);
+    assert_eq!(opts(&v.sqrt()), vec![None, Some(2.0), Some(0.0)]);
+    assert_eq!(opts(&v.relu()), vec![Some(0.0), Some(4.0), Some(0.0)]);
+    assert_eq!(opts(&(&v + 1.0f32)), vec![Some(-0.5), Some(5.0), Some(1.0)]);
+
+    // exp10 overflows f32 well before f64: Inf still becomes NA
+    let e = vd(vec![2.0f32, 50.0], vec![true, true]).exp10();
+    assert_eq!(opts(&e), vec![Some(100.0), None]);
+
+    let erf = vd(vec![0.0f32, 10.0], vec![true, true]).erf();
+    assert_eq!(opts(&erf), vec![Some(0.0), Some(1.0)]);
+
+    let sm = vd(vec![1.0f32, 1.0, 0.0], vec![true, true, false]).softmax();
+    assert_eq!(opts(&sm), vec![Some(0.5), Some(0.5), None]);
+}
+
+#[test]
+fn test_reductions_on_new_types() {
+    let u = vd(vec![200u8, 50, 3], vec![true, true, false]);
+    assert_eq!(u.sum(), 250);
+    assert_eq!(u.min(), Some(50));
+    assert_eq!(u.max(), Some(200));
+    assert_eq!(u.mean(), 125.0);
+    assert_eq!(u.n_unique(), 2);
+
+    let f = vd(vec![1.5f32, 2.5, 1.5], vec![true, true, true]);
+    assert_eq!(f.sum(), 5.5);
+    assert!((f.mean() - 5.5 / 3.0).abs() < 1e-12);
+    assert_eq!(f.unique().data, vec![1.5, 2.5]);
+
+    let big = vd(vec![i128::MAX - 1, 1], vec![true, true]);
+    assert_eq!(big.sum(), i128::MAX);
+    assert_eq!(big.argmin(), Some(1));
+
+    let empty = vd(Vec::<u32>::new(), vec![]);
+    assert!(empty.mean().is_nan());
+}
+
+#[test]
+fn test_sum_and_mean_widen_narrow_types() {
+    let u = vd(vec![200u8, 100, 255], vec![true, true, true]);
+    let s: u64 = u.sum();
+    assert_eq!(s, 555);
+    assert_eq!(u.mean(), 185.0);
+
+    let i = vd(vec![i16::MAX, i16::MAX, i16::MIN], vec![true, true, false]);
+    let s: i64 = i.sum();
+    assert_eq!(s, 2 * i16::MAX as i64);
+    assert_eq!(i.mean(), i16::MAX as f64);
+
+    let j = vd(vec![i32::MIN, i32::MIN, i32::MIN], vec![true; 3]);
+    assert_eq!(j.sum(), 3 * i32::MIN as i64);
+    assert_eq!(j.mean(), i32::MIN as f64);
+
+    let big = vd(vec![i64::MAX, i64::MAX], vec![true; 2]);
+    assert_eq!(big.mean(), i64::MAX as f64);
+}
+
+#[test]
+fn test_fill_null_with_on_new_types() {
+    let u = vd(vec![250u8, 0, 255], vec![true, false, true]);
+    assert_eq!(u.fill_null_with(FillStrategy::Mean).data, vec![250, 253, 255]);
+    assert_eq!(u.fill_null_with(FillStrategy::Zero).data, vec![250, 0, 255]);
+
+    let i = vd(vec![-3i16, 0, 4, 0], vec![true, false, true, false]);
+    assert_eq!(i.fill_null_with(FillStrategy::Median).data, vec![-3, 1, 4, 1]);
+    assert_eq!(opts(&i.fill_null_with(FillStrategy::Forward)),
+        vec![Some(-3), Some(-3), Some(4), Some(4)]);
+
+    let f = vd(vec![1.5f32, 0.0, 2.5], vec![true, false, true]);
+    assert_eq!(f.fill_null_with(FillStrategy::Mean).data, vec![1.5, 2.0, 2.5]);
+
+    let empty = vd(vec![0u64; 2], vec![false; 2]);
+    assert_eq!(opts(&empty.fill_null_with(FillStrategy::Mean)), vec![None, None]);
+}
+
+#[test]
+fn test_sort_new_types_all_algos() {
+    for algo in sort_algos_with_radix() {
+        let v = vd(vec![5i16, -300, 0, i16::MIN, 7], vec![true, true, false, true, true]);
+        let s = v.sort(true, true, algo).unwrap();
+        assert_eq!(opts(&s), vec![Some(i16::MIN), Some(-300), Some(5), Some(7), None]);
+
+        let v = vd(vec![u32::MAX, 0, 70_000, 3], vec![true; 4]);
+        assert_eq!(v.sort(true, true, algo).unwrap().data, vec![0, 3, 70_000, u32::MAX]);
+        assert_eq!(v.sort(false, true, algo).unwrap().data, vec![u32::MAX, 70_000, 3, 0]);
+
+        let v = vd(vec![1i128 << 100, -(1i128 << 90), 0], vec![true; 3]);
+        assert_eq!(v.sort(true, true, algo).unwrap().data, vec![-(1i128 << 90), 0, 1i128 << 100]);
+
+        let v = vd(vec![2.5f32, -0.5, f32::NEG_INFINITY, 1e30], vec![true; 4]);
+        assert_eq!(v.sort(true, true, algo).unwrap().data, vec![f32::NEG_INFINITY, -0.5, 2.5, 1e30]);
+
+        let v = vd(vec![9u8, 255, 0], vec![true; 3]);
+        assert_eq!(v.sort(true, false, algo).unwrap().data, vec![0, 9, 255]);
+    }
+}
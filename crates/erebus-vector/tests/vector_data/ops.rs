@@ -1621,6 +1621,23 @@ fn test_i64_powi_pos_range_slice_only() {
     assert_eq!(out.data, vec![4, 9]);
 }
 
+#[test]
+fn test_narrow_int_powi_pos_overflow() {
+    let v = VectorData::<u8>::from_vec(vec![2, 16, 3], bitvec![1, 1, 1]).unwrap();
+    let out = v.powi_pos(2);
+
+    assert_eq!(out.data[0], 4);
+    assert_eq!(out.data[2], 9);
+    assert_eq!(out.validity.to_vec(), bitvec![1, 0, 1]);
+
+    let mut v = VectorData::<i16>::from_vec(vec![300, -10, 181], bitvec![1, 1, 1]).unwrap();
+    v.powi_pos_inplace(2);
+
+    assert_eq!(v.data[1], 100);
+    assert_eq!(v.data[2], 32761);
+    assert_eq!(v.validity.to_vec(), bitvec![0, 1, 1]);
+}
+
 #[test]
 fn test_f64_logs_owned() {
     let input = vec![1.0, 2.0, 10.0, -1.0, 0.0];
@@ -2895,4 +2912,247 @@ fn test_i64_binarize_eq_range_full_with_nulls() {
 
     assert_eq!(out.data, vec![0, 0, 1, 1]);
     assert_eq!(out.validity.to_vec(), bitvec![0, 0, 1, 1]);
-}
\ No newline at end of file
+}
+
+// -- Kleene logic --
+
+// Every (a, b) pair over {true, false, null}.
+fn kleene_pairs() -> (VectorData<bool>, VectorData<bool>) {
+    let a = vd_bool(
+        vec![true, true, true, false, false, false, false, false, false],
+        vec![true, true, true, true, true, true, false, false, false],
+    );
+    let b = vd_bool(
+        vec![true, false, false, true, false, false, true, false, false],
+        vec![true, true, false, true, true, false, true, true, false],
+    );
+    (a, b)
+}
+
+fn kleene_opts(v: &VectorData<bool>) -> Vec<Option<bool>> {
+    v.data.iter().zip(v.validity.iter().by_vals())
+        .map(|(x, ok)| ok.then_some(*x))
+        .collect()
+}
+
+const T: Option<bool> = Some(true);
+const F: Option<bool> = Some(false);
+const N: Option<bool> = None;
+
+#[test]
+fn test_bool_kleene_and() {
+    let (a, b) = kleene_pairs();
+    assert_eq!(kleene_opts(&a.and(&b).unwrap()), vec![T, F, N, F, F, F, N, F, N]);
+    assert_eq!(kleene_opts(&(&a & &b)), vec![T, F, N, F, F, F, N, F, N]);
+}
+
+#[test]
+fn test_bool_kleene_or() {
+    let (a, b) = kleene_pairs();
+    assert_eq!(kleene_opts(&a.or(&b).unwrap()), vec![T, T, T, T, F, N, T, N, N]);
+    assert_eq!(kleene_opts(&(a | b)), vec![T, T, T, T, F, N, T, N, N]);
+}
+
+#[test]
+fn test_bool_kleene_xor_and_not() {
+    let (a, b) = kleene_pairs();
+    assert_eq!(kleene_opts(&a.xor(&b).unwrap()), vec![F, T, N, T, F, N, N, N, N]);
+    assert_eq!(kleene_opts(&(&a ^ &b)), vec![F, T, N, T, F, N, N, N, N]);
+    assert_eq!(kleene_opts(&a.and_not(&b).unwrap()), vec![F, T, N, F, F, F, F, N, N]);
+    assert_eq!(kleene_opts(&!&a), vec![F, F, F, T, T, T, N, N, N]);
+}
+
+#[test]
+fn test_bool_kleene_length_mismatch() {
+    let a = vd_bool(vec![true, false], vec![true, true]);
+    let b = vd_bool(vec![true], vec![true]);
+    assert!(a.and(&b).is_err());
+    assert!(a.or(&b).is_err());
+}
+
+#[test]
+fn test_bool_kleene_spans_multiple_words() {
+    let n = 150;
+    let a = vd_bool((0..n).map(|i| i % 2 == 0).collect(), (0..n).map(|i| i % 5 != 0).collect());
+    let b = vd_bool(vec![false; n], (0..n).map(|i| i % 7 != 0).collect());
+    let out = a.and(&b).unwrap();
+    for i in 0..n {
+        // b is always false, so the result is false wherever b is valid or a is a valid false.
+        let known = i % 7 != 0 || (i % 5 != 0 && i % 2 == 1);
+        assert_eq!(out.validity[i], known, "row {i}");
+        assert!(!out.data[i]);
+    }
+}
+
+#[test]
+fn test_bool_any_all_kleene() {
+    let with_true = vd_bool(vec![false, true, false], vec![true, true, false]);
+    assert_eq!(with_true.any_kleene(), Some(true));
+    assert_eq!(with_true.all_kleene(), Some(false));
+
+    let false_and_null = vd_bool(vec![false, true], vec![true, false]);
+    assert_eq!(false_and_null.any_kleene(), None);
+    assert_eq!(false_and_null.all_kleene(), Some(false));
+
+    let true_and_null = vd_bool(vec![true, false], vec![true, false]);
+    assert_eq!(true_and_null.any_kleene(), Some(true));
+    assert_eq!(true_and_null.all_kleene(), None);
+
+    let all_valid = vd_bool(vec![true; 70], vec![true; 70]);
+    assert_eq!(all_valid.all_kleene(), Some(true));
+    assert_eq!(vd_bool(vec![false; 70], vec![true; 70]).any_kleene(), Some(false));
+    assert_eq!(vd_bool(vec![], vec![]).all_kleene(), Some(true));
+}
+
+// -- Activations and special functions --
+
+fn close(a: f64, b: f64, rel: f64) -> bool {
+    (a - b).abs() <= rel * b.abs().max(1e-300)
+}
+
+#[test]
+fn test_logit_expit_round_trip() {
+    let v = vd_f64(vec![0.25, 0.5, 0.0, 1.0, 1.5], vec![true; 5]);
+    let l = v.logit();
+    assert_f64_eq(l.data[0], (1.0_f64 / 3.0).ln());
+    assert_f64_eq(l.data[1], 0.0);
+    // 0 and 1 give +/-Inf, 1.5 gives NaN: all become NA
+    assert_eq!(l.validity.to_vec(), bitvec![1, 1, 0, 0, 0]);
+
+    let back = l.expit();
+    assert!(close(back.data[0], 0.25, 1e-15));
+    assert_f64_eq(back.data[1], 0.5);
+}
+
+#[test]
+fn test_expit_and_softplus_are_stable() {
+    let v = vd_f64(vec![-800.0, 0.0, 800.0], vec![true; 3]);
+    let e = v.expit();
+    assert_eq!(e.data, vec![0.0, 0.5, 1.0]);
+    assert_eq!(e.validity.count_ones(), 3);
+
+    let s = v.softplus();
+    assert_eq!(s.validity.count_ones(), 3);
+    assert_f64_eq(s.data[0], 0.0);
+    assert_f64_eq(s.data[1], 2.0_f64.ln());
+    assert_f64_eq(s.data[2], 800.0);
+}
+
+#[test]
+fn test_exp10_inf_becomes_na() {
+    let mut v = vd_f64(vec![2.0, -1.0, 400.0], vec![true, true, true]);
+    let out = v.exp10();
+    assert!(close(out.data[0], 100.0, 1e-15));
+    assert!(close(out.data[1], 0.1, 1e-15));
+    assert_eq!(out.validity.to_vec(), bitvec![1, 1, 0]);
+
+    v.exp10_inplace();
+    assert_eq!(v.validity.to_vec(), bitvec![1, 1, 0]);
+}
+
+#[test]
+fn test_relu_family() {
+    let v = vd_f64(vec![-2.0, 3.0, 8.0, 0.0], vec![true, true, true, false]);
+    assert_eq!(v.relu().data[..3], [0.0, 3.0, 8.0]);
+    assert_eq!(v.relu6().data[..3], [0.0, 3.0, 6.0]);
+    assert_eq!(v.relu_n(2.5).data[..3], [0.0, 2.5, 2.5]);
+    assert_eq!(v.leaky_relu(0.1).data[..3], [-0.2, 3.0, 8.0]);
+    assert_eq!(v.relu().validity.to_vec(), bitvec![1, 1, 1, 0]);
+
+    let r = v.relu_range(0, 2, false);
+    assert_eq!(r.data, vec![0.0, 3.0]);
+}
+
+#[test]
+fn test_gelu_values() {
+    let v = vd_f64(vec![0.0, 1.0, -1.0], vec![true; 3]);
+    let g = v.gelu();
+    assert_f64_eq(g.data[0], 0.0);
+    assert!(close(g.data[1], 0.841_344_746_068_542_9, 1e-14));
+    assert!(close(g.data[2], -0.158_655_253_931_457_05, 1e-13));
+
+    // The lower tail survives instead of cancelling to zero
+    let v = vd_f64(vec![-6.0, -10.0], vec![true; 2]);
+    let g = v.gelu();
+    assert!(close(g.data[0], -5.919_525_870_226_207e-9, 1e-12));
+    assert!(close(g.data[1], -7.619_853_024_160_593e-23, 1e-12));
+}
+
+#[test]
+fn test_erf_erfc_values() {
+    let v = vd_f64(vec![0.5, 2.5, -1.0, 3.0], vec![true; 4]);
+    let e = v.erf();
+    assert!(close(e.data[0], 0.520_499_877_813_046_5, 1e-14));
+    assert!(close(e.data[1], 0.999_593_047_982_555, 1e-14));
+    assert!(close(e.data[2], -0.842_700_792_949_714_9, 1e-14));
+
+    let c = v.erfc();
+    assert!(close(c.data[3], 2.209_049_699_858_544e-5, 1e-12));
+    assert!(close(c.data[2], 1.842_700_792_949_715, 1e-14));
+}
+
+#[test]
+fn test_gamma_lgamma_values() {
+    let v = vd_f64(vec![5.0, 0.5, -1.5, 0.0, 200.0], vec![true; 5]);
+    let g = v.gamma();
+    assert!(close(g.data[0], 24.0, 1e-13));
+    assert!(close(g.data[1], std::f64::consts::PI.sqrt(), 1e-13));
+    assert!(close(g.data[2], 2.363_271_801_207_354_5, 1e-13));
+    // pole at 0 and overflow at 200 are NA
+    assert_eq!(g.validity.to_vec(), bitvec![1, 1, 1, 0, 0]);
+
+    let lg = v.lgamma();
+    assert!(close(lg.data[0], 24.0_f64.ln(), 1e-13));
+    assert!(close(lg.data[4], 857.933_669_825_857_5, 1e-13));
+    assert_eq!(lg.validity.to_vec(), bitvec![1, 1, 1, 0, 1]);
+}
+
+#[test]
+fn test_gamma_large_finite_arguments() {
+    // Γ stays finite up to ~171.6; t^(x + 0.5) alone overflows past ~143.
+    let v = vd_f64(vec![150.0, 160.0, 171.5], vec![true; 3]);
+    let g = v.gamma();
+    assert!(close(g.data[1], 2.946_702_272_495_038e282, 1e-12));
+    assert!(close(g.data[2], 9.483_367_566_824_801e307, 1e-11));
+    assert_eq!(g.validity.to_vec(), bitvec![1, 1, 1]);
+}
+
+#[test]
+fn test_softmax_and_log_softmax() {
+    let v = vd_f64(vec![1.0, 2.0, 0.0, 3.0], vec![true, true, false, true]);
+    let s = v.softmax();
+    let z: f64 = 1.0_f64.exp() + 2.0_f64.exp() + 3.0_f64.exp();
+    assert!(close(s.data[0], 1.0_f64.exp() / z, 1e-15));
+    assert!(close(s.data[3], 3.0_f64.exp() / z, 1e-15));
+    assert_eq!(s.validity.to_vec(), bitvec![1, 1, 0, 1]);
+    assert!(close(s.data[0] + s.data[1] + s.data[3], 1.0, 1e-15));
+
+    let ls = v.log_softmax();
+    assert!(close(ls.data[1], 2.0 - z.ln(), 1e-15));
+
+    // Large inputs do not overflow
+    let big = vd_f64(vec![1000.0, 1000.0], vec![true; 2]);
+    assert_eq!(big.softmax().data, vec![0.5, 0.5]);
+}
+
+#[test]
+fn test_softmax_drops_infinite_inputs() {
+    let v = vd_f64(vec![1.0, 2.0, f64::INFINITY, 3.0, f64::NEG_INFINITY], vec![true; 5]);
+    let finite = vd_f64(vec![1.0, 2.0, 3.0], vec![true; 3]);
+    let (s, want) = (v.softmax(), finite.softmax());
+    assert_eq!(s.validity.to_vec(), bitvec![1, 1, 0, 1, 0]);
+    assert_eq!([s.data[0], s.data[1], s.data[3]], want.data[..]);
+
+    let (ls, want) = (v.log_softmax(), finite.log_softmax());
+    assert_eq!(ls.validity.to_vec(), bitvec![1, 1, 0, 1, 0]);
+    assert_eq!([ls.data[0], ls.data[1], ls.data[3]], want.data[..]);
+}
+
+#[test]
+fn test_softmax_range() {
+    let v = vd_f64(vec![5.0, 0.0, 0.0, 5.0], vec![true; 4]);
+    let mid = v.softmax_range(1, 3, false);
+    assert_eq!(mid.data, vec![0.5, 0.5]);
+    let full = v.softmax_range(1, 3, true);
+    assert_eq!(full.data, vec![5.0, 0.5, 0.5, 5.0]);
+}
@@ -592,4 +592,25 @@ fn test_vd_min_with_index_sparse_valid() {
     let (val, idx) = vd.min_with_index();
     assert_eq!(val.unwrap(), 5);
     assert_eq!(idx.unwrap(), 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_log_sum_exp() {
+    let v = vd_f64(vec![1.0, 2.0, 3.0, 100.0], vec![true, true, true, false]);
+    let expected = (1.0_f64.exp() + 2.0_f64.exp() + 3.0_f64.exp()).ln();
+    assert!((v.log_sum_exp() - expected).abs() < 1e-12);
+
+    // Stable for large values
+    let big = vd_f64(vec![1000.0, 1000.0], vec![true; 2]);
+    assert!((big.log_sum_exp() - (1000.0 + 2.0_f64.ln())).abs() < 1e-12);
+
+    let i = vd_i64(vec![0, 0], vec![true, true]);
+    assert!((i.log_sum_exp() - 2.0_f64.ln()).abs() < 1e-12);
+
+    let empty = vd_f64(vec![1.0], vec![false]);
+    assert_eq!(empty.log_sum_exp(), f64::NEG_INFINITY);
+
+    // ±Inf and NaN are NA
+    let inf = vd_f64(vec![1.0, f64::INFINITY, f64::NEG_INFINITY, f64::NAN], vec![true; 4]);
+    assert_eq!(inf.log_sum_exp(), 1.0);
+}
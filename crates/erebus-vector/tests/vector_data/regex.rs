@@ -0,0 +1,152 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd_str(data: &[&str], valid: Vec<bool>) -> VectorData<String> {
+    VectorData::from_vec(data.iter().map(|s| s.to_string()).collect(), valid.into_iter().collect()).unwrap()
+}
+
+fn opts<T: Clone>(v: &VectorData<T>) -> Vec<Option<T>> {
+    v.data.iter().zip(v.validity.iter().by_vals())
+        .map(|(x, ok)| ok.then(|| x.clone()))
+        .collect()
+}
+
+fn s(x: &str) -> Option<String> {
+    Some(x.to_string())
+}
+
+fn strs(xs: &[&str]) -> Vec<String> {
+    xs.iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn test_regex_engine_basics() {
+    let re = Regex::new(r"(\w+)@(\w+)\.com").unwrap();
+    assert_eq!(re.group_count(), 2);
+    let caps = re.captures("mail: bob@example.com!").unwrap();
+    assert_eq!(caps.get(0), Some("bob@example.com"));
+    assert_eq!(caps.get(1), Some("bob"));
+    assert_eq!(caps.get(2), Some("example"));
+
+    assert!(Regex::new("^a{2,3}$").unwrap().is_match("aaa"));
+    assert!(!Regex::new("^a{2,3}$").unwrap().is_match("aaaa"));
+    assert!(Regex::new("(?i)^hello$").unwrap().is_match("HeLLo"));
+    assert!(Regex::new(r"\bcat\b").unwrap().is_match("a cat sat"));
+    assert!(!Regex::new(r"\bcat\b").unwrap().is_match("concatenate"));
+    assert!(Regex::new("[^0-9-]").unwrap().is_match("12-a"));
+}
+
+#[test]
+fn test_regex_leftmost_first_and_lazy() {
+    assert_eq!(Regex::new("a|ab").unwrap().find("xab"), Some((1, 2)));
+    assert_eq!(Regex::new("<.+>").unwrap().find("<a><b>"), Some((0, 6)));
+    assert_eq!(Regex::new("<.+?>").unwrap().find("<a><b>"), Some((0, 3)));
+    assert_eq!(Regex::new("x*").unwrap().find_iter("axxb"), vec![(0, 0), (1, 3), (3, 3), (4, 4)]);
+}
+
+#[test]
+fn test_regex_no_catastrophic_backtracking() {
+    let text = "a".repeat(30);
+    assert!(!Regex::new("^(a+)+b$").unwrap().is_match(&text));
+}
+
+#[test]
+fn test_regex_invalid_patterns() {
+    for p in ["(ab", "ab)", "[a-", "*a", "a{3,1}", r"\q", "a{5000}"] {
+        assert!(
+            matches!(Regex::new(p), Err(ErebusError::InvalidPattern(_))),
+            "pattern {p:?} should be rejected"
+        );
+    }
+}
+
+#[test]
+fn test_regex_nesting_limit() {
+    let deep = format!("{}a{}", "(".repeat(600), ")".repeat(600));
+    assert!(matches!(Regex::new(&deep), Err(ErebusError::InvalidPattern(_))));
+    let stacked = format!("a{}", "*".repeat(300));
+    assert!(matches!(Regex::new(&stacked), Err(ErebusError::InvalidPattern(_))));
+    let unclosed = "(".repeat(100_000);
+    assert!(matches!(Regex::new(&unclosed), Err(ErebusError::InvalidPattern(_))));
+
+    let ok = format!("{}a{}+", "(".repeat(200), ")".repeat(200));
+    assert!(Regex::new(&ok).unwrap().is_match("aaa"));
+}
+
+#[test]
+fn test_matches_keeps_nulls() {
+    let v = vd_str(&["order-123", "no digits", "x9"], vec![true, true, false]);
+    assert_eq!(opts(&v.matches(r"\d+").unwrap()), vec![Some(true), Some(false), None]);
+    assert!(v.matches("(").is_err());
+}
+
+#[test]
+fn test_extract() {
+    let v = vd_str(&["id=42;", "id=;", "none", "id=7"], vec![true, true, true, false]);
+    let out = v.extract(r"id=(\d+)", 1).unwrap();
+    assert_eq!(opts(&out), vec![s("42"), None, None, None]);
+
+    let opt = v.extract(r"id=(\d+)?", 1).unwrap();
+    assert_eq!(opts(&opt), vec![s("42"), None, None, None]);
+
+    assert!(matches!(v.extract(r"id=(\d+)", 2), Err(ErebusError::InvalidPattern(_))));
+}
+
+#[test]
+fn test_extract_all() {
+    let v = vd_str(&["a1b22c333", "none"], vec![true, true]);
+    let out = v.extract_all(r"\d+", 0).unwrap();
+    assert_eq!(out.data, vec![strs(&["1", "22", "333"]), vec![]]);
+
+    let kv = vd_str(&["k=v; x=y"], vec![true]);
+    assert_eq!(kv.extract_all(r"(\w)=(\w)", 2).unwrap().data, vec![strs(&["v", "y"])]);
+}
+
+#[test]
+fn test_replace_regex() {
+    let v = vd_str(&["2024-01-31", "bad", "1999-12-01"], vec![true, true, false]);
+    let out = v.replace_regex(r"(\d{4})-(\d{2})-(\d{2})", "$3/$2/${1}").unwrap();
+    assert_eq!(opts(&out), vec![s("31/01/2024"), s("bad"), None]);
+
+    let money = vd_str(&["cost 5"], vec![true]);
+    assert_eq!(money.replace_regex(r"\d", "$$$0").unwrap().data, vec!["cost $5"]);
+    assert_eq!(money.replace_regex(r"\s+", "").unwrap().data, vec!["cost5"]);
+}
+
+#[test]
+fn test_split_regex() {
+    let v = vd_str(&["a, b;c", "single", ""], vec![true, true, true]);
+    let out = v.split_regex(r"[,;]\s*").unwrap();
+    assert_eq!(out.data, vec![strs(&["a", "b", "c"]), strs(&["single"]), strs(&[""])]);
+}
+
+#[test]
+fn test_glob() {
+    let v = vd_str(&["data.csv", "data.json", "notes.txt", "d.csv"], vec![true, true, true, false]);
+    assert_eq!(opts(&v.glob("*.csv").unwrap()), vec![Some(true), Some(false), Some(false), None]);
+    assert_eq!(opts(&v.glob("data.[!c]*").unwrap()), vec![Some(false), Some(true), Some(false), None]);
+    assert_eq!(v.glob("????.txt").unwrap().data[..3], [false, false, false]);
+    assert_eq!(v.glob("?????.txt").unwrap().data[2], true);
+    assert!(v.glob("[abc").is_err());
+}
+
+#[test]
+fn test_like_and_ilike() {
+    let v = vd_str(&["Apple pie", "apple", "50% off", "pineapple"], vec![true, true, true, true]);
+    assert_eq!(v.like("apple%").unwrap().data, vec![false, true, false, false]);
+    assert_eq!(v.ilike("apple%").unwrap().data, vec![true, true, false, false]);
+    assert_eq!(v.like("%apple").unwrap().data, vec![false, true, false, true]);
+    assert_eq!(v.like("__\\% off").unwrap().data, vec![false, false, true, false]);
+    assert_eq!(v.like("a.ple").unwrap().data, vec![false, false, false, false]);
+}
+
+#[test]
+fn test_regex_parallel_large_input() {
+    let data: Vec<String> = (0..50_000).map(|i| format!("row-{i}")).collect();
+    let v = VectorData::from_vec(data, std::iter::repeat(true).take(50_000).collect()).unwrap();
+    let out = v.extract(r"row-(\d*7)$", 1).unwrap();
+    assert_eq!(out.validity.count_ones(), 5_000);
+    assert_eq!(out.data[17], "17");
+}
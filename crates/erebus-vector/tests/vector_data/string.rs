@@ -0,0 +1,103 @@
+// === Imports ===
+use erebus_vector::prelude::*;
+
+// === Tests ===
+
+fn vd_str(data: &[&str], valid: Vec<bool>) -> VectorData<String> {
+    VectorData::from_vec(data.iter().map(|s| s.to_string()).collect(), valid.into_iter().collect()).unwrap()
+}
+
+fn opts<T: Clone>(v: &VectorData<T>) -> Vec<Option<T>> {
+    v.data.iter().zip(v.validity.iter().by_vals())
+        .map(|(x, ok)| ok.then(|| x.clone()))
+        .collect()
+}
+
+fn s(x: &str) -> Option<String> {
+    Some(x.to_string())
+}
+
+#[test]
+fn test_case_ops() {
+    let v = vd_str(&["hello wORLD", "ÉCOLE", ""], vec![true, true, false]);
+    assert_eq!(opts(&v.to_upper()), vec![s("HELLO WORLD"), s("ÉCOLE"), None]);
+    assert_eq!(opts(&v.to_lower()), vec![s("hello world"), s("école"), None]);
+    assert_eq!(opts(&v.capitalize()), vec![s("Hello world"), s("École"), None]);
+    assert_eq!(opts(&v.title()), vec![s("Hello World"), s("École"), None]);
+    assert_eq!(v.reverse().data[0], "DLROw olleh");
+}
+
+#[test]
+fn test_lengths() {
+    let v = vd_str(&["abc", "né", ""], vec![true, true, true]);
+    assert_eq!(v.len_chars().data, vec![3, 2, 0]);
+    assert_eq!(v.len_bytes().data, vec![3, 3, 0]);
+}
+
+#[test]
+fn test_trim_family() {
+    let v = vd_str(&["  pad  ", "\tx\n"], vec![true, true]);
+    assert_eq!(v.trim().data, vec!["pad", "x"]);
+    assert_eq!(v.lstrip().data, vec!["pad  ", "x\n"]);
+    assert_eq!(v.rstrip().data, vec!["  pad", "\tx"]);
+}
+
+#[test]
+fn test_predicates_keep_nulls() {
+    let v = vd_str(&["prefix_body", "body_suffix", "prefix"], vec![true, true, false]);
+    assert_eq!(opts(&v.starts_with("prefix")), vec![Some(true), Some(false), None]);
+    assert_eq!(opts(&v.ends_with("suffix")), vec![Some(false), Some(true), None]);
+    assert_eq!(opts(&v.contains("body")), vec![Some(true), Some(true), None]);
+}
+
+#[test]
+fn test_replace_substring_repeat() {
+    let v = vd_str(&["a-b-c", "héllo"], vec![true, true]);
+    assert_eq!(v.replace("-", "+").data, vec!["a+b+c", "héllo"]);
+    assert_eq!(v.substring(1, 3).data, vec!["-b-", "éll"]);
+    assert_eq!(v.substring(10, 2).data, vec!["", ""]);
+    assert_eq!(v.repeat(2).data, vec!["a-b-ca-b-c", "héllohéllo"]);
+}
+
+#[test]
+fn test_padding() {
+    let v = vd_str(&["7", "1234"], vec![true, true]);
+    assert_eq!(v.pad_left(3, '0').data, vec!["007", "1234"]);
+    assert_eq!(v.pad_right(3, '.').data, vec!["7..", "1234"]);
+}
+
+#[test]
+fn test_split_nth_piece() {
+    let v = vd_str(&["a,b,c", "single", "x"], vec![true, true, false]);
+    assert_eq!(opts(&v.split(",", 1)), vec![s("b"), None, None]);
+    assert_eq!(opts(&v.split(",", 0)), vec![s("a"), s("single"), None]);
+}
+
+#[test]
+fn test_concat_str_combines_validity() {
+    let a = vd_str(&["first", "x", "y"], vec![true, false, true]);
+    let b = vd_str(&["last", "z", "w"], vec![true, true, false]);
+    let out = a.concat_str(&b, " ").unwrap();
+    assert_eq!(opts(&out), vec![s("first last"), None, None]);
+    assert!(a.concat_str(&vd_str(&["x"], vec![true]), "").is_err());
+}
+
+#[test]
+fn test_concat_str_range() {
+    let a = vd_str(&["a", "b", "c", "d"], vec![true, true, false, true]);
+    let b = vd_str(&["1", "2", "3", "4"], vec![true; 4]);
+    assert_eq!(opts(&a.concat_str_range(&b, "-", 1, 3, false).unwrap()), vec![s("b-2"), None]);
+    assert_eq!(opts(&a.concat_str_range(&b, "-", 1, 3, true).unwrap()),
+        vec![s("a"), s("b-2"), None, s("d")]);
+    assert_eq!(opts(&a.concat_str_range(&b, "", 2, 10, true).unwrap()), vec![s("a"), s("b"), None, s("d4")]);
+    assert!(a.concat_str_range(&b, "", 3, 1, true).unwrap().data.is_empty());
+    assert!(a.concat_str_range(&b.slice(0, 2), "", 0, 1, false).is_err());
+}
+
+#[test]
+fn test_string_range_variants() {
+    let v = vd_str(&["a", "b", "c"], vec![true; 3]);
+    assert_eq!(v.to_upper_range(1, 3, false).data, vec!["B", "C"]);
+    assert_eq!(v.to_upper_range(1, 3, true).data, vec!["a", "B", "C"]);
+    assert_eq!(v.pad_left_range(2, '_', 0, 1, false).data, vec!["_a"]);
+}